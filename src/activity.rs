@@ -0,0 +1,47 @@
+use crate::logger::Phase;
+use crate::messages;
+use crate::platform;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// Repositorio y etapa que procesa el ciclo en curso. Se guarda en `activity.toml` al entrar
+/// en cada etapa y se elimina al terminar el ciclo, para que la TUI muestre qué se está
+/// sincronizando: `state.toml` solo se escribe al final del ciclo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Activity {
+    /// Proceso que sincroniza; si ya no existe, el archivo quedó de un ciclo interrumpido
+    pub pid: u32,
+    pub repo_path: String,
+    pub phase: Phase,
+    /// Inicio de la etapa
+    pub since_ts: i64,
+}
+
+impl Activity {
+    pub fn new(repo_path: &str, phase: Phase) -> Self {
+        Activity {
+            pid: std::process::id(),
+            repo_path: repo_path.to_string(),
+            phase,
+            since_ts: Utc::now().timestamp(),
+        }
+    }
+
+    /// La actividad en curso, si el proceso que la escribió sigue en marcha.
+    pub fn load(path: &str) -> Option<Self> {
+        let contents = fs::read_to_string(path).ok()?;
+        let activity: Activity = toml::from_str(&contents).ok()?;
+        platform::process_alive(activity.pid).then_some(activity)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let serialized =
+            toml::to_string_pretty(self).map_err(messages::activity_serialize_failed)?;
+        fs::write(path, serialized).map_err(|e| messages::activity_save_failed(path, e))
+    }
+
+    pub fn clear(path: &str) {
+        let _ = fs::remove_file(path);
+    }
+}
@@ -0,0 +1,871 @@
+//! Línea de comandos de git-sync. Las opciones globales (`--config`, `--user`) se aceptan
+//! antes o después del subcomando, como las escriben las unidades de systemd
+//! (`git-sync daemon --user --config <dir>`).
+
+use chrono::NaiveDateTime;
+use clap::error::{ContextKind, ContextValue, ErrorKind};
+use clap::{ArgAction, Args, Command, CommandFactory, FromArgMatches, Parser, Subcommand};
+use git_sync::config;
+use git_sync::exchange::Format;
+use git_sync::i18n::{self, Language};
+use git_sync::logger::LogLevel;
+use git_sync::logs;
+use git_sync::messages;
+use std::error::Error;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "git-sync",
+    about = "🧭 Servicio de sincronización de repositorios Git.",
+    long_about = "🧭 Servicio de sincronización de repositorios Git.\n\nSin subcomando abre la interfaz interactiva para gestionar repositorios (e instala el servicio si es necesario).",
+    disable_help_flag = true,
+    disable_version_flag = true,
+    disable_help_subcommand = true
+)]
+pub struct Cli {
+    /// Usa otra carpeta de configuración (instancia aislada, con sus registros en
+    /// <DIRECTORIO>/logs y su propio servicio). También: GIT_SYNC_CONFIG_DIR
+    #[arg(long, global = true, value_name = "DIRECTORIO")]
+    pub config: Option<String>,
+
+    /// Usa la configuración del usuario y un servicio de usuario en lugar de los del sistema
+    #[arg(long, global = true)]
+    pub user: bool,
+
+    /// Sin mensajes informativos: solo los errores y el código de salida
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// Abre la interfaz en modo de solo lectura (sin altas ni ediciones; se puede pedir sincronizar)
+    #[arg(long, conflicts_with = "add_current")]
+    pub read_only: bool,
+
+    /// Pregunta si desea agregar el directorio actual como repositorio
+    #[arg(long)]
+    pub add_current: bool,
+
+    /// Muestra la versión actual
+    #[arg(short = 'v', long)]
+    pub version: bool,
+
+    /// Muestra la ayuda
+    #[arg(short, long, global = true, action = ArgAction::Help)]
+    help: Option<bool>,
+
+    #[command(subcommand)]
+    pub command: Option<CliCommand>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CliCommand {
+    /// Ejecuta el daemon de sincronización (pensado para systemd)
+    ///
+    /// Envíe SIGUSR1 para forzar un ciclo inmediato: `sudo systemctl kill -s SIGUSR1 git-sync`.
+    Daemon {
+        /// Para sistemas sin systemd: relanza el daemon si termina con error y guarda el PID
+        /// del supervisor en git-sync.pid
+        #[arg(long)]
+        supervise: bool,
+
+        /// Solo descarga y registra lo que haría, sin modificar los repositorios
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Ejecuta un solo ciclo con todos los repositorios y termina, sin importar
+        /// `continuous_mode`
+        #[arg(long, conflicts_with = "supervise")]
+        once: bool,
+
+        /// Muestra el progreso con la hora de cada línea y, en una terminal, con colores
+        #[arg(long)]
+        foreground: bool,
+    },
+
+    /// Ejecuta un ciclo de sincronización en primer plano
+    Sync {
+        #[command(flatten)]
+        target: SyncTarget,
+
+        /// Solo descarga y registra lo que haría, sin modificar los repositorios
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Agrega un repositorio a la lista
+    ///
+    /// Sin --url la ruta debe ser un repositorio Git existente; con --url se clona en la
+    /// siguiente sincronización.
+    Add(AddArgs),
+
+    /// Quita un repositorio de la lista (sus archivos no se tocan)
+    Remove {
+        /// Nombre o ruta del repositorio (también relativa al directorio actual)
+        #[arg(value_name = "NOMBRE")]
+        repo: String,
+    },
+
+    /// Muestra los repositorios de la lista con sus opciones
+    List {
+        /// Un arreglo JSON en lugar de texto
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Pausa la sincronización de todos los repositorios, de uno o de los de un grupo
+    ///
+    /// Sin repositorio ni grupo el daemon deja de sincronizar hasta `git-sync resume` (o hasta
+    /// que pase --for), por ejemplo durante un mantenimiento manual.
+    Pause {
+        #[command(flatten)]
+        target: PauseTarget,
+
+        /// Reanudar solo tras este tiempo (ej. 30m, 2h, 1d)
+        #[arg(long = "for", value_name = "DURACIÓN", value_parser = parse_pause_duration)]
+        duration: Option<u64>,
+    },
+
+    /// Reanuda la sincronización pausada de todos los repositorios, de uno o de los de un grupo
+    Resume(#[command(flatten)] PauseTarget),
+
+    /// Exporta o importa la configuración completa (ajustes y lista de repositorios)
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+
+    /// Revisa config.toml y la lista de repositorios sin modificarlos
+    ///
+    /// Comprueba rutas, permisos, opciones y que los remotos sean accesibles. Termina con
+    /// error si encuentra problemas.
+    Validate {
+        /// No comprueba los remotos
+        #[arg(long)]
+        offline: bool,
+    },
+
+    /// Muestra en vivo los eventos de sincronización del daemon (requiere el socket de control)
+    Watch {
+        /// Un objeto JSON por evento en lugar de una línea de texto
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Muestra el registro de git-sync, con filtros
+    Logs(LogsArgs),
+
+    /// Consulta el servicio instalado
+    Service {
+        #[command(subcommand)]
+        action: ServiceAction,
+    },
+
+    /// Instala el servicio si no lo está
+    InstallService(#[command(flatten)] InstallArgs),
+
+    /// Regenera el servicio con el binario y la configuración actuales y lo reinicia
+    /// (equivale a `install-service --force`)
+    Reinstall(#[command(flatten)] InstallArgs),
+
+    /// Detiene y elimina el servicio
+    UninstallService {
+        /// Elimina también la configuración, los logs y el estado (los repositorios no se
+        /// tocan)
+        #[arg(long)]
+        purge: bool,
+
+        /// No pide confirmación para --purge
+        #[arg(long, requires = "purge")]
+        yes: bool,
+    },
+
+    /// Elimina servicio, configuración y logs de git-sync
+    Uninstall,
+
+    /// Actualiza a la última versión estable
+    Update,
+}
+
+/// Repositorios a los que se limita `sync`: uno por nombre o ruta, los de un grupo, o
+/// todos si no se indica ninguno.
+#[derive(Args, Debug)]
+pub struct SyncTarget {
+    /// Nombre o ruta del repositorio (también relativa al directorio actual, ej. `.`)
+    #[arg(value_name = "NOMBRE")]
+    pub name: Option<String>,
+
+    /// Los repositorios del grupo (o etiqueta) indicado
+    #[arg(
+        long,
+        visible_alias = "tag",
+        value_name = "GRUPO",
+        conflicts_with = "name"
+    )]
+    pub group: Option<String>,
+}
+
+/// Repositorios que pausan o reanudan `pause` y `resume`; sin ninguno, todos.
+#[derive(Args, Debug)]
+#[group(multiple = false)]
+pub struct PauseTarget {
+    /// Nombre o ruta del repositorio
+    #[arg(value_name = "NOMBRE")]
+    pub name: Option<String>,
+
+    /// Igual que NOMBRE
+    #[arg(long = "repo", value_name = "NOMBRE")]
+    pub repo: Option<String>,
+
+    /// Los repositorios del grupo (o etiqueta) indicado
+    #[arg(long, visible_alias = "tag", value_name = "GRUPO")]
+    pub group: Option<String>,
+}
+
+impl PauseTarget {
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref().or(self.repo.as_deref())
+    }
+}
+
+fn parse_pause_duration(value: &str) -> Result<u64, String> {
+    config::parse_duration(value).ok_or_else(|| messages::invalid_pause_duration(value))
+}
+
+#[derive(Args, Debug)]
+pub struct AddArgs {
+    /// Ruta local del repositorio (también relativa al directorio actual)
+    #[arg(value_name = "RUTA")]
+    pub path: String,
+
+    /// URL desde la que clonarlo si la ruta aún no existe
+    #[arg(long, value_name = "URL")]
+    pub url: Option<String>,
+
+    /// Rama a sincronizar en lugar de la predeterminada del remoto (`branch=`)
+    #[arg(long, value_name = "RAMA")]
+    pub branch: Option<String>,
+
+    /// Nombre del repositorio (`name=`); sin él, el del directorio
+    #[arg(long, value_name = "NOMBRE")]
+    pub name: Option<String>,
+
+    /// Grupo al que pertenece (`groups=`); se puede repetir
+    #[arg(long, visible_alias = "tag", value_name = "GRUPO")]
+    pub group: Vec<String>,
+
+    /// Cualquier otra opción de repositories.txt (ej. `preset=laravel`); se puede repetir
+    #[arg(long = "option", short = 'o', value_name = "CLAVE=VALOR")]
+    pub options: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct LogsArgs {
+    /// Sigue mostrando las entradas nuevas a medida que se registran
+    #[arg(short, long)]
+    pub follow: bool,
+
+    /// Solo las entradas de este repositorio (nombre o ruta)
+    #[arg(long, value_name = "NOMBRE")]
+    pub repo: Option<String>,
+
+    /// Desde hace cuánto (30m, 2h, 1d) o desde qué fecha (2025-01-01 10:00); incluye los
+    /// registros rotados
+    #[arg(long, value_name = "DESDE", value_parser = logs::parse_since)]
+    pub since: Option<NaiveDateTime>,
+
+    /// Nivel mínimo: error, warn, info, debug o trace
+    #[arg(long, value_name = "NIVEL", value_parser = LogLevel::parse)]
+    pub level: Option<LogLevel>,
+
+    /// Cuántas entradas mostrar como máximo (predeterminado: 100, sin límite con --since)
+    #[arg(short = 'n', long, value_name = "N")]
+    pub lines: Option<usize>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigAction {
+    /// Escribe la configuración en la salida estándar
+    Export {
+        /// Formato del documento: json (predeterminado) o yaml
+        #[arg(long, value_name = "FORMATO", value_parser = Format::parse)]
+        format: Option<Format>,
+    },
+
+    /// Reemplaza config.toml y repositories.txt con un documento exportado
+    Import {
+        /// Archivo a importar, o `-` para la entrada estándar
+        #[arg(value_name = "ARCHIVO")]
+        source: String,
+
+        /// Formato del documento; sin él se deduce de la extensión del archivo
+        #[arg(long, value_name = "FORMATO", value_parser = Format::parse)]
+        format: Option<Format>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ServiceAction {
+    /// Muestra si el servicio está instalado con el binario actual, habilitado y activo,
+    /// sus últimas líneas de registro y la última sincronización correcta
+    ///
+    /// Termina con error si el servicio no está activo.
+    Status {
+        /// Muestra también el estado de cada repositorio del grupo (o etiqueta) indicado, y
+        /// limita a ellos la última sincronización correcta
+        #[arg(long, visible_alias = "tag", value_name = "GRUPO")]
+        group: Option<String>,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct InstallArgs {
+    /// Regenera el servicio aunque ya esté instalado, muestra las diferencias y lo reinicia
+    #[arg(long)]
+    pub force: bool,
+
+    /// Usuario del servicio del sistema (reemplaza a `service_user` de config.toml)
+    #[arg(long, value_name = "USUARIO")]
+    pub service_user: Option<String>,
+
+    /// Grupo del servicio del sistema (reemplaza a `service_group` de config.toml)
+    #[arg(long, value_name = "GRUPO")]
+    pub service_group: Option<String>,
+}
+
+impl CliCommand {
+    /// Si la salida estándar solo lleva mensajes informativos, que `--quiet` descarta. `list`,
+    /// `logs`, `watch` y `config export` la usan para lo que se pidió, y `uninstall-service
+    /// --purge` sin `--yes` para preguntar.
+    pub fn output_is_informational(&self) -> bool {
+        !matches!(
+            self,
+            CliCommand::List { .. }
+                | CliCommand::Logs(_)
+                | CliCommand::Watch { .. }
+                | CliCommand::Config {
+                    action: ConfigAction::Export { .. }
+                }
+                | CliCommand::UninstallService {
+                    purge: true,
+                    yes: false
+                }
+        )
+    }
+}
+
+impl Cli {
+    /// Interpreta los argumentos del proceso. Ante `--help` muestra la ayuda y termina; ante
+    /// un uso inválido muestra el error y el uso correcto, y termina con código 1.
+    pub fn parse_args(system_dir: &str) -> Self {
+        let mut command = localize(Cli::command()).after_help(messages::help_files(system_dir));
+        if i18n::current() == Language::En {
+            command = translate(command, "git-sync");
+        }
+        // El uso de cada subcomando incluye su ruta completa solo tras construir el comando.
+        command.build();
+        let mut command = localize_usage(command);
+        let result = command
+            .try_get_matches_from_mut(std::env::args_os())
+            .and_then(|mut matches| Cli::from_arg_matches_mut(&mut matches));
+        match result {
+            Ok(cli) => cli,
+            Err(err) if matches!(err.kind(), ErrorKind::DisplayHelp) => err.exit(),
+            Err(err) => {
+                eprintln!("❌ {}", describe_error(&err));
+                if let Some(usage) = err.get(ContextKind::Usage) {
+                    let usage = usage.to_string();
+                    eprintln!(
+                        "{}",
+                        messages::usage_hint(usage.trim().trim_start_matches("Usage:").trim())
+                    );
+                }
+                eprintln!("{}", messages::help_hint());
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// Encabezados de la ayuda en el idioma de los mensajes para el comando y sus subcomandos.
+/// Las secciones vacías se omiten.
+fn localize(command: Command) -> Command {
+    let mut template = format!(
+        "{{about-with-newline}}\n{}: {{usage}}\n",
+        messages::help_usage()
+    );
+    if command.has_subcommands() {
+        template.push_str(&format!(
+            "\n{}:\n{{subcommands}}\n",
+            messages::help_commands()
+        ));
+    }
+    if command.get_positionals().next().is_some() {
+        template.push_str(&format!(
+            "\n{}:\n{{positionals}}\n",
+            messages::help_arguments()
+        ));
+    }
+    template.push_str(&format!(
+        "\n{}:\n{{options}}{{after-help}}",
+        messages::help_options()
+    ));
+
+    command
+        .help_template(template)
+        .subcommand_value_name(messages::help_command_value())
+        .mut_subcommands(localize)
+}
+
+/// Reemplaza `[OPTIONS]` en la línea de uso de cada comando, que clap no permite traducir.
+fn localize_usage(mut command: Command) -> Command {
+    let usage = command
+        .render_usage()
+        .to_string()
+        .trim()
+        .trim_start_matches("Usage:")
+        .trim()
+        .replace("[OPTIONS]", &messages::help_options_value());
+    command
+        .override_usage(usage)
+        .mut_subcommands(localize_usage)
+}
+
+/// Mensaje de error en el idioma de los mensajes a partir del contexto que aporta clap.
+fn describe_error(err: &clap::Error) -> String {
+    let context = |kind| match err.get(kind) {
+        Some(ContextValue::String(value)) => value.clone(),
+        Some(ContextValue::Strings(values)) => values.join(", "),
+        Some(ContextValue::StyledStr(value)) => value.to_string(),
+        Some(ContextValue::StyledStrs(values)) => values
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => String::new(),
+    };
+    let suggestion = match err.get(ContextKind::SuggestedSubcommand) {
+        Some(_) => messages::did_you_mean(context(ContextKind::SuggestedSubcommand)),
+        None => match err.get(ContextKind::SuggestedArg) {
+            Some(_) => messages::did_you_mean(context(ContextKind::SuggestedArg)),
+            None => String::new(),
+        },
+    };
+
+    match err.kind() {
+        ErrorKind::InvalidSubcommand => {
+            messages::unknown_command(context(ContextKind::InvalidSubcommand), suggestion)
+        }
+        ErrorKind::UnknownArgument => {
+            messages::unknown_option(context(ContextKind::InvalidArg), suggestion)
+        }
+        ErrorKind::MissingRequiredArgument => {
+            messages::missing_argument(context(ContextKind::InvalidArg))
+        }
+        ErrorKind::MissingSubcommand | ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand => {
+            messages::missing_subcommand()
+        }
+        ErrorKind::ArgumentConflict => messages::argument_conflict(
+            context(ContextKind::InvalidArg),
+            context(ContextKind::PriorArg),
+        ),
+        ErrorKind::ValueValidation => messages::invalid_argument_value(
+            context(ContextKind::InvalidArg),
+            err.source()
+                .map(ToString::to_string)
+                .unwrap_or_else(|| context(ContextKind::InvalidValue)),
+        ),
+        ErrorKind::InvalidValue => messages::invalid_argument_literal(
+            context(ContextKind::InvalidArg),
+            context(ContextKind::InvalidValue),
+        ),
+        _ => match context(ContextKind::InvalidArg) {
+            arg if arg.is_empty() => messages::invalid_usage(),
+            arg => messages::invalid_usage_of(arg),
+        },
+    }
+}
+
+/// Ayuda en inglés de cada comando, por su ruta: descripción y, si la tiene, la larga. La
+/// ayuda en español son los comentarios de `Cli` y sus subcomandos.
+const COMMANDS_EN: &[(&str, &str, Option<&str>)] = &[
+    (
+        "git-sync",
+        "🧭 Git repository synchronization service.",
+        Some(
+            "🧭 Git repository synchronization service.\n\nWithout a subcommand it opens the interactive interface to manage repositories (and installs the service if needed).",
+        ),
+    ),
+    (
+        "git-sync daemon",
+        "Run the synchronization daemon (meant for systemd)",
+        Some(
+            "Run the synchronization daemon (meant for systemd)\n\nSend SIGUSR1 to force an immediate cycle: `sudo systemctl kill -s SIGUSR1 git-sync`.",
+        ),
+    ),
+    (
+        "git-sync sync",
+        "Run a synchronization cycle in the foreground",
+        None,
+    ),
+    (
+        "git-sync add",
+        "Add a repository to the list",
+        Some(
+            "Add a repository to the list\n\nWithout --url the path must be an existing Git repository; with --url it is cloned on the next sync.",
+        ),
+    ),
+    (
+        "git-sync remove",
+        "Remove a repository from the list (its files are left untouched)",
+        None,
+    ),
+    (
+        "git-sync list",
+        "Show the repositories in the list with their options",
+        None,
+    ),
+    (
+        "git-sync pause",
+        "Pause the synchronization of all repositories, of one or of those in a group",
+        Some(
+            "Pause the synchronization of all repositories, of one or of those in a group\n\nWithout a repository or group the daemon stops syncing until `git-sync resume` (or until --for elapses), for example during manual maintenance.",
+        ),
+    ),
+    (
+        "git-sync resume",
+        "Resume the paused synchronization of all repositories, of one or of those in a group",
+        None,
+    ),
+    (
+        "git-sync config",
+        "Export or import the whole configuration (settings and repository list)",
+        None,
+    ),
+    (
+        "git-sync config export",
+        "Write the configuration to standard output",
+        None,
+    ),
+    (
+        "git-sync config import",
+        "Replace config.toml and repositories.txt with an exported document",
+        None,
+    ),
+    (
+        "git-sync validate",
+        "Check config.toml and the repository list without modifying them",
+        Some(
+            "Check config.toml and the repository list without modifying them\n\nChecks paths, permissions, options and that the remotes are reachable. Fails if it finds problems.",
+        ),
+    ),
+    (
+        "git-sync watch",
+        "Show the daemon's synchronization events live (requires the control socket)",
+        None,
+    ),
+    ("git-sync logs", "Show the git-sync log, with filters", None),
+    ("git-sync service", "Query the installed service", None),
+    (
+        "git-sync service status",
+        "Show whether the service is installed with the current binary, enabled and active, its last log lines and the last successful sync",
+        Some(
+            "Show whether the service is installed with the current binary, enabled and active, its last log lines and the last successful sync\n\nFails if the service is not active.",
+        ),
+    ),
+    (
+        "git-sync install-service",
+        "Install the service if it is not installed",
+        None,
+    ),
+    (
+        "git-sync reinstall",
+        "Regenerate the service with the current binary and configuration and restart it (same as `install-service --force`)",
+        None,
+    ),
+    (
+        "git-sync uninstall-service",
+        "Stop and remove the service",
+        None,
+    ),
+    (
+        "git-sync uninstall",
+        "Remove the git-sync service, configuration and logs",
+        None,
+    ),
+    (
+        "git-sync update",
+        "Update to the latest stable version",
+        None,
+    ),
+];
+
+/// Ayuda en inglés de cada argumento, por la ruta del comando y el identificador. Los
+/// globales se traducen en `git-sync` y clap los copia a los subcomandos.
+const ARGS_EN: &[(&str, &str, &str)] = &[
+    (
+        "git-sync",
+        "config",
+        "Use another configuration directory (isolated instance, with its logs in <DIR>/logs and its own service). Also: GIT_SYNC_CONFIG_DIR",
+    ),
+    (
+        "git-sync",
+        "user",
+        "Use the user configuration and a user service instead of the system ones",
+    ),
+    (
+        "git-sync",
+        "quiet",
+        "No informational messages: only errors and the exit code",
+    ),
+    (
+        "git-sync",
+        "read_only",
+        "Open the interface in read-only mode (no additions or edits; syncs can still be requested)",
+    ),
+    (
+        "git-sync",
+        "add_current",
+        "Ask whether to add the current directory as a repository",
+    ),
+    ("git-sync", "version", "Show the current version"),
+    ("git-sync", "help", "Show the help"),
+    (
+        "git-sync daemon",
+        "supervise",
+        "For systems without systemd: restart the daemon if it exits with an error and store the supervisor PID in git-sync.pid",
+    ),
+    (
+        "git-sync daemon",
+        "dry_run",
+        "Only fetch and log what would be done, without modifying the repositories",
+    ),
+    (
+        "git-sync daemon",
+        "once",
+        "Run a single cycle with all the repositories and exit, regardless of `continuous_mode`",
+    ),
+    (
+        "git-sync daemon",
+        "foreground",
+        "Show the progress with the time of each line and, in a terminal, with colors",
+    ),
+    (
+        "git-sync sync",
+        "name",
+        "Repository name or path (also relative to the current directory, e.g. `.`)",
+    ),
+    (
+        "git-sync sync",
+        "group",
+        "The repositories in the given group (or tag)",
+    ),
+    (
+        "git-sync sync",
+        "dry_run",
+        "Only fetch and log what would be done, without modifying the repositories",
+    ),
+    (
+        "git-sync add",
+        "path",
+        "Local path of the repository (also relative to the current directory)",
+    ),
+    (
+        "git-sync add",
+        "url",
+        "URL to clone it from if the path does not exist yet",
+    ),
+    (
+        "git-sync add",
+        "branch",
+        "Branch to sync instead of the remote default (`branch=`)",
+    ),
+    (
+        "git-sync add",
+        "name",
+        "Repository name (`name=`); without it, the directory name",
+    ),
+    (
+        "git-sync add",
+        "group",
+        "Group it belongs to (`groups=`); can be repeated",
+    ),
+    (
+        "git-sync add",
+        "options",
+        "Any other repositories.txt option (e.g. `preset=laravel`); can be repeated",
+    ),
+    (
+        "git-sync remove",
+        "repo",
+        "Repository name or path (also relative to the current directory)",
+    ),
+    ("git-sync list", "json", "A JSON array instead of text"),
+    ("git-sync pause", "name", "Repository name or path"),
+    ("git-sync pause", "repo", "Same as NAME"),
+    (
+        "git-sync pause",
+        "group",
+        "The repositories in the given group (or tag)",
+    ),
+    (
+        "git-sync pause",
+        "duration",
+        "Resume only after this time (e.g. 30m, 2h, 1d)",
+    ),
+    ("git-sync resume", "name", "Repository name or path"),
+    ("git-sync resume", "repo", "Same as NAME"),
+    (
+        "git-sync resume",
+        "group",
+        "The repositories in the given group (or tag)",
+    ),
+    (
+        "git-sync service status",
+        "group",
+        "Also show the state of each repository in the given group (or tag), and limit the last successful sync to them",
+    ),
+    (
+        "git-sync config export",
+        "format",
+        "Document format: json (default) or yaml",
+    ),
+    (
+        "git-sync config import",
+        "source",
+        "File to import, or `-` for standard input",
+    ),
+    (
+        "git-sync config import",
+        "format",
+        "Document format; without it, taken from the file extension",
+    ),
+    ("git-sync validate", "offline", "Do not check the remotes"),
+    (
+        "git-sync watch",
+        "json",
+        "One JSON object per event instead of a line of text",
+    ),
+    (
+        "git-sync logs",
+        "follow",
+        "Keep showing new entries as they are recorded",
+    ),
+    (
+        "git-sync logs",
+        "repo",
+        "Only the entries of this repository (name or path)",
+    ),
+    (
+        "git-sync logs",
+        "since",
+        "How long ago (30m, 2h, 1d) or since which date (2025-01-01 10:00); includes the rotated logs",
+    ),
+    (
+        "git-sync logs",
+        "level",
+        "Minimum level: error, warn, info, debug or trace",
+    ),
+    (
+        "git-sync logs",
+        "lines",
+        "Maximum number of entries to show (default: 100, unlimited with --since)",
+    ),
+    (
+        "git-sync install-service",
+        "force",
+        "Regenerate the service even if it is installed, show the differences and restart it",
+    ),
+    (
+        "git-sync install-service",
+        "service_user",
+        "User of the system service (overrides `service_user` in config.toml)",
+    ),
+    (
+        "git-sync install-service",
+        "service_group",
+        "Group of the system service (overrides `service_group` in config.toml)",
+    ),
+    (
+        "git-sync reinstall",
+        "force",
+        "Regenerate the service even if it is installed, show the differences and restart it",
+    ),
+    (
+        "git-sync reinstall",
+        "service_user",
+        "User of the system service (overrides `service_user` in config.toml)",
+    ),
+    (
+        "git-sync reinstall",
+        "service_group",
+        "Group of the system service (overrides `service_group` in config.toml)",
+    ),
+    (
+        "git-sync uninstall-service",
+        "purge",
+        "Also remove the configuration, logs and state (repositories are left untouched)",
+    ),
+    (
+        "git-sync uninstall-service",
+        "yes",
+        "Do not ask for confirmation for --purge",
+    ),
+];
+
+/// Nombres de los valores en la ayuda en inglés.
+const VALUE_NAMES_EN: &[(&str, &str)] = &[
+    ("DIRECTORIO", "DIR"),
+    ("NOMBRE", "NAME"),
+    ("GRUPO", "GROUP"),
+    ("DURACIÓN", "DURATION"),
+    ("RUTA", "PATH"),
+    ("RAMA", "BRANCH"),
+    ("CLAVE=VALOR", "KEY=VALUE"),
+    ("DESDE", "SINCE"),
+    ("NIVEL", "LEVEL"),
+    ("FORMATO", "FORMAT"),
+    ("ARCHIVO", "FILE"),
+    ("USUARIO", "USER"),
+];
+
+/// Ayuda en inglés para `command` (de ruta `path`) y sus subcomandos.
+fn translate(mut command: Command, path: &str) -> Command {
+    if let Some((_, about, long_about)) = COMMANDS_EN.iter().find(|(name, ..)| *name == path) {
+        command = command.about(*about).long_about(*long_about);
+    }
+    let args = command
+        .get_arguments()
+        .map(|arg| arg.get_id().to_string())
+        .collect::<Vec<_>>();
+    for id in args {
+        let help = ARGS_EN
+            .iter()
+            .find(|(name, arg, _)| *name == path && *arg == id)
+            .map(|(_, _, help)| *help);
+        command = command.mut_arg(id, |arg| {
+            let value_names = arg
+                .get_value_names()
+                .unwrap_or_default()
+                .iter()
+                .map(|value| {
+                    VALUE_NAMES_EN
+                        .iter()
+                        .find(|(es, _)| value.as_str() == *es)
+                        .map_or(value.to_string(), |(_, en)| en.to_string())
+                })
+                .collect::<Vec<_>>();
+            let arg = match help {
+                Some(help) => arg.help(help),
+                None => arg,
+            };
+            if value_names.is_empty() {
+                arg
+            } else {
+                arg.value_names(value_names)
+            }
+        });
+    }
+    command.mut_subcommands(|subcommand| {
+        let path = format!("{} {}", path, subcommand.get_name());
+        translate(subcommand, &path)
+    })
+}
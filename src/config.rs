@@ -1,25 +1,357 @@
-use crate::settings::Settings;
+use crate::environment::is_valid_var_name;
+use crate::error::Error;
+use crate::git::{DEFAULT_REMOTE, GitRepo};
+use crate::messages;
+use crate::migrate;
+use crate::platform;
+use crate::preset::{DeployPreset, PresetStep};
+use crate::settings::{Settings, TimeWindow};
 use crate::sync_state::SyncStateSnapshot;
-use std::fs::{self, File};
-use std::os::unix::fs::PermissionsExt;
-use std::path::Path;
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Component, Path};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SyncMode {
+    /// `git pull` de la rama remota (conserva cambios locales compatibles)
+    #[default]
+    Pull,
+    /// `reset --hard` a la rama remota: el árbol de trabajo refleja exactamente el remoto
+    Reset,
+    /// Repositorio bare (ej. un mirror): solo `git remote update --prune`, sin árbol de trabajo
+    Bare,
+}
+
+impl SyncMode {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "pull" => Ok(SyncMode::Pull),
+            "reset" => Ok(SyncMode::Reset),
+            "bare" => Ok(SyncMode::Bare),
+            _ => Err(messages::invalid_sync_mode(value)),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SyncMode::Pull => "pull",
+            SyncMode::Reset => "reset",
+            SyncMode::Bare => "bare",
+        }
+    }
+}
+
+/// Cómo integrar commits locales con los remotos en modo pull.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PullStrategy {
+    /// Con `ff_only` los commits locales se tratan como historial divergente
+    /// (`on_divergence`); sin él se fusionan con un commit de merge
+    #[default]
+    Merge,
+    /// Los commits locales se reaplican sobre la rama remota con `git rebase`
+    Rebase,
+}
+
+impl PullStrategy {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "merge" => Ok(PullStrategy::Merge),
+            "rebase" => Ok(PullStrategy::Rebase),
+            _ => Err(messages::invalid_pull_strategy(value)),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PullStrategy::Merge => "merge",
+            PullStrategy::Rebase => "rebase",
+        }
+    }
+}
+
+/// Qué hacer cuando hay cambios sin confirmar en archivos con seguimiento antes de actualizar.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DirtyPolicy {
+    /// Omitir el repositorio indicando qué archivos tienen cambios
+    #[default]
+    Abort,
+    /// Guardar los cambios en el stash, actualizar y restaurarlos
+    Stash,
+    /// Descartar los cambios de los archivos con seguimiento (`checkout --`)
+    Discard,
+}
+
+impl DirtyPolicy {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "abort" => Ok(DirtyPolicy::Abort),
+            "stash" => Ok(DirtyPolicy::Stash),
+            "discard" => Ok(DirtyPolicy::Discard),
+            _ => Err(messages::invalid_dirty_policy(value)),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DirtyPolicy::Abort => "abort",
+            DirtyPolicy::Stash => "stash",
+            DirtyPolicy::Discard => "discard",
+        }
+    }
+}
+
+/// Qué hacer cuando el repositorio tiene HEAD desacoplado (ej. tras `git checkout <tag>`).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DetachedPolicy {
+    /// Omitir el repositorio con un error que indica cómo volver a la rama
+    #[default]
+    Skip,
+    /// Volver a la rama sincronizada si no se pierde ningún commit
+    Reattach,
+}
+
+impl DetachedPolicy {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "skip" => Ok(DetachedPolicy::Skip),
+            "reattach" => Ok(DetachedPolicy::Reattach),
+            _ => Err(messages::invalid_detached_policy(value)),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DetachedPolicy::Skip => "skip",
+            DetachedPolicy::Reattach => "reattach",
+        }
+    }
+}
+
+/// Qué hacer cuando la rama local y la remota divergieron (ej. tras un force push).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DivergencePolicy {
+    /// Omitir el repositorio y registrar una advertencia
+    #[default]
+    Skip,
+    /// Descartar los commits locales con `reset --hard` al remoto
+    Reset,
+    /// Guardar HEAD en una rama de rescate (y los cambios en el stash) antes del reset
+    Rescue,
+}
+
+impl DivergencePolicy {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "skip" => Ok(DivergencePolicy::Skip),
+            "reset" => Ok(DivergencePolicy::Reset),
+            "rescue" => Ok(DivergencePolicy::Rescue),
+            _ => Err(messages::invalid_divergence_policy(value)),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DivergencePolicy::Skip => "skip",
+            DivergencePolicy::Reset => "reset",
+            DivergencePolicy::Rescue => "rescue",
+        }
+    }
+}
+
+/// Motor de contenedores con el que se vuelve a desplegar un proyecto Compose.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ContainerEngine {
+    Docker,
+    Podman,
+}
+
+impl ContainerEngine {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "docker" => Ok(ContainerEngine::Docker),
+            "podman" => Ok(ContainerEngine::Podman),
+            _ => Err(messages::invalid_container_engine(value)),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ContainerEngine::Docker => "docker",
+            ContainerEngine::Podman => "podman",
+        }
+    }
+}
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RepoDefinition {
     pub repo_path: String,
+    /// Identificador estable; si falta se usa el nombre del directorio
+    pub name: Option<String>,
     pub enabled: bool,
+    pub groups: Vec<String>,
+    /// URL desde la que se clona el repositorio si la ruta local aún no existe
+    pub clone_url: Option<String>,
+    pub sync_mode: SyncMode,
+    /// En modo pull, cómo integrar los commits locales hechos en el servidor
+    pub pull_strategy: PullStrategy,
+    /// Prohibir commits de merge; si falta se usa `ff_only` de config.toml
+    pub ff_only: Option<bool>,
+    /// Eliminar también los archivos sin seguimiento (`git clean -fd`)
+    pub clean: bool,
+    /// Patrones que `clean_untracked` conserva, como `git clean -e`
+    pub clean_exclude: Vec<String>,
+    /// Política ante historial divergente; si falta se usa `on_divergence` de config.toml
+    pub on_divergence: Option<DivergencePolicy>,
+    /// Política ante cambios locales sin confirmar; si falta se usa `on_dirty` de config.toml
+    pub on_dirty: Option<DirtyPolicy>,
+    /// Política ante HEAD desacoplado; si falta se usa `on_detached` de config.toml
+    pub on_detached: Option<DetachedPolicy>,
+    /// Patrón de tags a seguir en lugar de una rama (`track=tag:v*`)
+    pub track_tag: Option<String>,
+    /// Profundidad de historial propia; si falta se usa `fetch_depth` de config.toml
+    pub fetch_depth: Option<u32>,
+    /// `fetch --prune`; si falta se usa `fetch_prune` de config.toml
+    pub fetch_prune: Option<bool>,
+    /// Eliminar ramas locales huérfanas; si falta se usa `prune_local_branches` de config.toml
+    pub prune_local_branches: Option<bool>,
+    /// Remoto desde el que se sincroniza; si falta se usa `origin`
+    pub remote: Option<String>,
+    /// Rama que se sincroniza; si falta se usa la predeterminada del remoto
+    pub branch: Option<String>,
+    /// Remoto al que se replica el resultado tras cada sincronización correcta
+    pub mirror_to: Option<String>,
+    /// Exigir que el commit a aplicar tenga una firma GPG/SSH válida
+    pub require_signed: bool,
+    /// Keyring GPG (directorio) o archivo `allowed_signers` de SSH para verificar firmas
+    pub signers: Option<String>,
+    /// Simular la sincronización; si falta se usa `dry_run` de config.toml
+    pub dry_run: Option<bool>,
+    /// Segundos entre sincronizaciones en modo continuo; si falta se usa `sync_interval`
+    pub interval: Option<u64>,
+    /// Fallos seguidos antes de desactivarlo; si falta se usa `max_failures` de config.toml
+    pub max_failures: Option<u32>,
+    /// Franjas sin despliegues; si falta se usa `quiet_hours` de config.toml (vacío = ninguna)
+    pub quiet_hours: Option<Vec<TimeWindow>>,
+    /// Repositorios (ruta o nombre del directorio) que deben sincronizarse antes que este
+    pub after: Vec<String>,
+    /// Unidades systemd que se recargan o reinician tras aplicar cambios
+    pub restart_units: Vec<String>,
+    /// Despliegue predefinido (ej. Laravel) que se ejecuta tras aplicar cambios
+    pub preset: Option<DeployPreset>,
+    /// Pasos del preset; si está vacío se usan los predeterminados del preset
+    pub preset_steps: Vec<PresetStep>,
+    /// Directorio de salida de la compilación; si falta se usa el del preset
+    pub preset_output: Option<String>,
+    /// Motor con el que se ejecuta `compose up -d --build` tras aplicar cambios
+    pub compose: Option<ContainerEngine>,
+    /// Clave SSH (deploy key) exclusiva para este repositorio
+    pub ssh_key: Option<String>,
+    /// Variables de entorno propias (`env.NOMBRE=valor`), añadidas a las del archivo global
+    pub env: Vec<(String, String)>,
 }
 
 impl RepoDefinition {
     pub fn new<P: Into<String>>(repo_path: P) -> Self {
-        let repo_path = repo_path.into();
+        let repo_path = normalize_repo_path(&repo_path.into());
         RepoDefinition {
             repo_path,
+            name: None,
             enabled: true,
+            groups: Vec::new(),
+            clone_url: None,
+            sync_mode: SyncMode::default(),
+            pull_strategy: PullStrategy::default(),
+            ff_only: None,
+            clean: false,
+            clean_exclude: Vec::new(),
+            on_divergence: None,
+            on_dirty: None,
+            on_detached: None,
+            track_tag: None,
+            fetch_depth: None,
+            fetch_prune: None,
+            prune_local_branches: None,
+            remote: None,
+            branch: None,
+            mirror_to: None,
+            require_signed: false,
+            signers: None,
+            dry_run: None,
+            interval: None,
+            max_failures: None,
+            quiet_hours: None,
+            after: Vec::new(),
+            restart_units: Vec::new(),
+            preset: None,
+            preset_steps: Vec::new(),
+            preset_output: None,
+            compose: None,
+            ssh_key: None,
+            env: Vec::new(),
+        }
+    }
+
+    /// Interpreta `ruta` o `url => ruta` y devuelve la ruta local junto con la URL de clonado.
+    ///
+    /// Las entradas antiguas `origen => destino` con un origen local conservan el origen
+    /// y descartan el destino, porque el modo compilación fue eliminado. Los avisos sobre
+    /// la entrada se añaden a `warnings`.
+    pub fn parse_source(raw: &str, warnings: &mut Vec<String>) -> Option<(String, Option<String>)> {
+        let Some((source, target)) = raw.split_once("=>") else {
+            let path = raw.trim();
+            return (!path.is_empty()).then(|| (normalize_repo_path(path), None));
+        };
+
+        let source = source.trim();
+        let target = target.trim();
+        if source.is_empty() {
+            return None;
+        }
+
+        if is_clone_url(source) {
+            if target.is_empty() {
+                warnings.push(messages::clone_path_missing(source));
+                return None;
+            }
+            return Some((normalize_repo_path(target), Some(source.to_string())));
+        }
+
+        if !target.is_empty() {
+            warnings.push(messages::deploy_target_ignored(source));
+        }
+        Some((normalize_repo_path(source), None))
+    }
+
+    /// Para un checkout existente que se añade sin URL, registra la de su remoto de
+    /// sincronización, de modo que se verifique en cada ciclo y se pueda volver a clonar
+    /// si se borra. Los remotos locales (una ruta) no se registran.
+    pub fn record_remote_url(&mut self) {
+        if self.clone_url.is_none()
+            && let Ok(Some(url)) =
+                GitRepo::new(self.repo_path.clone()).remote_url(self.remote_name())
+            && is_clone_url(&url)
+        {
+            self.clone_url = Some(url);
         }
     }
 
-    pub fn from_line(line: &str) -> Option<Self> {
+    /// Representación editable del origen: `url => ruta` o solo la ruta.
+    pub fn source_spec(&self) -> String {
+        match &self.clone_url {
+            Some(url) => format!("{} => {}", url, self.repo_path.trim()),
+            None => self.repo_path.trim().to_string(),
+        }
+    }
+
+    /// Interpreta una línea de `repositories.txt` junto con sus avisos. Los comentarios y
+    /// las líneas en blanco devuelven `None`.
+    pub fn parse_line(line: &str) -> Option<(Self, Vec<String>)> {
+        let mut warnings = Vec::new();
         let trimmed = line.trim();
         if trimmed.is_empty() || trimmed.starts_with('#') {
             return None;
@@ -31,90 +363,984 @@ impl RepoDefinition {
             (true, trimmed)
         };
 
+        let (raw_path, raw_options) = match raw_path.split_once('|') {
+            Some((path_part, options_part)) => (path_part.trim(), options_part.trim()),
+            None => (raw_path, ""),
+        };
+
         let raw_path = if let Some((path_part, command_part)) = raw_path.split_once(";;") {
             if !command_part.trim().is_empty() {
-                eprintln!(
-                    "⚠️ Se ignoró el comando post-sync en {}. Esta función fue eliminada.",
-                    path_part.trim()
-                );
+                warnings.push(messages::post_sync_ignored(path_part.trim()));
             }
             path_part.trim()
         } else {
             raw_path
         };
 
-        if let Some((source, target)) = raw_path.split_once("=>") {
-            let source = source.trim();
-            if source.is_empty() {
-                return None;
+        let (repo_path, clone_url) = Self::parse_source(raw_path, &mut warnings)?;
+        let mut repo = RepoDefinition::new(repo_path);
+        repo.enabled = enabled;
+        repo.clone_url = clone_url;
+
+        for (key, value) in split_options(raw_options) {
+            if let Err(err) = repo.apply_option(&key, &value) {
+                warnings.push(messages::option_ignored(err, &repo.repo_path));
             }
-            if !target.trim().is_empty() {
-                eprintln!(
-                    "⚠️ Se ignoró el destino de despliegue en {}. El modo compilación fue eliminado.",
-                    source
-                );
+        }
+
+        Some((repo, warnings))
+    }
+
+    /// Aplica una opción `clave=valor` tal como se escribe en `repositories.txt`.
+    pub fn apply_option(&mut self, key: &str, value: &str) -> Result<(), String> {
+        if let Some(name) = key.strip_prefix("env.") {
+            if !is_valid_var_name(name) {
+                return Err(messages::invalid_var_name(name));
             }
-            let mut repo = RepoDefinition::new(source.to_string());
-            repo.enabled = enabled;
-            Some(repo)
-        } else {
-            let mut repo = RepoDefinition::new(raw_path.to_string());
-            repo.enabled = enabled;
-            Some(repo)
+            self.env.retain(|(existing, _)| existing != name);
+            self.env.push((name.to_string(), value.to_string()));
+            return Ok(());
+        }
+
+        match key {
+            "name" => {
+                self.name = Some(parse_repo_name(value)?);
+                Ok(())
+            }
+            "groups" | "group" | "tags" | "tag" => {
+                self.groups = split_list(value);
+                Ok(())
+            }
+            "sync_mode" => {
+                self.sync_mode = SyncMode::parse(value)?;
+                Ok(())
+            }
+            "pull_strategy" => {
+                self.pull_strategy = PullStrategy::parse(value)?;
+                Ok(())
+            }
+            "ff_only" => {
+                self.ff_only = Some(parse_bool(key, value)?);
+                Ok(())
+            }
+            "clean_untracked" | "clean" => {
+                self.clean = parse_bool(key, value)?;
+                Ok(())
+            }
+            "clean_exclude" => {
+                self.clean_exclude = split_list(value);
+                Ok(())
+            }
+            "track" => match value.strip_prefix("tag:") {
+                Some(pattern) if !pattern.is_empty() => {
+                    self.track_tag = Some(pattern.to_string());
+                    Ok(())
+                }
+                _ => Err(messages::invalid_track(value)),
+            },
+            "fetch_depth" => {
+                let depth = value
+                    .parse()
+                    .map_err(|_| messages::invalid_fetch_depth(value))?;
+                self.fetch_depth = Some(depth);
+                Ok(())
+            }
+            "fetch_prune" => {
+                self.fetch_prune = Some(parse_bool(key, value)?);
+                Ok(())
+            }
+            "prune_local_branches" => {
+                self.prune_local_branches = Some(parse_bool(key, value)?);
+                Ok(())
+            }
+            "remote" => {
+                self.remote = Some(parse_remote_name(value)?);
+                Ok(())
+            }
+            "branch" => {
+                self.branch = Some(parse_branch_name(value)?);
+                Ok(())
+            }
+            "mirror_to" => {
+                self.mirror_to = Some(parse_remote_name(value)?);
+                Ok(())
+            }
+            "on_divergence" => {
+                self.on_divergence = Some(DivergencePolicy::parse(value)?);
+                Ok(())
+            }
+            "on_dirty" => {
+                self.on_dirty = Some(DirtyPolicy::parse(value)?);
+                Ok(())
+            }
+            "on_detached" => {
+                self.on_detached = Some(DetachedPolicy::parse(value)?);
+                Ok(())
+            }
+            "require_signed" => {
+                self.require_signed = parse_bool(key, value)?;
+                Ok(())
+            }
+            "signers" => {
+                if value.is_empty() {
+                    return Err(messages::signers_path_required());
+                }
+                self.signers = Some(value.to_string());
+                Ok(())
+            }
+            "dry_run" => {
+                self.dry_run = Some(parse_bool(key, value)?);
+                Ok(())
+            }
+            "interval" => {
+                self.interval = Some(parse_interval(value)?);
+                Ok(())
+            }
+            "max_failures" => {
+                let failures = value
+                    .parse()
+                    .map_err(|_| messages::invalid_max_failures(value))?;
+                self.max_failures = Some(failures);
+                Ok(())
+            }
+            "quiet_hours" => {
+                let windows = if value == "none" {
+                    Vec::new()
+                } else {
+                    split_list(value)
+                        .into_iter()
+                        .map(TimeWindow::try_from)
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(messages::invalid_quiet_hours)?
+                };
+                self.quiet_hours = Some(windows);
+                Ok(())
+            }
+            "after" => {
+                self.after = split_list(value);
+                Ok(())
+            }
+            "restart_units" => {
+                let units = split_list(value);
+                if let Some(unit) = units
+                    .iter()
+                    .find(|unit| unit.starts_with('-') || unit.contains(char::is_whitespace))
+                {
+                    return Err(messages::invalid_unit_name(unit));
+                }
+                self.restart_units = units;
+                Ok(())
+            }
+            "preset" => {
+                self.preset = Some(DeployPreset::parse(value)?);
+                Ok(())
+            }
+            "preset_steps" => {
+                let mut steps = split_list(value)
+                    .iter()
+                    .map(|step| PresetStep::parse(step))
+                    .collect::<Result<Vec<_>, _>>()?;
+                steps.sort();
+                steps.dedup();
+                self.preset_steps = steps;
+                Ok(())
+            }
+            "preset_output" => {
+                let path = Path::new(value);
+                if value.is_empty()
+                    || path.is_absolute()
+                    || path.components().any(|c| c == Component::ParentDir)
+                {
+                    return Err(messages::invalid_preset_output(value));
+                }
+                self.preset_output = Some(value.trim_end_matches('/').to_string());
+                Ok(())
+            }
+            "compose" => {
+                self.compose = Some(ContainerEngine::parse(value)?);
+                Ok(())
+            }
+            "ssh_key" => {
+                if !value.starts_with('/') {
+                    return Err(messages::invalid_ssh_key(value));
+                }
+                self.ssh_key = Some(value.to_string());
+                Ok(())
+            }
+            _ => Err(messages::unknown_repo_option(key)),
+        }
+    }
+
+    /// Franja sin despliegues en la que está `time`, según `quiet_hours` del repositorio o,
+    /// si no tiene, las globales.
+    pub fn quiet_window(&self, global: &[TimeWindow], time: NaiveTime) -> Option<TimeWindow> {
+        self.quiet_hours
+            .as_deref()
+            .unwrap_or(global)
+            .iter()
+            .find(|window| window.contains(time))
+            .copied()
+    }
+
+    /// Pasos del preset que se ejecutan, en orden; vacío si no hay preset.
+    pub fn deploy_steps(&self) -> Vec<PresetStep> {
+        match self.preset {
+            Some(_) if !self.preset_steps.is_empty() => self.preset_steps.clone(),
+            Some(preset) => preset.default_steps().to_vec(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Patrones que conserva `clean`: los de `clean_exclude` o, si no hay, los del preset.
+    pub fn clean_excludes(&self) -> Vec<String> {
+        match self.preset {
+            Some(preset) if self.clean_exclude.is_empty() => preset
+                .clean_exclude()
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect(),
+            _ => self.clean_exclude.clone(),
+        }
+    }
+
+    /// Directorio que deben generar los pasos de compilación del preset, si hay alguno.
+    pub fn preset_output_dir(&self) -> Option<String> {
+        let preset = self.preset?;
+        if !self.deploy_steps().iter().any(|step| step.builds()) {
+            return None;
+        }
+        self.preset_output
+            .clone()
+            .or_else(|| preset.output_dir().map(str::to_string))
+    }
+
+    /// Nombre del repositorio: el de la opción `name` o, si falta, el de su directorio.
+    pub fn name(&self) -> &str {
+        if let Some(name) = &self.name {
+            return name;
+        }
+        let path = self.repo_path.trim_end_matches('/');
+        Path::new(path)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(path)
+    }
+
+    /// Si `reference` (de `after` o de la línea de comandos) designa este repositorio: su
+    /// nombre o su ruta completa.
+    pub fn matches_reference(&self, reference: &str) -> bool {
+        self.name() == reference
+            || self.repo_path.trim_end_matches('/') == reference.trim_end_matches('/')
+    }
+
+    pub fn remote_name(&self) -> &str {
+        self.remote.as_deref().unwrap_or(DEFAULT_REMOTE)
+    }
+
+    pub fn in_group(&self, group: &str) -> bool {
+        self.groups.iter().any(|g| g == group)
+    }
+
+    /// Opciones del repositorio como pares clave/valor, sin comillas, en el orden de `to_line`.
+    pub fn options(&self) -> Vec<(String, String)> {
+        let mut options = Vec::new();
+        let mut push = |key: &str, value: String| options.push((key.to_string(), value));
+        if let Some(name) = &self.name {
+            push("name", name.clone());
+        }
+        if !self.groups.is_empty() {
+            push("groups", self.groups.join(","));
+        }
+        if self.sync_mode != SyncMode::default() {
+            push("sync_mode", self.sync_mode.as_str().to_string());
+        }
+        if self.pull_strategy != PullStrategy::default() {
+            push("pull_strategy", self.pull_strategy.as_str().to_string());
+        }
+        if let Some(ff_only) = self.ff_only {
+            push("ff_only", ff_only.to_string());
+        }
+        if self.clean {
+            push("clean_untracked", "true".to_string());
         }
+        if !self.clean_exclude.is_empty() {
+            push("clean_exclude", self.clean_exclude.join(","));
+        }
+        if let Some(policy) = self.on_divergence {
+            push("on_divergence", policy.as_str().to_string());
+        }
+        if let Some(policy) = self.on_dirty {
+            push("on_dirty", policy.as_str().to_string());
+        }
+        if let Some(policy) = self.on_detached {
+            push("on_detached", policy.as_str().to_string());
+        }
+        if let Some(pattern) = &self.track_tag {
+            push("track", format!("tag:{}", pattern));
+        }
+        if let Some(depth) = self.fetch_depth {
+            push("fetch_depth", depth.to_string());
+        }
+        if let Some(prune) = self.fetch_prune {
+            push("fetch_prune", prune.to_string());
+        }
+        if let Some(prune) = self.prune_local_branches {
+            push("prune_local_branches", prune.to_string());
+        }
+        if let Some(remote) = &self.remote {
+            push("remote", remote.clone());
+        }
+        if let Some(branch) = &self.branch {
+            push("branch", branch.clone());
+        }
+        if let Some(mirror) = &self.mirror_to {
+            push("mirror_to", mirror.clone());
+        }
+        if self.require_signed {
+            push("require_signed", "true".to_string());
+        }
+        if let Some(signers) = &self.signers {
+            push("signers", signers.clone());
+        }
+        if let Some(dry_run) = self.dry_run {
+            push("dry_run", dry_run.to_string());
+        }
+        if let Some(interval) = self.interval {
+            push("interval", format_interval(interval));
+        }
+        if let Some(failures) = self.max_failures {
+            push("max_failures", failures.to_string());
+        }
+        if let Some(windows) = &self.quiet_hours {
+            let value = if windows.is_empty() {
+                "none".to_string()
+            } else {
+                let windows = windows.iter().map(|window| String::from(*window));
+                windows.collect::<Vec<_>>().join(",")
+            };
+            push("quiet_hours", value);
+        }
+        if !self.after.is_empty() {
+            push("after", self.after.join(","));
+        }
+        if !self.restart_units.is_empty() {
+            push("restart_units", self.restart_units.join(","));
+        }
+        if let Some(preset) = self.preset {
+            push("preset", preset.as_str().to_string());
+        }
+        if !self.preset_steps.is_empty() {
+            let steps = self.preset_steps.iter().map(|step| step.as_str());
+            push("preset_steps", steps.collect::<Vec<_>>().join(","));
+        }
+        if let Some(output) = &self.preset_output {
+            push("preset_output", output.clone());
+        }
+        if let Some(engine) = self.compose {
+            push("compose", engine.as_str().to_string());
+        }
+        if let Some(key) = &self.ssh_key {
+            push("ssh_key", key.clone());
+        }
+        for (name, value) in &self.env {
+            push(&format!("env.{}", name), value.clone());
+        }
+        options
     }
 
     pub fn to_line(&self) -> String {
-        if self.enabled {
-            self.repo_path.trim().to_string()
+        let options: Vec<String> = self
+            .options()
+            .into_iter()
+            .map(|(key, value)| format!("{}={}", key, quote_value(&value)))
+            .collect();
+
+        let mut line = if self.enabled {
+            self.source_spec()
         } else {
-            format!("! {}", self.repo_path.trim())
+            format!("! {}", self.source_spec())
+        };
+        if !options.is_empty() {
+            line.push_str(" | ");
+            line.push_str(&options.join(" "));
+        }
+        line
+    }
+}
+
+/// Directorios del sistema que nunca pueden ser un repositorio sincronizado: con
+/// `sync_mode=reset` o `clean_untracked=true`, un error tipográfico borraría su contenido.
+#[cfg(not(windows))]
+const PROTECTED_DIRS: &[&str] = &[
+    "/bin", "/boot", "/dev", "/etc", "/home", "/lib", "/lib64", "/opt", "/proc", "/root", "/run",
+    "/sbin", "/srv", "/sys", "/tmp", "/usr", "/var",
+];
+#[cfg(windows)]
+const PROTECTED_DIRS: &[&str] = &[
+    "C:/Windows",
+    "C:/Program Files",
+    "C:/Program Files (x86)",
+    "C:/ProgramData",
+    "C:/Users",
+];
+
+/// Directorio que contiene los directorios personales.
+const HOMES_DIR: &str = if cfg!(windows) { "C:/Users" } else { "/home" };
+
+/// Forma canónica de la ruta local de un repositorio: `~` se expande con `HOME` y se quitan
+/// las barras repetidas o finales y los componentes `.` y `..`. Es una normalización léxica:
+/// los enlaces simbólicos se mantienen (p. ej. un `current` que apunta a la última versión).
+/// Las rutas relativas se devuelven sin cambios para que `check_repo_path` las rechace.
+/// En Windows se conserva la unidad (`C:`) y `\` se escribe como `/`.
+pub fn normalize_repo_path(raw: &str) -> String {
+    let raw = if cfg!(windows) {
+        raw.trim().replace('\\', "/")
+    } else {
+        raw.trim().to_string()
+    };
+    let expanded = match raw.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => match platform::home_dir() {
+            Some(home) => format!("{}{}", home.replace('\\', "/").trim_end_matches('/'), rest),
+            None => raw.clone(),
+        },
+        _ => raw.clone(),
+    };
+    let (drive, rest) = match expanded.as_bytes() {
+        [letter, b':', ..] if cfg!(windows) && letter.is_ascii_alphabetic() => expanded.split_at(2),
+        _ => ("", expanded.as_str()),
+    };
+    if !rest.starts_with('/') {
+        return expanded;
+    }
+
+    let mut parts: Vec<&str> = Vec::new();
+    for part in rest.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            _ => parts.push(part),
+        }
+    }
+    format!("{}/{}", drive, parts.join("/"))
+}
+
+/// Ruta tal como aparece escrita en una línea de `repositories.txt`, antes de normalizarla.
+fn raw_repo_path(line: &str) -> &str {
+    let line = line.trim();
+    let line = line.strip_prefix('!').unwrap_or(line);
+    let source = line.split('|').next().unwrap_or_default();
+    let source = source.split(";;").next().unwrap_or_default();
+    source.rsplit("=>").next().unwrap_or_default().trim()
+}
+
+/// Comprueba que la ruta de un repositorio sea segura antes de clonar, resetear o limpiar en
+/// ella. Con `allowed_roots` no vacío, además debe estar dentro de alguna de esas raíces.
+pub fn check_repo_path(repo_path: &str, allowed_roots: &[String]) -> Result<(), String> {
+    let path = Path::new(repo_path);
+    if !path.is_absolute() {
+        return Err(messages::repo_path_not_absolute(repo_path));
+    }
+    if path.components().any(|c| c == Component::ParentDir) {
+        return Err(messages::repo_path_parent(repo_path));
+    }
+
+    let protected = path.parent().is_none()
+        || PROTECTED_DIRS.iter().any(|dir| path == Path::new(dir))
+        || path.parent() == Some(Path::new(HOMES_DIR))
+        || platform::home_dir().is_some_and(|home| path == Path::new(&home));
+    if protected {
+        return Err(messages::repo_path_protected(repo_path));
+    }
+
+    if !allowed_roots.is_empty() && !allowed_roots.iter().any(|root| path.starts_with(root)) {
+        return Err(messages::repo_path_outside_roots(
+            repo_path,
+            allowed_roots.join(", "),
+        ));
+    }
+
+    Ok(())
+}
+
+/// URLs remotas (`https://`, `ssh://`, `file://`...) o la sintaxis scp `usuario@host:ruta`.
+pub fn is_clone_url(source: &str) -> bool {
+    source.contains("://")
+        || (!source.starts_with('/')
+            && source
+                .split_once(':')
+                .is_some_and(|(host, _)| host.contains('@') && !host.contains('/')))
+}
+
+/// Si dos URLs de remoto apuntan al mismo repositorio: se ignoran la `/` y el `.git`
+/// finales, las credenciales (`usuario:token@`) y las mayúsculas del esquema y el host.
+pub fn same_remote_url(expected: &str, actual: &str) -> bool {
+    normalize_remote_url(expected) == normalize_remote_url(actual)
+}
+
+fn normalize_remote_url(url: &str) -> String {
+    let url = url.trim().trim_end_matches('/');
+    let url = url.strip_suffix(".git").unwrap_or(url);
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return url.to_string();
+    };
+    let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    format!(
+        "{}://{}/{}",
+        scheme.to_lowercase(),
+        host.to_lowercase(),
+        path
+    )
+}
+
+fn parse_interval(value: &str) -> Result<u64, String> {
+    parse_duration(value).ok_or_else(|| messages::invalid_interval(value))
+}
+
+/// Duración en segundos, con sufijo opcional `s`, `m`, `h` o `d` (ej. `30s`, `1h`).
+pub fn parse_duration(value: &str) -> Option<u64> {
+    let (number, unit) = match value.char_indices().last() {
+        Some((index, unit @ ('s' | 'm' | 'h' | 'd'))) => (&value[..index], unit),
+        _ => (value, 's'),
+    };
+    let multiplier = match unit {
+        'm' => 60,
+        'h' => 60 * 60,
+        'd' => 24 * 60 * 60,
+        _ => 1,
+    };
+    number
+        .parse::<u64>()
+        .ok()
+        .filter(|seconds| *seconds > 0)
+        .and_then(|seconds| seconds.checked_mul(multiplier))
+}
+
+pub fn format_interval(seconds: u64) -> String {
+    match seconds {
+        s if s % (24 * 60 * 60) == 0 => format!("{}d", s / (24 * 60 * 60)),
+        s if s % (60 * 60) == 0 => format!("{}h", s / (60 * 60)),
+        s if s % 60 == 0 => format!("{}m", s / 60),
+        s => format!("{}s", s),
+    }
+}
+
+fn parse_repo_name(value: &str) -> Result<String, String> {
+    let valid = !value.is_empty()
+        && !value.starts_with('-')
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid {
+        return Err(messages::invalid_repo_name(value));
+    }
+    Ok(value.to_string())
+}
+
+/// Busca un repositorio por nombre o ruta. Un nombre repetido es ambiguo y se rechaza.
+pub fn find_repo<'r>(
+    repos: &'r [RepoDefinition],
+    reference: &str,
+) -> Result<&'r RepoDefinition, String> {
+    let matches = repos
+        .iter()
+        .filter(|repo| repo.matches_reference(reference))
+        .collect::<Vec<_>>();
+    match matches.as_slice() {
+        [repo] => Ok(repo),
+        [] => Err(messages::repo_not_found(reference)),
+        _ => Err(messages::ambiguous_name(
+            reference,
+            matches
+                .iter()
+                .map(|repo| repo.repo_path.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        )),
+    }
+}
+
+/// Nombre libre para un repositorio nuevo: el del directorio o, si ya existe, con un sufijo
+/// numérico (`app-2`). Devuelve `None` cuando el nombre del directorio está libre.
+pub fn unique_name(repos: &[RepoDefinition], repo: &RepoDefinition) -> Option<String> {
+    let base = repo.name().to_string();
+    let taken = |name: &str| repos.iter().any(|other| other.name() == name);
+    if !taken(&base) {
+        return None;
+    }
+    (2..)
+        .map(|suffix| format!("{}-{}", base, suffix))
+        .find(|name| !taken(name))
+}
+
+fn parse_remote_name(value: &str) -> Result<String, String> {
+    if value.is_empty() || value.contains(char::is_whitespace) {
+        return Err(messages::invalid_remote_name(value));
+    }
+    Ok(value.to_string())
+}
+
+fn parse_branch_name(value: &str) -> Result<String, String> {
+    if value.is_empty()
+        || value.starts_with('-')
+        || value.contains("..")
+        || value.contains(char::is_whitespace)
+    {
+        return Err(messages::invalid_branch(value));
+    }
+    Ok(value.to_string())
+}
+
+fn parse_bool(key: &str, value: &str) -> Result<bool, String> {
+    match value {
+        "true" | "yes" | "1" => Ok(true),
+        "false" | "no" | "0" => Ok(false),
+        _ => Err(messages::invalid_bool(value, key)),
+    }
+}
+
+/// Separa las opciones `clave=valor` de una línea respetando valores entre comillas dobles.
+fn split_options(raw: &str) -> Vec<(String, String)> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for ch in raw.chars() {
+        match ch {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+        .into_iter()
+        .map(|token| match token.split_once('=') {
+            Some((key, value)) => (key.trim().to_string(), value.trim().to_string()),
+            None => (token.trim().to_string(), "true".to_string()),
+        })
+        .collect()
+}
+
+/// Entrecomilla los valores con espacios para que `split_options` los lea completos.
+fn quote_value(value: &str) -> String {
+    if value.contains(char::is_whitespace) {
+        format!("\"{}\"", value)
+    } else {
+        value.to_string()
+    }
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(|item| item.to_string())
+        .collect()
+}
+
+#[cfg(not(target_os = "macos"))]
+const SYSTEM_CONFIG_DIR: &str = "/etc/git-sync";
+#[cfg(not(target_os = "macos"))]
+const SYSTEM_LOG_DIR: &str = "/var/log/git-sync";
+
+// En macOS `/etc` pertenece al sistema: se sigue la convención de Homebrew.
+#[cfg(target_os = "macos")]
+const SYSTEM_CONFIG_DIR: &str = "/usr/local/etc/git-sync";
+#[cfg(target_os = "macos")]
+const SYSTEM_LOG_DIR: &str = "/usr/local/var/log/git-sync";
+
+/// Directorio de la configuración global; en Windows, `%ProgramData%\git-sync`.
+pub fn system_config_dir() -> String {
+    if cfg!(windows) {
+        windows_dir("ProgramData", "C:/ProgramData")
+    } else {
+        SYSTEM_CONFIG_DIR.to_string()
+    }
+}
+
+fn system_log_dir() -> String {
+    if cfg!(windows) {
+        format!("{}/logs", system_config_dir())
+    } else {
+        SYSTEM_LOG_DIR.to_string()
+    }
+}
+
+/// `%<var>%\git-sync`, o `<fallback>\git-sync` si la variable no está definida. Se usa `/`
+/// como separador, que Windows también acepta, igual que en el resto de rutas.
+fn windows_dir(var: &str, fallback: &str) -> String {
+    let base = std::env::var(var)
+        .ok()
+        .filter(|dir| !dir.is_empty())
+        .unwrap_or_else(|| fallback.to_string());
+    format!("{}/git-sync", base.replace('\\', "/").trim_end_matches('/'))
+}
+
+/// `~/Library/<subdir>/git-sync`, las rutas de usuario de macOS.
+fn library_dir(subdir: &str) -> String {
+    format!(
+        "{}/Library/{}/git-sync",
+        std::env::var("HOME")
+            .unwrap_or_default()
+            .trim_end_matches('/'),
+        subdir
+    )
+}
+
+/// `$<var>/git-sync`, o `$HOME/<fallback>/git-sync` si la variable no está definida o no es absoluta.
+fn xdg_dir(var: &str, fallback: &str) -> String {
+    let base = std::env::var(var)
+        .ok()
+        .filter(|dir| Path::new(dir).is_absolute())
+        .unwrap_or_else(|| {
+            format!(
+                "{}/{}",
+                std::env::var("HOME")
+                    .unwrap_or_default()
+                    .trim_end_matches('/'),
+                fallback
+            )
+        });
+    format!("{}/git-sync", base.trim_end_matches('/'))
+}
+
+/// Nombre de instancia a partir del directorio de configuración, apto para una unidad systemd.
+/// Se omite el prefijo `git-sync-` (`/etc/git-sync-acme` → `acme`).
+fn instance_name(dir: &str) -> String {
+    let name: String = Path::new(dir)
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    match name.strip_prefix("git-sync-") {
+        Some(rest) if !rest.is_empty() => rest.to_string(),
+        _ if name.is_empty() => "custom".to_string(),
+        _ => name,
+    }
+}
+
+/// Reescribe el contenido de `repositories.txt` con la lista `repos` tocando solo las
+/// líneas necesarias: los comentarios y las líneas que no se pueden interpretar se
+/// conservan tal cual, cada entrada queda en su posición (se empareja por ruta) y solo
+/// se regenera si cambió o si su ruta no estaba en forma canónica. Las entradas eliminadas desaparecen y las nuevas se añaden al
+/// final; si exactamente una entrada cambió de ruta, se actualiza en su lugar.
+fn merge_repo_lines(existing: &str, repos: &[RepoDefinition]) -> String {
+    let lines: Vec<(&str, Option<RepoDefinition>)> = existing
+        .lines()
+        .map(|line| (line, RepoDefinition::parse_line(line).map(|(repo, _)| repo)))
+        .collect();
+
+    let mut used = vec![false; repos.len()];
+    let mut assigned: Vec<Option<usize>> = lines
+        .iter()
+        .map(|(_, old)| {
+            let old = old.as_ref()?;
+            let index = (0..repos.len())
+                .find(|&index| !used[index] && repos[index].repo_path == old.repo_path)?;
+            used[index] = true;
+            Some(index)
+        })
+        .collect();
+
+    let unmatched_old: Vec<usize> = (0..lines.len())
+        .filter(|&line| lines[line].1.is_some() && assigned[line].is_none())
+        .collect();
+    let unmatched_new: Vec<usize> = (0..repos.len()).filter(|&index| !used[index]).collect();
+    if let ([line], [index]) = (unmatched_old.as_slice(), unmatched_new.as_slice()) {
+        assigned[*line] = Some(*index);
+        used[*index] = true;
+    }
+
+    let mut content = String::new();
+    for ((raw, old), index) in lines.iter().zip(&assigned) {
+        match (old, index) {
+            (None, _) => content.push_str(raw),
+            (Some(_), None) => continue,
+            (Some(old), Some(index)) => {
+                let line = repos[*index].to_line();
+                // Una ruta escrita de otra forma (`~/app`, `/srv/app/`) se guarda canónica.
+                if old.to_line() == line && raw_repo_path(raw) == repos[*index].repo_path {
+                    content.push_str(raw);
+                } else {
+                    content.push_str(&line);
+                }
+            }
         }
+        content.push('\n');
+    }
+    for (repo, _) in repos.iter().zip(&used).filter(|(_, used)| !**used) {
+        content.push_str(&repo.to_line());
+        content.push('\n');
     }
+    content
 }
 
 pub struct Config {
+    /// Configuración del usuario (rutas XDG y servicio systemd de usuario) en lugar de la del sistema
+    pub user_mode: bool,
+    /// Directorio indicado con `--config` o `GIT_SYNC_CONFIG_DIR` (instancia aislada)
+    pub custom_dir: bool,
+    /// Unidad systemd de esta instancia: `git-sync` o `git-sync-<directorio>`
+    pub service_name: String,
     pub config_dir: String,
     pub repos_file: String,
+    /// Bloqueo compartido entre la TUI, el daemon y los comandos que modifican la lista
+    pub repos_lock_file: String,
     pub settings_file: String,
+    pub secrets_file: String,
+    pub secrets_key_file: String,
+    pub credentials_file: String,
+    pub env_file: String,
     pub log_dir: String,
     pub log_file: String,
+    /// Resultado de cada repositorio en cada ciclo, un objeto JSON por línea
+    pub events_file: String,
     pub state_file: String,
+    /// Pausas pedidas con `git-sync pause`
+    pub pause_file: String,
+    /// Repositorio y etapa del ciclo en curso, para la TUI
+    pub activity_file: String,
+    /// PID del supervisor de `git-sync daemon --supervise`
+    pub pid_file: String,
+    /// Socket de control del daemon (`control_socket = true`)
+    pub control_socket: String,
 }
 
 impl Config {
-    pub fn new() -> Self {
-        let (config_dir, log_dir) = ("/etc/git-sync".to_string(), "/var/log/git-sync".to_string());
+    /// Usa `/etc/git-sync` y `/var/log/git-sync` salvo en modo usuario: con `--user`, o al
+    /// ejecutarse sin root en un sistema sin configuración global, la configuración pasa a
+    /// `$XDG_CONFIG_HOME/git-sync` y los registros y el estado a `$XDG_STATE_HOME/git-sync`.
+    /// En macOS son `/usr/local/etc/git-sync` y `/usr/local/var/log/git-sync`, y en modo
+    /// usuario `~/Library/Application Support/git-sync` y `~/Library/Logs/git-sync`. En
+    /// Windows son `%ProgramData%\git-sync` (registros en `logs`) y, en modo usuario,
+    /// `%APPDATA%\git-sync` y `%LOCALAPPDATA%\git-sync\logs`.
+    /// Con un directorio propio (`custom_dir`, ruta absoluta) todo queda dentro de él, con
+    /// los registros y el estado en `<directorio>/logs`.
+    pub fn new(force_user: bool, custom_dir: Option<String>) -> Self {
+        let user_mode = force_user
+            || (!platform::is_root()
+                && (custom_dir.is_some() || !Path::new(&system_config_dir()).exists()));
+        let service_name = match &custom_dir {
+            Some(dir) => format!("git-sync-{}", instance_name(dir)),
+            None => "git-sync".to_string(),
+        };
+        let (config_dir, log_dir) = if let Some(dir) = &custom_dir {
+            let dir = dir.trim_end_matches(['/', '\\']).to_string();
+            let log_dir = format!("{}/logs", dir);
+            (dir, log_dir)
+        } else if user_mode && cfg!(target_os = "macos") {
+            (library_dir("Application Support"), library_dir("Logs"))
+        } else if user_mode && cfg!(windows) {
+            let profile = platform::home_dir().unwrap_or_default();
+            (
+                windows_dir("APPDATA", &format!("{}/AppData/Roaming", profile)),
+                format!(
+                    "{}/logs",
+                    windows_dir("LOCALAPPDATA", &format!("{}/AppData/Local", profile))
+                ),
+            )
+        } else if user_mode {
+            (
+                xdg_dir("XDG_CONFIG_HOME", ".config"),
+                xdg_dir("XDG_STATE_HOME", ".local/state"),
+            )
+        } else {
+            (system_config_dir(), system_log_dir())
+        };
 
         let repos_file = format!("{}/repositories.txt", config_dir);
+        let repos_lock_file = format!("{}/.repositories.lock", config_dir);
         let settings_file = format!("{}/config.toml", config_dir);
+        let secrets_file = format!("{}/secrets.toml", config_dir);
+        let secrets_key_file = format!("{}/age.key", config_dir);
+        let credentials_file = format!("{}/credentials.toml", config_dir);
+        let env_file = format!("{}/environment", config_dir);
         let log_file = format!("{}/git-sync.log", log_dir);
+        let events_file = format!("{}/events.jsonl", log_dir);
         let state_file = format!("{}/state.toml", log_dir);
+        let pause_file = format!("{}/pause.toml", log_dir);
+        let activity_file = format!("{}/activity.toml", log_dir);
+        let pid_file = format!("{}/git-sync.pid", log_dir);
+        let control_socket = format!("{}/git-sync.sock", log_dir);
 
         Config {
+            user_mode,
+            custom_dir: custom_dir.is_some(),
+            service_name,
             config_dir,
             repos_file,
+            repos_lock_file,
             settings_file,
+            secrets_file,
+            secrets_key_file,
+            credentials_file,
+            env_file,
             log_dir,
             log_file,
+            events_file,
             state_file,
+            pause_file,
+            activity_file,
+            pid_file,
+            control_socket,
         }
     }
 
-    pub fn ensure_exists(&self) -> Result<bool, String> {
-        self.ensure_directory(&self.config_dir, 0o755)?;
-        self.ensure_directory(&self.log_dir, 0o755)?;
+    /// Cómo invocar git-sync con esta configuración, para las indicaciones al usuario.
+    pub fn command(&self) -> String {
+        let command = if self.user_mode {
+            "git-sync --user"
+        } else {
+            "sudo git-sync"
+        };
+        if self.custom_dir {
+            format!("{} --config {}", command, quote_value(&self.config_dir))
+        } else {
+            command.to_string()
+        }
+    }
+
+    /// Crea los directorios y archivos de configuración que falten y aplica las migraciones
+    /// pendientes. Devuelve si se acaba de crear `repositories.txt`.
+    pub fn ensure_exists(&self) -> Result<bool, Error> {
+        self.ensure_directory(&self.config_dir, 0o755)
+            .map_err(Error::Config)?;
+        self.ensure_directory(&self.log_dir, 0o755)
+            .map_err(Error::Config)?;
 
-        let repos_created = self.ensure_repos_file()?;
-        self.ensure_settings_file()?;
-        self.ensure_log_file()?;
-        self.ensure_state_file()?;
+        let repos_created = self.ensure_repos_file().map_err(Error::Config)?;
+        self.ensure_settings_file().map_err(Error::Config)?;
+        self.ensure_secrets_file().map_err(Error::Config)?;
+        self.ensure_credentials_file().map_err(Error::Config)?;
+        self.ensure_log_file().map_err(Error::Config)?;
+        self.ensure_state_file().map_err(Error::Config)?;
+        if let Err(err) = migrate::migrate(self) {
+            eprintln!("{}", messages::migration_failed(&self.config_dir, err));
+        }
 
         if repos_created {
-            println!(
-                "\n📌 Agregue las rutas de los repositorios en {} y reinicie el servicio.\n",
-                self.repos_file
-            );
+            println!("{}", messages::add_repos_hint(&self.repos_file));
         }
 
         Ok(repos_created)
@@ -122,41 +1348,24 @@ impl Config {
 
     fn ensure_directory(&self, path: &str, _mode: u32) -> Result<(), String> {
         if !Path::new(path).exists() {
-            fs::create_dir_all(path)
-                .map_err(|e| format!("❌ No se pudo crear el directorio {}: {}", path, e))?;
+            fs::create_dir_all(path).map_err(|e| messages::dir_create_failed(path, e))?;
 
-            let permissions = fs::Permissions::from_mode(_mode);
-            fs::set_permissions(path, permissions)
-                .map_err(|e| format!("❌ No se pudieron asignar permisos a {}: {}", path, e))?;
+            platform::set_mode(path, _mode).map_err(|e| messages::permissions_failed(path, e))?;
 
-            println!("📁 Directorio creado: {}", path);
+            println!("{}", messages::dir_created(path));
         }
         Ok(())
     }
 
     fn ensure_repos_file(&self) -> Result<bool, String> {
         if !Path::new(&self.repos_file).exists() {
-            let default_content = "# Añada rutas absolutas de repositorios Git, una por línea\n\
-                                    # Use rutas locales del servidor (no URLs de GitHub/GitLab)\n\
-                                    # Para desactivar un repo temporalmente, use prefijo !\n\
-                                    # Ejemplo:\n\
-                                    # /var/www/html/mi-app\n";
-            fs::write(&self.repos_file, default_content).map_err(|e| {
-                format!(
-                    "❌ No se pudo crear el archivo de repositorios {}: {}",
-                    self.repos_file, e
-                )
-            })?;
+            fs::write(&self.repos_file, messages::repos_file_template())
+                .map_err(|e| messages::repos_file_create_failed(&self.repos_file, e))?;
 
-            let permissions = fs::Permissions::from_mode(0o644);
-            fs::set_permissions(&self.repos_file, permissions).map_err(|e| {
-                format!(
-                    "❌ No se pudieron asignar permisos a {}: {}",
-                    self.repos_file, e
-                )
-            })?;
+            platform::set_mode(&self.repos_file, 0o644)
+                .map_err(|e| messages::permissions_failed(&self.repos_file, e))?;
 
-            println!("🗂️ Archivo de repositorios creado: {}", self.repos_file);
+            println!("{}", messages::repos_file_created(&self.repos_file));
             return Ok(true);
         }
 
@@ -167,28 +1376,49 @@ impl Config {
         if !Path::new(&self.settings_file).exists() {
             let default_settings = Settings::default();
 
-            let toml_string = toml::to_string_pretty(&default_settings).map_err(|e| {
-                format!(
-                    "❌ No se pudo serializar la configuración predeterminada: {}",
-                    e
-                )
-            })?;
-            fs::write(&self.settings_file, toml_string).map_err(|e| {
-                format!(
-                    "❌ No se pudo crear el archivo de configuración {}: {}",
-                    self.settings_file, e
-                )
-            })?;
+            let toml_string = toml::to_string_pretty(&default_settings)
+                .map_err(messages::default_settings_serialize_failed)?;
+            fs::write(&self.settings_file, toml_string)
+                .map_err(|e| messages::settings_file_create_failed(&self.settings_file, e))?;
 
-            let permissions = fs::Permissions::from_mode(0o644);
-            fs::set_permissions(&self.settings_file, permissions).map_err(|e| {
-                format!(
-                    "❌ No se pudieron asignar permisos a {}: {}",
-                    self.settings_file, e
-                )
-            })?;
+            platform::set_mode(&self.settings_file, 0o644)
+                .map_err(|e| messages::permissions_failed(&self.settings_file, e))?;
+
+            println!("{}", messages::settings_file_created(&self.settings_file));
+        }
+
+        Ok(())
+    }
+
+    fn ensure_secrets_file(&self) -> Result<(), String> {
+        if !Path::new(&self.secrets_file).exists() {
+            fs::write(&self.secrets_file, messages::secrets_file_template())
+                .map_err(|e| messages::secrets_file_create_failed(&self.secrets_file, e))?;
+
+            platform::set_mode(&self.secrets_file, 0o600)
+                .map_err(|e| messages::permissions_failed(&self.secrets_file, e))?;
+
+            println!("{}", messages::secrets_file_created(&self.secrets_file));
+        }
+
+        Ok(())
+    }
+
+    fn ensure_credentials_file(&self) -> Result<(), String> {
+        if !Path::new(&self.credentials_file).exists() {
+            fs::write(
+                &self.credentials_file,
+                messages::credentials_file_template(),
+            )
+            .map_err(|e| messages::credentials_file_create_failed(&self.credentials_file, e))?;
+
+            platform::set_mode(&self.credentials_file, 0o600)
+                .map_err(|e| messages::permissions_failed(&self.credentials_file, e))?;
 
-            println!("⚙️ Archivo de configuración creado: {}", self.settings_file);
+            println!(
+                "{}",
+                messages::credentials_file_created(&self.credentials_file)
+            );
         }
 
         Ok(())
@@ -196,22 +1426,13 @@ impl Config {
 
     fn ensure_log_file(&self) -> Result<(), String> {
         if !Path::new(&self.log_file).exists() {
-            File::create(&self.log_file).map_err(|e| {
-                format!(
-                    "❌ No se pudo crear el archivo de registro {}: {}",
-                    self.log_file, e
-                )
-            })?;
+            File::create(&self.log_file)
+                .map_err(|e| messages::log_file_create_failed(&self.log_file, e))?;
 
-            let permissions = fs::Permissions::from_mode(0o644);
-            fs::set_permissions(&self.log_file, permissions).map_err(|e| {
-                format!(
-                    "❌ No se pudieron asignar permisos a {}: {}",
-                    self.log_file, e
-                )
-            })?;
+            platform::set_mode(&self.log_file, 0o644)
+                .map_err(|e| messages::permissions_failed(&self.log_file, e))?;
 
-            println!("📝 Archivo de registro creado: {}", self.log_file);
+            println!("{}", messages::log_file_created(&self.log_file));
         }
 
         Ok(())
@@ -220,74 +1441,350 @@ impl Config {
     fn ensure_state_file(&self) -> Result<(), String> {
         if !Path::new(&self.state_file).exists() {
             let default_state = SyncStateSnapshot::default();
-            let content = toml::to_string_pretty(&default_state).map_err(|e| {
-                format!(
-                    "❌ No se pudo serializar el estado inicial de sincronización: {}",
-                    e
-                )
-            })?;
+            let content = toml::to_string_pretty(&default_state)
+                .map_err(messages::initial_state_serialize_failed)?;
 
-            fs::write(&self.state_file, content).map_err(|e| {
-                format!(
-                    "❌ No se pudo crear el archivo de estado {}: {}",
-                    self.state_file, e
-                )
-            })?;
+            fs::write(&self.state_file, content)
+                .map_err(|e| messages::state_file_create_failed(&self.state_file, e))?;
 
-            let permissions = fs::Permissions::from_mode(0o644);
-            fs::set_permissions(&self.state_file, permissions).map_err(|e| {
-                format!(
-                    "❌ No se pudieron asignar permisos a {}: {}",
-                    self.state_file, e
-                )
-            })?;
+            platform::set_mode(&self.state_file, 0o644)
+                .map_err(|e| messages::permissions_failed(&self.state_file, e))?;
 
-            println!("📊 Archivo de estado creado: {}", self.state_file);
+            println!("{}", messages::state_file_created(&self.state_file));
         }
 
         Ok(())
     }
 
-    pub fn read_repos(&self) -> Vec<RepoDefinition> {
-        let contents = fs::read_to_string(&self.repos_file).unwrap_or_else(|e| {
-            panic!(
-                "❌ No se pudo leer el archivo de repositorios {}: {}",
-                self.repos_file, e
-            )
-        });
+    /// Indica si el usuario actual puede modificar el archivo de repositorios.
+    pub fn can_write_repos(&self) -> bool {
+        OpenOptions::new()
+            .append(true)
+            .open(&self.repos_file)
+            .is_ok()
+    }
+
+    /// Bloqueo advisory (`flock`) sobre `.repositories.lock`: compartido para leer y
+    /// exclusivo para modificar la lista. No se bloquea `repositories.txt` en sí porque
+    /// se reemplaza con `rename` en cada escritura. Si el archivo de bloqueo no se puede
+    /// abrir (ej. un usuario sin permisos sobre el directorio) se continúa sin bloqueo.
+    fn lock_repos(&self, exclusive: bool) -> Option<File> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&self.repos_lock_file)
+            .or_else(|_| File::open(&self.repos_lock_file))
+            .ok()?;
+        let locked = if exclusive {
+            file.lock()
+        } else {
+            file.lock_shared()
+        };
+        locked.ok().map(|_| file)
+    }
+
+    /// Lee la lista de repositorios con el bloqueo compartido, de modo que nunca se
+    /// observa una escritura a medias de otro proceso.
+    pub fn try_read_repos(&self) -> Result<Vec<RepoDefinition>, Error> {
+        self.read_repos_with_warnings().map(|(repos, _)| repos)
+    }
+
+    /// Como `try_read_repos`, pero también devuelve los avisos de la lista: opciones
+    /// ignoradas y rutas o nombres repetidos.
+    pub fn read_repos_with_warnings(&self) -> Result<(Vec<RepoDefinition>, Vec<String>), Error> {
+        let _lock = self.lock_repos(false);
+        self.read_repos_unlocked()
+    }
+
+    fn read_repos_unlocked(&self) -> Result<(Vec<RepoDefinition>, Vec<String>), Error> {
+        let contents = fs::read_to_string(&self.repos_file)
+            .map_err(|e| Error::Config(messages::repos_file_read_failed(&self.repos_file, e)))?;
+
+        let mut warnings = Vec::new();
+        let mut repos: Vec<RepoDefinition> = Vec::new();
+        for (number, line) in contents.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let Some((repo, line_warnings)) = RepoDefinition::parse_line(line) else {
+                warnings.push(messages::line_unparsable(number + 1, trimmed));
+                continue;
+            };
+            warnings.extend(line_warnings);
+            repos.push(repo);
+        }
+
+        for (index, repo) in repos.iter().enumerate() {
+            if repos[..index]
+                .iter()
+                .any(|other| other.repo_path == repo.repo_path)
+            {
+                warnings.push(messages::duplicate_repo_path(
+                    &repo.repo_path,
+                    &self.repos_file,
+                ));
+            } else if let Some(first) = repos[..index]
+                .iter()
+                .find(|other| other.name() == repo.name())
+            {
+                warnings.push(messages::duplicate_repo_name(
+                    repo.name(),
+                    &first.repo_path,
+                    &repo.repo_path,
+                ));
+            }
+        }
+        Ok((repos, warnings))
+    }
+
+    pub fn write_repos(&self, repos: &[RepoDefinition]) -> Result<(), Error> {
+        let _lock = self.lock_repos(true);
+        self.write_repos_unlocked(repos).map_err(Error::Config)
+    }
+
+    /// Lee, modifica y guarda la lista con el bloqueo exclusivo durante todo el proceso,
+    /// para no perder los cambios que otro proceso haga entre la lectura y la escritura.
+    /// Solo se escribe si `update` cambió algo.
+    pub fn update_repos<T>(
+        &self,
+        update: impl FnOnce(&mut Vec<RepoDefinition>) -> Result<T, String>,
+    ) -> Result<T, Error> {
+        let _lock = self.lock_repos(true);
+        let (mut repos, _) = self.read_repos_unlocked()?;
+        let before: Vec<String> = repos.iter().map(RepoDefinition::to_line).collect();
+        let result = update(&mut repos).map_err(Error::Config)?;
+        if repos.iter().map(RepoDefinition::to_line).ne(before) {
+            self.write_repos_unlocked(&repos).map_err(Error::Config)?;
+        }
+        Ok(result)
+    }
 
-        contents
-            .lines()
-            .filter_map(RepoDefinition::from_line)
-            .collect()
+    /// Aplica `edit` al texto de `repositories.txt` con el bloqueo exclusivo y lo guarda si
+    /// cambió. Para transformaciones que no pasan por `RepoDefinition`, como las migraciones.
+    pub fn rewrite_repos_file(&self, edit: impl FnOnce(&str) -> String) -> Result<(), Error> {
+        let _lock = self.lock_repos(true);
+        let Ok(contents) = fs::read_to_string(&self.repos_file) else {
+            return Ok(());
+        };
+        let edited = edit(&contents);
+        if edited != contents {
+            self.replace_repos_file(&edited).map_err(Error::Config)?;
+        }
+        Ok(())
     }
 
-    pub fn write_repos(&self, repos: &[RepoDefinition]) -> Result<(), String> {
-        let mut content = String::from("# Lista de repositorios administrada por git-sync\n");
-        content.push_str("# Especifique una ruta absoluta por línea (ruta local, no URL remota)\n");
-        content.push_str("# Para desactivar un repo temporalmente use: ! /ruta/al/repo\n");
-        content.push_str("# Ejemplo:\n");
-        content.push_str("#   /var/www/html/mi-app\n");
+    /// Si el archivo ya tiene contenido se conservan sus comentarios, líneas en blanco y
+    /// el formato de las entradas que no cambiaron (ver `merge_repo_lines`).
+    fn write_repos_unlocked(&self, repos: &[RepoDefinition]) -> Result<(), String> {
+        if let Ok(existing) = fs::read_to_string(&self.repos_file)
+            && !existing.trim().is_empty()
+        {
+            return self.replace_repos_file(&merge_repo_lines(&existing, repos));
+        }
+
+        let mut content = messages::repos_list_header();
         for repo in repos {
             content.push_str(&repo.to_line());
             content.push('\n');
         }
 
-        fs::write(&self.repos_file, content).map_err(|e| {
-            format!(
-                "❌ No se pudo escribir en el archivo de repositorios {}: {}",
-                self.repos_file, e
-            )
-        })?;
+        self.replace_repos_file(&content)
+    }
 
-        let permissions = fs::Permissions::from_mode(0o644);
-        fs::set_permissions(&self.repos_file, permissions).map_err(|e| {
-            format!(
-                "❌ No se pudieron asignar permisos a {}: {}",
-                self.repos_file, e
-            )
-        })?;
+    /// Escribe la lista en un archivo temporal y lo renombra sobre `repositories.txt`, de
+    /// modo que los lectores ven el archivo anterior o el nuevo, nunca uno truncado. Se
+    /// conservan el propietario y el grupo del archivo original. Si no se puede crear el
+    /// temporal (sin permisos sobre el directorio) se escribe directamente en el archivo.
+    fn replace_repos_file(&self, content: &str) -> Result<(), String> {
+        let write_error =
+            |e: std::io::Error| messages::repos_file_write_failed(&self.repos_file, e);
+        let permissions_error =
+            |e: std::io::Error| messages::permissions_failed(&self.repos_file, e);
 
-        Ok(())
+        let temp_file = format!("{}.tmp", self.repos_file);
+        let mut file = match File::create(&temp_file) {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::PermissionDenied => {
+                fs::write(&self.repos_file, content).map_err(write_error)?;
+                return platform::set_mode(&self.repos_file, 0o644).map_err(permissions_error);
+            }
+            Err(e) => return Err(write_error(e)),
+        };
+
+        let result = file
+            .write_all(content.as_bytes())
+            .map_err(write_error)
+            .and_then(|_| platform::set_file_mode(&file, 0o644).map_err(permissions_error))
+            .and_then(|_| {
+                if let Ok(metadata) = fs::metadata(&self.repos_file) {
+                    platform::copy_owner(&file, &metadata);
+                }
+                file.sync_all().map_err(write_error)
+            })
+            .and_then(|_| fs::rename(&temp_file, &self.repos_file).map_err(write_error));
+        if result.is_err() {
+            let _ = fs::remove_file(&temp_file);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(raw: &str) -> Option<(String, Option<String>)> {
+        RepoDefinition::parse_source(raw, &mut Vec::new())
+    }
+
+    fn parse(line: &str) -> RepoDefinition {
+        let (repo, warnings) = RepoDefinition::parse_line(line).expect("línea válida");
+        assert!(warnings.is_empty(), "avisos inesperados: {:?}", warnings);
+        repo
+    }
+
+    #[test]
+    fn parse_source_accepts_paths_and_clone_urls() {
+        assert_eq!(
+            source("/srv/www/app"),
+            Some(("/srv/www/app".to_string(), None))
+        );
+        assert_eq!(
+            source(" https://github.com/org/app.git => /srv/www/app "),
+            Some((
+                "/srv/www/app".to_string(),
+                Some("https://github.com/org/app.git".to_string())
+            ))
+        );
+        assert_eq!(
+            source("git@github.com:org/app.git => /srv/www/app"),
+            Some((
+                "/srv/www/app".to_string(),
+                Some("git@github.com:org/app.git".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_source_keeps_the_source_of_legacy_local_entries() {
+        let mut warnings = Vec::new();
+        assert_eq!(
+            RepoDefinition::parse_source("/srv/src/app => /srv/www/app", &mut warnings),
+            Some(("/srv/src/app".to_string(), None))
+        );
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn parse_source_rejects_empty_entries() {
+        assert_eq!(source("   "), None);
+        assert_eq!(source("=> /srv/www/app"), None);
+        assert_eq!(source("https://github.com/org/app.git =>"), None);
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn parse_source_normalizes_the_path() {
+        assert_eq!(
+            source("/srv//www/./app/"),
+            Some(("/srv/www/app".to_string(), None))
+        );
+    }
+
+    #[test]
+    fn parse_line_skips_comments_and_blank_lines() {
+        assert!(RepoDefinition::parse_line("").is_none());
+        assert!(RepoDefinition::parse_line("  # /srv/www/app").is_none());
+    }
+
+    #[test]
+    fn parse_line_reads_options() {
+        let repo = parse(
+            "! https://github.com/org/app.git => /srv/www/app | name=app tags=web,prod interval=90 clean=true env.GIT_SSH_COMMAND=\"ssh -i /etc/keys/app\"",
+        );
+        assert!(!repo.enabled);
+        assert_eq!(repo.repo_path, "/srv/www/app");
+        assert_eq!(
+            repo.clone_url.as_deref(),
+            Some("https://github.com/org/app.git")
+        );
+        assert_eq!(repo.name(), "app");
+        assert_eq!(repo.groups, vec!["web", "prod"]);
+        assert_eq!(repo.interval, Some(90));
+        assert!(repo.clean);
+        assert_eq!(
+            repo.env,
+            vec![(
+                "GIT_SSH_COMMAND".to_string(),
+                "ssh -i /etc/keys/app".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_line_warns_about_invalid_options() {
+        let (repo, warnings) =
+            RepoDefinition::parse_line("/srv/www/app | fetch_depth=muchos env.MY-VAR=1").unwrap();
+        assert_eq!(repo.fetch_depth, None);
+        assert!(repo.env.is_empty());
+        assert_eq!(warnings.len(), 2);
+    }
+
+    #[test]
+    fn apply_option_replaces_repeated_env_vars() {
+        let mut repo = RepoDefinition::new("/srv/www/app".to_string());
+        repo.apply_option("env.NODE_ENV", "development").unwrap();
+        repo.apply_option("env.NODE_ENV", "production").unwrap();
+        assert_eq!(
+            repo.env,
+            vec![("NODE_ENV".to_string(), "production".to_string())]
+        );
+    }
+
+    #[test]
+    fn apply_option_rejects_unknown_keys_and_values() {
+        let mut repo = RepoDefinition::new("/srv/www/app".to_string());
+        assert!(repo.apply_option("colour", "blue").is_err());
+        assert!(repo.apply_option("sync_mode", "sideways").is_err());
+        assert!(repo.apply_option("track", "tag:").is_err());
+        assert!(repo.apply_option("interval", "soon").is_err());
+    }
+
+    #[test]
+    fn to_line_round_trips() {
+        let lines = [
+            "/srv/www/app",
+            "! /srv/www/app",
+            "https://github.com/org/app.git => /srv/www/app | name=app groups=web,prod clean_untracked=true interval=1h",
+            "/srv/www/app | branch=main quiet_hours=22:00-06:00 after=api,db env.GIT_SSH_COMMAND=\"ssh -i /etc/keys/app\"",
+            "/srv/git/app.git | sync_mode=bare fetch_depth=50 fetch_prune=true",
+        ];
+        for line in lines {
+            let repo = parse(line);
+            assert_eq!(repo.to_line(), line);
+            assert_eq!(parse(&repo.to_line()).to_line(), line);
+        }
+    }
+
+    #[test]
+    fn to_line_writes_the_canonical_clean_key() {
+        assert_eq!(
+            parse("/srv/www/app | clean=true").to_line(),
+            "/srv/www/app | clean_untracked=true"
+        );
+    }
+
+    #[test]
+    fn format_interval_uses_the_largest_exact_unit() {
+        assert_eq!(format_interval(45), "45s");
+        assert_eq!(format_interval(120), "2m");
+        assert_eq!(format_interval(7200), "2h");
+        assert_eq!(format_interval(86400), "1d");
+        assert_eq!(parse_duration("90"), Some(90));
+        assert_eq!(parse_duration("2h"), Some(7200));
+        assert_eq!(parse_duration("h"), None);
     }
 }
@@ -0,0 +1,131 @@
+use crate::config::Config;
+use crate::messages;
+#[cfg(target_os = "linux")]
+use std::ffi::CString;
+#[cfg(target_os = "linux")]
+use std::fs::File;
+#[cfg(target_os = "linux")]
+use std::io::{ErrorKind, Read};
+#[cfg(target_os = "linux")]
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+#[cfg(target_os = "linux")]
+use std::path::Path;
+
+/// Cabecera fija de cada `struct inotify_event`: wd, mask, cookie y len.
+#[cfg(target_os = "linux")]
+const EVENT_HEADER_LEN: usize = 16;
+
+/// Vigila con inotify el directorio de configuración para despertar al daemon en cuanto
+/// cambia alguno de sus archivos (lista de repositorios, ajustes, entorno, secretos o
+/// credenciales), en lugar de esperar al final del intervalo. Solo existe en Linux; en
+/// otros sistemas (macOS) los cambios se aplican en el siguiente ciclo.
+#[cfg(target_os = "linux")]
+pub struct ConfigWatcher {
+    events: File,
+    /// Nombres de los archivos que interesan dentro del directorio
+    files: Vec<String>,
+}
+
+#[cfg(not(target_os = "linux"))]
+pub struct ConfigWatcher;
+
+#[cfg(not(target_os = "linux"))]
+impl ConfigWatcher {
+    pub fn new(_config: &Config) -> Result<Self, String> {
+        Err(messages::config_watch_unsupported())
+    }
+
+    pub fn changed(&mut self) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl ConfigWatcher {
+    pub fn new(config: &Config) -> Result<Self, String> {
+        let dir = CString::new(config.config_dir.as_str())
+            .map_err(|_| messages::invalid_config_path(&config.config_dir))?;
+        let error = |e: std::io::Error| messages::inotify_failed(&config.config_dir, e);
+
+        // SAFETY: inotify_init1 no recibe punteros; el descriptor devuelto pasa a ser
+        // propiedad de `OwnedFd`, que lo cierra al soltarse.
+        let fd = unsafe { libc::inotify_init1(libc::IN_NONBLOCK | libc::IN_CLOEXEC) };
+        if fd < 0 {
+            return Err(error(std::io::Error::last_os_error()));
+        }
+        let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+        // Las escrituras en el sitio terminan con IN_CLOSE_WRITE y los reemplazos atómicos
+        // (archivo temporal + rename, como hacen la TUI y muchos editores) con IN_MOVED_TO.
+        let mask = libc::IN_CLOSE_WRITE | libc::IN_MOVED_TO | libc::IN_DELETE;
+        // SAFETY: `dir` es una cadena C válida durante toda la llamada.
+        if unsafe { libc::inotify_add_watch(fd.as_raw_fd(), dir.as_ptr(), mask) } < 0 {
+            return Err(error(std::io::Error::last_os_error()));
+        }
+
+        let files = [
+            &config.repos_file,
+            &config.settings_file,
+            &config.env_file,
+            &config.secrets_file,
+            &config.credentials_file,
+        ]
+        .iter()
+        .filter_map(|file| Path::new(file).file_name()?.to_str().map(str::to_string))
+        .collect();
+
+        Ok(ConfigWatcher {
+            events: File::from(fd),
+            files,
+        })
+    }
+
+    /// Consume los eventos pendientes y devuelve el nombre del primer archivo vigilado que
+    /// cambió desde la última llamada, si hay alguno.
+    pub fn changed(&mut self) -> Option<String> {
+        let mut buffer = [0u8; 4096];
+        let mut changed = None;
+
+        loop {
+            let read = match self.events.read(&mut buffer) {
+                Ok(read) if read > 0 => read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                // WouldBlock: no quedan eventos.
+                _ => return changed,
+            };
+
+            let mut offset = 0;
+            while offset + EVENT_HEADER_LEN <= read {
+                let field = |index: usize| {
+                    let start = offset + index * 4;
+                    u32::from_ne_bytes(buffer[start..start + 4].try_into().unwrap_or_default())
+                };
+                let mask = field(1);
+                let len = field(3) as usize;
+                let name_start = offset + EVENT_HEADER_LEN;
+                let name_end = (name_start + len).min(read);
+                let name = String::from_utf8_lossy(&buffer[name_start..name_end])
+                    .trim_end_matches('\0')
+                    .to_string();
+                offset = name_end;
+
+                if changed.is_some() {
+                    continue;
+                }
+                if mask & libc::IN_Q_OVERFLOW != 0 {
+                    changed = Some(String::new());
+                } else if self.is_watched(&name) {
+                    changed = Some(name);
+                }
+            }
+        }
+    }
+
+    /// Los secretos y las credenciales pueden estar cifrados (`<archivo>.age`, `<archivo>.sops`).
+    fn is_watched(&self, name: &str) -> bool {
+        self.files.iter().any(|file| {
+            name.strip_prefix(file.as_str())
+                .is_some_and(|ext| ext.is_empty() || ext == ".age" || ext == ".sops")
+        })
+    }
+}
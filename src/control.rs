@@ -0,0 +1,281 @@
+use crate::messages;
+#[cfg(unix)]
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+#[cfg(unix)]
+use std::os::fd::FromRawFd;
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+#[cfg(unix)]
+use std::sync::{Arc, Mutex};
+#[cfg(unix)]
+use std::thread;
+#[cfg(unix)]
+use std::time::Duration;
+
+/// Primer descriptor que pasa systemd con la activación por socket (`SD_LISTEN_FDS_START`).
+#[cfg(unix)]
+const LISTEN_FDS_START: i32 = 3;
+
+/// Tiempo máximo para leer la orden de un cliente, para que uno lento no bloquee el daemon.
+#[cfg(unix)]
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Qué pidió sincronizar un cliente del socket de control (o SIGUSR1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncRequest {
+    /// Todos los repositorios (`sync`)
+    All,
+    /// Solo estos repositorios, por nombre o ruta (`sync <repositorio>`)
+    Repos(Vec<String>),
+}
+
+impl SyncRequest {
+    /// Une dos pedidos recibidos antes de atenderlos; `All` incluye a cualquier otro.
+    pub fn merge(self, other: SyncRequest) -> SyncRequest {
+        match (self, other) {
+            (SyncRequest::Repos(mut repos), SyncRequest::Repos(others)) => {
+                repos.extend(others);
+                SyncRequest::Repos(repos)
+            }
+            _ => SyncRequest::All,
+        }
+    }
+}
+
+/// Clientes de `watch` conectados al socket de control, a los que el daemon envía cada
+/// evento de sincronización como un objeto JSON por línea.
+#[derive(Clone, Default)]
+pub struct EventStream {
+    #[cfg(unix)]
+    clients: Arc<Mutex<Vec<UnixStream>>>,
+}
+
+impl EventStream {
+    /// Envía `event` a todos los clientes; los que se desconectaron o no leen a tiempo se
+    /// descartan.
+    #[cfg(unix)]
+    pub fn publish(&self, event: &serde_json::Value) {
+        let Ok(mut clients) = self.clients.lock() else {
+            return;
+        };
+        if clients.is_empty() {
+            return;
+        }
+        let line = format!("{}\n", event);
+        clients.retain_mut(|client| client.write_all(line.as_bytes()).is_ok());
+    }
+
+    #[cfg(not(unix))]
+    pub fn publish(&self, _event: &serde_json::Value) {}
+
+    #[cfg(unix)]
+    fn subscribe(&self, client: UnixStream) {
+        if let Ok(mut clients) = self.clients.lock() {
+            clients.push(client);
+        }
+    }
+}
+
+/// Socket de control del daemon: cada conexión envía una orden por línea (`sync` para
+/// sincronizar de inmediato, como SIGUSR1, `sync <repositorio>` para sincronizar solo ese
+/// repositorio, `ping`, o `watch` para recibir los eventos de sincronización) y recibe una
+/// línea de respuesta. Las conexiones se atienden en un hilo propio, también durante un
+/// ciclo. Con la activación por socket de systemd (`git-sync.socket`, `Accept=no`) el socket
+/// lo crea systemd con sus permisos y lo pasa al daemon, que se arranca con la primera
+/// conexión; sin ella, el daemon lo crea en `control_socket`.
+#[cfg(unix)]
+pub struct ControlSocket {
+    /// Sincronizaciones pedidas desde la última consulta
+    requested: Arc<Mutex<Option<SyncRequest>>>,
+    /// Ruta del socket creado por el daemon, que se elimina al terminar
+    owned_path: Option<String>,
+}
+
+#[cfg(unix)]
+impl ControlSocket {
+    /// El socket que pasó systemd o, si no hay activación, uno nuevo en `path`. Los clientes
+    /// de `watch` se añaden a `events`.
+    pub fn open(path: &str, events: EventStream) -> Result<Self, String> {
+        let (listener, owned_path) = match Self::from_systemd() {
+            Some(listener) => (listener, None),
+            None => (Self::bind(path)?, Some(path.to_string())),
+        };
+        listener
+            .set_nonblocking(false)
+            .map_err(messages::control_socket_setup_failed)?;
+
+        let requested: Arc<Mutex<Option<SyncRequest>>> = Arc::new(Mutex::new(None));
+        let pending = Arc::clone(&requested);
+        thread::Builder::new()
+            .name("control".to_string())
+            .spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    if let Some(request) = handle_client(stream, &events)
+                        && let Ok(mut pending) = pending.lock()
+                    {
+                        *pending = Some(match pending.take() {
+                            Some(previous) => previous.merge(request),
+                            None => request,
+                        });
+                    }
+                }
+            })
+            .map_err(messages::control_socket_setup_failed)?;
+
+        Ok(ControlSocket {
+            requested,
+            owned_path,
+        })
+    }
+
+    /// Si el daemon se inició con activación por socket (`LISTEN_PID`/`LISTEN_FDS`).
+    pub fn is_socket_activated(&self) -> bool {
+        self.owned_path.is_none()
+    }
+
+    fn from_systemd() -> Option<UnixListener> {
+        let pid: u32 = std::env::var("LISTEN_PID").ok()?.parse().ok()?;
+        let fds: i32 = std::env::var("LISTEN_FDS").ok()?.parse().ok()?;
+        if pid != std::process::id() || fds < 1 {
+            return None;
+        }
+        // systemd no marca el descriptor como close-on-exec: sin esto lo heredarían los
+        // comandos de despliegue.
+        // SAFETY: systemd garantiza que el descriptor 3 es el socket abierto de la unidad.
+        unsafe {
+            libc::fcntl(LISTEN_FDS_START, libc::F_SETFD, libc::FD_CLOEXEC);
+            Some(UnixListener::from_raw_fd(LISTEN_FDS_START))
+        }
+    }
+
+    fn bind(path: &str) -> Result<UnixListener, String> {
+        // Un socket que quedó de una ejecución anterior no acepta conexiones.
+        if UnixStream::connect(path).is_ok() {
+            return Err(messages::control_socket_in_use(path));
+        }
+        let _ = std::fs::remove_file(path);
+        let listener = UnixListener::bind(path)
+            .map_err(|e| messages::control_socket_create_failed(path, e))?;
+        crate::platform::set_mode(path, 0o660).map_err(|e| messages::mode_set_failed(path, e))?;
+        Ok(listener)
+    }
+
+    /// Lo que pidieron sincronizar los clientes desde la última consulta, si algo.
+    pub fn sync_requested(&self) -> Option<SyncRequest> {
+        self.requested.lock().ok()?.take()
+    }
+}
+
+#[cfg(unix)]
+impl Drop for ControlSocket {
+    fn drop(&mut self) {
+        if let Some(path) = &self.owned_path {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// Lee una orden y responde. Devuelve lo que pide sincronizar si era `sync`; con `watch` la
+/// conexión queda abierta y pasa a `events`.
+#[cfg(unix)]
+fn handle_client(mut stream: UnixStream, events: &EventStream) -> Option<SyncRequest> {
+    let _ = stream.set_read_timeout(Some(CLIENT_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(CLIENT_TIMEOUT));
+
+    let mut line = String::new();
+    if let Ok(reader) = stream.try_clone() {
+        let _ = BufReader::new(reader).read_line(&mut line);
+    }
+    let (reply, sync) = match line.trim() {
+        "sync" => ("ok\n".to_string(), Some(SyncRequest::All)),
+        "ping" => ("pong\n".to_string(), None),
+        "watch" => {
+            if stream.write_all(b"ok\n").is_ok() {
+                events.subscribe(stream);
+            }
+            return None;
+        }
+        command => match command.strip_prefix("sync ").map(str::trim) {
+            Some(repo) if !repo.is_empty() => (
+                "ok\n".to_string(),
+                Some(SyncRequest::Repos(vec![repo.to_string()])),
+            ),
+            _ => (messages::control_unknown_command(), None),
+        },
+    };
+    let _ = stream.write_all(reply.as_bytes());
+    sync
+}
+
+/// Envía `command` al daemon por el socket de `path` y devuelve su respuesta. `None` si no
+/// respondió a tiempo.
+#[cfg(unix)]
+pub fn send(path: &str, command: &str) -> Result<Option<String>, String> {
+    let mut stream =
+        UnixStream::connect(path).map_err(|e| messages::control_connect_failed(path, e))?;
+    let _ = stream.set_read_timeout(Some(CLIENT_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(CLIENT_TIMEOUT));
+    stream
+        .write_all(format!("{}\n", command).as_bytes())
+        .map_err(|e| messages::control_connect_failed(path, e))?;
+
+    let mut reply = String::new();
+    match BufReader::new(stream).read_line(&mut reply) {
+        Ok(_) => Ok(Some(reply.trim().to_string())),
+        Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => Ok(None),
+        Err(e) => Err(messages::control_connect_failed(path, e)),
+    }
+}
+
+/// Se suscribe a los eventos de sincronización del daemon con `watch`; cada línea que se lee
+/// después es un objeto JSON.
+#[cfg(unix)]
+pub fn watch(path: &str) -> Result<Box<dyn BufRead>, String> {
+    let mut stream =
+        UnixStream::connect(path).map_err(|e| messages::control_connect_failed(path, e))?;
+    let _ = stream.set_read_timeout(Some(CLIENT_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(CLIENT_TIMEOUT));
+    stream
+        .write_all(b"watch\n")
+        .map_err(|e| messages::control_connect_failed(path, e))?;
+
+    let mut reader = BufReader::new(stream);
+    let mut reply = String::new();
+    reader
+        .read_line(&mut reply)
+        .map_err(|e| messages::control_connect_failed(path, e))?;
+    if reply.trim() != "ok" {
+        return Err(messages::watch_rejected(path, reply.trim()));
+    }
+    // Entre ciclos el daemon puede pasar horas sin enviar nada.
+    let _ = reader.get_ref().set_read_timeout(None);
+    Ok(Box::new(reader))
+}
+
+#[cfg(not(unix))]
+pub struct ControlSocket;
+
+#[cfg(not(unix))]
+impl ControlSocket {
+    pub fn open(_path: &str, _events: EventStream) -> Result<Self, String> {
+        Err(messages::control_socket_unsupported())
+    }
+
+    pub fn is_socket_activated(&self) -> bool {
+        false
+    }
+
+    pub fn sync_requested(&self) -> Option<SyncRequest> {
+        None
+    }
+}
+
+#[cfg(not(unix))]
+pub fn send(_path: &str, _command: &str) -> Result<Option<String>, String> {
+    Err(messages::control_socket_unsupported())
+}
+
+#[cfg(not(unix))]
+pub fn watch(_path: &str) -> Result<Box<dyn std::io::BufRead>, String> {
+    Err(messages::control_socket_unsupported())
+}
@@ -0,0 +1,116 @@
+use crate::messages;
+use crate::platform;
+use crate::secrets::read_protected;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Usuario por defecto cuando solo se indica el token; GitHub, GitLab y Gitea lo aceptan.
+const DEFAULT_USERNAME: &str = "git";
+
+/// Token de un host, como texto (`"github.com" = "ghp_..."`) o con usuario explícito.
+#[derive(Clone, Deserialize)]
+#[serde(untagged)]
+enum HostEntry {
+    Token(String),
+    Full {
+        #[serde(default)]
+        username: Option<String>,
+        token: String,
+    },
+}
+
+#[derive(Default, Deserialize)]
+struct CredentialsFile {
+    #[serde(default)]
+    hosts: BTreeMap<String, HostEntry>,
+}
+
+/// Usuario y token para autenticarse por HTTPS en un host.
+#[derive(Clone)]
+pub struct HostCredential {
+    pub host: String,
+    pub username: String,
+    pub token: String,
+}
+
+/// Credenciales HTTPS por host leídas de `credentials.toml`.
+///
+/// No implementa `Debug` a propósito: los tokens no deben acabar en ningún registro.
+#[derive(Clone, Default)]
+pub struct Credentials {
+    hosts: Vec<HostCredential>,
+}
+
+impl Credentials {
+    /// Carga el archivo de credenciales. Como los secretos, admite las variantes cifradas
+    /// `.age` y `.sops`; en texto plano se exige que solo lo pueda leer su propietario.
+    pub fn load(path: &str, key_file: &str) -> Result<Self, String> {
+        check_permissions(path)?;
+        let Some(contents) = read_protected(path, key_file)? else {
+            return Ok(Credentials::default());
+        };
+
+        let file: CredentialsFile =
+            toml::from_str(&contents).map_err(|e| messages::credentials_parse_failed(path, e))?;
+
+        let hosts = file
+            .hosts
+            .into_iter()
+            .map(|(host, entry)| {
+                let (username, token) = match entry {
+                    HostEntry::Token(token) => (None, token),
+                    HostEntry::Full { username, token } => (username, token),
+                };
+                HostCredential {
+                    host: host.to_ascii_lowercase(),
+                    username: username.unwrap_or_else(|| DEFAULT_USERNAME.to_string()),
+                    token,
+                }
+            })
+            .collect();
+
+        Ok(Credentials { hosts })
+    }
+
+    pub fn hosts(&self) -> &[HostCredential] {
+        &self.hosts
+    }
+
+    /// Tokens de todos los hosts, para ocultarlos en el registro.
+    pub fn tokens(&self) -> Vec<String> {
+        self.hosts.iter().map(|host| host.token.clone()).collect()
+    }
+
+    /// Credencial para una URL `https://`, buscando primero `host:puerto` y luego el host.
+    pub fn for_url(&self, url: &str) -> Option<&HostCredential> {
+        let authority = url.strip_prefix("https://")?.split('/').next()?;
+        let authority = authority
+            .rsplit_once('@')
+            .map_or(authority, |(_, host)| host)
+            .to_ascii_lowercase();
+        let hostname = authority.split(':').next().unwrap_or_default();
+
+        self.hosts
+            .iter()
+            .find(|credential| credential.host == authority)
+            .or_else(|| {
+                self.hosts
+                    .iter()
+                    .find(|credential| credential.host == hostname)
+            })
+    }
+}
+
+fn check_permissions(path: &str) -> Result<(), String> {
+    let Ok(metadata) = fs::metadata(Path::new(path)) else {
+        return Ok(());
+    };
+
+    if !platform::is_private(&metadata) {
+        return Err(messages::credentials_not_private(path));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use crate::config::{RepoDefinition, SyncMode};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Repositorios Git encontrados en los directorios que coinciden con los patrones de
+/// `discover`, como entradas simples sin opciones ni despliegue. Se omiten las rutas
+/// que ya están en la lista de repositorios (incluidas las pausadas), de modo que se
+/// pueden configurar o desactivar añadiéndolas allí.
+pub fn discover_repos(patterns: &[String], known: &[RepoDefinition]) -> Vec<RepoDefinition> {
+    let mut found: Vec<RepoDefinition> = Vec::new();
+    for pattern in patterns {
+        for path in expand(pattern) {
+            let listed = known
+                .iter()
+                .chain(found.iter())
+                .any(|repo| Path::new(&repo.repo_path) == path);
+            if listed {
+                continue;
+            }
+
+            if let Some(sync_mode) = repo_kind(&path) {
+                let mut repo = RepoDefinition::new(path.to_string_lossy().into_owned());
+                repo.sync_mode = sync_mode;
+                found.push(repo);
+            }
+        }
+    }
+    found
+}
+
+/// Directorios que coinciden con un patrón absoluto con comodines `*` y `?` en
+/// cualquiera de sus componentes, ej. `/srv/*/repos/*`. Los directorios ocultos
+/// solo coinciden si el componente se escribe sin comodines.
+fn expand(pattern: &str) -> Vec<PathBuf> {
+    if !pattern.starts_with('/') {
+        return Vec::new();
+    }
+
+    let mut paths = vec![PathBuf::from("/")];
+    for component in pattern.split('/').filter(|c| !c.is_empty()) {
+        let mut next = Vec::new();
+        for base in &paths {
+            if !component.contains(['*', '?']) {
+                let path = base.join(component);
+                if path.is_dir() {
+                    next.push(path);
+                }
+                continue;
+            }
+
+            let Ok(entries) = fs::read_dir(base) else {
+                continue;
+            };
+            let mut matches: Vec<PathBuf> = entries
+                .flatten()
+                .filter(|entry| {
+                    let name = entry.file_name().to_string_lossy().into_owned();
+                    !name.starts_with('.') && wildcard_match(component, &name)
+                })
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .collect();
+            matches.sort();
+            next.extend(matches);
+        }
+        paths = next;
+    }
+    paths
+}
+
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Posición del último `*` y del carácter del nombre desde el que se reintenta.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star, start)) = backtrack {
+            p = star + 1;
+            n = start + 1;
+            backtrack = Some((star, start + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Tipo de repositorio en la ruta: con árbol de trabajo (`.git`) o bare (ej. un mirror).
+fn repo_kind(path: &Path) -> Option<SyncMode> {
+    if path.join(".git").exists() {
+        Some(SyncMode::Pull)
+    } else if path.join("HEAD").is_file()
+        && path.join("objects").is_dir()
+        && path.join("refs").is_dir()
+    {
+        Some(SyncMode::Bare)
+    } else {
+        None
+    }
+}
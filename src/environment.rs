@@ -0,0 +1,118 @@
+use crate::messages;
+use crate::secrets::read_protected;
+
+/// Lee un archivo de variables de entorno con formato `CLAVE=valor`.
+///
+/// Se admiten comentarios con `#`, el prefijo opcional `export` y valores entre
+/// comillas simples o dobles. Al igual que los secretos, el archivo puede estar
+/// cifrado (`.age` o `.sops`). Si no existe se devuelve una lista vacía.
+pub fn load_env_file(path: &str, key_file: &str) -> Result<Vec<(String, String)>, String> {
+    let Some(contents) = read_protected(path, key_file)? else {
+        return Ok(Vec::new());
+    };
+    parse_env(&contents, path)
+}
+
+/// Variables de `contents`, el contenido ya descifrado de `path`.
+fn parse_env(contents: &str, path: &str) -> Result<Vec<(String, String)>, String> {
+    let mut vars = Vec::new();
+    for (number, line) in contents.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let trimmed = trimmed.strip_prefix("export ").unwrap_or(trimmed).trim();
+        let Some((key, value)) = trimmed.split_once('=') else {
+            return Err(messages::env_line_invalid(number + 1, path));
+        };
+
+        let key = key.trim();
+        if !is_valid_var_name(key) {
+            return Err(messages::env_name_invalid(number + 1, path, key));
+        }
+
+        vars.push((key.to_string(), unquote(value.trim()).to_string()));
+    }
+
+    Ok(vars)
+}
+
+/// Valores de las variables cuyo nombre indica un secreto (`*TOKEN*`, `*SECRET*`,
+/// `*PASSWORD*`, `*PASSWD*`), para ocultarlos en el registro.
+pub fn secret_values(env: &[(String, String)]) -> Vec<String> {
+    env.iter()
+        .filter(|(key, _)| {
+            let key = key.to_ascii_uppercase();
+            ["TOKEN", "SECRET", "PASSWORD", "PASSWD"]
+                .iter()
+                .any(|marker| key.contains(marker))
+        })
+        .map(|(_, value)| value.clone())
+        .collect()
+}
+
+/// Nombres de variable aceptados: letras, dígitos y `_`.
+pub fn is_valid_var_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if value.len() >= 2 && value.starts_with(quote) && value.ends_with(quote) {
+            return &value[1..value.len() - 1];
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_comments_export_and_quotes() {
+        let contents = "# proxy\n\nexport HTTPS_PROXY=http://proxy:3128\nNODE_ENV = \"production\"\nGREETING='hola mundo'\nEMPTY=\n";
+        assert_eq!(
+            parse_env(contents, "environment").unwrap(),
+            vars(&[
+                ("HTTPS_PROXY", "http://proxy:3128"),
+                ("NODE_ENV", "production"),
+                ("GREETING", "hola mundo"),
+                ("EMPTY", ""),
+            ])
+        );
+    }
+
+    #[test]
+    fn keeps_equals_signs_in_values() {
+        assert_eq!(
+            parse_env("QUERY=a=1&b=2", "environment").unwrap(),
+            vars(&[("QUERY", "a=1&b=2")])
+        );
+    }
+
+    #[test]
+    fn rejects_lines_without_equals_and_invalid_names() {
+        assert!(parse_env("HTTPS_PROXY", "environment").is_err());
+        assert!(parse_env("MY-VAR=1", "environment").is_err());
+        assert!(parse_env("=1", "environment").is_err());
+    }
+
+    #[test]
+    fn secret_values_match_names_case_insensitively() {
+        let env = vars(&[
+            ("API_TOKEN", "t0k3n"),
+            ("db_password", "hunter2"),
+            ("NODE_ENV", "production"),
+        ]);
+        assert_eq!(secret_values(&env), vec!["t0k3n", "hunter2"]);
+    }
+}
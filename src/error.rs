@@ -0,0 +1,51 @@
+//! Errores de la API de la biblioteca. Los mensajes ya están en el idioma activo (ver
+//! `i18n`); la variante permite distinguir el tipo de fallo sin interpretar el texto.
+
+use crate::git::is_timeout_error;
+use thiserror::Error;
+
+#[derive(Debug, Clone, Error)]
+pub enum Error {
+    /// La configuración (config.toml, repositories.txt o sus directorios) no se pudo leer,
+    /// crear o escribir, o no es válida
+    #[error("{0}")]
+    Config(String),
+    /// Una operación de Git falló
+    #[error("{0}")]
+    Git(String),
+    /// Una operación de Git superó su límite de tiempo
+    #[error("{0}")]
+    Timeout(String),
+    /// No hay repositorios configurados que sincronizar
+    #[error("{0}")]
+    NoRepos(String),
+    /// Algunos repositorios no se pudieron sincronizar en el ciclo
+    #[error("{message}")]
+    Sync {
+        message: String,
+        /// Ruta y error de cada repositorio que falló
+        failures: Vec<(String, String)>,
+    },
+}
+
+impl Error {
+    /// Error de una operación de Git: `Timeout` si se agotó el límite de tiempo.
+    pub fn git(message: String) -> Self {
+        if is_timeout_error(&message) {
+            Error::Timeout(message)
+        } else {
+            Error::Git(message)
+        }
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Error::Timeout(_))
+    }
+}
+
+/// El código interno trabaja con mensajes; `?` convierte los errores de la API en ellos.
+impl From<Error> for String {
+    fn from(error: Error) -> Self {
+        error.to_string()
+    }
+}
@@ -0,0 +1,156 @@
+use crate::logger::{LogRotation, LogWriter};
+use crate::messages;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Resultado de un repositorio en un ciclo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncResult {
+    /// Llegaron cambios y se desplegaron
+    Updated,
+    /// Ya estaba actualizado
+    Unchanged,
+    Failed,
+    /// Omitido porque falló una dependencia en el mismo ciclo
+    Skipped,
+    /// Sincronización desactivada en la lista de repositorios
+    Paused,
+    /// Desactivado tras superar `max_failures`
+    Disabled,
+    /// Simulación (`dry_run`): solo se descargó
+    DryRun,
+    /// Despliegue retenido por `quiet_hours`: solo se descargó
+    Held,
+}
+
+impl SyncResult {
+    /// Icono y descripción para el resumen de `git-sync sync`.
+    pub fn label(self) -> (&'static str, String) {
+        match self {
+            SyncResult::Updated => ("✅", messages::result_updated()),
+            SyncResult::Unchanged => ("✅", messages::result_unchanged()),
+            SyncResult::Failed => ("❌", messages::result_failed()),
+            SyncResult::Skipped => ("⏭️", messages::result_skipped()),
+            SyncResult::Paused => ("⏸️", messages::result_paused()),
+            SyncResult::Disabled => ("⛔", messages::result_disabled()),
+            SyncResult::DryRun => ("🧪", messages::result_dry_run()),
+            SyncResult::Held => ("🌙", messages::result_held()),
+        }
+    }
+}
+
+/// Una línea de `events.jsonl`: qué pasó con un repositorio en un ciclo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncRecord {
+    pub repo: String,
+    pub name: String,
+    #[serde(
+        serialize_with = "serialize_time",
+        deserialize_with = "deserialize_time"
+    )]
+    pub started_at: DateTime<Local>,
+    #[serde(
+        serialize_with = "serialize_time",
+        deserialize_with = "deserialize_time"
+    )]
+    pub finished_at: DateTime<Local>,
+    pub duration_ms: u64,
+    pub result: SyncResult,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Rango desplegado `anterior..nuevo`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head: Option<String>,
+    /// Commits que llegaron (`hash | autor | asunto`)
+    pub commits: Vec<String>,
+    /// Archivos que cambiaron en el árbol de trabajo
+    pub files: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl SyncRecord {
+    pub fn new(repo: String, name: String, started_at: DateTime<Local>, elapsed: Duration) -> Self {
+        SyncRecord {
+            repo,
+            name,
+            started_at,
+            finished_at: Local::now(),
+            duration_ms: elapsed.as_millis() as u64,
+            result: SyncResult::Unchanged,
+            branch: None,
+            range: None,
+            head: None,
+            commits: Vec::new(),
+            files: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Línea de resumen: icono, nombre, resultado, el error o los commits que llegaron y
+    /// la duración.
+    pub fn summary(&self) -> String {
+        let (icon, label) = self.result.label();
+        let detail = match (&self.error, self.commits.len()) {
+            (Some(error), _) => format!(
+                ": {}",
+                error
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .trim_start_matches("❌ ")
+            ),
+            (None, 0) => String::new(),
+            (None, commits) => format!(": {}", messages::commit_count(commits)),
+        };
+        format!(
+            "{} {} — {}{} ({:.1} s)",
+            icon,
+            self.name,
+            label,
+            detail,
+            self.duration_ms as f64 / 1000.0
+        )
+    }
+}
+
+fn serialize_time<S: serde::Serializer>(
+    time: &DateTime<Local>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&time.format("%Y-%m-%dT%H:%M:%S%.3f%:z").to_string())
+}
+
+fn deserialize_time<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<DateTime<Local>, D::Error> {
+    let time = String::deserialize(deserializer)?;
+    DateTime::parse_from_rfc3339(&time)
+        .map(|time| time.with_timezone(&Local))
+        .map_err(serde::de::Error::custom)
+}
+
+/// Registro de eventos de sincronización (`events.jsonl`): un objeto JSON por repositorio
+/// y ciclo, solo añadido, para paneles y auditorías que no deben interpretar el registro
+/// legible. Se escribe en segundo plano y rota con los mismos límites que `git-sync.log`.
+pub struct EventLog {
+    writer: LogWriter,
+}
+
+impl EventLog {
+    pub fn new(events_file: String, rotation: LogRotation) -> Self {
+        EventLog {
+            writer: LogWriter::new(events_file, rotation),
+        }
+    }
+
+    pub fn record(&self, record: &SyncRecord) {
+        if let Ok(line) = serde_json::to_string(record) {
+            self.writer.write(&format!("{}\n", line));
+        }
+    }
+}
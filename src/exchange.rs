@@ -0,0 +1,203 @@
+use crate::config::{Config, RepoDefinition};
+use crate::messages;
+use crate::migrate::CONFIG_VERSION;
+use crate::settings::Settings;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// Formato de `config export` / `config import`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    Json,
+    Yaml,
+}
+
+impl Format {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "json" => Ok(Format::Json),
+            "yaml" | "yml" => Ok(Format::Yaml),
+            _ => Err(messages::invalid_format(value)),
+        }
+    }
+
+    /// Formato según la extensión del archivo; sin extensión conocida se usa YAML,
+    /// que también acepta JSON.
+    fn for_path(path: &str) -> Self {
+        match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Format::Json,
+            _ => Format::Yaml,
+        }
+    }
+}
+
+/// Configuración completa: los ajustes de `config.toml` y la lista de repositorios.
+/// Los secretos, las credenciales y el archivo de entorno no se incluyen.
+#[derive(Serialize, Deserialize)]
+struct Document {
+    settings: Settings,
+    #[serde(default)]
+    repositories: Vec<RepoEntry>,
+}
+
+/// Una línea de `repositories.txt`, con las opciones como claves de un mapa.
+#[derive(Serialize, Deserialize)]
+struct RepoEntry {
+    path: String,
+    #[serde(default = "default_enabled", skip_serializing_if = "is_enabled")]
+    enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    options: BTreeMap<String, OptionValue>,
+}
+
+/// Valor de una opción. Además del texto de `repositories.txt` se admiten booleanos,
+/// números y listas (`clean: true`, `fetch_depth: 1`, `groups: [web, prod]`).
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum OptionValue {
+    Text(String),
+    Flag(bool),
+    Number(u64),
+    List(Vec<String>),
+}
+
+impl OptionValue {
+    fn into_text(self) -> String {
+        match self {
+            OptionValue::Text(text) => text,
+            OptionValue::Flag(flag) => flag.to_string(),
+            OptionValue::Number(number) => number.to_string(),
+            OptionValue::List(items) => items.join(","),
+        }
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn is_enabled(enabled: &bool) -> bool {
+    *enabled
+}
+
+impl RepoEntry {
+    fn from_repo(repo: &RepoDefinition) -> Self {
+        RepoEntry {
+            path: repo.repo_path.clone(),
+            enabled: repo.enabled,
+            url: repo.clone_url.clone(),
+            options: repo
+                .options()
+                .into_iter()
+                .map(|(key, value)| (key, OptionValue::Text(value)))
+                .collect(),
+        }
+    }
+
+    fn into_repo(self) -> Result<RepoDefinition, String> {
+        if self.path.trim().is_empty() {
+            return Err(messages::repo_without_path());
+        }
+
+        let mut repo = RepoDefinition::new(self.path.trim());
+        repo.enabled = self.enabled;
+        repo.clone_url = self.url;
+        for (key, value) in self.options {
+            repo.apply_option(&key, &value.into_text())
+                .map_err(|err| messages::option_error_in(err, &repo.repo_path))?;
+        }
+        Ok(repo)
+    }
+}
+
+/// Escribe en la salida estándar la configuración actual en el formato indicado.
+pub fn export(config: &Config, format: Format) -> Result<(), String> {
+    let settings = if Path::new(&config.settings_file).exists() {
+        Settings::load(&config.settings_file)?
+    } else {
+        Settings::default()
+    };
+    let repos = if Path::new(&config.repos_file).exists() {
+        config.try_read_repos()?
+    } else {
+        Vec::new()
+    };
+    let document = Document {
+        settings,
+        repositories: repos.iter().map(RepoEntry::from_repo).collect(),
+    };
+
+    let output = match format {
+        Format::Json => serde_json::to_string_pretty(&document)
+            .map(|json| json + "\n")
+            .map_err(|e| e.to_string()),
+        Format::Yaml => serde_yaml::to_string(&document).map_err(|e| e.to_string()),
+    }
+    .map_err(messages::config_serialize_failed)?;
+
+    print!("{}", output);
+    Ok(())
+}
+
+/// Reemplaza `config.toml` y `repositories.txt` con el documento de `source` (`-` para la
+/// entrada estándar). Todo el documento se valida antes de escribir ninguno de los dos.
+pub fn import(config: &Config, source: &str, format: Option<Format>) -> Result<(), String> {
+    let contents = if source == "-" {
+        let mut contents = String::new();
+        io::stdin()
+            .read_to_string(&mut contents)
+            .map_err(messages::stdin_read_failed)?;
+        contents
+    } else {
+        fs::read_to_string(source).map_err(|e| messages::read_failed(source, e))?
+    };
+
+    let format = format.unwrap_or_else(|| Format::for_path(source));
+    let document: Document = match format {
+        Format::Json => serde_json::from_str(&contents).map_err(|e| e.to_string()),
+        Format::Yaml => serde_yaml::from_str(&contents).map_err(|e| e.to_string()),
+    }
+    .map_err(|e| messages::parse_failed(source, e))?;
+
+    let repos = document
+        .repositories
+        .into_iter()
+        .map(RepoEntry::into_repo)
+        .collect::<Result<Vec<_>, _>>()?;
+    // El documento se escribe completo con el esquema actual, sin migraciones pendientes.
+    let settings = Settings {
+        version: CONFIG_VERSION,
+        ..document.settings
+    };
+    let settings = toml::to_string_pretty(&settings).map_err(messages::config_serialize_failed)?;
+
+    fs::create_dir_all(&config.config_dir)
+        .map_err(|e| messages::config_dir_create_failed(&config.config_dir, e))?;
+    fs::write(&config.settings_file, settings)
+        .map_err(|e| messages::config_write_failed(&config.settings_file, e))?;
+    config.write_repos(&repos)?;
+    // Crea el resto de archivos (secretos, registro, estado) si es una instalación nueva.
+    config.ensure_exists()?;
+
+    println!(
+        "{}",
+        messages::config_imported(&config.settings_file, &config.repos_file, repos.len())
+    );
+    println!(
+        "{}",
+        messages::restart_to_apply(
+            if config.user_mode {
+                "systemctl --user"
+            } else {
+                "sudo systemctl"
+            },
+            &config.service_name,
+        )
+    );
+    Ok(())
+}
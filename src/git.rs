@@ -1,125 +1,599 @@
-use std::process::Command;
+mod cli;
+mod libgit2;
+
+use crate::credentials::Credentials;
+use crate::error::Error;
+use crate::logger::{LogLevel, Logger};
+use crate::messages;
+use crate::settings::Timeouts;
+use cli::CliBackend;
+use libgit2::Libgit2Backend;
+pub use libgit2::configure_timeouts;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const DEFAULT_REMOTE: &str = "origin";
+
+/// Marca de los errores por tiempo de espera agotado, igual en todos los idiomas.
+const TIMEOUT_MARKER: &str = "⏱️ ";
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GitBackendKind {
+    /// Operaciones en proceso mediante libgit2
+    #[default]
+    Libgit2,
+    /// Ejecuta el binario `git` del sistema
+    Cli,
+}
+
+/// Operaciones Git que necesita la sincronización, independientes de cómo se ejecutan.
+trait GitBackend {
+    fn clone(&self, repo: &GitRepo, url: &str) -> Result<(), String>;
+    fn clone_mirror(&self, repo: &GitRepo, url: &str) -> Result<(), String>;
+    fn fetch(&self, repo: &GitRepo) -> Result<(), String>;
+    fn remote_update(&self, repo: &GitRepo) -> Result<(), String>;
+    fn ref_targets(&self, repo: &GitRepo) -> Result<Vec<(String, String)>, String>;
+    fn get_default_branch(&self, repo: &GitRepo) -> String;
+    fn count_commits_behind(&self, repo: &GitRepo, branch: &str) -> Result<usize, String>;
+    fn count_commits_ahead(&self, repo: &GitRepo, branch: &str) -> Result<usize, String>;
+    fn current_branch(&self, repo: &GitRepo) -> Result<Option<String>, String>;
+    fn checkout_branch(&self, repo: &GitRepo, branch: &str) -> Result<(), String>;
+    fn gone_branches(&self, repo: &GitRepo) -> Result<Vec<String>, String>;
+    fn delete_merged_branch(&self, repo: &GitRepo, name: &str) -> Result<bool, String>;
+    fn create_branch(&self, repo: &GitRepo, name: &str) -> Result<(), String>;
+    fn fetch_tags(&self, repo: &GitRepo) -> Result<(), String>;
+    fn list_tags(&self, repo: &GitRepo, pattern: &str) -> Result<Vec<String>, String>;
+    fn resolve_commit(&self, repo: &GitRepo, rev: &str) -> Result<String, String>;
+    fn checkout_detached(&self, repo: &GitRepo, rev: &str) -> Result<(), String>;
+    fn modified_files(&self, repo: &GitRepo) -> Result<Vec<String>, String>;
+    fn stash_pop(&self, repo: &GitRepo) -> Result<(), String>;
+    fn discard_changes(&self, repo: &GitRepo) -> Result<(), String>;
+    fn stash_changes(&self, repo: &GitRepo, message: &str) -> Result<bool, String>;
+    fn rebase(&self, repo: &GitRepo, branch: &str) -> Result<String, String>;
+    fn merge(&self, repo: &GitRepo, branch: &str) -> Result<String, String>;
+    fn pull(&self, repo: &GitRepo, branch: &str) -> Result<String, String>;
+    fn reset_hard(&self, repo: &GitRepo, target: &str) -> Result<String, String>;
+    fn clean(
+        &self,
+        repo: &GitRepo,
+        excludes: &[String],
+        dry_run: bool,
+    ) -> Result<Vec<String>, String>;
+    fn prune_history(&self, repo: &GitRepo) -> Result<(), String>;
+    fn gc(&self, repo: &GitRepo) -> Result<(), String>;
+    fn verify_commit(
+        &self,
+        repo: &GitRepo,
+        rev: &str,
+        signers: Option<&Path>,
+    ) -> Result<(), String>;
+    fn mirror_refs(&self, repo: &GitRepo) -> Result<Vec<String>, String>;
+    fn push(&self, repo: &GitRepo, remote: &str, refspecs: &[String]) -> Result<(), String>;
+    fn ls_remote(&self, repo: &GitRepo, remote: &str) -> Result<usize, String>;
+    fn remote_url(&self, repo: &GitRepo, remote: &str) -> Result<Option<String>, String>;
+    fn head_commit_summary(&self, repo: &GitRepo) -> Result<String, String>;
+    fn commits_between(
+        &self,
+        repo: &GitRepo,
+        from: &str,
+        to: &str,
+        limit: usize,
+    ) -> Result<Vec<String>, String>;
+    fn recent_commits(&self, repo: &GitRepo, limit: usize) -> Result<Vec<String>, String>;
+    fn diff_files(&self, repo: &GitRepo, from: &str, to: &str) -> Result<Vec<String>, String>;
+    fn work_tree(&self, repo: &GitRepo) -> Result<PathBuf, String>;
+    fn common_dir(&self, repo: &GitRepo) -> Result<PathBuf, String>;
+}
 
 pub struct GitRepo {
     pub path: String,
+    env: Vec<(String, String)>,
+    backend: GitBackendKind,
+    timeout: Option<Duration>,
+    fetch_timeout: Option<Duration>,
+    pull_timeout: Option<Duration>,
+    depth: Option<u32>,
+    remote: String,
+    /// Rama configurada con `branch=`; sin ella se detecta la predeterminada del remoto
+    branch: Option<String>,
+    prune: bool,
+    ssh_key: Option<String>,
+    credentials: Credentials,
+    /// El entorno define variables de Git que libgit2 no aplica: se usa el binario `git`
+    cli_env: bool,
+    /// Registro de los comandos `git` ejecutados (con `log_level` debug o trace)
+    logger: Option<Logger>,
 }
 
 impl GitRepo {
     pub fn new(path: String) -> Self {
-        GitRepo { path }
+        GitRepo {
+            path,
+            env: Vec::new(),
+            backend: GitBackendKind::default(),
+            timeout: None,
+            fetch_timeout: None,
+            pull_timeout: None,
+            depth: None,
+            remote: DEFAULT_REMOTE.to_string(),
+            branch: None,
+            prune: false,
+            ssh_key: None,
+            credentials: Credentials::default(),
+            cli_env: false,
+            logger: None,
+        }
     }
 
-    pub fn fetch(&self) -> Result<(), String> {
-        let output = Command::new("git")
-            .current_dir(&self.path)
-            .arg("fetch")
-            .output()
-            .map_err(|e| format!("❌ No se pudo ejecutar `git fetch`: {}", e))?;
+    /// Variables de entorno adicionales para todos los comandos `git` del repositorio. Si
+    /// alguna no la aplica libgit2 (ej. `GIT_SSH_COMMAND`), el repositorio usa el binario `git`
+    /// aunque `git_backend` sea `libgit2`.
+    pub fn with_env(mut self, env: &[(String, String)]) -> Self {
+        self.cli_env |= needs_git_binary(env);
+        self.env.extend(env.iter().cloned());
+        self
+    }
 
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
-        }
+    pub fn with_backend(mut self, backend: GitBackendKind) -> Self {
+        self.backend = backend;
+        self
+    }
 
-        Ok(())
+    /// Límites por tipo de operación: `fetch` para las descargas, `pull` para integrar los
+    /// cambios (solo con el binario `git`) y `git` para el resto de operaciones con el remoto
+    /// (clone, push, ls-remote).
+    pub fn with_timeouts(mut self, timeouts: &Timeouts) -> Self {
+        let limit = |seconds: u64| (seconds > 0).then(|| Duration::from_secs(seconds));
+        self.timeout = limit(timeouts.git);
+        self.fetch_timeout = limit(timeouts.fetch);
+        self.pull_timeout = limit(timeouts.pull);
+        self
     }
 
-    pub fn get_default_branch(&self) -> String {
-        // Intentar detectar la rama predeterminada
-        let branch_output = Command::new("git")
-            .current_dir(&self.path)
-            .args(["symbolic-ref", "refs/remotes/origin/HEAD"])
-            .output();
-
-        if let Ok(output) = branch_output {
-            let default_branch = String::from_utf8_lossy(&output.stdout)
-                .trim()
-                .replace("refs/remotes/origin/", "");
-
-            if !default_branch.is_empty() {
-                return default_branch;
-            }
-        }
+    /// Profundidad de historial para clones, tags y mantenimiento (0 = historial completo).
+    ///
+    /// Los fetch habituales no la aplican: extienden el historial superficial existente
+    /// para que HEAD siga conectado con el remoto aunque haya más de N commits nuevos.
+    pub fn with_depth(mut self, depth: u32) -> Self {
+        self.depth = (depth > 0).then_some(depth);
+        self
+    }
 
-        // Alternativa: verificar qué rama está disponible
-        let main_exists = Command::new("git")
-            .current_dir(&self.path)
-            .args(["rev-parse", "--verify", "origin/main"])
-            .output()
-            .map(|o| o.status.success())
-            .unwrap_or(false);
-
-        if main_exists {
-            "main".to_string()
-        } else {
-            "master".to_string()
-        }
+    /// Remoto desde el que se sincroniza (predeterminado: `origin`).
+    pub fn with_remote(mut self, remote: &str) -> Self {
+        self.remote = remote.to_string();
+        self
     }
 
-    pub fn count_commits_behind(&self, branch: &str) -> Result<usize, String> {
-        let output = Command::new("git")
-            .current_dir(&self.path)
-            .args(["rev-list", "--count", &format!("HEAD..origin/{}", branch)])
-            .output()
-            .map_err(|e| format!("❌ No se pudo comprobar el estado de Git: {}", e))?;
+    /// Rama que se sincroniza en lugar de la predeterminada del remoto.
+    pub fn with_branch(mut self, branch: Option<&str>) -> Self {
+        self.branch = branch.map(str::to_string);
+        self
+    }
+
+    /// Eliminar en cada fetch las ramas de seguimiento que ya no existen en el remoto.
+    pub fn with_prune(mut self, prune: bool) -> Self {
+        self.prune = prune;
+        self
+    }
+
+    /// Clave SSH exclusiva del repositorio (deploy key). Se usa solo esa clave, sin
+    /// el agente ni las de `~/.ssh`, y tiene prioridad sobre `GIT_SSH_COMMAND`.
+    pub fn with_ssh_key(mut self, key: Option<&str>) -> Self {
+        if let Some(key) = key {
+            let command = format!("ssh -i {} -o IdentitiesOnly=yes", shell_quote(key));
+            self.env.push(("GIT_SSH_COMMAND".to_string(), command));
+            self.ssh_key = Some(key.to_string());
+        }
+        self
+    }
 
-        let count = String::from_utf8_lossy(&output.stdout)
-            .trim()
-            .parse::<usize>()
+    /// Tokens HTTPS por host. libgit2 los entrega desde su callback de credenciales; para
+    /// el binario `git` se define, mediante `GIT_CONFIG_*`, un credential helper por host que
+    /// lee el token de una variable de entorno, de modo que no aparece en los argumentos.
+    pub fn with_credentials(mut self, credentials: &Credentials) -> Self {
+        let mut index = self
+            .env_var(&["GIT_CONFIG_COUNT"])
+            .and_then(|count| count.parse::<usize>().ok())
             .unwrap_or(0);
+        let mut config = |key: String, value: String| {
+            self.env.push((format!("GIT_CONFIG_KEY_{}", index), key));
+            self.env
+                .push((format!("GIT_CONFIG_VALUE_{}", index), value));
+            index += 1;
+        };
+
+        let mut secrets = Vec::new();
+        for (i, credential) in credentials.hosts().iter().enumerate() {
+            let key = format!("credential.https://{}.helper", credential.host);
+            // El valor vacío descarta otros helpers (ej. `store`) para que no guarden el token.
+            config(key.clone(), String::new());
+            config(
+                key,
+                format!(
+                    "!f() {{ test \"$1\" = get && printf 'username=%s\\npassword=%s\\n' \"$GIT_SYNC_USER_{i}\" \"$GIT_SYNC_TOKEN_{i}\"; }}; f"
+                ),
+            );
+            secrets.push((format!("GIT_SYNC_USER_{}", i), credential.username.clone()));
+            secrets.push((format!("GIT_SYNC_TOKEN_{}", i), credential.token.clone()));
+        }
 
-        Ok(count)
+        if !secrets.is_empty() {
+            self.env
+                .push(("GIT_CONFIG_COUNT".to_string(), index.to_string()));
+            self.env.extend(secrets);
+        }
+        self.credentials = credentials.clone();
+        self
+    }
+
+    /// Registrar los comandos `git` y su salida si el nivel de `logger` lo pide. Solo se
+    /// aplica a `git_backend = "cli"`: libgit2 no ejecuta comandos.
+    pub fn with_logger(mut self, logger: &Logger) -> Self {
+        self.logger = logger.enabled(LogLevel::Debug).then(|| logger.clone());
+        self
     }
 
-    pub fn pull(&self, branch: &str) -> Result<String, String> {
-        let output = Command::new("git")
-            .current_dir(&self.path)
-            .args(["pull", "origin", branch])
-            .output()
-            .map_err(|e| format!("❌ No se pudo ejecutar `git pull`: {}", e))?;
+    /// Rama de seguimiento del remoto configurado, ej. `origin/main`.
+    pub fn remote_branch(&self, branch: &str) -> String {
+        format!("{}/{}", self.remote, branch)
+    }
 
-        if output.status.success() {
-            Ok(String::from_utf8_lossy(&output.stdout).to_string())
-        } else {
-            Err(String::from_utf8_lossy(&output.stderr).to_string())
+    fn remote_ref(&self, branch: &str) -> String {
+        format!("refs/remotes/{}/{}", self.remote, branch)
+    }
+
+    fn backend(&self) -> &'static dyn GitBackend {
+        match self.backend {
+            GitBackendKind::Libgit2 if !self.cli_env => &Libgit2Backend,
+            _ => &CliBackend,
         }
     }
 
-    pub fn head_commit_summary(&self) -> Result<String, String> {
-        let output = Command::new("git")
-            .current_dir(&self.path)
-            .args(["log", "-1", "--pretty=format:%h %s"])
-            .output()
-            .map_err(|e| format!("❌ No se pudo ejecutar `git log`: {}", e))?;
+    fn env_var(&self, names: &[&str]) -> Option<&str> {
+        names.iter().find_map(|name| {
+            self.env
+                .iter()
+                .rev()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        })
+    }
 
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    /// Clona `url` en la ruta del repositorio, que no debe existir o estar vacía.
+    pub fn clone(&self, url: &str) -> Result<(), Error> {
+        self.backend().clone(self, url).map_err(Error::git)
+    }
+
+    /// Clona `url` como repositorio bare que replica todas sus referencias (`git clone --mirror`).
+    pub fn clone_mirror(&self, url: &str) -> Result<(), Error> {
+        self.backend().clone_mirror(self, url).map_err(Error::git)
+    }
+
+    pub fn fetch(&self) -> Result<(), Error> {
+        self.backend().fetch(self).map_err(Error::git)
+    }
+
+    /// Actualiza todos los remotos eliminando las referencias borradas en ellos
+    /// (`git remote update --prune`). Devuelve cuántas referencias se crearon,
+    /// movieron o eliminaron.
+    pub fn remote_update(&self) -> Result<usize, Error> {
+        let before = self.backend().ref_targets(self).map_err(Error::git)?;
+        self.backend().remote_update(self).map_err(Error::git)?;
+        let after = self
+            .backend()
+            .ref_targets(self)
+            .map_err(Error::git)?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        let removed_or_moved = before
+            .iter()
+            .filter(|(name, target)| after.get(name) != Some(target))
+            .count();
+        let before = before.into_iter().collect::<HashMap<_, _>>();
+        let added = after
+            .keys()
+            .filter(|name| !before.contains_key(*name))
+            .count();
+        Ok(removed_or_moved + added)
+    }
+
+    /// La rama configurada con `with_branch` o, sin ella, la predeterminada del remoto.
+    pub fn get_default_branch(&self) -> String {
+        if let Some(branch) = &self.branch {
+            return branch.clone();
         }
+        self.backend().get_default_branch(self)
+    }
 
-        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    pub fn count_commits_behind(&self, branch: &str) -> Result<usize, Error> {
+        self.backend()
+            .count_commits_behind(self, branch)
+            .map_err(Error::git)
     }
 
-    pub fn recent_commits(&self, limit: usize) -> Result<Vec<String>, String> {
-        let output = Command::new("git")
-            .current_dir(&self.path)
-            .args([
-                "log",
-                "--pretty=format:%h | %cr | %s",
-                "-n",
-                &limit.to_string(),
-            ])
-            .output()
-            .map_err(|e| format!("❌ No se pudo ejecutar `git log`: {}", e))?;
+    /// Commits locales que no están en `<remoto>/<rama>`.
+    pub fn count_commits_ahead(&self, branch: &str) -> Result<usize, Error> {
+        self.backend()
+            .count_commits_ahead(self, branch)
+            .map_err(Error::git)
+    }
 
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    /// Crea una rama que apunta al HEAD actual.
+    pub fn create_branch(&self, name: &str) -> Result<(), Error> {
+        self.backend().create_branch(self, name).map_err(Error::git)
+    }
+
+    /// Guarda en el stash los cambios sin confirmar, incluidos los archivos sin seguimiento.
+    /// Devuelve `false` si no había nada que guardar.
+    pub fn stash_changes(&self, message: &str) -> Result<bool, Error> {
+        self.backend()
+            .stash_changes(self, message)
+            .map_err(Error::git)
+    }
+
+    /// Descarga todos los tags del remoto, incluidos los que se movieron.
+    pub fn fetch_tags(&self) -> Result<(), Error> {
+        self.backend().fetch_tags(self).map_err(Error::git)
+    }
+
+    /// Tags locales que coinciden con un patrón glob (ej. `v*`).
+    pub fn list_tags(&self, pattern: &str) -> Result<Vec<String>, Error> {
+        self.backend().list_tags(self, pattern).map_err(Error::git)
+    }
+
+    /// Identificador completo del commit al que apunta `rev`.
+    pub fn resolve_commit(&self, rev: &str) -> Result<String, Error> {
+        self.backend().resolve_commit(self, rev).map_err(Error::git)
+    }
+
+    /// Rama local en la que está HEAD, o `None` si HEAD está desacoplado.
+    pub fn current_branch(&self) -> Result<Option<String>, Error> {
+        self.backend().current_branch(self).map_err(Error::git)
+    }
+
+    /// Vuelve a la rama local `branch`, creándola desde el remoto si no existe.
+    pub fn checkout_branch(&self, branch: &str) -> Result<(), Error> {
+        self.backend()
+            .checkout_branch(self, branch)
+            .map_err(Error::git)
+    }
+
+    /// Ramas locales cuya rama de seguimiento en el remoto configurado ya no existe.
+    pub fn gone_branches(&self) -> Result<Vec<String>, Error> {
+        self.backend().gone_branches(self).map_err(Error::git)
+    }
+
+    /// Elimina la rama local `name` solo si todos sus commits están en HEAD.
+    /// Devuelve `false` si se conservó por tener commits sin integrar.
+    pub fn delete_merged_branch(&self, name: &str) -> Result<bool, Error> {
+        self.backend()
+            .delete_merged_branch(self, name)
+            .map_err(Error::git)
+    }
+
+    /// Cambia el árbol de trabajo a `rev` con HEAD desacoplado, sin pisar cambios locales.
+    pub fn checkout_detached(&self, rev: &str) -> Result<(), Error> {
+        self.backend()
+            .checkout_detached(self, rev)
+            .map_err(Error::git)
+    }
+
+    /// Archivos con seguimiento que tienen cambios sin confirmar (los nuevos no cuentan).
+    pub fn modified_files(&self) -> Result<Vec<String>, Error> {
+        self.backend().modified_files(self).map_err(Error::git)
+    }
+
+    /// Restaura el último stash guardado y lo elimina.
+    pub fn stash_pop(&self) -> Result<(), Error> {
+        self.backend().stash_pop(self).map_err(Error::git)
+    }
+
+    /// Devuelve los archivos con seguimiento al estado de HEAD; los nuevos se conservan.
+    pub fn discard_changes(&self) -> Result<(), Error> {
+        self.backend().discard_changes(self).map_err(Error::git)
+    }
+
+    /// Reaplica los commits locales sobre `<remoto>/<rama>`. Si hay conflictos el
+    /// rebase se deshace y el repositorio queda como estaba.
+    pub fn rebase(&self, branch: &str) -> Result<String, Error> {
+        self.backend().rebase(self, branch).map_err(Error::git)
+    }
+
+    /// Fusiona `<remoto>/<rama>` con un commit de merge. Si hay conflictos la fusión
+    /// se deshace y el repositorio queda como estaba.
+    pub fn merge(&self, branch: &str) -> Result<String, Error> {
+        self.backend().merge(self, branch).map_err(Error::git)
+    }
+
+    /// Integra `<remoto>/<rama>` solo mediante fast-forward; nunca crea commits de merge.
+    pub fn pull(&self, branch: &str) -> Result<String, Error> {
+        self.backend().pull(self, branch).map_err(Error::git)
+    }
+
+    /// Descarta commits y cambios locales y deja HEAD en `target` (ej. `origin/main`).
+    pub fn reset_hard(&self, target: &str) -> Result<String, Error> {
+        self.backend().reset_hard(self, target).map_err(Error::git)
+    }
+
+    /// Elimina archivos y directorios sin seguimiento (respeta `.gitignore` y conserva
+    /// los que coinciden con `excludes`). Devuelve las rutas eliminadas.
+    pub fn clean(&self, excludes: &[String]) -> Result<Vec<String>, Error> {
+        self.backend()
+            .clean(self, excludes, false)
+            .map_err(Error::git)
+    }
+
+    /// Archivos sin seguimiento que `clean` eliminaría, sin borrarlos (`git clean -n`).
+    pub fn untracked_files(&self, excludes: &[String]) -> Result<Vec<String>, Error> {
+        self.backend()
+            .clean(self, excludes, true)
+            .map_err(Error::git)
+    }
+
+    /// Comprueba que `rev` tenga una firma válida. `signers` puede ser un directorio
+    /// usado como keyring GPG o un archivo `allowed_signers` de SSH.
+    pub fn verify_commit(&self, rev: &str, signers: Option<&Path>) -> Result<(), Error> {
+        self.backend()
+            .verify_commit(self, rev, signers)
+            .map_err(Error::git)
+    }
+
+    /// Recorta el historial a la profundidad configurada y elimina los objetos que
+    /// quedan inalcanzables, para que el almacén de objetos no crezca sin límite.
+    pub fn prune_history(&self) -> Result<(), Error> {
+        self.backend().prune_history(self).map_err(Error::git)
+    }
+
+    /// Compacta el repositorio con `git gc` (empaqueta objetos sueltos y referencias).
+    pub fn gc(&self) -> Result<(), Error> {
+        self.backend().gc(self).map_err(Error::git)
+    }
+
+    /// Indica si la ruta es la raíz de un árbol de trabajo de Git: un clon normal,
+    /// un worktree enlazado (`.git` es un archivo) o un `GIT_DIR` definido en el entorno.
+    pub fn is_work_tree(&self) -> bool {
+        let (Ok(top), Ok(path)) = (self.backend().work_tree(self), fs::canonicalize(&self.path))
+        else {
+            return false;
+        };
+        fs::canonicalize(top).is_ok_and(|top| top == path)
+    }
+
+    /// Indica si la ruta es un repositorio bare (sin árbol de trabajo), como los creados
+    /// con `git clone --mirror`.
+    pub fn is_bare_repository(&self) -> bool {
+        if self.backend().work_tree(self).is_ok() {
+            return false;
         }
+        let (Ok(dir), Ok(path)) = (
+            self.backend().common_dir(self),
+            fs::canonicalize(&self.path),
+        ) else {
+            return false;
+        };
+        fs::canonicalize(dir).is_ok_and(|dir| dir == path)
+    }
+
+    /// Tamaño en bytes del almacén de objetos. En un worktree enlazado es el del
+    /// repositorio principal, que comparte sus objetos.
+    pub fn object_store_size(&self) -> u64 {
+        self.backend()
+            .common_dir(self)
+            .map(|dir| dir_size(&dir.join("objects")))
+            .unwrap_or(0)
+    }
+
+    /// Comprueba que `remote` (nombre de remoto o URL) responde, sin descargar nada
+    /// (`git ls-remote`). Devuelve cuántas referencias anuncia.
+    pub fn ls_remote(&self, remote: &str) -> Result<usize, Error> {
+        self.backend().ls_remote(self, remote).map_err(Error::git)
+    }
+
+    /// URL configurada del remoto (`remote.<nombre>.url`), o `None` si no existe.
+    pub fn remote_url(&self, remote: &str) -> Result<Option<String>, Error> {
+        self.backend().remote_url(self, remote).map_err(Error::git)
+    }
 
-        let content = String::from_utf8_lossy(&output.stdout);
-        let commits = content
-            .lines()
-            .filter(|line| !line.trim().is_empty())
-            .map(|line| line.to_string())
+    /// Replica en `mirror` las ramas del remoto de sincronización y todos los tags,
+    /// forzando la actualización para reflejar también los force push.
+    /// Devuelve cuántas referencias se enviaron.
+    pub fn push_mirror(&self, mirror: &str) -> Result<usize, Error> {
+        let tracking_prefix = self.remote_ref("");
+        let refspecs = self
+            .backend()
+            .mirror_refs(self)
+            .map_err(Error::git)?
+            .into_iter()
+            .filter_map(|refname| {
+                if let Some(branch) = refname.strip_prefix(&tracking_prefix) {
+                    (branch != "HEAD").then(|| format!("+{}:refs/heads/{}", refname, branch))
+                } else if refname.starts_with("refs/tags/") {
+                    Some(format!("+{}:{}", refname, refname))
+                } else {
+                    None
+                }
+            })
             .collect::<Vec<_>>();
-        Ok(commits)
+
+        if refspecs.is_empty() {
+            return Ok(0);
+        }
+
+        self.backend()
+            .push(self, mirror, &refspecs)
+            .map_err(Error::git)?;
+        Ok(refspecs.len())
+    }
+
+    pub fn head_commit_summary(&self) -> Result<String, Error> {
+        self.backend().head_commit_summary(self).map_err(Error::git)
     }
+
+    /// Commits de `from..to` (como `git log --oneline from..to`) en formato
+    /// `hash | autor | asunto`, del más reciente al más antiguo.
+    pub fn commits_between(
+        &self,
+        from: &str,
+        to: &str,
+        limit: usize,
+    ) -> Result<Vec<String>, Error> {
+        self.backend()
+            .commits_between(self, from, to, limit)
+            .map_err(Error::git)
+    }
+
+    /// Rutas que difieren entre dos revisiones (`git diff --name-only from to`).
+    pub fn diff_files(&self, from: &str, to: &str) -> Result<Vec<String>, Error> {
+        self.backend()
+            .diff_files(self, from, to)
+            .map_err(Error::git)
+    }
+
+    pub fn recent_commits(&self, limit: usize) -> Result<Vec<String>, Error> {
+        self.backend()
+            .recent_commits(self, limit)
+            .map_err(Error::git)
+    }
+}
+
+/// Si `env` define variables de Git que libgit2 no aplica (ej. `GIT_SSH_COMMAND` en
+/// `env.<NOMBRE>`), por las que el repositorio usa el binario `git` con cualquier backend.
+pub fn needs_git_binary(env: &[(String, String)]) -> bool {
+    env.iter().any(|(name, _)| libgit2::ignores_env(name))
+}
+
+/// Entrecomilla un argumento para `GIT_SSH_COMMAND`, que Git ejecuta con `sh`.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+
+    entries
+        .flatten()
+        .map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => dir_size(&entry.path()),
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+/// Error de una operación cancelada al superar su límite de tiempo.
+pub fn timeout_error(operation: &str, timeout: Duration) -> String {
+    messages::timeout(operation, timeout.as_secs())
+}
+
+/// Indica si un error se debe a que se agotó alguno de los límites de tiempo.
+pub fn is_timeout_error(message: &str) -> bool {
+    message.contains(TIMEOUT_MARKER)
 }
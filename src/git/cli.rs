@@ -0,0 +1,944 @@
+use super::{GitBackend, GitRepo, timeout_error};
+use crate::logger::LogLevel;
+use crate::messages;
+use crate::platform;
+use std::ffi::OsString;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Backend que invoca el binario `git` instalado en el sistema.
+pub struct CliBackend;
+
+/// Comando `git` para operaciones que crean commits. Sin identidad configurada,
+/// `rebase` y `merge` fallarían, así que se usa la de git-sync.
+fn committer(repo: &GitRepo) -> Command {
+    let mut command = git(repo);
+    let has_identity = git(repo)
+        .args(["var", "GIT_COMMITTER_IDENT"])
+        .traced_output(repo)
+        .map(|o| o.status.success())
+        .unwrap_or(false);
+    if !has_identity {
+        command
+            .env("GIT_AUTHOR_NAME", "git-sync")
+            .env("GIT_AUTHOR_EMAIL", "git-sync@localhost")
+            .env("GIT_COMMITTER_NAME", "git-sync")
+            .env("GIT_COMMITTER_EMAIL", "git-sync@localhost");
+    }
+    command
+}
+
+/// Primera línea `CONFLICT` de un rebase o merge fallido, o el último error de git.
+fn conflict_message(output: &Output, fallback: String) -> String {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    stdout
+        .lines()
+        .chain(stderr.lines())
+        .map(str::trim)
+        .find(|line| line.starts_with("CONFLICT"))
+        .or_else(|| stderr.lines().map(str::trim).find(|line| !line.is_empty()))
+        .map(str::to_string)
+        .unwrap_or(fallback)
+}
+
+/// `--depth N` cuando el repositorio limita su historial.
+fn depth_args(repo: &GitRepo) -> Vec<OsString> {
+    repo.depth
+        .map(|depth| vec!["--depth".into(), depth.to_string().into()])
+        .unwrap_or_default()
+}
+
+/// `Command::output` que registra el comando en nivel debug y su salida en nivel trace.
+trait TracedOutput {
+    fn traced_output(&mut self, repo: &GitRepo) -> std::io::Result<Output>;
+}
+
+impl TracedOutput for Command {
+    fn traced_output(&mut self, repo: &GitRepo) -> std::io::Result<Output> {
+        trace_command(repo, self);
+        let output = self.output();
+        if let Ok(output) = &output {
+            trace_output(repo, output);
+        }
+        output
+    }
+}
+
+fn trace_command(repo: &GitRepo, command: &Command) {
+    if let Some(logger) = &repo.logger {
+        let args: Vec<_> = command
+            .get_args()
+            .map(|arg| arg.to_string_lossy())
+            .collect();
+        let dir = command
+            .get_current_dir()
+            .map(|dir| dir.display().to_string())
+            .unwrap_or_else(|| repo.path.clone());
+        logger.debug(&messages::git_command_debug(args.join(" "), dir));
+    }
+}
+
+fn trace_output(repo: &GitRepo, output: &Output) {
+    let Some(logger) = repo.logger.as_ref().filter(|l| l.enabled(LogLevel::Trace)) else {
+        return;
+    };
+    logger.trace(&format!("   ↳ {}", output.status));
+    for (name, stream) in [("stdout", &output.stdout), ("stderr", &output.stderr)] {
+        let text = String::from_utf8_lossy(stream);
+        if !text.trim().is_empty() {
+            logger.trace(&format!("   {}:\n{}", name, text.trim_end()));
+        }
+    }
+}
+
+fn git(repo: &GitRepo) -> Command {
+    let mut command = Command::new("git");
+    command
+        .current_dir(&repo.path)
+        .envs(repo.env.iter().cloned());
+    command
+}
+
+/// Ejecuta una operación cancelándola si supera `timeout` (`None` = sin límite).
+///
+/// Al vencer el plazo se mata el grupo de procesos completo, porque `git` delega
+/// la conexión en procesos hijos (`ssh`, `git-remote-https`) que también pueden colgarse.
+fn run(
+    repo: &GitRepo,
+    timeout: Option<Duration>,
+    command: &mut Command,
+    operation: &str,
+) -> Result<Output, String> {
+    let spawn_error = |e: std::io::Error| messages::run_failed(operation, e);
+    let Some(timeout) = timeout else {
+        return command.traced_output(repo).map_err(spawn_error);
+    };
+
+    trace_command(repo, command);
+    let mut child = platform::new_process_group(
+        command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped()),
+    )
+    .spawn()
+    .map_err(spawn_error)?;
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() >= deadline => {
+                platform::kill_process_group(&mut child);
+                return Err(timeout_error(operation, timeout));
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(100)),
+            Err(e) => return Err(spawn_error(e)),
+        }
+    };
+
+    let output = Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    };
+    trace_output(repo, &output);
+    Ok(output)
+}
+
+fn read_in_background<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
+
+impl GitBackend for CliBackend {
+    fn clone(&self, repo: &GitRepo, url: &str) -> Result<(), String> {
+        // La ruta aún no existe, así que no se puede usar como directorio de trabajo.
+        let output = run(
+            repo,
+            repo.timeout,
+            Command::new("git")
+                .envs(repo.env.iter().cloned())
+                .args(["clone", "--origin", &repo.remote])
+                .args(depth_args(repo))
+                .args(["--", url, &repo.path]),
+            "git clone",
+        )?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(())
+    }
+
+    fn clone_mirror(&self, repo: &GitRepo, url: &str) -> Result<(), String> {
+        let output = run(
+            repo,
+            repo.timeout,
+            Command::new("git")
+                .envs(repo.env.iter().cloned())
+                .args(["clone", "--mirror", "--origin", &repo.remote])
+                .args(depth_args(repo))
+                .args(["--", url, &repo.path]),
+            "git clone --mirror",
+        )?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(())
+    }
+
+    fn fetch(&self, repo: &GitRepo) -> Result<(), String> {
+        let mut command = git(repo);
+        command.arg("fetch");
+        if repo.prune {
+            command.arg("--prune");
+        }
+        let output = run(
+            repo,
+            repo.fetch_timeout,
+            command.arg(&repo.remote),
+            "git fetch",
+        )?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        Ok(())
+    }
+
+    fn remote_update(&self, repo: &GitRepo) -> Result<(), String> {
+        let output = run(
+            repo,
+            repo.fetch_timeout,
+            git(repo).args(["remote", "update", "--prune"]),
+            "git remote update",
+        )?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(())
+    }
+
+    fn ref_targets(&self, repo: &GitRepo) -> Result<Vec<(String, String)>, String> {
+        let output = git(repo)
+            .args(["for-each-ref", "--format=%(refname) %(objectname)"])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git for-each-ref", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.trim().split_once(' '))
+            .map(|(name, target)| (name.to_string(), target.to_string()))
+            .collect())
+    }
+
+    fn get_default_branch(&self, repo: &GitRepo) -> String {
+        // Intentar detectar la rama predeterminada
+        let branch_output = git(repo)
+            .args(["symbolic-ref", &repo.remote_ref("HEAD")])
+            .traced_output(repo);
+
+        if let Ok(output) = branch_output {
+            let default_branch = String::from_utf8_lossy(&output.stdout)
+                .trim()
+                .replace(&repo.remote_ref(""), "");
+
+            if !default_branch.is_empty() {
+                return default_branch;
+            }
+        }
+
+        // Alternativa: verificar qué rama está disponible
+        let main_exists = git(repo)
+            .args(["rev-parse", "--verify", &repo.remote_branch("main")])
+            .traced_output(repo)
+            .map(|o| o.status.success())
+            .unwrap_or(false);
+
+        if main_exists {
+            "main".to_string()
+        } else {
+            "master".to_string()
+        }
+    }
+
+    fn count_commits_behind(&self, repo: &GitRepo, branch: &str) -> Result<usize, String> {
+        let output = run(
+            repo,
+            repo.timeout,
+            git(repo).args([
+                "rev-list",
+                "--count",
+                &format!("HEAD..{}", repo.remote_branch(branch)),
+            ]),
+            "git rev-list",
+        )?;
+
+        let count = String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse::<usize>()
+            .unwrap_or(0);
+
+        Ok(count)
+    }
+
+    fn count_commits_ahead(&self, repo: &GitRepo, branch: &str) -> Result<usize, String> {
+        let output = git(repo)
+            .args([
+                "rev-list",
+                "--count",
+                &format!("{}..HEAD", repo.remote_branch(branch)),
+            ])
+            .traced_output(repo)
+            .map_err(messages::git_state_failed)?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse::<usize>()
+            .map_err(|e| messages::unexpected_output("git rev-list", e))
+    }
+
+    fn current_branch(&self, repo: &GitRepo) -> Result<Option<String>, String> {
+        let output = git(repo)
+            .args(["symbolic-ref", "--quiet", "--short", "HEAD"])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git symbolic-ref", e))?;
+
+        // Con HEAD desacoplado `symbolic-ref --quiet` termina con código 1 sin mensaje.
+        match output.status.code() {
+            Some(0) => Ok(Some(
+                String::from_utf8_lossy(&output.stdout).trim().to_string(),
+            )),
+            Some(1) => Ok(None),
+            _ => Err(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+        }
+    }
+
+    fn checkout_branch(&self, repo: &GitRepo, branch: &str) -> Result<(), String> {
+        let exists = git(repo)
+            .args([
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("refs/heads/{}", branch),
+            ])
+            .traced_output(repo)
+            .map(|o| o.status.success())
+            .unwrap_or(false);
+
+        let mut command = git(repo);
+        command.args(["checkout", "--quiet"]);
+        if exists {
+            command.arg(branch);
+        } else {
+            command
+                .args(["--track", "-b", branch])
+                .arg(repo.remote_branch(branch));
+        }
+
+        let output = command
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git checkout", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(())
+    }
+
+    fn gone_branches(&self, repo: &GitRepo) -> Result<Vec<String>, String> {
+        let output = git(repo)
+            .args([
+                "for-each-ref",
+                "--format=%(refname:short)%09%(upstream)%09%(upstream:track)",
+                "refs/heads/",
+            ])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git for-each-ref", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        let tracking_prefix = repo.remote_ref("");
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split('\t');
+                let (name, upstream, track) = (fields.next()?, fields.next()?, fields.next()?);
+                (upstream.starts_with(&tracking_prefix) && track == "[gone]")
+                    .then(|| name.to_string())
+            })
+            .collect())
+    }
+
+    fn delete_merged_branch(&self, repo: &GitRepo, name: &str) -> Result<bool, String> {
+        let branch_ref = format!("refs/heads/{}", name);
+        let merged = git(repo)
+            .args(["merge-base", "--is-ancestor", &branch_ref, "HEAD"])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git merge-base", e))?;
+
+        match merged.status.code() {
+            Some(0) => {}
+            Some(1) => return Ok(false),
+            _ => return Err(String::from_utf8_lossy(&merged.stderr).trim().to_string()),
+        }
+
+        let output = git(repo)
+            .args(["branch", "--quiet", "-D", name])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git branch", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(true)
+    }
+
+    fn create_branch(&self, repo: &GitRepo, name: &str) -> Result<(), String> {
+        let output = git(repo)
+            .args(["branch", name, "HEAD"])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git branch", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(())
+    }
+
+    fn fetch_tags(&self, repo: &GitRepo) -> Result<(), String> {
+        let output = run(
+            repo,
+            repo.fetch_timeout,
+            git(repo)
+                .args(["fetch", "--tags", "--force"])
+                .args(depth_args(repo))
+                .arg(&repo.remote),
+            "git fetch --tags",
+        )?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(())
+    }
+
+    fn list_tags(&self, repo: &GitRepo, pattern: &str) -> Result<Vec<String>, String> {
+        let output = git(repo)
+            .args(["tag", "--list", pattern])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git tag", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+            .collect())
+    }
+
+    fn resolve_commit(&self, repo: &GitRepo, rev: &str) -> Result<String, String> {
+        let output = git(repo)
+            .args(["rev-parse", "--verify", &format!("{}^{{commit}}", rev)])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git rev-parse", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    fn checkout_detached(&self, repo: &GitRepo, rev: &str) -> Result<(), String> {
+        let output = git(repo)
+            .args(["checkout", "--quiet", "--detach", rev])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git checkout", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(())
+    }
+
+    fn modified_files(&self, repo: &GitRepo) -> Result<Vec<String>, String> {
+        let output = git(repo)
+            .args(["status", "--porcelain", "--untracked-files=no"])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git status", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.get(3..))
+            .map(|path| path.to_string())
+            .collect())
+    }
+
+    fn stash_pop(&self, repo: &GitRepo) -> Result<(), String> {
+        let output = git(repo)
+            .args(["stash", "pop"])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git stash pop", e))?;
+
+        if output.status.success() {
+            return Ok(());
+        }
+
+        // Con conflictos, `stash pop` deja marcas en los archivos y conserva el stash:
+        // se vuelve a HEAD para no dejar el árbol de trabajo a medias.
+        let _ = git(repo)
+            .args(["reset", "--hard", "--quiet", "HEAD"])
+            .traced_output(repo);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(stdout
+            .lines()
+            .chain(stderr.lines())
+            .map(str::trim)
+            .find(|line| line.starts_with("CONFLICT"))
+            .or_else(|| stderr.lines().map(str::trim).rfind(|line| !line.is_empty()))
+            .map(str::to_string)
+            .unwrap_or_else(messages::stash_conflict))
+    }
+
+    fn discard_changes(&self, repo: &GitRepo) -> Result<(), String> {
+        let output = git(repo)
+            .args(["checkout", "HEAD", "--", "."])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git checkout", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(())
+    }
+
+    fn stash_changes(&self, repo: &GitRepo, message: &str) -> Result<bool, String> {
+        let status = git(repo)
+            .args(["status", "--porcelain"])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git status", e))?;
+        if String::from_utf8_lossy(&status.stdout).trim().is_empty() {
+            return Ok(false);
+        }
+
+        let output = git(repo)
+            .args(["stash", "push", "--include-untracked", "-m", message])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git stash", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(true)
+    }
+
+    fn rebase(&self, repo: &GitRepo, branch: &str) -> Result<String, String> {
+        let abort = || git(repo).args(["rebase", "--abort"]).traced_output(repo);
+        let output = run(
+            repo,
+            repo.pull_timeout,
+            committer(repo).args(["rebase", &repo.remote_branch(branch)]),
+            "git rebase",
+        )
+        .inspect_err(|_| {
+            let _ = abort();
+        })?;
+
+        if output.status.success() {
+            return Ok(String::from_utf8_lossy(&output.stdout).to_string());
+        }
+
+        let _ = abort();
+        Err(conflict_message(&output, messages::rebase_incomplete()))
+    }
+
+    fn merge(&self, repo: &GitRepo, branch: &str) -> Result<String, String> {
+        let abort = || git(repo).args(["merge", "--abort"]).traced_output(repo);
+        let output = run(
+            repo,
+            repo.pull_timeout,
+            committer(repo).args(["merge", "--no-edit", &repo.remote_branch(branch)]),
+            "git merge",
+        )
+        .inspect_err(|_| {
+            let _ = abort();
+        })?;
+
+        if output.status.success() {
+            return Ok(String::from_utf8_lossy(&output.stdout).to_string());
+        }
+
+        let _ = abort();
+        Err(conflict_message(&output, messages::merge_incomplete()))
+    }
+
+    fn pull(&self, repo: &GitRepo, branch: &str) -> Result<String, String> {
+        let output = run(
+            repo,
+            repo.pull_timeout,
+            git(repo).args(["pull", "--ff-only", &repo.remote, branch]),
+            "git pull",
+        )?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).to_string())
+        }
+    }
+
+    fn reset_hard(&self, repo: &GitRepo, target: &str) -> Result<String, String> {
+        let output = git(repo)
+            .args(["reset", "--hard", target])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git reset", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    fn clean(
+        &self,
+        repo: &GitRepo,
+        excludes: &[String],
+        dry_run: bool,
+    ) -> Result<Vec<String>, String> {
+        let mut command = git(repo);
+        command.args(["clean", if dry_run { "-nd" } else { "-fd" }]);
+        for pattern in excludes {
+            command.arg("-e").arg(pattern);
+        }
+        let output = command
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git clean", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        let content = String::from_utf8_lossy(&output.stdout);
+        Ok(content
+            .lines()
+            .filter_map(|line| {
+                line.strip_prefix("Removing ")
+                    .or_else(|| line.strip_prefix("Would remove "))
+            })
+            .map(|path| path.to_string())
+            .collect())
+    }
+
+    fn prune_history(&self, repo: &GitRepo) -> Result<(), String> {
+        if repo.depth.is_some() {
+            let output = run(
+                repo,
+                repo.fetch_timeout,
+                git(repo)
+                    .arg("fetch")
+                    .args(depth_args(repo))
+                    .arg(&repo.remote),
+                "git fetch --depth",
+            )?;
+            if !output.status.success() {
+                return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+            }
+        }
+
+        let output = git(repo)
+            .args(["reflog", "expire", "--expire=now", "--all"])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git reflog", e))?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        let output = run(
+            repo,
+            repo.timeout,
+            git(repo).args(["gc", "--prune=now", "--quiet"]),
+            "git gc",
+        )?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(())
+    }
+
+    fn verify_commit(
+        &self,
+        repo: &GitRepo,
+        rev: &str,
+        signers: Option<&Path>,
+    ) -> Result<(), String> {
+        let mut command = git(repo);
+        match signers {
+            Some(path) if path.is_dir() => {
+                command.env("GNUPGHOME", path);
+            }
+            Some(path) => {
+                command
+                    .arg("-c")
+                    .arg(format!("gpg.ssh.allowedSignersFile={}", path.display()));
+            }
+            None => {}
+        }
+
+        let output = command
+            .args(["verify-commit", rev])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git verify-commit", e))?;
+
+        if output.status.success() {
+            return Ok(());
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        match stderr.lines().map(str::trim).rfind(|line| !line.is_empty()) {
+            Some(line) => Err(line.trim_end_matches('.').to_string()),
+            None => Err(messages::commit_unsigned()),
+        }
+    }
+
+    fn gc(&self, repo: &GitRepo) -> Result<(), String> {
+        let output = run(
+            repo,
+            repo.timeout,
+            git(repo).args(["gc", "--quiet"]),
+            "git gc",
+        )?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(())
+    }
+
+    fn mirror_refs(&self, repo: &GitRepo) -> Result<Vec<String>, String> {
+        let output = git(repo)
+            .args([
+                "for-each-ref",
+                "--format=%(refname)",
+                &repo.remote_ref(""),
+                "refs/tags/",
+            ])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git for-each-ref", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+            .collect())
+    }
+
+    fn push(&self, repo: &GitRepo, remote: &str, refspecs: &[String]) -> Result<(), String> {
+        let output = run(
+            repo,
+            repo.timeout,
+            git(repo)
+                .args(["push", "--porcelain", remote])
+                .args(refspecs),
+            "git push",
+        )?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(())
+    }
+
+    fn ls_remote(&self, repo: &GitRepo, remote: &str) -> Result<usize, String> {
+        // Sirve también para la URL de un repositorio que todavía no se clonó.
+        let mut command = Command::new("git");
+        command.envs(repo.env.iter().cloned());
+        if Path::new(&repo.path).is_dir() {
+            command.current_dir(&repo.path);
+        }
+        let output = run(
+            repo,
+            repo.timeout,
+            command.args(["ls-remote", "--", remote]),
+            "git ls-remote",
+        )?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).lines().count())
+    }
+
+    fn remote_url(&self, repo: &GitRepo, remote: &str) -> Result<Option<String>, String> {
+        let output = git(repo)
+            .args(["config", "--get", &format!("remote.{}.url", remote)])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git config", e))?;
+
+        // `git config --get` termina con 1 cuando la clave no existe.
+        match output.status.code() {
+            Some(0) => Ok(Some(
+                String::from_utf8_lossy(&output.stdout).trim().to_string(),
+            )),
+            Some(1) => Ok(None),
+            _ => Err(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+        }
+    }
+
+    fn head_commit_summary(&self, repo: &GitRepo) -> Result<String, String> {
+        let output = git(repo)
+            .args(["log", "-1", "--pretty=format:%h %s"])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git log", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    fn commits_between(
+        &self,
+        repo: &GitRepo,
+        from: &str,
+        to: &str,
+        limit: usize,
+    ) -> Result<Vec<String>, String> {
+        let output = git(repo)
+            .args([
+                "log",
+                "--pretty=format:%h | %an | %s",
+                "-n",
+                &limit.to_string(),
+                &format!("{}..{}", from, to),
+            ])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git log", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.to_string())
+            .collect())
+    }
+
+    fn diff_files(&self, repo: &GitRepo, from: &str, to: &str) -> Result<Vec<String>, String> {
+        let output = git(repo)
+            .args(["diff", "--name-only", from, to, "--"])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git diff", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.to_string())
+            .collect())
+    }
+
+    fn recent_commits(&self, repo: &GitRepo, limit: usize) -> Result<Vec<String>, String> {
+        let output = git(repo)
+            .args([
+                "log",
+                "--pretty=format:%h | %cr | %s",
+                "-n",
+                &limit.to_string(),
+            ])
+            .traced_output(repo)
+            .map_err(|e| messages::run_failed("git log", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        let content = String::from_utf8_lossy(&output.stdout);
+        let commits = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>();
+        Ok(commits)
+    }
+
+    fn work_tree(&self, repo: &GitRepo) -> Result<PathBuf, String> {
+        rev_parse_path(repo, "--show-toplevel")
+    }
+
+    fn common_dir(&self, repo: &GitRepo) -> Result<PathBuf, String> {
+        rev_parse_path(repo, "--git-common-dir")
+    }
+}
+
+/// Ruta que devuelve `git rev-parse <flag>`, resuelta respecto al repositorio.
+fn rev_parse_path(repo: &GitRepo, flag: &str) -> Result<PathBuf, String> {
+    let output = git(repo)
+        .args(["rev-parse", flag])
+        .traced_output(repo)
+        .map_err(|e| messages::run_failed("git rev-parse", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok(Path::new(&repo.path).join(path))
+}
@@ -0,0 +1,965 @@
+use super::cli::CliBackend;
+use super::{GitBackend, GitRepo, timeout_error};
+use crate::messages;
+use crate::settings::Timeouts;
+use chrono::Utc;
+use git2::build::{CheckoutBuilder, RepoBuilder};
+use git2::{
+    BranchType, Cred, CredentialType, Direction, ErrorCode, FetchOptions, FetchPrune, ProxyOptions,
+    PushOptions, Remote, RemoteCallbacks, Repository, ResetType, Signature, StashFlags, Status,
+    StatusOptions,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+/// Backend en proceso basado en libgit2: no depende del binario `git` ni de su idioma.
+pub struct Libgit2Backend;
+
+const SSH_KEY_FILES: [&str; 3] = ["id_ed25519", "id_ecdsa", "id_rsa"];
+
+/// Variables de Git del entorno configurado que este backend aplica por su cuenta (ver `open`).
+const APPLIED_ENV: [&str; 2] = ["GIT_DIR", "GIT_WORK_TREE"];
+
+/// Si `name` cambia el comportamiento de `git` o `ssh` pero libgit2 no lo aplica (ej.
+/// `GIT_SSH_COMMAND`, `GIT_CONFIG_*` o `SSH_AUTH_SOCK`).
+pub fn ignores_env(name: &str) -> bool {
+    (name.starts_with("GIT_") || name.starts_with("SSH_")) && !APPLIED_ENV.contains(&name)
+}
+
+/// Abre el repositorio respetando `GIT_DIR` y `GIT_WORK_TREE` del entorno configurado,
+/// como hace el binario `git`; las rutas relativas se resuelven desde `repo.path`.
+fn open(repo: &GitRepo) -> Result<Repository, String> {
+    let base = Path::new(&repo.path);
+    let result = match repo.env_var(&["GIT_DIR"]) {
+        Some(git_dir) => Repository::open(base.join(git_dir)).and_then(|repository| {
+            let work_tree = repo.env_var(&["GIT_WORK_TREE"]).unwrap_or(".");
+            repository.set_workdir(&base.join(work_tree), false)?;
+            Ok(repository)
+        }),
+        None => Repository::open(base),
+    };
+    result.map_err(|e| messages::repo_open_failed(&repo.path, e.message()))
+}
+
+fn git_error(err: git2::Error) -> String {
+    err.message().to_string()
+}
+
+/// Autenticación con el remoto: la clave SSH del repositorio si tiene una; si no, agente
+/// SSH y claves de `~/.ssh`. Por HTTPS, el token de `credentials.toml` o el credential
+/// helper de Git.
+fn remote_callbacks(repo: &GitRepo, deadline: Option<Instant>) -> RemoteCallbacks<'static> {
+    let mut ssh_attempt = 0;
+    let mut token_tried = false;
+    let credentials = repo.credentials.clone();
+    let ssh_key = repo.ssh_key.as_ref().map(PathBuf::from);
+    let mut helper_tried = false;
+    let home = repo
+        .env_var(&["HOME"])
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(PathBuf::from));
+
+    let mut callbacks = RemoteCallbacks::new();
+    callbacks.credentials(move |url, username, allowed| {
+        let username = username.unwrap_or("git");
+
+        if allowed.contains(CredentialType::SSH_KEY)
+            && let Some(key) = &ssh_key
+        {
+            if ssh_attempt > 0 {
+                return Err(git2::Error::from_str(&messages::ssh_key_rejected(
+                    key.display(),
+                )));
+            }
+            ssh_attempt += 1;
+            return Cred::ssh_key(username, None, key, None);
+        }
+
+        if allowed.contains(CredentialType::SSH_KEY) {
+            if ssh_attempt == 0 {
+                ssh_attempt += 1;
+                return Cred::ssh_key_from_agent(username);
+            }
+
+            let ssh_dir = home.as_ref().map(|home| home.join(".ssh"));
+            while let Some(name) = SSH_KEY_FILES.get(ssh_attempt - 1) {
+                ssh_attempt += 1;
+                if let Some(key) = ssh_dir.as_ref().map(|dir| dir.join(name))
+                    && key.exists()
+                {
+                    return Cred::ssh_key(username, None, &key, None);
+                }
+            }
+        }
+
+        if allowed.contains(CredentialType::USER_PASS_PLAINTEXT)
+            && !token_tried
+            && let Some(credential) = credentials.for_url(url)
+        {
+            token_tried = true;
+            return Cred::userpass_plaintext(&credential.username, &credential.token);
+        }
+
+        if allowed.contains(CredentialType::USER_PASS_PLAINTEXT) && !helper_tried {
+            helper_tried = true;
+            let config = git2::Config::open_default()?;
+            return Cred::credential_helper(&config, url, Some(username));
+        }
+
+        if allowed.contains(CredentialType::USERNAME) {
+            return Cred::username(username);
+        }
+
+        Err(git2::Error::from_str(&messages::no_credentials(url)))
+    });
+
+    // Cancelar la transferencia si se supera el plazo aunque el servidor siga respondiendo.
+    let in_time = move || deadline.is_none_or(|deadline| Instant::now() < deadline);
+    callbacks.transfer_progress(move |_| in_time());
+    callbacks.sideband_progress(move |_| in_time());
+    callbacks
+}
+
+/// Proxy definido en el archivo de entorno o, si no hay, el de la configuración de Git.
+fn proxy_options(repo: &GitRepo) -> ProxyOptions<'static> {
+    let mut proxy = ProxyOptions::new();
+    match repo.env_var(&["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy"]) {
+        Some(url) => proxy.url(url),
+        None => proxy.auto(),
+    };
+    proxy
+}
+
+fn fetch_options(repo: &GitRepo, deadline: Option<Instant>) -> FetchOptions<'static> {
+    let mut options = FetchOptions::new();
+    options
+        .remote_callbacks(remote_callbacks(repo, deadline))
+        .proxy_options(proxy_options(repo));
+    options
+}
+
+/// Ejecuta una operación con el remoto cancelándola si supera `timeout` (`None` = sin límite).
+fn remote_operation<T>(
+    timeout: Option<Duration>,
+    operation: &str,
+    run: impl FnOnce(Option<Instant>) -> Result<T, git2::Error>,
+) -> Result<T, String> {
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+    run(deadline).map_err(|err| match timeout {
+        Some(timeout) if deadline.is_some_and(|deadline| Instant::now() >= deadline) => {
+            timeout_error(operation, timeout)
+        }
+        _ => git_error(err),
+    })
+}
+
+/// Límites de conexión y de lectura/escritura de libgit2. Son opciones globales del proceso,
+/// así que se fijan una sola vez al iniciar, antes de que otros hilos usen libgit2: el mayor
+/// de `git_timeout` y `fetch_timeout` (sin límite si alguno es 0). El plazo de cada operación
+/// lo controlan después los callbacks de progreso de `remote_operation`.
+pub fn configure_timeouts(timeouts: &Timeouts) {
+    static CONFIGURED: Once = Once::new();
+    let seconds = if timeouts.git == 0 || timeouts.fetch == 0 {
+        0
+    } else {
+        timeouts.git.max(timeouts.fetch)
+    };
+    let millis = i32::try_from(seconds.saturating_mul(1000)).unwrap_or(i32::MAX);
+
+    CONFIGURED.call_once(|| {
+        // SAFETY: `call_once` garantiza una sola escritura, que se hace al iniciar el proceso
+        // antes de abrir cualquier conexión con libgit2.
+        unsafe {
+            let _ = git2::opts::set_server_connect_timeout_in_milliseconds(millis);
+            let _ = git2::opts::set_server_timeout_in_milliseconds(millis);
+        }
+    });
+}
+
+fn limit_depth(repo: &GitRepo, options: &mut FetchOptions<'_>) {
+    if let Some(depth) = repo.depth {
+        options.depth(i32::try_from(depth).unwrap_or(i32::MAX));
+    }
+}
+
+fn relative_time(commit_time: i64) -> String {
+    let seconds = (Utc::now().timestamp() - commit_time).max(0);
+    let (value, unit) = match seconds {
+        s if s < 60 => (s, messages::unit_seconds()),
+        s if s < 3_600 => (s / 60, messages::unit_minutes()),
+        s if s < 86_400 => (s / 3_600, messages::unit_hours()),
+        s if s < 604_800 => (s / 86_400, messages::unit_days()),
+        s if s < 2_592_000 => (s / 604_800, messages::unit_weeks()),
+        s if s < 31_536_000 => (s / 2_592_000, messages::unit_months()),
+        s => (s / 31_536_000, messages::unit_years()),
+    };
+    messages::time_ago(value, unit)
+}
+
+impl GitBackend for Libgit2Backend {
+    fn clone(&self, repo: &GitRepo, url: &str) -> Result<(), String> {
+        remote_operation(repo.timeout, "git clone", |deadline| {
+            let mut options = fetch_options(repo, deadline);
+            limit_depth(repo, &mut options);
+            RepoBuilder::new()
+                .remote_create(|repository, _, url| repository.remote(&repo.remote, url))
+                .fetch_options(options)
+                .clone(url, Path::new(&repo.path))
+                .map(|_| ())
+        })
+    }
+
+    fn clone_mirror(&self, repo: &GitRepo, url: &str) -> Result<(), String> {
+        remote_operation(repo.timeout, "git clone --mirror", |deadline| {
+            let mut options = fetch_options(repo, deadline);
+            limit_depth(repo, &mut options);
+            RepoBuilder::new()
+                .bare(true)
+                .remote_create(|repository, _, url| {
+                    let remote =
+                        repository.remote_with_fetch(&repo.remote, url, "+refs/*:refs/*")?;
+                    let key = format!("remote.{}.mirror", repo.remote);
+                    repository.config()?.set_bool(&key, true)?;
+                    Ok(remote)
+                })
+                .fetch_options(options)
+                .clone(url, Path::new(&repo.path))
+                .map(|_| ())
+        })
+    }
+
+    fn fetch(&self, repo: &GitRepo) -> Result<(), String> {
+        let repository = open(repo)?;
+        let mut remote = repository.find_remote(&repo.remote).map_err(git_error)?;
+        remote_operation(repo.fetch_timeout, "git fetch", |deadline| {
+            let mut options = fetch_options(repo, deadline);
+            if repo.prune {
+                options.prune(FetchPrune::On);
+            }
+            remote.fetch::<&str>(&[], Some(&mut options), None)
+        })
+    }
+
+    fn remote_update(&self, repo: &GitRepo) -> Result<(), String> {
+        let repository = open(repo)?;
+        let config = repository.config().map_err(git_error)?;
+        let remotes = repository.remotes().map_err(git_error)?;
+        for name in remotes.iter().flatten() {
+            // Igual que `git remote update`, se omiten los remotos marcados con skipDefaultUpdate.
+            let skip_key = format!("remote.{}.skipDefaultUpdate", name);
+            if config.get_bool(&skip_key).unwrap_or(false) {
+                continue;
+            }
+
+            let mut remote = repository.find_remote(name).map_err(git_error)?;
+            remote_operation(repo.fetch_timeout, "git remote update", |deadline| {
+                let mut options = fetch_options(repo, deadline);
+                options.prune(FetchPrune::On);
+                remote.fetch::<&str>(&[], Some(&mut options), None)
+            })?;
+        }
+        Ok(())
+    }
+
+    fn ref_targets(&self, repo: &GitRepo) -> Result<Vec<(String, String)>, String> {
+        let repository = open(repo)?;
+        let references = repository.references().map_err(git_error)?;
+        let mut targets = Vec::new();
+        for reference in references {
+            let reference = reference.map_err(git_error)?;
+            let (Some(name), Some(target)) = (reference.name(), reference.target()) else {
+                continue;
+            };
+            targets.push((name.to_string(), target.to_string()));
+        }
+        Ok(targets)
+    }
+
+    fn get_default_branch(&self, repo: &GitRepo) -> String {
+        let Ok(repository) = open(repo) else {
+            return "master".to_string();
+        };
+
+        // Intentar detectar la rama predeterminada
+        if let Ok(reference) = repository.find_reference(&repo.remote_ref("HEAD"))
+            && let Some(target) = reference.symbolic_target()
+            && let Some(branch) = target.strip_prefix(&repo.remote_ref(""))
+        {
+            return branch.to_string();
+        }
+
+        // Alternativa: verificar qué rama está disponible
+        if repository.find_reference(&repo.remote_ref("main")).is_ok() {
+            "main".to_string()
+        } else {
+            "master".to_string()
+        }
+    }
+
+    fn count_commits_behind(&self, repo: &GitRepo, branch: &str) -> Result<usize, String> {
+        let repository = open(repo)?;
+        let head = repository
+            .head()
+            .and_then(|head| head.peel_to_commit())
+            .map_err(git_error)?;
+        let upstream = repository
+            .find_reference(&repo.remote_ref(branch))
+            .and_then(|reference| reference.peel_to_commit())
+            .map_err(|e| messages::revision_not_found(repo.remote_branch(branch), e.message()))?;
+
+        let (_, behind) = repository
+            .graph_ahead_behind(head.id(), upstream.id())
+            .map_err(git_error)?;
+        Ok(behind)
+    }
+
+    fn count_commits_ahead(&self, repo: &GitRepo, branch: &str) -> Result<usize, String> {
+        let repository = open(repo)?;
+        let head = repository
+            .head()
+            .and_then(|head| head.peel_to_commit())
+            .map_err(git_error)?;
+        let upstream = repository
+            .find_reference(&repo.remote_ref(branch))
+            .and_then(|reference| reference.peel_to_commit())
+            .map_err(|e| messages::revision_not_found(repo.remote_branch(branch), e.message()))?;
+
+        let (ahead, _) = repository
+            .graph_ahead_behind(head.id(), upstream.id())
+            .map_err(git_error)?;
+        Ok(ahead)
+    }
+
+    fn current_branch(&self, repo: &GitRepo) -> Result<Option<String>, String> {
+        let repository = open(repo)?;
+        if repository.head_detached().map_err(git_error)? {
+            return Ok(None);
+        }
+
+        let head = repository.head().map_err(git_error)?;
+        Ok(head.shorthand().map(|name| name.to_string()))
+    }
+
+    fn checkout_branch(&self, repo: &GitRepo, branch: &str) -> Result<(), String> {
+        let repository = open(repo)?;
+        let local = match repository.find_branch(branch, BranchType::Local) {
+            Ok(local) => local,
+            Err(err) if err.code() == ErrorCode::NotFound => {
+                let upstream = repository
+                    .find_reference(&repo.remote_ref(branch))
+                    .and_then(|reference| reference.peel_to_commit())
+                    .map_err(|e| {
+                        messages::revision_not_found(repo.remote_branch(branch), e.message())
+                    })?;
+                let mut local = repository
+                    .branch(branch, &upstream, false)
+                    .map_err(git_error)?;
+                local
+                    .set_upstream(Some(&repo.remote_branch(branch)))
+                    .map_err(git_error)?;
+                local
+            }
+            Err(err) => return Err(git_error(err)),
+        };
+
+        let reference = local.into_reference();
+        let commit = reference.peel_to_commit().map_err(git_error)?;
+        // `safe` se niega a sobrescribir archivos con cambios locales.
+        repository
+            .checkout_tree(commit.as_object(), Some(CheckoutBuilder::new().safe()))
+            .map_err(git_error)?;
+        let name = reference.name().ok_or_else(messages::invalid_branch_name)?;
+        repository.set_head(name).map_err(git_error)
+    }
+
+    fn gone_branches(&self, repo: &GitRepo) -> Result<Vec<String>, String> {
+        let repository = open(repo)?;
+        let config = repository.config().map_err(git_error)?;
+        let branches = repository
+            .branches(Some(BranchType::Local))
+            .map_err(git_error)?;
+
+        let mut gone = Vec::new();
+        for branch in branches {
+            let (branch, _) = branch.map_err(git_error)?;
+            let Some(name) = branch.name().map_err(git_error)? else {
+                continue;
+            };
+            // La configuración de seguimiento sigue presente aunque la rama remota
+            // se haya eliminado con `fetch --prune`.
+            let remote = config.get_string(&format!("branch.{}.remote", name));
+            let merge = config.get_string(&format!("branch.{}.merge", name));
+            let (Ok(remote), Ok(merge)) = (remote, merge) else {
+                continue;
+            };
+            let Some(upstream) = merge.strip_prefix("refs/heads/") else {
+                continue;
+            };
+            if remote == repo.remote
+                && repository
+                    .find_reference(&repo.remote_ref(upstream))
+                    .is_err()
+            {
+                gone.push(name.to_string());
+            }
+        }
+        Ok(gone)
+    }
+
+    fn delete_merged_branch(&self, repo: &GitRepo, name: &str) -> Result<bool, String> {
+        let repository = open(repo)?;
+        let mut branch = repository
+            .find_branch(name, BranchType::Local)
+            .map_err(git_error)?;
+        let tip = branch.get().peel_to_commit().map_err(git_error)?.id();
+        let head = repository
+            .head()
+            .and_then(|head| head.peel_to_commit())
+            .map_err(git_error)?
+            .id();
+
+        let merged = tip == head
+            || repository
+                .graph_descendant_of(head, tip)
+                .map_err(git_error)?;
+        if !merged {
+            return Ok(false);
+        }
+
+        branch.delete().map_err(git_error)?;
+        Ok(true)
+    }
+
+    fn create_branch(&self, repo: &GitRepo, name: &str) -> Result<(), String> {
+        let repository = open(repo)?;
+        let head = repository
+            .head()
+            .and_then(|head| head.peel_to_commit())
+            .map_err(git_error)?;
+
+        repository
+            .branch(name, &head, false)
+            .map(|_| ())
+            .map_err(git_error)
+    }
+
+    fn fetch_tags(&self, repo: &GitRepo) -> Result<(), String> {
+        let repository = open(repo)?;
+        let mut remote = repository.find_remote(&repo.remote).map_err(git_error)?;
+        remote_operation(repo.fetch_timeout, "git fetch --tags", |deadline| {
+            let mut options = fetch_options(repo, deadline);
+            limit_depth(repo, &mut options);
+            remote.fetch(&["+refs/tags/*:refs/tags/*"], Some(&mut options), None)
+        })
+    }
+
+    fn list_tags(&self, repo: &GitRepo, pattern: &str) -> Result<Vec<String>, String> {
+        let repository = open(repo)?;
+        let tags = repository.tag_names(Some(pattern)).map_err(git_error)?;
+        Ok(tags.iter().flatten().map(|tag| tag.to_string()).collect())
+    }
+
+    fn resolve_commit(&self, repo: &GitRepo, rev: &str) -> Result<String, String> {
+        let repository = open(repo)?;
+        repository
+            .revparse_single(rev)
+            .and_then(|object| object.peel_to_commit())
+            .map(|commit| commit.id().to_string())
+            .map_err(|e| messages::revision_not_found(rev, e.message()))
+    }
+
+    fn checkout_detached(&self, repo: &GitRepo, rev: &str) -> Result<(), String> {
+        let repository = open(repo)?;
+        let commit = repository
+            .revparse_single(rev)
+            .and_then(|object| object.peel_to_commit())
+            .map_err(|e| messages::revision_not_found(rev, e.message()))?;
+
+        // `safe` se niega a sobrescribir archivos con cambios locales.
+        repository
+            .checkout_tree(commit.as_object(), Some(CheckoutBuilder::new().safe()))
+            .map_err(git_error)?;
+        repository.set_head_detached(commit.id()).map_err(git_error)
+    }
+
+    fn modified_files(&self, repo: &GitRepo) -> Result<Vec<String>, String> {
+        let repository = open(repo)?;
+        let mut options = StatusOptions::new();
+        options.include_untracked(false).include_ignored(false);
+        let statuses = repository.statuses(Some(&mut options)).map_err(git_error)?;
+
+        Ok(statuses
+            .iter()
+            .filter(|entry| entry.status() != Status::CURRENT)
+            .filter_map(|entry| entry.path().map(|path| path.to_string()))
+            .collect())
+    }
+
+    fn stash_pop(&self, repo: &GitRepo) -> Result<(), String> {
+        let mut repository = open(repo)?;
+        repository.stash_apply(0, None).map_err(git_error)?;
+
+        // Con conflictos libgit2 deja marcas en los archivos: se vuelve a HEAD y el stash
+        // se conserva para no perder los cambios.
+        if repository.index().map_err(git_error)?.has_conflicts() {
+            let head = repository
+                .head()
+                .and_then(|head| head.peel_to_commit())
+                .map_err(git_error)?;
+            repository
+                .reset(head.as_object(), ResetType::Hard, None)
+                .map_err(git_error)?;
+            return Err(messages::stash_conflict());
+        }
+
+        repository.stash_drop(0).map_err(git_error)
+    }
+
+    fn discard_changes(&self, repo: &GitRepo) -> Result<(), String> {
+        let repository = open(repo)?;
+        let mut checkout = CheckoutBuilder::new();
+        checkout.force();
+        repository
+            .checkout_head(Some(&mut checkout))
+            .map_err(git_error)
+    }
+
+    fn stash_changes(&self, repo: &GitRepo, message: &str) -> Result<bool, String> {
+        let mut repository = open(repo)?;
+        let signature = repository
+            .signature()
+            .or_else(|_| Signature::now("git-sync", "git-sync@localhost"))
+            .map_err(git_error)?;
+
+        match repository.stash_save(&signature, message, Some(StashFlags::INCLUDE_UNTRACKED)) {
+            Ok(_) => Ok(true),
+            Err(err) if err.code() == ErrorCode::NotFound => Ok(false),
+            Err(err) => Err(git_error(err)),
+        }
+    }
+
+    fn rebase(&self, repo: &GitRepo, branch: &str) -> Result<String, String> {
+        let repository = open(repo)?;
+        let upstream = repository
+            .find_reference(&repo.remote_ref(branch))
+            .and_then(|reference| repository.reference_to_annotated_commit(&reference))
+            .map_err(|e| messages::revision_not_found(repo.remote_branch(branch), e.message()))?;
+        let signature = repository
+            .signature()
+            .or_else(|_| Signature::now("git-sync", "git-sync@localhost"))
+            .map_err(git_error)?;
+
+        let mut rebase = repository
+            .rebase(None, Some(&upstream), None, None)
+            .map_err(git_error)?;
+        let mut applied = 0;
+        while let Some(operation) = rebase.next() {
+            let step = operation.map(|_| ()).and_then(|_| {
+                if repository.index()?.has_conflicts() {
+                    return Err(git2::Error::from_str(&messages::rebase_conflict()));
+                }
+                match rebase.commit(None, &signature, None) {
+                    Ok(_) => {
+                        applied += 1;
+                        Ok(())
+                    }
+                    // El commit ya estaba en el remoto: queda vacío y se omite.
+                    Err(err) if err.code() == ErrorCode::Applied => Ok(()),
+                    Err(err) => Err(err),
+                }
+            });
+            if let Err(err) = step {
+                let _ = rebase.abort();
+                return Err(git_error(err));
+            }
+        }
+        rebase.finish(Some(&signature)).map_err(git_error)?;
+
+        Ok(messages::rebase_applied(
+            applied,
+            repo.remote_branch(branch),
+        ))
+    }
+
+    fn merge(&self, repo: &GitRepo, branch: &str) -> Result<String, String> {
+        let repository = open(repo)?;
+        let upstream_ref = repository
+            .find_reference(&repo.remote_ref(branch))
+            .map_err(|e| messages::revision_not_found(repo.remote_branch(branch), e.message()))?;
+        let annotated = repository
+            .reference_to_annotated_commit(&upstream_ref)
+            .map_err(git_error)?;
+        let head = repository
+            .head()
+            .and_then(|head| head.peel_to_commit())
+            .map_err(git_error)?;
+        let upstream = upstream_ref.peel_to_commit().map_err(git_error)?;
+
+        repository
+            .merge(&[&annotated], None, Some(CheckoutBuilder::new().safe()))
+            .map_err(git_error)?;
+
+        let mut index = repository.index().map_err(git_error)?;
+        if index.has_conflicts() {
+            // Deshacer la fusión a medias para no dejar marcas de conflicto.
+            let _ = repository.cleanup_state();
+            let _ = repository.reset(head.as_object(), ResetType::Hard, None);
+            return Err(messages::merge_conflict());
+        }
+
+        let signature = repository
+            .signature()
+            .or_else(|_| Signature::now("git-sync", "git-sync@localhost"))
+            .map_err(git_error)?;
+        let tree = index
+            .write_tree()
+            .and_then(|id| repository.find_tree(id))
+            .map_err(git_error)?;
+        let message = format!("Merge {} (git-sync)", repo.remote_branch(branch));
+        let merged = repository
+            .commit(
+                Some("HEAD"),
+                &signature,
+                &signature,
+                &message,
+                &tree,
+                &[&head, &upstream],
+            )
+            .map_err(git_error)?;
+        repository.cleanup_state().map_err(git_error)?;
+
+        let short = |oid: git2::Oid| oid.to_string().chars().take(7).collect::<String>();
+        Ok(format!("Merge {}..{}", short(head.id()), short(merged)))
+    }
+
+    /// Integra `<remoto>/<rama>` (ya actualizada por `fetch`) solo mediante fast-forward.
+    fn pull(&self, repo: &GitRepo, branch: &str) -> Result<String, String> {
+        let repository = open(repo)?;
+        let upstream = repository
+            .find_reference(&repo.remote_ref(branch))
+            .map_err(|e| messages::revision_not_found(repo.remote_branch(branch), e.message()))?;
+        let annotated = repository
+            .reference_to_annotated_commit(&upstream)
+            .map_err(git_error)?;
+        let (analysis, _) = repository
+            .merge_analysis(&[&annotated])
+            .map_err(git_error)?;
+
+        if analysis.is_up_to_date() {
+            return Ok(messages::already_up_to_date());
+        }
+
+        if !analysis.is_fast_forward() {
+            return Err(messages::not_fast_forward(repo.remote_branch(branch)));
+        }
+
+        let mut head = repository.head().map_err(git_error)?;
+        if !head.is_branch() {
+            return Err(messages::head_not_branch());
+        }
+
+        let target = repository
+            .find_object(annotated.id(), None)
+            .map_err(git_error)?;
+        // `safe` se niega a sobrescribir archivos con cambios locales.
+        repository
+            .checkout_tree(&target, Some(CheckoutBuilder::new().safe()))
+            .map_err(git_error)?;
+
+        let previous = head.target();
+        head.set_target(
+            annotated.id(),
+            &messages::fast_forward_reflog(repo.remote_branch(branch)),
+        )
+        .map_err(git_error)?;
+
+        let short = |oid: git2::Oid| oid.to_string().chars().take(7).collect::<String>();
+        Ok(match previous {
+            Some(previous) => format!(
+                "Fast-forward {}..{}",
+                short(previous),
+                short(annotated.id())
+            ),
+            None => messages::fast_forward_to(short(annotated.id())),
+        })
+    }
+
+    fn reset_hard(&self, repo: &GitRepo, target: &str) -> Result<String, String> {
+        let repository = open(repo)?;
+        let commit = repository
+            .revparse_single(target)
+            .and_then(|object| object.peel_to_commit())
+            .map_err(|e| messages::revision_not_found(target, e.message()))?;
+
+        repository
+            .reset(commit.as_object(), ResetType::Hard, None)
+            .map_err(git_error)?;
+
+        let short_id = commit.as_object().short_id().map_err(git_error)?;
+        Ok(messages::head_now_at(
+            short_id.as_str().unwrap_or_default(),
+            commit.summary().unwrap_or_default(),
+        ))
+    }
+
+    fn clean(
+        &self,
+        repo: &GitRepo,
+        excludes: &[String],
+        dry_run: bool,
+    ) -> Result<Vec<String>, String> {
+        let repository = open(repo)?;
+        let workdir = repository
+            .workdir()
+            .ok_or_else(messages::no_worktree)?
+            .to_path_buf();
+
+        // Las exclusiones se añaden como reglas de `.gitignore` solo en memoria.
+        if !excludes.is_empty() {
+            repository
+                .add_ignore_rule(&excludes.join("\n"))
+                .map_err(git_error)?;
+        }
+
+        // Sin recorrer los directorios no rastreados se obtienen como `dir/`, igual que `git clean -d`.
+        // Con exclusiones se recorren para no borrar un directorio que contenga archivos excluidos.
+        let mut options = StatusOptions::new();
+        options
+            .include_untracked(true)
+            .recurse_untracked_dirs(!excludes.is_empty())
+            .include_ignored(false);
+        let statuses = repository.statuses(Some(&mut options)).map_err(git_error)?;
+
+        let mut removed = Vec::new();
+        for entry in statuses.iter() {
+            if !entry.status().contains(Status::WT_NEW) {
+                continue;
+            }
+            let Some(relative) = entry.path() else {
+                continue;
+            };
+
+            if dry_run {
+                removed.push(relative.to_string());
+                continue;
+            }
+
+            let path = workdir.join(relative);
+            let result = if path.is_dir() {
+                fs::remove_dir_all(&path)
+            } else {
+                fs::remove_file(&path)
+            };
+            result.map_err(|e| messages::remove_failed(path.display(), e))?;
+            removed.push(relative.to_string());
+
+            // Eliminar los directorios que quedaron vacíos, como `git clean -d`.
+            let mut parent = path.parent();
+            while let Some(dir) = parent.filter(|dir| *dir != workdir.as_path()) {
+                if fs::remove_dir(dir).is_err() {
+                    break;
+                }
+                parent = dir.parent();
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// libgit2 no implementa `gc`; el mantenimiento usa el binario `git` si está instalado.
+    fn prune_history(&self, repo: &GitRepo) -> Result<(), String> {
+        CliBackend.prune_history(repo)
+    }
+
+    fn verify_commit(
+        &self,
+        repo: &GitRepo,
+        rev: &str,
+        signers: Option<&Path>,
+    ) -> Result<(), String> {
+        // libgit2 no verifica firmas; se delega en gpg/ssh-keygen a través de git.
+        CliBackend.verify_commit(repo, rev, signers)
+    }
+
+    fn gc(&self, repo: &GitRepo) -> Result<(), String> {
+        // libgit2 no implementa `gc`; se usa el binario `git`.
+        CliBackend.gc(repo)
+    }
+
+    fn mirror_refs(&self, repo: &GitRepo) -> Result<Vec<String>, String> {
+        let repository = open(repo)?;
+        let mut refs = Vec::new();
+        for glob in [
+            format!("{}*", repo.remote_ref("")),
+            "refs/tags/*".to_string(),
+        ] {
+            let references = repository.references_glob(&glob).map_err(git_error)?;
+            for reference in references {
+                let reference = reference.map_err(git_error)?;
+                if let Some(name) = reference.name() {
+                    refs.push(name.to_string());
+                }
+            }
+        }
+        Ok(refs)
+    }
+
+    fn push(&self, repo: &GitRepo, remote: &str, refspecs: &[String]) -> Result<(), String> {
+        let repository = open(repo)?;
+        let mut remote = repository.find_remote(remote).map_err(git_error)?;
+        remote_operation(repo.timeout, "git push", |deadline| {
+            let mut callbacks = remote_callbacks(repo, deadline);
+            // Un rechazo del servidor llega por referencia, no como error de la operación.
+            callbacks.push_update_reference(|refname, status| match status {
+                Some(message) => Err(git2::Error::from_str(&format!(
+                    "{} rechazada: {}",
+                    refname, message
+                ))),
+                None => Ok(()),
+            });
+
+            let mut options = PushOptions::new();
+            options
+                .remote_callbacks(callbacks)
+                .proxy_options(proxy_options(repo));
+            remote.push(refspecs, Some(&mut options))
+        })
+    }
+
+    fn ls_remote(&self, repo: &GitRepo, remote: &str) -> Result<usize, String> {
+        // Sin repositorio (aún no clonado) o sin un remoto con ese nombre, se trata como URL.
+        let repository = open(repo).ok();
+        let mut remote = match repository
+            .as_ref()
+            .map(|repository| repository.find_remote(remote))
+        {
+            Some(Ok(found)) => found,
+            Some(Err(_)) if !remote.contains(['/', ':']) => {
+                return Err(messages::remote_not_found(remote));
+            }
+            _ => Remote::create_detached(remote).map_err(git_error)?,
+        };
+        remote_operation(repo.timeout, "git ls-remote", |deadline| {
+            let connection = remote.connect_auth(
+                Direction::Fetch,
+                Some(remote_callbacks(repo, deadline)),
+                Some(proxy_options(repo)),
+            )?;
+            Ok(connection.list()?.len())
+        })
+    }
+
+    fn remote_url(&self, repo: &GitRepo, remote: &str) -> Result<Option<String>, String> {
+        let repository = open(repo)?;
+        match repository.find_remote(remote) {
+            Ok(found) => Ok(found.url().map(str::to_string)),
+            Err(e) if e.code() == ErrorCode::NotFound => Ok(None),
+            Err(e) => Err(git_error(e)),
+        }
+    }
+
+    fn head_commit_summary(&self, repo: &GitRepo) -> Result<String, String> {
+        let repository = open(repo)?;
+        let commit = repository
+            .head()
+            .and_then(|head| head.peel_to_commit())
+            .map_err(git_error)?;
+        let short_id = commit.as_object().short_id().map_err(git_error)?;
+
+        Ok(format!(
+            "{} {}",
+            short_id.as_str().unwrap_or_default(),
+            commit.summary().unwrap_or_default()
+        ))
+    }
+
+    fn commits_between(
+        &self,
+        repo: &GitRepo,
+        from: &str,
+        to: &str,
+        limit: usize,
+    ) -> Result<Vec<String>, String> {
+        let repository = open(repo)?;
+        let mut revwalk = repository.revwalk().map_err(git_error)?;
+        let resolve = |rev: &str| {
+            repository
+                .revparse_single(rev)
+                .and_then(|object| object.peel_to_commit())
+                .map(|commit| commit.id())
+                .map_err(|e| messages::revision_not_found(rev, e.message()))
+        };
+        revwalk.push(resolve(to)?).map_err(git_error)?;
+        revwalk.hide(resolve(from)?).map_err(git_error)?;
+
+        let mut commits = Vec::new();
+        for oid in revwalk.take(limit) {
+            let commit = oid
+                .and_then(|oid| repository.find_commit(oid))
+                .map_err(git_error)?;
+            let short_id = commit.as_object().short_id().map_err(git_error)?;
+            commits.push(format!(
+                "{} | {} | {}",
+                short_id.as_str().unwrap_or_default(),
+                commit.author().name().unwrap_or_default(),
+                commit.summary().unwrap_or_default()
+            ));
+        }
+        Ok(commits)
+    }
+
+    fn diff_files(&self, repo: &GitRepo, from: &str, to: &str) -> Result<Vec<String>, String> {
+        let repository = open(repo)?;
+        let tree = |rev: &str| {
+            repository
+                .revparse_single(rev)
+                .and_then(|object| object.peel_to_tree())
+                .map_err(git_error)
+        };
+        let (old_tree, new_tree) = (tree(from)?, tree(to)?);
+        let diff = repository
+            .diff_tree_to_tree(Some(&old_tree), Some(&new_tree), None)
+            .map_err(git_error)?;
+
+        Ok(diff
+            .deltas()
+            .filter_map(|delta| delta.new_file().path().or_else(|| delta.old_file().path()))
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect())
+    }
+
+    fn recent_commits(&self, repo: &GitRepo, limit: usize) -> Result<Vec<String>, String> {
+        let repository = open(repo)?;
+        let mut revwalk = repository.revwalk().map_err(git_error)?;
+        revwalk.push_head().map_err(git_error)?;
+
+        let mut commits = Vec::new();
+        for oid in revwalk.take(limit) {
+            let commit = oid
+                .and_then(|oid| repository.find_commit(oid))
+                .map_err(git_error)?;
+            let short_id = commit.as_object().short_id().map_err(git_error)?;
+            commits.push(format!(
+                "{} | {} | {}",
+                short_id.as_str().unwrap_or_default(),
+                relative_time(commit.time().seconds()),
+                commit.summary().unwrap_or_default()
+            ));
+        }
+        Ok(commits)
+    }
+
+    fn work_tree(&self, repo: &GitRepo) -> Result<PathBuf, String> {
+        open(repo)?
+            .workdir()
+            .map(Path::to_path_buf)
+            .ok_or_else(messages::bare_no_worktree)
+    }
+
+    fn common_dir(&self, repo: &GitRepo) -> Result<PathBuf, String> {
+        Ok(open(repo)?.commondir().to_path_buf())
+    }
+}
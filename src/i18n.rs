@@ -0,0 +1,80 @@
+//! Idioma de los mensajes de la línea de comandos, la TUI y el registro. Cada mensaje tiene
+//! un identificador, la función que lo genera en `messages`, con su texto en español y en
+//! inglés. El idioma sale de `language` en config.toml o, sin él, del entorno.
+
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Idiomas disponibles para los mensajes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    /// Español
+    #[default]
+    Es,
+    /// Inglés
+    En,
+}
+
+static LANGUAGE: AtomicU8 = AtomicU8::new(Language::Es as u8);
+
+impl Language {
+    /// Idioma de un valor de locale (`es_ES.UTF-8`, `en_US`...), si es uno de los disponibles.
+    fn from_locale(value: &str) -> Option<Self> {
+        match value.get(..2) {
+            Some("es") => Some(Language::Es),
+            Some("en") => Some(Language::En),
+            _ => None,
+        }
+    }
+}
+
+/// Idioma de `LC_ALL`, `LC_MESSAGES` o `LANG`: cuenta la primera variable con valor, como en
+/// cualquier programa. Con un locale sin idioma (`C`, `POSIX`) o sin traducción, español.
+fn from_env() -> Language {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .and_then(|value| Language::from_locale(&value))
+        .unwrap_or_default()
+}
+
+/// Usa `language` (el de config.toml) o, sin él, el del entorno.
+pub fn select(language: Option<Language>) {
+    let language = language.unwrap_or_else(from_env);
+    LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+pub fn current() -> Language {
+    if LANGUAGE.load(Ordering::Relaxed) == Language::En as u8 {
+        Language::En
+    } else {
+        Language::Es
+    }
+}
+
+/// Define el catálogo: por cada identificador, una función que recibe los valores que se
+/// interpolan (por nombre en ambos textos) y devuelve el mensaje en el idioma activo.
+macro_rules! messages {
+    ($(
+        $(#[$attr:meta])*
+        $id:ident($($arg:ident),*) {
+            es: $es:literal,
+            en: $en:literal $(,)?
+        }
+    )*) => {
+        $(
+            $(#[$attr])*
+            pub fn $id($($arg: impl std::fmt::Display),*) -> String {
+                match $crate::i18n::current() {
+                    $crate::i18n::Language::Es => format!($es),
+                    $crate::i18n::Language::En => format!($en),
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use messages;
@@ -0,0 +1,76 @@
+//! Sincronización de repositorios Git para despliegues: la lógica de `git-sync` como
+//! biblioteca, para integrarla en otros programas. El binario `git-sync` es solo la línea
+//! de comandos sobre ella.
+//!
+//! - `Config`: rutas de la configuración y lista de repositorios (`repositories.txt`).
+//! - `Settings`: opciones de config.toml.
+//! - `GitRepo`: operaciones Git de un repositorio, con libgit2 o el binario `git`.
+//! - `RepoProcessor`: un ciclo de sincronización completo sobre una lista de repositorios.
+//!
+//! Los errores de esta API son `Error`. Los mensajes y el registro usan el idioma elegido
+//! con `i18n::select`, español si no se llama.
+
+pub mod activity;
+pub mod config;
+pub mod credentials;
+pub mod error;
+pub mod events;
+pub mod git;
+pub mod i18n;
+pub mod logger;
+pub mod notifier;
+pub mod processor;
+pub mod settings;
+pub mod sync_state;
+
+// Módulos del daemon y de la línea de comandos: públicos para el binario, pero no forman
+// parte de la API.
+#[doc(hidden)]
+pub mod config_watch;
+#[doc(hidden)]
+pub mod control;
+#[doc(hidden)]
+pub mod discover;
+#[doc(hidden)]
+pub mod environment;
+#[doc(hidden)]
+pub mod exchange;
+#[doc(hidden)]
+pub mod logs;
+#[doc(hidden)]
+pub mod messages;
+#[doc(hidden)]
+pub mod migrate;
+#[doc(hidden)]
+pub mod pause;
+#[doc(hidden)]
+pub mod platform;
+#[doc(hidden)]
+pub mod preset;
+#[doc(hidden)]
+pub mod retention;
+#[doc(hidden)]
+pub mod schedule;
+#[doc(hidden)]
+pub mod secrets;
+#[doc(hidden)]
+pub mod semver;
+#[doc(hidden)]
+pub mod service;
+#[doc(hidden)]
+pub mod signals;
+#[doc(hidden)]
+pub mod supervise;
+#[doc(hidden)]
+pub mod tui;
+#[doc(hidden)]
+pub mod validate;
+#[doc(hidden)]
+pub mod watch;
+
+pub use config::{Config, RepoDefinition};
+pub use error::Error;
+pub use git::GitRepo;
+pub use logger::Logger;
+pub use processor::RepoProcessor;
+pub use settings::Settings;
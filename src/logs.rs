@@ -0,0 +1,430 @@
+use crate::config::{self, Config};
+use crate::logger::{LogBackend, LogLevel};
+use crate::messages;
+use crate::platform;
+use crate::settings::Settings;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime};
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+
+/// Entradas que se muestran sin `--since` ni `--lines`.
+const DEFAULT_LINES: usize = 100;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Filtros de `git-sync logs`; una entrada se muestra si cumple todos.
+#[derive(Default)]
+pub struct LogFilter {
+    /// Nombre y ruta del repositorio cuyas entradas se muestran
+    pub repo: Option<(String, String)>,
+    /// Solo las entradas registradas desde este momento (hora local)
+    pub since: Option<NaiveDateTime>,
+    /// Nivel mínimo: `warn` muestra advertencias y errores
+    pub level: Option<LogLevel>,
+}
+
+/// `--since`: una duración hacia atrás (`30m`, `2h`, `1d`) o una fecha local
+/// (`2025-01-01`, `2025-01-01 10:00` o `2025-01-01 10:00:00`).
+pub fn parse_since(value: &str) -> Result<NaiveDateTime, String> {
+    if let Some(seconds) = config::parse_duration(value) {
+        return Ok(Local::now().naive_local() - chrono::Duration::seconds(seconds as i64));
+    }
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S"] {
+        if let Ok(moment) = NaiveDateTime::parse_from_str(value, format) {
+            return Ok(moment);
+        }
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .ok_or_else(|| messages::invalid_since(value))
+}
+
+/// Muestra las entradas de `git-sync.log` que cumplen `filter` y, con `follow`, sigue
+/// mostrando las nuevas. Con `--since` también se leen los archivos rotados.
+pub fn show_logs(
+    config: &Config,
+    filter: LogFilter,
+    lines: Option<usize>,
+    follow: bool,
+) -> Result<(), String> {
+    let log_file = &config.log_file;
+    if !Path::new(log_file).exists() {
+        let settings = Settings::load_or_default(&config.settings_file);
+        return Err(missing_log(log_file, settings.log_backend));
+    }
+
+    let limit = lines.or(filter.since.is_none().then_some(DEFAULT_LINES));
+    let mut reader = LogReader::new(filter);
+    let mut entries = Vec::new();
+
+    if reader.filter.since.is_some() {
+        for archive in rotated_logs(log_file) {
+            for line in read_archive(&archive)?.lines() {
+                if reader.accept(line) {
+                    entries.push(line.to_string());
+                }
+            }
+        }
+    }
+
+    let current = fs::read(log_file).map_err(|e| messages::log_read_failed(log_file, e))?;
+    for line in String::from_utf8_lossy(&current).lines() {
+        if reader.accept(line) {
+            entries.push(line.to_string());
+        }
+    }
+
+    let skip = limit.map_or(0, |limit| entries.len().saturating_sub(limit));
+    let mut stdout = io::stdout();
+    for entry in &entries[skip..] {
+        // Si la salida se cerró (por ejemplo `| head`) se termina sin error.
+        if writeln!(stdout, "{}", entry).is_err() {
+            return Ok(());
+        }
+    }
+
+    if follow {
+        let _ = stdout.flush();
+        follow_file(log_file, Some(current.len() as u64), |line| {
+            reader.accept(line).then(|| line.to_string())
+        })?;
+    }
+    Ok(())
+}
+
+/// Error cuando no existe `git-sync.log`, con una pista si las entradas van al journal o a
+/// syslog.
+pub fn missing_log(log_file: &str, backend: LogBackend) -> String {
+    let hint = match backend {
+        LogBackend::Journald => messages::journald_hint(),
+        LogBackend::Syslog => messages::syslog_hint(),
+        _ => String::new(),
+    };
+    messages::log_missing(log_file, hint)
+}
+
+/// Lectura incremental de `git-sync.log` para el panel de registro de la TUI: conserva las
+/// últimas `capacity` entradas que cumplen el filtro y en cada `poll` añade las escritas
+/// desde la anterior. Tras una rotación sigue desde el principio del archivo nuevo y mantiene
+/// las entradas ya leídas.
+pub struct LogTail {
+    log_file: String,
+    reader: LogReader,
+    entries: VecDeque<String>,
+    capacity: usize,
+    position: u64,
+    inode: Option<u64>,
+    pending: String,
+}
+
+impl LogTail {
+    pub fn new(log_file: &str, filter: LogFilter, capacity: usize) -> Self {
+        LogTail {
+            log_file: log_file.to_string(),
+            reader: LogReader::new(filter),
+            entries: VecDeque::new(),
+            capacity,
+            position: 0,
+            inode: None,
+            pending: String::new(),
+        }
+    }
+
+    pub fn entries(&self) -> &VecDeque<String> {
+        &self.entries
+    }
+
+    /// Lee lo nuevo del registro y devuelve cuántas entradas se añadieron.
+    pub fn poll(&mut self) -> Result<usize, String> {
+        let metadata = fs::metadata(&self.log_file)
+            .map_err(|e| messages::log_read_failed(&self.log_file, e))?;
+        let inode = platform::file_id(&metadata);
+        if self.inode != Some(inode) || metadata.len() < self.position {
+            self.inode = Some(inode);
+            self.position = 0;
+            self.pending.clear();
+        }
+        if metadata.len() == self.position {
+            return Ok(0);
+        }
+
+        let mut file =
+            File::open(&self.log_file).map_err(|e| messages::log_open_failed(&self.log_file, e))?;
+        file.seek(SeekFrom::Start(self.position))
+            .map_err(|e| messages::log_read_failed(&self.log_file, e))?;
+        let mut buffer = Vec::new();
+        let read = file
+            .read_to_end(&mut buffer)
+            .map_err(|e| messages::log_read_failed(&self.log_file, e))?;
+        self.position += read as u64;
+        self.pending.push_str(&String::from_utf8_lossy(&buffer));
+
+        // La última línea puede estar a medio escribir: se completa en la siguiente lectura.
+        let mut added = 0;
+        while let Some(index) = self.pending.find('\n') {
+            let line = self.pending[..index].to_string();
+            self.pending.drain(..=index);
+            if line.trim().is_empty() || !self.reader.accept(&line) {
+                continue;
+            }
+            self.entries.push_back(line);
+            added += 1;
+        }
+        while self.entries.len() > self.capacity {
+            self.entries.pop_front();
+        }
+        Ok(added)
+    }
+}
+
+/// Nivel y texto legible de una entrada del registro; las de `log_format = "json"` se muestran
+/// como `[fecha] mensaje`, igual que las de texto.
+pub fn display_entry(line: &str) -> (LogLevel, String) {
+    if line.starts_with('{')
+        && let Ok(entry) = serde_json::from_str::<serde_json::Value>(line)
+    {
+        let level = entry["level"]
+            .as_str()
+            .and_then(|level| LogLevel::parse(level).ok())
+            .unwrap_or_default();
+        let message = entry["message"].as_str().unwrap_or_default();
+        let text = match entry["timestamp"]
+            .as_str()
+            .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+        {
+            Some(time) => format!(
+                "[{}] {}",
+                time.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S"),
+                message
+            ),
+            None => message.to_string(),
+        };
+        return (level, text);
+    }
+
+    let (_, message) = split_timestamp(line);
+    (text_level(message), line.to_string())
+}
+
+/// Archivos rotados (`git-sync.log.1.gz`, `.2.gz`...), del más antiguo al más reciente.
+fn rotated_logs(log_file: &str) -> Vec<String> {
+    let mut archives = Vec::new();
+    for index in 1.. {
+        let plain = format!("{}.{}", log_file, index);
+        let compressed = format!("{}.gz", plain);
+        if Path::new(&compressed).exists() {
+            archives.push(compressed);
+        } else if Path::new(&plain).exists() {
+            archives.push(plain);
+        } else {
+            break;
+        }
+    }
+    archives.reverse();
+    archives
+}
+
+fn read_archive(path: &str) -> Result<String, String> {
+    if !path.ends_with(".gz") {
+        return fs::read(path)
+            .map(|content| String::from_utf8_lossy(&content).into_owned())
+            .map_err(|e| messages::log_read_failed(path, e));
+    }
+
+    let output = Command::new("gzip")
+        .args(["-dc", "--", path])
+        .output()
+        .map_err(|e| messages::gzip_failed(path, e))?;
+    if !output.status.success() {
+        return Err(messages::decompress_failed(
+            path,
+            String::from_utf8_lossy(&output.stderr).trim(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Recorre el registro en orden. En texto las entradas no llevan el repositorio, así que se
+/// atribuyen al del último encabezado `🔄 Procesando repositorio: <nombre> (<ruta>)` hasta
+/// que termina el ciclo; las líneas sin fecha toman la de la entrada anterior. Los
+/// encabezados se reconocen por el emoji y la forma, no por el texto, porque el registro
+/// puede tener entradas escritas en cualquiera de los idiomas.
+struct LogReader {
+    filter: LogFilter,
+    current_repo: Option<(String, String)>,
+    last_time: Option<NaiveDateTime>,
+}
+
+impl LogReader {
+    fn new(filter: LogFilter) -> Self {
+        LogReader {
+            filter,
+            current_repo: None,
+            last_time: None,
+        }
+    }
+
+    fn accept(&mut self, line: &str) -> bool {
+        let (time, level, repo) = if line.starts_with('{') {
+            Self::parse_json(line)
+        } else {
+            self.parse_text(line)
+        };
+        if time.is_some() {
+            self.last_time = time;
+        }
+
+        if let Some(since) = self.filter.since
+            && self.last_time.is_none_or(|time| time < since)
+        {
+            return false;
+        }
+        if let Some(level_filter) = self.filter.level
+            && level > level_filter
+        {
+            return false;
+        }
+        if let Some((name, path)) = &self.filter.repo {
+            return repo.iter().any(|repo| repo == name || repo == path);
+        }
+        true
+    }
+
+    /// Fecha, nivel y los identificadores del repositorio (nombre o ruta) de una entrada.
+    fn parse_json(line: &str) -> (Option<NaiveDateTime>, LogLevel, Vec<String>) {
+        let Ok(entry) = serde_json::from_str::<serde_json::Value>(line) else {
+            return (None, LogLevel::Info, Vec::new());
+        };
+        let time = entry["timestamp"]
+            .as_str()
+            .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+            .map(|timestamp| timestamp.with_timezone(&Local).naive_local());
+        let level = entry["level"]
+            .as_str()
+            .and_then(|level| LogLevel::parse(level).ok())
+            .unwrap_or_default();
+        let repo = entry["repo"]
+            .as_str()
+            .map(str::to_string)
+            .into_iter()
+            .collect();
+        (time, level, repo)
+    }
+
+    fn parse_text(&mut self, line: &str) -> (Option<NaiveDateTime>, LogLevel, Vec<String>) {
+        let (timestamp, message) = split_timestamp(line);
+        let time = timestamp.and_then(|timestamp| {
+            NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d %H:%M:%S").ok()
+        });
+
+        if let Some(repo) = message
+            .strip_prefix("🔄 ")
+            .and_then(|header| header.split_once(": "))
+            .and_then(|(_, header)| header.strip_suffix(')'))
+            .and_then(|header| header.rsplit_once(" ("))
+        {
+            self.current_repo = Some((repo.0.to_string(), repo.1.to_string()));
+        } else if message.starts_with("📦 ") || message.starts_with("🎉 ") {
+            self.current_repo = None;
+        }
+
+        let repo = self
+            .current_repo
+            .iter()
+            .flat_map(|(name, path)| [name.clone(), path.clone()])
+            .collect();
+        (time, text_level(message), repo)
+    }
+}
+
+/// Sigue `log_file` desde `start` (o desde el final) y escribe lo que `format` devuelve para
+/// cada línea nueva. Tras una rotación continúa desde el principio del archivo nuevo.
+fn follow_file(
+    log_file: &str,
+    start: Option<u64>,
+    mut format: impl FnMut(&str) -> Option<String>,
+) -> Result<(), String> {
+    let mut file = open_log(log_file)?;
+    let mut inode = file
+        .metadata()
+        .map(|m| platform::file_id(&m))
+        .map_err(|e| messages::log_read_failed(log_file, e))?;
+    let mut position = file
+        .seek(start.map_or(SeekFrom::End(0), SeekFrom::Start))
+        .map_err(|e| messages::log_read_failed(log_file, e))?;
+    let mut pending = String::new();
+    let mut stdout = io::stdout();
+
+    loop {
+        let metadata = match fs::metadata(log_file) {
+            Ok(metadata) => metadata,
+            Err(_) => {
+                // El archivo puede desaparecer momentáneamente durante una rotación.
+                thread::sleep(POLL_INTERVAL);
+                continue;
+            }
+        };
+
+        if platform::file_id(&metadata) != inode || metadata.len() < position {
+            file = open_log(log_file)?;
+            inode = platform::file_id(&metadata);
+            position = 0;
+        }
+
+        if metadata.len() > position {
+            file.seek(SeekFrom::Start(position))
+                .map_err(|e| messages::log_read_failed(log_file, e))?;
+            let mut buffer = Vec::new();
+            let read = file
+                .read_to_end(&mut buffer)
+                .map_err(|e| messages::log_read_failed(log_file, e))?;
+            position += read as u64;
+            pending.push_str(&String::from_utf8_lossy(&buffer));
+
+            while let Some(index) = pending.find('\n') {
+                let line = pending[..index].to_string();
+                pending.drain(..=index);
+
+                if let Some(output) = format(&line) {
+                    // Si la salida se cerró (por ejemplo `| head`) se termina sin error.
+                    if writeln!(stdout, "{}", output).is_err() || stdout.flush().is_err() {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn open_log(log_file: &str) -> Result<File, String> {
+    File::open(log_file).map_err(|e| messages::log_open_failed(log_file, e))
+}
+
+/// Nivel de una entrada de texto, que no lo incluye: se deduce de sus marcas.
+fn text_level(message: &str) -> LogLevel {
+    if message.contains("ERROR") || message.starts_with('❌') {
+        LogLevel::Error
+    } else if message.starts_with("⚠️") {
+        LogLevel::Warn
+    } else {
+        LogLevel::Info
+    }
+}
+
+fn split_timestamp(line: &str) -> (Option<&str>, &str) {
+    if let Some(rest) = line.strip_prefix('[')
+        && let Some((timestamp, message)) = rest.split_once("] ")
+    {
+        return (Some(timestamp), message.trim());
+    }
+
+    (None, line.trim())
+}
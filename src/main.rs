@@ -1,242 +1,1202 @@
-mod config;
-mod git;
-mod logger;
-mod processor;
-mod service;
-mod settings;
-mod sync_state;
-mod tui;
-
-use config::{Config, RepoDefinition};
-use logger::Logger;
-use processor::RepoProcessor;
-use service::{install_service, uninstall_all, uninstall_service};
-use settings::Settings;
+mod args;
+
+use args::{
+    AddArgs, Cli, CliCommand, ConfigAction, InstallArgs, LogsArgs, PauseTarget, ServiceAction,
+};
+use chrono::{Local, Utc};
+use git_sync::config::{self, Config, RepoDefinition, SyncMode};
+use git_sync::config_watch::ConfigWatcher;
+use git_sync::control::{ControlSocket, EventStream, SyncRequest};
+use git_sync::credentials::Credentials;
+use git_sync::events::{EventLog, SyncRecord, SyncResult};
+use git_sync::git::{self, GitRepo};
+use git_sync::logger::{ConsoleStyle, Logger};
+use git_sync::notifier::Notifier;
+use git_sync::pause::{self, PauseState};
+use git_sync::processor::RepoProcessor;
+use git_sync::schedule::{self, Schedule};
+use git_sync::secrets::Secrets;
+use git_sync::service::{
+    self, install_service, print_status, reinstall_service, uninstall_all, uninstall_service,
+};
+use git_sync::settings::Settings;
+use git_sync::signals::{DaemonSignals, WakeReason};
+use git_sync::tui::run_repo_manager;
+use git_sync::{
+    discover, environment, exchange, i18n, logs, messages, platform, retention, supervise,
+    validate, watch,
+};
+use std::cell::RefCell;
 use std::env;
 use std::fs;
-use std::io::{self, Write};
-use std::os::unix::fs::PermissionsExt;
+use std::io::{self, IsTerminal, Write};
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use tui::run_repo_manager;
+use std::time::{Duration, Instant};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
-fn print_version() {
-    println!("ℹ️ git-sync v{}", VERSION);
-}
-
-fn print_help() {
-    let help = format!(
-        r#"
-ℹ️ git-sync v{version}
-
-🧭 Servicio de sincronización de repositorios Git.
-━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
-
-📘 Uso rápido
-  • git-sync
-      Abre la interfaz interactiva para gestionar repositorios
-      (instala el servicio si es necesario).
-  • git-sync daemon
-      Ejecuta el daemon de sincronización (pensado para systemd).
-  • git-sync uninstall-service
-      Detiene y elimina el servicio systemd.
-  • git-sync uninstall
-      Elimina servicio, configuración y logs de git-sync.
-  • git-sync update
-      Actualiza a la última versión estable.
-  • git-sync --add-current
-      Pregunta si desea agregar el directorio actual como repositorio.
-  • git-sync --help
-      Muestra esta ayuda.
-  • git-sync --version
-      Muestra la versión actual.
-
-🗂️ Archivos de configuración
-  • Configuración  → /etc/git-sync/config.toml
-  • Repositorios   → /etc/git-sync/repositories.txt
-  • Registros      → /var/log/git-sync/git-sync.log
-
-🛠️ Recuerde
-    • Utilice rutas locales del servidor (no URLs remotas).
-    • El daemon solo sincroniza repositorios Git locales.
-  • Revise los permisos de archivos si ejecuta como otro usuario.
-"#,
-        version = VERSION
-    );
+/// Directorio de `--config` o, sin él, de `GIT_SYNC_CONFIG_DIR`. Las rutas relativas se
+/// resuelven desde el directorio actual.
+fn resolve_config_dir(dir: Option<String>) -> Result<Option<String>, String> {
+    let Some(dir) = dir.or_else(|| env::var("GIT_SYNC_CONFIG_DIR").ok()) else {
+        return Ok(None);
+    };
+    if dir.trim().is_empty() {
+        return Err(messages::config_dir_empty());
+    }
+
+    let path = Path::new(&dir);
+    if path.is_absolute() {
+        return Ok(Some(dir));
+    }
+    let current = env::current_dir().map_err(messages::current_dir_failed)?;
+    Ok(Some(current.join(path).to_string_lossy().into_owned()))
+}
+
+/// Códigos de salida, para que scripts y comprobaciones de monitorización distingan los
+/// fallos sin interpretar los mensajes. 0 es éxito.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Exit {
+    /// Error general o uso inválido de la línea de comandos
+    Failure = 1,
+    /// Configuración inexistente, ilegible o inválida
+    Config = 2,
+    /// Algunos repositorios no se pudieron sincronizar
+    PartialSync = 3,
+    /// Ningún repositorio se pudo sincronizar
+    SyncFailed = 4,
+    /// El servicio no está instalado o activo, o no se pudo instalar o quitar
+    Service = 5,
+}
 
-    println!("{}", help.trim_start());
+/// Error de un subcomando con el código con el que termina el proceso.
+struct CommandError {
+    exit: Exit,
+    message: String,
+}
+
+impl CommandError {
+    fn new(exit: Exit) -> impl Fn(String) -> Self {
+        move |message| CommandError { exit, message }
+    }
+}
+
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        CommandError {
+            exit: Exit::Failure,
+            message,
+        }
+    }
+}
+
+impl From<git_sync::Error> for CommandError {
+    fn from(error: git_sync::Error) -> Self {
+        let exit = match error {
+            git_sync::Error::Config(_) | git_sync::Error::NoRepos(_) => Exit::Config,
+            _ => Exit::Failure,
+        };
+        CommandError {
+            exit,
+            message: error.to_string(),
+        }
+    }
+}
+
+fn exit_with(exit: Exit) -> ! {
+    std::process::exit(exit as i32)
+}
+
+fn print_version() {
+    println!("{}", messages::version(VERSION));
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    let config = Config::new();
+    i18n::select(None);
+    let cli = Cli::parse_args(&config::system_config_dir());
+    if cli.version {
+        print_version();
+        return;
+    }
 
-    match args.get(1).map(|s| s.as_str()) {
-        Some("--version") | Some("-v") => {
-            print_version();
-            return;
+    let config_dir = match resolve_config_dir(cli.config) {
+        Ok(dir) => dir,
+        Err(err) => {
+            eprintln!("❌ {}", err);
+            exit_with(Exit::Config);
         }
-        Some("--help") | Some("-h") => {
-            print_help();
-            return;
+    };
+    let config = Config::new(cli.user, config_dir);
+    let settings = Settings::load(&config.settings_file).ok();
+    // La ayuda y los errores de uso ya se mostraron con el idioma del entorno.
+    i18n::select(settings.as_ref().and_then(|settings| settings.language));
+    // Los límites de red de libgit2 son globales: se fijan antes de que la TUI o el daemon
+    // abran otros hilos.
+    git::configure_timeouts(&settings.unwrap_or_default().timeouts());
+
+    if cli.read_only {
+        if !Path::new(&config.repos_file).exists() {
+            eprintln!(
+                "{}",
+                messages::repos_file_missing(&config.repos_file, config.command())
+            );
+            exit_with(Exit::Config);
         }
-        Some("daemon") => {
-            run_daemon(config);
-            return;
+
+        let settings = Settings::load_or_default(&config.settings_file);
+        if let Err(err) = run_repo_manager(&config, &settings, true) {
+            eprintln!("{}", messages::repo_manager_failed(err));
+            std::process::exit(1);
         }
-        Some("uninstall-service") => {
-            if let Err(err) = uninstall_service() {
-                eprintln!("❌ No se pudo desinstalar el servicio: {}", err);
-                std::process::exit(1);
+        return;
+    }
+
+    let result = match cli.command {
+        Some(command) => {
+            if cli.quiet && command.output_is_informational() {
+                platform::silence_stdout();
             }
-            return;
+            run_command(config, command)
         }
-        Some("uninstall") => {
-            if let Err(err) = uninstall_all() {
-                eprintln!("❌ No se pudo completar la desinstalación: {}", err);
-                std::process::exit(1);
-            }
+        None if cli.add_current => config
+            .ensure_exists()
+            .map_err(CommandError::from)
+            .and_then(|_| add_current_repo_prompt(&config).map_err(CommandError::from)),
+        None => {
+            run_interactive(config);
             return;
         }
-        Some("update") => {
-            if args.len() > 2 {
-                eprintln!("❌ Uso inválido: `git-sync update` no acepta parámetros.");
-                std::process::exit(1);
-            }
+    };
+    if let Err(err) = result {
+        eprintln!("❌ {}", err.message);
+        exit_with(err.exit);
+    }
+}
 
-            if let Err(err) = update_self() {
-                eprintln!("❌ {}", err);
-                std::process::exit(1);
+/// Código de salida de los errores de cada subcomando; `sync` decide el suyo.
+fn command_exit(command: &CliCommand) -> Exit {
+    match command {
+        CliCommand::Config { .. } | CliCommand::Validate { .. } => Exit::Config,
+        CliCommand::Service { .. }
+        | CliCommand::InstallService(_)
+        | CliCommand::Reinstall(_)
+        | CliCommand::UninstallService { .. }
+        | CliCommand::Uninstall => Exit::Service,
+        _ => Exit::Failure,
+    }
+}
+
+fn run_command(config: Config, command: CliCommand) -> Result<(), CommandError> {
+    let exit = command_exit(&command);
+    match command {
+        CliCommand::Daemon {
+            supervise,
+            dry_run,
+            once,
+            foreground,
+        } => {
+            if supervise {
+                supervise::supervise(&config, dry_run, console_style(foreground))
+            } else {
+                let options = DaemonOptions {
+                    dry_run,
+                    once,
+                    console: console_style(foreground),
+                };
+                return run_daemon(config, options);
             }
-            return;
         }
-        Some("--add-current") => {
-            if let Err(err) = config.ensure_exists() {
-                eprintln!("❌ {}", err);
-                std::process::exit(1);
-            }
-            if let Err(err) = add_current_repo_prompt(&config) {
-                eprintln!("❌ {}", err);
-                std::process::exit(1);
+        CliCommand::Sync { target, dry_run } => {
+            let filter = SyncFilter::new(target.name.as_deref(), target.group.as_deref());
+            return run_sync_command(&config, filter, dry_run);
+        }
+        CliCommand::Add(args) => add_repo(&config, args),
+        CliCommand::Remove { repo } => remove_repo(&config, &repo),
+        CliCommand::List { json } => list_repos(&config, json),
+        CliCommand::Pause { target, duration } => pause_command(&config, target, duration),
+        CliCommand::Resume(target) => resume_command(&config, target),
+        CliCommand::Config { action } => match action {
+            ConfigAction::Export { format } => {
+                exchange::export(&config, format.unwrap_or(exchange::Format::Json))
             }
-            return;
+            ConfigAction::Import { source, format } => exchange::import(&config, &source, format),
+        },
+        CliCommand::Validate { offline } => validate::validate(&config, !offline),
+        CliCommand::Watch { json } => watch::watch_events(&config.control_socket, json),
+        CliCommand::Logs(args) => show_logs(&config, args),
+        CliCommand::Service {
+            action: ServiceAction::Status { group },
+        } => {
+            let settings = Settings::load_or_default(&config.settings_file);
+            let group = match group.as_deref() {
+                Some(group) => {
+                    let repos: Vec<RepoDefinition> = config
+                        .try_read_repos()?
+                        .into_iter()
+                        .filter(|repo| repo.in_group(group))
+                        .collect();
+                    if repos.is_empty() {
+                        return Err(messages::empty_group(group, &config.repos_file).into());
+                    }
+                    Some((group, repos))
+                }
+                None => None,
+            };
+            print_status(&config, &settings, group)
         }
-        Some(other) => {
-            eprintln!("⚠️ Opción desconocida: {}", other);
-            eprintln!("👉 Utilice --help para consultar los comandos disponibles.");
-            std::process::exit(1);
+        CliCommand::InstallService(args) => install_command(&config, args, false),
+        CliCommand::Reinstall(args) => install_command(&config, args, true),
+        CliCommand::UninstallService { purge, yes } => {
+            let result = if !purge {
+                uninstall_service(&config)
+            } else if confirm_purge(&config, yes)? {
+                uninstall_all(&config)
+            } else {
+                println!("{}", messages::operation_cancelled());
+                return Ok(());
+            };
+            result.map_err(messages::service_uninstall_failed)
         }
-        None => {}
+        CliCommand::Uninstall => uninstall_all(&config).map_err(messages::uninstall_failed),
+        CliCommand::Update => update_self(),
+    }
+    .map_err(CommandError::new(exit))
+}
+
+/// `install-service` y `reinstall`: `--service-user` y `--service-group` reemplazan a los
+/// de `config.toml`; con `reinstall` o `--force` se regenera la unidad instalada.
+fn install_command(config: &Config, args: InstallArgs, reinstall: bool) -> Result<(), String> {
+    let mut settings = Settings::load_or_create(&config.settings_file);
+    if args.service_user.is_some() {
+        settings.service_user = args.service_user;
+    }
+    if args.service_group.is_some() {
+        settings.service_group = args.service_group;
     }
 
+    let result = if reinstall || args.force {
+        reinstall_service(config, &settings)
+    } else {
+        install_service(config, &settings)
+    };
+    result.map_err(messages::service_install_failed)
+}
+
+/// Sin subcomando: instalar el servicio y abrir la TUI.
+fn run_interactive(config: Config) {
     match config.ensure_exists() {
         Ok(_) => {}
         Err(err) => {
             eprintln!("❌ {}", err);
-            std::process::exit(1);
+            exit_with(Exit::Config);
         }
     }
 
     let settings = Settings::load_or_create(&config.settings_file);
 
-    // Sin argumentos: instalar el servicio y abrir la TUI
-    if let Err(err) = install_service() {
-        eprintln!(
-            "⚠️ No fue posible instalar o habilitar el servicio automáticamente: {}",
-            err
-        );
-        eprintln!("👉 Ejecute `sudo git-sync daemon` o complete la instalación de forma manual.");
+    // Sin permisos de escritura sobre la lista de repositorios se abre en solo lectura.
+    let read_only = !config.can_write_repos();
+    if read_only {
+        eprintln!("{}", messages::tui_read_only_notice(&config.repos_file));
+    } else if !service::manager_available() {
+        eprintln!("{}", messages::no_service_manager(config.command()));
+    } else if let Err(err) = install_service(&config, &settings) {
+        eprintln!("{}", messages::auto_install_failed(err));
+        eprintln!("{}", messages::auto_install_hint(config.command()));
     }
 
-    if let Err(err) = run_repo_manager(&config, settings.sync_interval) {
-        eprintln!("❌ Error al ejecutar el gestor de repositorios: {}", err);
+    if let Err(err) = run_repo_manager(&config, &settings, read_only) {
+        eprintln!("{}", messages::repo_manager_failed(err));
         std::process::exit(1);
     }
 }
 
-fn run_daemon(config: Config) {
+/// Opciones de `git-sync daemon` que prevalecen sobre config.toml.
+#[derive(Clone, Copy)]
+struct DaemonOptions {
+    dry_run: bool,
+    /// Un solo ciclo con todos los repositorios (`--once`)
+    once: bool,
+    console: ConsoleStyle,
+}
+
+/// `--foreground` muestra la hora de cada línea; los colores, solo en una terminal y sin
+/// `NO_COLOR` (https://no-color.org).
+fn console_style(foreground: bool) -> ConsoleStyle {
+    if !foreground {
+        ConsoleStyle::Plain
+    } else if io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none() {
+        ConsoleStyle::Colored
+    } else {
+        ConsoleStyle::Timed
+    }
+}
+
+/// Estado compartido por el daemon y la sincronización puntual.
+struct SyncRuntime {
+    settings: Settings,
+    logger: Logger,
+    env: Vec<(String, String)>,
+    credentials: Credentials,
+    notifier: Notifier,
+    /// Clientes de `git-sync watch` conectados al socket de control
+    events: EventStream,
+}
+
+impl SyncRuntime {
+    fn load(config: &Config) -> Self {
+        let settings = Settings::load_or_create(&config.settings_file);
+        i18n::select(settings.language);
+        let logger = Logger::new(config.log_file.clone())
+            .with_level(settings.log_level)
+            .with_format(settings.log_format)
+            .with_backend(settings.log_backend)
+            .with_rotation(settings.log_rotation());
+        let env = environment::load_env_file(&config.env_file, &config.secrets_key_file)
+            .unwrap_or_else(|err| {
+                logger.log_error(&messages::continue_without_env(err));
+                Vec::new()
+            });
+        // Los secretos se descifran una sola vez al iniciar.
+        let secrets =
+            Secrets::load(&config.secrets_file, &config.secrets_key_file).unwrap_or_else(|err| {
+                logger.log_error(&messages::skip_notifications(err));
+                Secrets::default()
+            });
+        let notifier = Notifier::new(&settings, &secrets);
+        let credentials = Credentials::load(&config.credentials_file, &config.secrets_key_file)
+            .unwrap_or_else(|err| {
+                logger.log_error(&messages::continue_without_credentials(err));
+                Credentials::default()
+            });
+
+        let mut secret_values = secrets.values();
+        secret_values.extend(credentials.tokens());
+        secret_values.extend(environment::secret_values(&env));
+        let logger = logger.with_secrets(secret_values);
+
+        SyncRuntime {
+            settings,
+            logger,
+            env,
+            credentials,
+            notifier,
+            events: EventStream::default(),
+        }
+    }
+
+    /// Vuelve a leer `config.toml`, el entorno, los secretos y las credenciales tras un cambio.
+    /// Si `config.toml` no es válido (ej. se guardó a medio editar) se mantiene todo como estaba.
+    fn reload(&mut self, config: &Config) {
+        if let Err(err) = Settings::load(&config.settings_file) {
+            self.logger
+                .log_error(&messages::keep_previous_settings(err));
+            return;
+        }
+        let events = std::mem::take(&mut self.events);
+        *self = SyncRuntime::load(config);
+        self.events = events;
+        self.logger
+            .log_line(&messages::settings_reloaded(&config.config_dir));
+    }
+
+    /// Aplica las opciones de la línea de comandos tras cargar o recargar la configuración.
+    fn apply(&mut self, options: DaemonOptions) {
+        self.settings.dry_run |= options.dry_run;
+        self.logger = self.logger.clone().with_console(options.console);
+    }
+
+    fn sync(&self, config: &Config, repos: Vec<RepoDefinition>) -> Result<(), String> {
+        self.processor(config)
+            .process_all(repos)
+            .map_err(|err| self.logger.redact(&err.to_string()))
+    }
+
+    fn processor(&self, config: &Config) -> RepoProcessor<'_> {
+        RepoProcessor::new(&self.logger, config.state_file.clone())
+            .with_pause_file(config.pause_file.clone())
+            .with_activity_file(config.activity_file.clone())
+            .with_env(&self.env)
+            .with_credentials(&self.credentials)
+            .with_git_backend(self.settings.git_backend)
+            .with_timeouts(self.settings.timeouts())
+            .with_divergence_policy(self.settings.on_divergence)
+            .with_ff_only(self.settings.ff_only)
+            .with_dirty_policy(self.settings.on_dirty)
+            .with_detached_policy(self.settings.on_detached)
+            .with_fetch_depth(self.settings.fetch_depth)
+            .with_fetch_prune(self.settings.fetch_prune)
+            .with_prune_local_branches(self.settings.prune_local_branches)
+            .with_maintenance(
+                self.settings.maintenance_days,
+                self.settings.maintenance_window,
+            )
+            .with_quiet_hours(&self.settings.quiet_hours)
+            .with_dry_run(self.settings.dry_run)
+            .with_max_failures(self.settings.max_failures)
+            .with_min_free_mb(self.settings.min_free_mb)
+            .with_allowed_roots(&self.settings.allowed_roots)
+            .with_notifier(&self.notifier)
+            .with_event_log(EventLog::new(
+                config.events_file.clone(),
+                self.settings.log_rotation(),
+            ))
+            .with_event_stream(self.events.clone())
+    }
+}
+
+fn run_daemon(config: Config, options: DaemonOptions) -> Result<(), CommandError> {
     let repos_created = match config.ensure_exists() {
         Ok(created) => created,
         Err(err) => {
             eprintln!("❌ {}", err);
-            std::process::exit(1);
+            exit_with(Exit::Config);
         }
     };
 
     if repos_created {
-        return;
+        return Ok(());
     }
 
-    let settings = Settings::load_or_create(&config.settings_file);
-    let logger = Logger::new(config.log_file.clone());
-
-    if settings.verbose {
-        logger.log_line("=================================================");
-        logger.log_line("🚀 Git Sync - Daemon de sincronización de repositorios");
-        logger.log_line("=================================================");
-        logger.log_line(&format!(
-            "⏱️ Intervalo de sincronización: {} segundos",
-            settings.sync_interval
-        ));
-        logger.log_line(&format!(
-            "🛑 Detener ante error: {}",
-            settings.stop_on_error
+    let mut runtime = SyncRuntime::load(&config);
+    runtime.apply(options);
+    let settings = &runtime.settings;
+    let logger = &runtime.logger;
+
+    logger.log_line("=================================================");
+    logger.log_line(&messages::daemon_banner());
+    logger.log_line("=================================================");
+    logger.log_line(&messages::daemon_interval(settings.sync_interval));
+    if settings.sync_jitter > 0 && settings.continuous_mode && !options.once {
+        logger.log_line(&messages::daemon_jitter(settings.sync_jitter.min(100)));
+    }
+    logger.log_line(&messages::daemon_stop_on_error(settings.stop_on_error));
+    let timeouts = settings.timeouts();
+    logger.log_line(&messages::daemon_timeouts(
+        timeouts.git,
+        timeouts.fetch,
+        timeouts.pull,
+        timeouts.build,
+        timeouts.hook,
+    ));
+    logger.log_line(&messages::daemon_max_retries(settings.max_retries));
+    logger.log_line(&messages::daemon_git_backend(format!(
+        "{:?}",
+        settings.git_backend
+    )));
+    if settings.maintenance_days > 0 {
+        logger.log_line(&match settings.maintenance_window {
+            Some(window) => {
+                messages::daemon_maintenance_window(settings.maintenance_days, String::from(window))
+            }
+            None => messages::daemon_maintenance(settings.maintenance_days),
+        });
+    }
+    if !settings.quiet_hours.is_empty() {
+        let windows = settings
+            .quiet_hours
+            .iter()
+            .map(|window| String::from(*window));
+        logger.log_line(&messages::daemon_quiet_hours(
+            windows.collect::<Vec<_>>().join(", "),
         ));
-        logger.log_line(&format!(
-            "⌛ Tiempo de espera para Git: {} segundos",
-            settings.git_timeout
+    }
+    let retention = settings.log_retention();
+    if retention.is_enabled() {
+        let mut limits = Vec::new();
+        if settings.log_retention_mb > 0 {
+            limits.push(format!("{} MB", settings.log_retention_mb));
+        }
+        if settings.log_retention_days > 0 {
+            limits.push(messages::days(settings.log_retention_days));
+        }
+        logger.log_line(&messages::daemon_retention(limits.join(", ")));
+    }
+    if settings.min_free_mb > 0 {
+        logger.log_line(&messages::daemon_min_free(settings.min_free_mb));
+    }
+    if !runtime.env.is_empty() {
+        logger.log_line(&messages::daemon_env_loaded(
+            &config.env_file,
+            runtime.env.len(),
         ));
-        logger.log_line(&format!("🔁 Reintentos máximos: {}", settings.max_retries));
-        logger.log_line("🕹️ Modo de sincronización: manual (sin ciclos automáticos)\n");
+    }
+    if settings.dry_run {
+        logger.log_line(&messages::daemon_dry_run());
+    }
+    if options.once {
+        logger.log_line(&messages::daemon_mode_once());
+    } else if settings.continuous_mode {
+        logger.log_line(&messages::daemon_mode_continuous());
+    } else {
+        logger.log_line(&messages::daemon_mode_manual());
+    }
+
+    if options.once {
+        let result = sync_repos(&config, &runtime, None);
+        runtime.logger.log_line(&messages::daemon_once_finished());
+        return result;
+    }
+
+    let mut signals = match DaemonSignals::register() {
+        Ok(signals) => signals,
+        Err(err) => {
+            logger.log_error(&err);
+            logger.flush();
+            std::process::exit(1);
+        }
+    };
+    match ConfigWatcher::new(&config) {
+        Ok(watcher) => signals.watch_config(watcher),
+        Err(err) => logger.warn(&messages::config_watch_unavailable(err)),
+    }
+    if settings.control_socket {
+        match ControlSocket::open(&config.control_socket, runtime.events.clone()) {
+            Ok(control) => {
+                logger.log_line(&if control.is_socket_activated() {
+                    messages::control_socket_activated(&config.control_socket)
+                } else {
+                    messages::control_socket(&config.control_socket)
+                });
+                signals.listen_control(control);
+            }
+            Err(err) => logger.warn(&format!("⚠️ {}.", err)),
+        }
+    }
+
+    let mut schedule = Schedule::new(
+        Duration::from_secs(settings.sync_interval.max(1)),
+        settings.groups.clone(),
+    );
+    // El primer ciclo y los pedidos con SIGUSR1 sincronizan todos los repositorios, y los
+    // de `sync <repositorio>` por el socket de control, solo esos; al vencer un intervalo,
+    // solo aquellos a los que les toca.
+    let mut requested = Some(SyncRequest::All);
+
+    // Última lista leída de `repositories.txt`; si la siguiente lectura falla se conserva.
+    let mut listed: Option<Vec<RepoDefinition>> = None;
+    let mut last_cleanup: Option<Instant> = None;
+
+    loop {
+        let settings = &runtime.settings;
+        let logger = &runtime.logger;
+        match config.read_repos_with_warnings() {
+            Ok((current, warnings)) => {
+                let changed = listed.as_ref().is_none_or(|previous| {
+                    previous
+                        .iter()
+                        .map(RepoDefinition::to_line)
+                        .ne(current.iter().map(RepoDefinition::to_line))
+                });
+                if changed && listed.is_some() {
+                    logger.log_line(&messages::repos_file_changed(
+                        &config.repos_file,
+                        current.len(),
+                    ));
+                }
+                // Los avisos de la lista se registran una vez por versión del archivo.
+                if changed {
+                    for warning in &warnings {
+                        logger.warn(warning);
+                    }
+                }
+                listed = Some(current);
+            }
+            Err(err) => logger.log_error(&messages::keep_previous_repos(err)),
+        }
+        let repos = with_discovered(listed.clone().unwrap_or_default(), settings);
+        let now = Instant::now();
+        let due = match requested.take() {
+            Some(SyncRequest::All) => repos.clone(),
+            Some(SyncRequest::Repos(references)) => {
+                let due: Vec<RepoDefinition> = repos
+                    .iter()
+                    .filter(|repo| {
+                        references
+                            .iter()
+                            .any(|reference| repo.matches_reference(reference))
+                    })
+                    .cloned()
+                    .collect();
+                if due.is_empty() {
+                    logger.warn(&messages::sync_requested_unknown(references.join(", ")));
+                }
+                due
+            }
+            None if settings.continuous_mode => schedule.due(repos.clone(), now),
+            None => schedule.due_pending(repos.clone(), now),
+        };
+        if !due.is_empty() {
+            schedule.mark_run(&due, now);
+            // Los despliegues retenidos por `quiet_hours` se reintentan al terminar la franja.
+            let time = Local::now().time();
+            for repo in &due {
+                if repo.sync_mode != SyncMode::Bare
+                    && let Some(window) = repo.quiet_window(&settings.quiet_hours, time)
+                {
+                    schedule.defer_until(repo, now + window.until_end(time));
+                }
+            }
+            run_sync_cycle(&config, &runtime, due);
+        }
+
+        let retention = settings.log_retention();
+        if retention.is_enabled()
+            && last_cleanup.is_none_or(|last| last.elapsed() >= retention::CLEANUP_INTERVAL)
+        {
+            clean_rotated_logs(&config, retention, logger);
+            last_cleanup = Some(Instant::now());
+        }
+
+        if signals.shutdown_requested() {
+            break;
+        }
+
+        let wait = if settings.continuous_mode {
+            let now = Instant::now();
+            // La variación no retrasa los despliegues retenidos por `quiet_hours`.
+            let wait = schedule::with_jitter(schedule.next_wait(&repos, now), settings.sync_jitter);
+            Some(match schedule.next_deferred(&repos, now) {
+                Some(deferred) => wait.min(deferred),
+                None => wait,
+            })
+        } else {
+            schedule.next_deferred(&repos, Instant::now())
+        };
+        // En modo manual el daemon puede esperar indefinidamente: se despierta para limpiar.
+        let wait = match last_cleanup.filter(|_| retention.is_enabled()) {
+            Some(last) => {
+                let cleanup = retention::CLEANUP_INTERVAL.saturating_sub(last.elapsed());
+                Some(wait.map_or(cleanup, |wait| wait.min(cleanup)))
+            }
+            None => wait,
+        };
+        match signals.wait(wait) {
+            WakeReason::SyncRequested(request) => {
+                logger.log_line(&match &request {
+                    SyncRequest::All => messages::sync_requested(),
+                    SyncRequest::Repos(references) => {
+                        messages::sync_requested_repos(references.join(", "))
+                    }
+                });
+                requested = Some(request);
+            }
+            WakeReason::IntervalElapsed => {}
+            WakeReason::ConfigChanged(file) => {
+                // La lista de repositorios se vuelve a leer al principio de cada vuelta; los
+                // repositorios nuevos se sincronizan en ese momento.
+                if Path::new(&config.repos_file).file_name() != Some(file.as_ref()) {
+                    runtime.reload(&config);
+                    runtime.apply(options);
+                    schedule.set_intervals(
+                        Duration::from_secs(runtime.settings.sync_interval.max(1)),
+                        runtime.settings.groups.clone(),
+                    );
+                }
+            }
+            WakeReason::Shutdown => break,
+        }
     }
 
-    run_sync_cycle(&config, &logger, &settings);
+    runtime.logger.log_line(&messages::daemon_stopped());
+    Ok(())
 }
 
-fn run_sync_cycle(config: &Config, logger: &Logger, settings: &Settings) {
-    let repos = config.read_repos();
-    let processor = RepoProcessor::new(logger, settings.verbose, config.state_file.clone());
+fn run_sync_cycle(config: &Config, runtime: &SyncRuntime, repos: Vec<RepoDefinition>) {
+    let settings = &runtime.settings;
+    let logger = &runtime.logger;
 
-    match processor.process_all(repos) {
+    match runtime.sync(config, repos) {
         Ok(_) => {
-            if settings.verbose {
-                logger.log_line("\n✅ Ciclo completado correctamente.");
-            }
+            logger.log_line(&messages::cycle_completed());
         }
         Err(e) => {
             logger.log_error(&e.to_string());
             if settings.stop_on_error {
-                logger.log_error("🛑 Finalización por error (stop_on_error=true)");
+                logger.log_error(&messages::stopped_on_error());
+                logger.flush();
                 std::process::exit(1);
             }
         }
     }
 }
 
+/// Repositorios a los que se limita `git-sync sync`, `pause` o `resume`.
+#[derive(Clone, Copy)]
+enum SyncFilter<'a> {
+    Repo(&'a str),
+    Group(&'a str),
+}
+
+impl<'a> SyncFilter<'a> {
+    /// El grupo si se indicó; si no, el repositorio. `None` para todos.
+    fn new(name: Option<&'a str>, group: Option<&'a str>) -> Option<Self> {
+        group.map(SyncFilter::Group).or(name.map(SyncFilter::Repo))
+    }
+}
+
+/// `git-sync add`: la misma entrada que crea la TUI, sin interacción. Se rechaza si la ruta
+/// o el nombre ya están en la lista.
+fn add_repo(config: &Config, args: AddArgs) -> Result<(), String> {
+    config.ensure_exists()?;
+
+    let path = Path::new(&args.path);
+    let repo_path = if path.is_absolute() {
+        args.path.clone()
+    } else {
+        let current = env::current_dir().map_err(messages::current_dir_failed)?;
+        fs::canonicalize(path)
+            .unwrap_or_else(|_| current.join(path))
+            .to_string_lossy()
+            .into_owned()
+    };
+    let mut repo = RepoDefinition::new(repo_path);
+
+    let settings = Settings::load_or_default(&config.settings_file);
+    config::check_repo_path(&repo.repo_path, &settings.allowed_roots)?;
+
+    let mut options = Vec::new();
+    if let Some(name) = args.name {
+        options.push(("name".to_string(), name));
+    }
+    if !args.group.is_empty() {
+        options.push(("groups".to_string(), args.group.join(",")));
+    }
+    if let Some(branch) = args.branch {
+        options.push(("branch".to_string(), branch));
+    }
+    for option in &args.options {
+        let (key, value) = option
+            .split_once('=')
+            .ok_or_else(|| messages::invalid_add_option(option))?;
+        options.push((key.trim().to_string(), value.trim().to_string()));
+    }
+    for (key, value) in options {
+        repo.apply_option(&key, &value)?;
+    }
+
+    match args.url {
+        Some(url) if !config::is_clone_url(&url) => {
+            return Err(messages::invalid_clone_url(url));
+        }
+        Some(url) => repo.clone_url = Some(url),
+        None => {
+            let git_repo = GitRepo::new(repo.repo_path.clone());
+            let valid = if repo.sync_mode == SyncMode::Bare {
+                git_repo.is_bare_repository()
+            } else {
+                git_repo.is_work_tree()
+            };
+            if !valid {
+                return Err(messages::not_a_git_repo(&repo.repo_path));
+            }
+            repo.record_remote_url();
+        }
+    }
+
+    let name = config.update_repos(|repos| {
+        if let Some(existing) = repos.iter().find(|r| r.repo_path == repo.repo_path) {
+            return Err(messages::repo_already_listed(
+                &repo.repo_path,
+                existing.name(),
+            ));
+        }
+        if repo.name.is_some() {
+            if repos.iter().any(|r| r.name() == repo.name()) {
+                return Err(messages::repo_name_taken(repo.name()));
+            }
+        } else {
+            repo.name = config::unique_name(repos, &repo);
+        }
+
+        let name = repo.name().to_string();
+        repos.push(repo);
+        Ok(name)
+    })?;
+    println!("{}", messages::repo_added(name, &config.repos_file));
+    Ok(())
+}
+
+/// `git-sync remove`: quita la entrada de la lista; el checkout no se borra.
+fn remove_repo(config: &Config, reference: &str) -> Result<(), String> {
+    config.ensure_exists()?;
+
+    let (name, path) = config.update_repos(|repos| {
+        let repo = find_cli_repo(repos, reference)?;
+        let removed = (repo.name().to_string(), repo.repo_path.clone());
+        repos.retain(|r| r.repo_path != removed.1);
+        Ok(removed)
+    })?;
+    println!("{}", messages::repo_removed(name, &config.repos_file, path));
+    Ok(())
+}
+
+/// `git-sync list`: los repositorios de `repositories.txt` (sin los de `discover`).
+fn list_repos(config: &Config, json: bool) -> Result<(), String> {
+    let (repos, warnings) = config.read_repos_with_warnings()?;
+    for warning in warnings {
+        eprintln!("⚠️ {}", warning);
+    }
+
+    if json {
+        let entries = repos
+            .iter()
+            .map(|repo| {
+                serde_json::json!({
+                    "name": repo.name(),
+                    "path": repo.repo_path,
+                    "enabled": repo.enabled,
+                    "url": repo.clone_url,
+                    "options": repo
+                        .options()
+                        .into_iter()
+                        .map(|(key, value)| (key, serde_json::Value::String(value)))
+                        .collect::<serde_json::Map<_, _>>(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let output = serde_json::to_string_pretty(&entries).map_err(messages::json_failed)?;
+        println!("{}", output);
+        return Ok(());
+    }
+
+    let pauses = PauseState::load(&config.pause_file);
+    if let Some(pause) = pauses.global() {
+        println!("{}", messages::sync_paused(pause.describe()));
+    }
+    if repos.is_empty() {
+        println!("{}", messages::no_repos(&config.repos_file));
+        return Ok(());
+    }
+    println!("{}", messages::repo_count(repos.len(), &config.repos_file));
+    let width = repos
+        .iter()
+        .map(|repo| repo.name().len())
+        .max()
+        .unwrap_or(0);
+    for repo in &repos {
+        let paused_until = pauses.repo_until(&repo.repo_path);
+        let icon = if repo.enabled && paused_until.is_none() {
+            "▶️"
+        } else {
+            "⏸️"
+        };
+        let options = repo
+            .options()
+            .into_iter()
+            .filter(|(key, _)| key != "name")
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>();
+        let mut line = format!(
+            "  {} {:<width$}  {}",
+            icon,
+            repo.name(),
+            repo.source_spec(),
+            width = width
+        );
+        if !options.is_empty() {
+            line.push_str(&format!("  [{}]", options.join(" ")));
+        }
+        if let Some(until) = paused_until {
+            line.push_str(&format!(
+                "  {}",
+                messages::paused_until(pause::format_until(until))
+            ));
+        }
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// `git-sync logs`: `--repo` se resuelve contra la lista; un nombre que ya no está en ella
+/// (un repositorio quitado) se busca tal cual en el registro.
+fn show_logs(config: &Config, args: LogsArgs) -> Result<(), String> {
+    let repo = match args.repo {
+        Some(reference) => {
+            let repos = config.try_read_repos()?;
+            Some(match find_cli_repo(&repos, &reference) {
+                Ok(repo) => (repo.name().to_string(), repo.repo_path.clone()),
+                Err(_) => (reference.clone(), reference),
+            })
+        }
+        None => None,
+    };
+    let filter = logs::LogFilter {
+        repo,
+        since: args.since,
+        level: args.level,
+    };
+    logs::show_logs(config, filter, args.lines, args.follow)
+}
+
+/// `git-sync pause`: sin repositorio ni grupo, una pausa de todos en `pause.toml`; con
+/// `--for`, pausas temporales allí mismo; si no, el prefijo `!` de `repositories.txt`.
+fn pause_command(
+    config: &Config,
+    target: PauseTarget,
+    duration: Option<u64>,
+) -> Result<(), String> {
+    let until = duration.map(|seconds| Utc::now().timestamp() + seconds as i64);
+    let Some(filter) = SyncFilter::new(target.name(), target.group.as_deref()) else {
+        config.ensure_exists()?;
+        let mut pauses = PauseState::load(&config.pause_file);
+        pauses.pause_all(until);
+        pauses.save(&config.pause_file)?;
+        if let Some(pause) = pauses.global() {
+            println!("{}", messages::all_paused(pause.describe()));
+        }
+        return Ok(());
+    };
+    let Some(until) = until else {
+        return set_repos_enabled(config, filter, false);
+    };
+
+    let repos = config.try_read_repos()?;
+    let (label, targets) = resolve_targets(config, &repos, filter)?;
+    let mut pauses = PauseState::load(&config.pause_file);
+    for path in &targets {
+        pauses.pause_repo(path, until);
+    }
+    pauses.save(&config.pause_file)?;
+
+    let until = pause::format_until(until);
+    match targets.len() {
+        1 => println!("{}", messages::repo_paused_until(label, until)),
+        total => println!("{}", messages::repos_paused_until(label, until, total)),
+    }
+    Ok(())
+}
+
+/// `git-sync resume`: sin repositorio ni grupo quita la pausa de todos y las temporales; con
+/// ellos, también sus pausas de `repositories.txt`. El daemon retoma en el siguiente ciclo.
+fn resume_command(config: &Config, target: PauseTarget) -> Result<(), String> {
+    let mut pauses = PauseState::load(&config.pause_file);
+    let Some(filter) = SyncFilter::new(target.name(), target.group.as_deref()) else {
+        let paused = pauses.resume_all();
+        pauses.save(&config.pause_file)?;
+        if paused {
+            println!("{}", messages::sync_resumed());
+        } else {
+            println!("{}", messages::sync_not_paused());
+        }
+
+        let disabled = config
+            .try_read_repos()?
+            .iter()
+            .filter(|repo| !repo.enabled)
+            .count();
+        if disabled > 0 {
+            println!(
+                "{}",
+                messages::repos_still_paused(disabled, &config.repos_file)
+            );
+        }
+        return Ok(());
+    };
+
+    let repos = config.try_read_repos()?;
+    let (_, targets) = resolve_targets(config, &repos, filter)?;
+    for path in &targets {
+        pauses.resume_repo(path);
+    }
+    pauses.save(&config.pause_file)?;
+    set_repos_enabled(config, filter, true)
+}
+
+/// Descripción y rutas de los repositorios que indica `filter`.
+fn resolve_targets(
+    config: &Config,
+    repos: &[RepoDefinition],
+    filter: SyncFilter,
+) -> Result<(String, Vec<String>), String> {
+    match filter {
+        SyncFilter::Repo(reference) => {
+            let repo = find_cli_repo(repos, reference)?;
+            Ok((format!("`{}`", repo.name()), vec![repo.repo_path.clone()]))
+        }
+        SyncFilter::Group(group) => {
+            let targets = repos
+                .iter()
+                .filter(|repo| repo.in_group(group))
+                .map(|repo| repo.repo_path.clone())
+                .collect::<Vec<_>>();
+            if targets.is_empty() {
+                return Err(messages::empty_group(group, &config.repos_file));
+            }
+            Ok((messages::group_label(group), targets))
+        }
+    }
+}
+
+/// `find_repo` para un repositorio indicado en la línea de comandos: además del nombre o la
+/// ruta registrada acepta una ruta relativa al directorio actual (ej. `.` desde el checkout).
+fn find_cli_repo<'r>(
+    repos: &'r [RepoDefinition],
+    reference: &str,
+) -> Result<&'r RepoDefinition, String> {
+    let err = match config::find_repo(repos, reference) {
+        Ok(repo) => return Ok(repo),
+        Err(err) => err,
+    };
+    let Ok(path) = fs::canonicalize(reference) else {
+        return Err(err);
+    };
+    config::find_repo(repos, &path.to_string_lossy()).map_err(|_| err)
+}
+
+/// Pausa o reactiva en `repositories.txt` un repositorio o todos los de un grupo.
+fn set_repos_enabled(config: &Config, filter: SyncFilter, enabled: bool) -> Result<(), String> {
+    config.ensure_exists()?;
+
+    let (label, targets, changed) = config.update_repos(|repos| {
+        let (label, targets) = resolve_targets(config, repos, filter)?;
+
+        let mut changed = 0;
+        for repo in repos.iter_mut() {
+            if targets.contains(&repo.repo_path) && repo.enabled != enabled {
+                repo.enabled = enabled;
+                changed += 1;
+            }
+        }
+        Ok((label, targets, changed))
+    })?;
+
+    let total = targets.len();
+    match (enabled, total) {
+        (true, 1) => println!("{}", messages::repo_resumed(label)),
+        (false, 1) => println!("{}", messages::repo_paused(label)),
+        (true, _) => println!("{}", messages::repos_resumed(label, changed, total)),
+        (false, _) => println!("{}", messages::repos_paused(label, changed, total)),
+    }
+    Ok(())
+}
+
+/// Aplica `log_retention_mb` y `log_retention_days` a los registros rotados: cada hora en el
+/// daemon y al final de cada `git-sync sync`.
+fn clean_rotated_logs(config: &Config, retention: retention::LogRetention, logger: &Logger) {
+    match retention::cleanup(&[&config.log_file, &config.events_file], retention) {
+        Ok(report) if !report.removed.is_empty() => logger.log_line(&messages::retention_cleaned(
+            report.removed.len(),
+            report.freed_bytes as f64 / (1024.0 * 1024.0),
+        )),
+        Ok(_) => {}
+        Err(err) => logger.warn(&messages::retention_failed(err)),
+    }
+}
+
+/// Ejecuta un único ciclo en primer plano, opcionalmente limitado a un repositorio o un grupo.
+fn run_sync_command(
+    config: &Config,
+    filter: Option<SyncFilter>,
+    dry_run: bool,
+) -> Result<(), CommandError> {
+    config.ensure_exists()?;
+
+    let mut runtime = SyncRuntime::load(config);
+    runtime.settings.dry_run |= dry_run;
+    sync_repos(config, &runtime, filter)
+}
+
+/// Un ciclo con los repositorios de `filter` (todos sin él), con el resumen por repositorio
+/// al final. Lo comparten `git-sync sync` y `git-sync daemon --once`.
+fn sync_repos(
+    config: &Config,
+    runtime: &SyncRuntime,
+    filter: Option<SyncFilter>,
+) -> Result<(), CommandError> {
+    let (repos, warnings) = config.read_repos_with_warnings()?;
+    for warning in warnings {
+        eprintln!("⚠️ {}", warning);
+    }
+    let mut repos = with_discovered(repos, &runtime.settings);
+    match filter {
+        Some(SyncFilter::Repo(reference)) => {
+            repos = vec![find_cli_repo(&repos, reference)?.clone()];
+        }
+        Some(SyncFilter::Group(group)) => {
+            repos.retain(|repo| repo.in_group(group));
+            if repos.is_empty() {
+                return Err(messages::empty_group(group, &config.repos_file).into());
+            }
+        }
+        None => {}
+    }
+
+    let results = RefCell::new(Vec::new());
+    let result = runtime
+        .processor(config)
+        .with_results(&results)
+        .process_all(repos)
+        .map_err(|err| runtime.logger.redact(&err.to_string()));
+    // Con `service_mode = "timer"` no hay daemon que aplique la retención periódicamente.
+    clean_rotated_logs(config, runtime.settings.log_retention(), &runtime.logger);
+    let results = results.into_inner();
+    print_sync_summary(&results);
+    result.map_err(CommandError::new(sync_exit(&results)))
+}
+
+/// Sin resultados el ciclo no empezó (no hay repositorios configurados). Los pausados,
+/// retenidos o desactivados no cuentan como intentos.
+fn sync_exit(results: &[SyncRecord]) -> Exit {
+    if results.is_empty() {
+        return Exit::Config;
+    }
+    let failed = results
+        .iter()
+        .filter(|record| matches!(record.result, SyncResult::Failed | SyncResult::Skipped))
+        .count();
+    let attempted = results
+        .iter()
+        .filter(|record| {
+            !matches!(
+                record.result,
+                SyncResult::Paused | SyncResult::Held | SyncResult::Disabled
+            )
+        })
+        .count();
+    match failed {
+        0 => Exit::Failure,
+        failed if failed == attempted => Exit::SyncFailed,
+        _ => Exit::PartialSync,
+    }
+}
+
+/// Una línea por repositorio al final de `git-sync sync`, tras el registro detallado.
+fn print_sync_summary(results: &[SyncRecord]) {
+    if results.is_empty() {
+        return;
+    }
+
+    println!("{}", messages::sync_summary_header());
+    for record in results {
+        println!("  {}", record.summary());
+    }
+}
+
+/// Repositorios de la lista seguidos de los encontrados con `discover`.
+fn with_discovered(mut repos: Vec<RepoDefinition>, settings: &Settings) -> Vec<RepoDefinition> {
+    let discovered = discover::discover_repos(&settings.discover, &repos);
+    repos.extend(discovered);
+    repos
+}
+
 fn update_self() -> Result<(), String> {
-    println!("🔄 Buscando la última versión en GitHub Releases...");
+    println!("{}", messages::update_checking());
 
     if env::consts::OS != "linux" {
-        return Err("La actualización automática solo está disponible para Linux.".to_string());
+        return Err(messages::update_linux_only());
     }
 
     if env::consts::ARCH != "x86_64" {
-        return Err(format!(
-            "Arquitectura no soportada para auto-actualización: {} (se espera x86_64).",
-            env::consts::ARCH
-        ));
+        return Err(messages::update_unsupported_arch(env::consts::ARCH));
     }
 
     let latest_tag = fetch_latest_release_tag()?;
     install_release(&latest_tag)?;
 
-    println!(
-        "\n✅ ¡git-sync se actualizó correctamente a {}!",
-        latest_tag
-    );
-    println!("👉 Reinicie el servicio: `sudo systemctl restart git-sync`.");
+    println!("{}", messages::update_done(latest_tag));
+    println!("{}", messages::update_restart_hint());
     Ok(())
 }
 
@@ -251,20 +1211,15 @@ fn fetch_latest_release_tag() -> Result<String, String> {
             "https://api.github.com/repos/lui5gl/git-sync/releases/latest",
         ])
         .output()
-        .map_err(|e| format!("No se pudo ejecutar `curl`: {}", e))?;
+        .map_err(|e| messages::command_failed("curl", e))?;
 
     if !output.status.success() {
-        return Err(format!(
-            "No se pudo consultar releases en GitHub (estado {}).",
-            output.status
-        ));
+        return Err(messages::releases_query_failed(output.status));
     }
 
-    let body = String::from_utf8(output.stdout)
-        .map_err(|e| format!("La respuesta de GitHub no es UTF-8 válida: {}", e))?;
+    let body = String::from_utf8(output.stdout).map_err(messages::releases_not_utf8)?;
 
-    extract_json_string_value(&body, "tag_name")
-        .ok_or("No se pudo obtener el tag de la última release.".to_string())
+    extract_json_string_value(&body, "tag_name").ok_or_else(messages::release_tag_missing)
 }
 
 fn extract_json_string_value(json: &str, key: &str) -> Option<String> {
@@ -307,65 +1262,43 @@ fn install_release(tag: &str) -> Result<(), String> {
 
     let temp_dir = env::temp_dir().join(format!("git-sync-update-{}", std::process::id()));
     if temp_dir.exists() {
-        fs::remove_dir_all(&temp_dir)
-            .map_err(|e| format!("No se pudo limpiar el directorio temporal: {}", e))?;
+        fs::remove_dir_all(&temp_dir).map_err(messages::temp_dir_clean_failed)?;
     }
-    fs::create_dir_all(&temp_dir)
-        .map_err(|e| format!("No se pudo crear el directorio temporal: {}", e))?;
+    fs::create_dir_all(&temp_dir).map_err(messages::temp_dir_create_failed)?;
 
     let archive_path = temp_dir.join(&asset);
     let archive_path_str = path_to_str(&archive_path)?;
 
-    println!("⬇️ Descargando {} ...", tag);
+    println!("{}", messages::update_downloading(tag));
     let download_status = Command::new("curl")
         .args(["-fL", &url, "-o", archive_path_str])
         .status()
-        .map_err(|e| format!("No se pudo ejecutar `curl`: {}", e))?;
+        .map_err(|e| messages::command_failed("curl", e))?;
     if !download_status.success() {
-        return Err(format!(
-            "No se pudo descargar {} (estado {}).",
-            url, download_status
-        ));
+        return Err(messages::download_failed(url, download_status));
     }
 
     let temp_dir_str = path_to_str(&temp_dir)?;
     let extract_status = Command::new("tar")
         .args(["-xzf", archive_path_str, "-C", temp_dir_str])
         .status()
-        .map_err(|e| format!("No se pudo ejecutar `tar`: {}", e))?;
+        .map_err(|e| messages::command_failed("tar", e))?;
     if !extract_status.success() {
-        return Err(format!(
-            "No se pudo extraer el archivo descargado (estado {}).",
-            extract_status
-        ));
+        return Err(messages::extract_failed(extract_status));
     }
 
-    let new_binary = find_binary_in_dir(&temp_dir)
-        .ok_or("No se encontró el binario `git-sync` dentro del release descargado.")?;
-    let current_binary = env::current_exe()
-        .map_err(|e| format!("No se pudo detectar la ruta del binario actual: {}", e))?;
+    let new_binary = find_binary_in_dir(&temp_dir).ok_or_else(messages::release_binary_missing)?;
+    let current_binary = env::current_exe().map_err(messages::current_exe_failed)?;
     let staged_binary = staged_path(&current_binary)?;
 
     fs::copy(&new_binary, &staged_binary).map_err(|e| {
-        format!(
-            "No se pudo copiar el nuevo binario desde {} a {}: {}",
-            new_binary.display(),
-            staged_binary.display(),
-            e
-        )
+        messages::binary_copy_failed(new_binary.display(), staged_binary.display(), e)
     })?;
 
-    let permissions = fs::Permissions::from_mode(0o755);
-    fs::set_permissions(&staged_binary, permissions)
-        .map_err(|e| format!("No se pudieron ajustar permisos del nuevo binario: {}", e))?;
+    platform::set_mode(&staged_binary, 0o755).map_err(messages::binary_mode_failed)?;
 
-    fs::rename(&staged_binary, &current_binary).map_err(|e| {
-        format!(
-            "No se pudo reemplazar el binario actual en {}: {}",
-            current_binary.display(),
-            e
-        )
-    })?;
+    fs::rename(&staged_binary, &current_binary)
+        .map_err(|e| messages::binary_replace_failed(current_binary.display(), e))?;
 
     let _ = fs::remove_dir_all(&temp_dir);
     Ok(())
@@ -373,14 +1306,14 @@ fn install_release(tag: &str) -> Result<(), String> {
 
 fn path_to_str(path: &Path) -> Result<&str, String> {
     path.to_str()
-        .ok_or_else(|| format!("Ruta con caracteres UTF-8 inválidos: {}", path.display()))
+        .ok_or_else(|| messages::path_not_utf8(path.display()))
 }
 
 fn staged_path(current_binary: &Path) -> Result<PathBuf, String> {
     let file_name = current_binary
         .file_name()
         .and_then(|f| f.to_str())
-        .ok_or_else(|| "No se pudo resolver el nombre del binario actual.".to_string())?;
+        .ok_or_else(messages::binary_name_failed)?;
     Ok(current_binary.with_file_name(format!("{}.new", file_name)))
 }
 
@@ -403,53 +1336,77 @@ fn find_binary_in_dir(root: &Path) -> Option<PathBuf> {
     None
 }
 
+/// Pide confirmación antes de `uninstall-service --purge`, mostrando qué se eliminará.
+fn confirm_purge(config: &Config, yes: bool) -> Result<bool, String> {
+    println!("{}", messages::purge_header(&config.service_name));
+    println!("{}", messages::purge_config(&config.config_dir));
+    println!("{}", messages::purge_logs(&config.log_dir));
+    println!("{}", messages::purge_repos_kept());
+    if yes {
+        return Ok(true);
+    }
+
+    print!("{}", messages::confirm_continue());
+    io::stdout()
+        .flush()
+        .map_err(messages::stdout_write_failed)?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(messages::answer_read_failed)?;
+
+    let answer = input.trim().to_lowercase();
+    Ok(answer == "y" || answer == "s")
+}
+
 fn add_current_repo_prompt(config: &Config) -> Result<(), String> {
-    let current_dir = env::current_dir()
-        .map_err(|e| format!("No se pudo obtener el directorio actual: {}", e))?;
+    let current_dir = env::current_dir().map_err(messages::current_dir_failed)?;
 
     let repo_path = current_dir
         .to_str()
-        .ok_or_else(|| "La ruta del directorio actual no es UTF-8 válida.".to_string())?
+        .ok_or_else(messages::current_dir_not_utf8)?
         .to_string();
 
-    if !current_dir.join(".git").exists() {
-        return Err(format!(
-            "El directorio actual no parece ser un repositorio Git: {}",
-            repo_path
-        ));
+    if !GitRepo::new(repo_path.clone()).is_work_tree() {
+        return Err(messages::current_dir_not_git(repo_path));
     }
 
-    println!("📂 Directorio actual: {}", repo_path);
-    print!("¿Desea agregar este repositorio a git-sync? (y/N): ");
+    let settings = Settings::load_or_default(&config.settings_file);
+    config::check_repo_path(&repo_path, &settings.allowed_roots)?;
+
+    println!("{}", messages::current_dir(&repo_path));
+    print!("{}", messages::confirm_add_current());
     io::stdout()
         .flush()
-        .map_err(|e| format!("No se pudo escribir en la salida: {}", e))?;
+        .map_err(messages::stdout_write_failed)?;
 
     let mut input = String::new();
     io::stdin()
         .read_line(&mut input)
-        .map_err(|e| format!("No se pudo leer la respuesta: {}", e))?;
+        .map_err(messages::answer_read_failed)?;
 
     let answer = input.trim().to_lowercase();
     if answer != "y" && answer != "s" {
-        println!("ℹ️ Operación cancelada.");
+        println!("{}", messages::operation_cancelled());
         return Ok(());
     }
 
-    let mut repos = config.read_repos();
-    if repos.iter().any(|r| r.repo_path == repo_path) {
-        println!(
-            "ℹ️ El repositorio ya está registrado en {}",
-            config.repos_file
-        );
+    let added = config.update_repos(|repos| {
+        if repos.iter().any(|r| r.repo_path == repo_path) {
+            return Ok(false);
+        }
+
+        let mut repo = RepoDefinition::new(repo_path);
+        repo.record_remote_url();
+        repo.name = config::unique_name(repos, &repo);
+        repos.push(repo);
+        Ok(true)
+    })?;
+    if !added {
+        println!("{}", messages::repo_already_registered(&config.repos_file));
         return Ok(());
     }
-
-    repos.push(RepoDefinition::new(repo_path));
-    config.write_repos(&repos)?;
-    println!(
-        "✅ Repositorio agregado correctamente en {}",
-        config.repos_file
-    );
+    println!("{}", messages::current_repo_added(&config.repos_file));
     Ok(())
 }
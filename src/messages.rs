@@ -0,0 +1,3260 @@
+//! Catálogo de mensajes en español e inglés, por identificador (ver `i18n`).
+
+use crate::i18n::messages;
+
+messages! {
+
+    // main.rs: línea de comandos
+    config_dir_empty() {
+        es: "El directorio de configuración no puede estar vacío",
+        en: "The configuration directory cannot be empty",
+    }
+    current_dir_failed(error) {
+        es: "No se pudo determinar el directorio actual: {error}",
+        en: "Could not determine the current directory: {error}",
+    }
+    version(version) {
+        es: "ℹ️ git-sync v{version}",
+        en: "ℹ️ git-sync v{version}",
+    }
+    repos_file_missing(file, command) {
+        es: "❌ No existe el archivo de repositorios {file}. Ejecute `{command}` para inicializar la configuración.",
+        en: "❌ The repository file {file} does not exist. Run `{command}` to initialize the configuration.",
+    }
+    repo_manager_failed(error) {
+        es: "❌ Error al ejecutar el gestor de repositorios: {error}",
+        en: "❌ Error while running the repository manager: {error}",
+    }
+    operation_cancelled() {
+        es: "ℹ️ Operación cancelada.",
+        en: "ℹ️ Operation cancelled.",
+    }
+    service_uninstall_failed(error) {
+        es: "No se pudo desinstalar el servicio: {error}",
+        en: "Could not uninstall the service: {error}",
+    }
+    uninstall_failed(error) {
+        es: "No se pudo completar la desinstalación: {error}",
+        en: "Could not complete the uninstallation: {error}",
+    }
+    service_install_failed(error) {
+        es: "No se pudo instalar el servicio: {error}",
+        en: "Could not install the service: {error}",
+    }
+    tui_read_only_notice(file) {
+        es: "ℹ️ No tiene permisos de escritura sobre {file}. La interfaz se abrirá en modo de solo lectura.",
+        en: "ℹ️ You cannot write to {file}. The interface will open in read-only mode.",
+    }
+    no_service_manager(command) {
+        es: "ℹ️ systemd no está disponible en este sistema: no se instala el servicio. Ejecute `{command} daemon --supervise` con el gestor de procesos del sistema o del contenedor.",
+        en: "ℹ️ systemd is not available on this system, so the service is not installed. Run `{command} daemon --supervise` under the system's or the container's process manager.",
+    }
+    auto_install_failed(error) {
+        es: "⚠️ No fue posible instalar o habilitar el servicio automáticamente: {error}",
+        en: "⚠️ Could not install or enable the service automatically: {error}",
+    }
+    auto_install_hint(command) {
+        es: "👉 Ejecute `{command} daemon` o complete la instalación de forma manual.",
+        en: "👉 Run `{command} daemon` or finish the installation manually.",
+    }
+
+    continue_without_env(error) {
+        es: "{error}. Se continuará sin variables de entorno adicionales.",
+        en: "{error}. Continuing without additional environment variables.",
+    }
+    skip_notifications(error) {
+        es: "{error}. Se omitirán las notificaciones.",
+        en: "{error}. Notifications will be skipped.",
+    }
+    continue_without_credentials(error) {
+        es: "{error}. Se continuará sin credenciales HTTPS.",
+        en: "{error}. Continuing without HTTPS credentials.",
+    }
+    keep_previous_settings(error) {
+        es: "{error}. Se mantiene la configuración anterior.",
+        en: "{error}. Keeping the previous configuration.",
+    }
+    settings_reloaded(dir) {
+        es: "🔄 Configuración recargada desde {dir}",
+        en: "🔄 Configuration reloaded from {dir}",
+    }
+
+    // main.rs: daemon
+    daemon_banner() {
+        es: "🚀 Git Sync - Daemon de sincronización de repositorios",
+        en: "🚀 Git Sync - Repository synchronization daemon",
+    }
+    daemon_interval(seconds) {
+        es: "⏱️ Intervalo de sincronización: {seconds} segundos",
+        en: "⏱️ Sync interval: {seconds} seconds",
+    }
+    daemon_jitter(percent) {
+        es: "🎲 Variación aleatoria de cada espera: hasta +{percent}%",
+        en: "🎲 Random variation of each wait: up to +{percent}%",
+    }
+    daemon_stop_on_error(value) {
+        es: "🛑 Detener ante error: {value}",
+        en: "🛑 Stop on error: {value}",
+    }
+    daemon_timeouts(git, fetch, pull, build, hook) {
+        es: "⌛ Tiempos de espera (s): git {git}, fetch {fetch}, pull {pull}, compilación {build}, hooks {hook}",
+        en: "⌛ Timeouts (s): git {git}, fetch {fetch}, pull {pull}, build {build}, hooks {hook}",
+    }
+    daemon_max_retries(retries) {
+        es: "🔁 Reintentos máximos: {retries}",
+        en: "🔁 Maximum retries: {retries}",
+    }
+    daemon_git_backend(backend) {
+        es: "🧰 Backend de Git: {backend}",
+        en: "🧰 Git backend: {backend}",
+    }
+    daemon_maintenance(days) {
+        es: "🧹 Mantenimiento (git gc): cada {days} días",
+        en: "🧹 Maintenance (git gc): every {days} days",
+    }
+    daemon_maintenance_window(days, window) {
+        es: "🧹 Mantenimiento (git gc): cada {days} días entre {window}",
+        en: "🧹 Maintenance (git gc): every {days} days between {window}",
+    }
+    daemon_quiet_hours(windows) {
+        es: "🌙 Franjas sin despliegues: {windows}",
+        en: "🌙 Deployment quiet hours: {windows}",
+    }
+    days(days) {
+        es: "{days} días",
+        en: "{days} days",
+    }
+    daemon_retention(limits) {
+        es: "🗄️ Retención de registros rotados: {limits}",
+        en: "🗄️ Rotated log retention: {limits}",
+    }
+    daemon_min_free(mb) {
+        es: "💾 Espacio libre mínimo para sincronizar: {mb} MB",
+        en: "💾 Minimum free space to sync: {mb} MB",
+    }
+    daemon_env_loaded(file, count) {
+        es: "🌱 Variables de entorno cargadas desde {file}: {count}",
+        en: "🌱 Environment variables loaded from {file}: {count}",
+    }
+    daemon_dry_run() {
+        es: "🧪 Modo simulación: solo se descargarán cambios y se registrará lo que se haría",
+        en: "🧪 Dry run: changes are only fetched and the actions that would be taken are logged",
+    }
+    daemon_mode_continuous() {
+        es: "🕹️ Modo de sincronización: continuo (cada intervalo o con SIGUSR1)\n",
+        en: "🕹️ Sync mode: continuous (every interval or on SIGUSR1)\n",
+    }
+    daemon_mode_manual() {
+        es: "🕹️ Modo de sincronización: manual (solo al iniciar o con SIGUSR1)\n",
+        en: "🕹️ Sync mode: manual (only at startup or on SIGUSR1)\n",
+    }
+    config_watch_unavailable(error) {
+        es: "⚠️ {error}. Los cambios de configuración se aplicarán en el próximo ciclo.",
+        en: "⚠️ {error}. Configuration changes will be applied in the next cycle.",
+    }
+    control_socket(path) {
+        es: "🔌 Socket de control: {path}",
+        en: "🔌 Control socket: {path}",
+    }
+    control_socket_activated(path) {
+        es: "🔌 Socket de control: {path} (activado por systemd)",
+        en: "🔌 Control socket: {path} (activated by systemd)",
+    }
+    repos_file_changed(file, count) {
+        es: "📝 {file} modificado: se aplican los cambios ({count} repositorios)",
+        en: "📝 {file} changed: applying the changes ({count} repositories)",
+    }
+    keep_previous_repos(error) {
+        es: "{error}. Se mantiene la lista anterior.",
+        en: "{error}. Keeping the previous list.",
+    }
+    sync_requested() {
+        es: "📨 Sincronización pedida (SIGUSR1 o socket de control): se inicia un ciclo.",
+        en: "📨 Sync requested (SIGUSR1 or control socket): starting a cycle.",
+    }
+    sync_requested_repos(repos) {
+        es: "📨 Sincronización pedida por el socket de control para {repos}: se inicia un ciclo.",
+        en: "📨 Sync requested through the control socket for {repos}: starting a cycle.",
+    }
+    sync_requested_unknown(repos) {
+        es: "⚠️ Se pidió sincronizar {repos}, pero no está en la lista de repositorios.",
+        en: "⚠️ A sync was requested for {repos}, but it is not in the repository list.",
+    }
+    daemon_stopped() {
+        es: "👋 Señal de finalización recibida. Daemon detenido.",
+        en: "👋 Termination signal received. Daemon stopped.",
+    }
+    cycle_completed() {
+        es: "\n✅ Ciclo completado correctamente.",
+        en: "\n✅ Cycle completed successfully.",
+    }
+    stopped_on_error() {
+        es: "🛑 Finalización por error (stop_on_error=true)",
+        en: "🛑 Stopping after an error (stop_on_error=true)",
+    }
+
+    // main.rs: add, remove, list, pause y resume
+    invalid_add_option(option) {
+        es: "Opción `{option}` no válida (use clave=valor)",
+        en: "Invalid option `{option}` (use key=value)",
+    }
+    invalid_clone_url(url) {
+        es: "`{url}` no es una URL de clonado válida",
+        en: "`{url}` is not a valid clone URL",
+    }
+    not_a_git_repo(path) {
+        es: "{path} no es un repositorio Git. Use --url <url> para clonarlo ahí",
+        en: "{path} is not a Git repository. Use --url <url> to clone it there",
+    }
+    repo_already_listed(path, name) {
+        es: "El repositorio {path} ya está en la lista como `{name}`",
+        en: "The repository {path} is already listed as `{name}`",
+    }
+    repo_name_taken(name) {
+        es: "Ya hay un repositorio llamado `{name}`",
+        en: "There is already a repository named `{name}`",
+    }
+    repo_added(name, file) {
+        es: "✅ Repositorio `{name}` agregado en {file}",
+        en: "✅ Repository `{name}` added to {file}",
+    }
+    repo_removed(name, file, path) {
+        es: "🗑️ Repositorio `{name}` quitado de {file} (los archivos de {path} se conservan)",
+        en: "🗑️ Repository `{name}` removed from {file} (the files in {path} are kept)",
+    }
+    json_failed(error) {
+        es: "No se pudo generar el JSON: {error}",
+        en: "Could not generate the JSON: {error}",
+    }
+    sync_paused(pause) {
+        es: "⏸️ Sincronización pausada {pause}",
+        en: "⏸️ Sync paused {pause}",
+    }
+    no_repos(file) {
+        es: "ℹ️ No hay repositorios en {file}",
+        en: "ℹ️ There are no repositories in {file}",
+    }
+    repo_count(count, file) {
+        es: "📚 {count} repositorio(s) en {file}:",
+        en: "📚 {count} repositories in {file}:",
+    }
+    paused_until(until) {
+        es: "(pausado hasta {until})",
+        en: "(paused until {until})",
+    }
+    all_paused(pause) {
+        es: "⏸️ Sincronización pausada para todos los repositorios {pause}",
+        en: "⏸️ Sync paused for all repositories {pause}",
+    }
+    repo_paused_until(label, until) {
+        es: "⏸️ Repositorio {label} pausado hasta {until}",
+        en: "⏸️ Repository {label} paused until {until}",
+    }
+    repos_paused_until(label, until, total) {
+        es: "⏸️ Repositorios {label} pausados hasta {until}: {total}",
+        en: "⏸️ Repositories {label} paused until {until}: {total}",
+    }
+    sync_resumed() {
+        es: "▶️ Sincronización reanudada",
+        en: "▶️ Sync resumed",
+    }
+    sync_not_paused() {
+        es: "ℹ️ La sincronización no estaba pausada",
+        en: "ℹ️ Sync was not paused",
+    }
+    repos_still_paused(count, file) {
+        es: "ℹ️ {count} repositorio(s) siguen pausados en {file}: use `git-sync resume <nombre>` o `--group <grupo>`",
+        en: "ℹ️ {count} repositories are still paused in {file}: use `git-sync resume <name>` or `--group <group>`",
+    }
+    empty_group(group, file) {
+        es: "No hay repositorios en el grupo `{group}` ({file})",
+        en: "There are no repositories in group `{group}` ({file})",
+    }
+    group_label(group) {
+        es: "del grupo `{group}`",
+        en: "in group `{group}`",
+    }
+    repo_resumed(label) {
+        es: "▶️ Repositorio {label} reactivado",
+        en: "▶️ Repository {label} resumed",
+    }
+    repo_paused(label) {
+        es: "⏸️ Repositorio {label} pausado",
+        en: "⏸️ Repository {label} paused",
+    }
+    repos_resumed(label, changed, total) {
+        es: "▶️ Repositorios {label} reactivados: {changed} de {total}",
+        en: "▶️ Repositories {label} resumed: {changed} of {total}",
+    }
+    repos_paused(label, changed, total) {
+        es: "⏸️ Repositorios {label} pausados: {changed} de {total}",
+        en: "⏸️ Repositories {label} paused: {changed} of {total}",
+    }
+    retention_cleaned(count, mb) {
+        es: "🗄️ Retención de registros: se eliminaron {count} archivos rotados ({mb:.1} MB)",
+        en: "🗄️ Log retention: removed {count} rotated files ({mb:.1} MB)",
+    }
+    retention_failed(error) {
+        es: "⚠️ Retención de registros: {error}",
+        en: "⚠️ Log retention: {error}",
+    }
+
+    // pause.rs
+    pause_remove_failed(path, error) {
+        es: "No se pudo eliminar el estado de pausa {path}: {error}",
+        en: "Could not remove the pause state {path}: {error}",
+    }
+    pause_serialize_failed(error) {
+        es: "No se pudo serializar el estado de pausa: {error}",
+        en: "Could not serialize the pause state: {error}",
+    }
+    pause_save_failed(path, error) {
+        es: "No se pudo guardar el estado de pausa en {path}: {error}",
+        en: "Could not save the pause state to {path}: {error}",
+    }
+    until(until) {
+        es: "hasta {until}",
+        en: "until {until}",
+    }
+    until_resume() {
+        es: "hasta `git-sync resume`",
+        en: "until `git-sync resume`",
+    }
+
+    // main.rs: sync, update, uninstall --purge y el repositorio actual
+    sync_summary_header() {
+        es: "\n📋 Resultado por repositorio:",
+        en: "\n📋 Result per repository:",
+    }
+    commit_count(count) {
+        es: "{count} commit(s)",
+        en: "{count} commit(s)",
+    }
+    update_checking() {
+        es: "🔄 Buscando la última versión en GitHub Releases...",
+        en: "🔄 Looking for the latest version in GitHub Releases...",
+    }
+    update_linux_only() {
+        es: "La actualización automática solo está disponible para Linux.",
+        en: "Self-update is only available on Linux.",
+    }
+    update_unsupported_arch(arch) {
+        es: "Arquitectura no soportada para auto-actualización: {arch} (se espera x86_64).",
+        en: "Unsupported architecture for self-update: {arch} (x86_64 expected).",
+    }
+    update_done(tag) {
+        es: "\n✅ ¡git-sync se actualizó correctamente a {tag}!",
+        en: "\n✅ git-sync was updated to {tag}!",
+    }
+    update_restart_hint() {
+        es: "👉 Reinicie el servicio: `sudo systemctl restart git-sync`.",
+        en: "👉 Restart the service: `sudo systemctl restart git-sync`.",
+    }
+    command_failed(command, error) {
+        es: "No se pudo ejecutar `{command}`: {error}",
+        en: "Could not run `{command}`: {error}",
+    }
+    releases_query_failed(status) {
+        es: "No se pudo consultar releases en GitHub (estado {status}).",
+        en: "Could not query the GitHub releases ({status}).",
+    }
+    releases_not_utf8(error) {
+        es: "La respuesta de GitHub no es UTF-8 válida: {error}",
+        en: "The GitHub response is not valid UTF-8: {error}",
+    }
+    release_tag_missing() {
+        es: "No se pudo obtener el tag de la última release.",
+        en: "Could not get the tag of the latest release.",
+    }
+    temp_dir_clean_failed(error) {
+        es: "No se pudo limpiar el directorio temporal: {error}",
+        en: "Could not clean the temporary directory: {error}",
+    }
+    temp_dir_create_failed(error) {
+        es: "No se pudo crear el directorio temporal: {error}",
+        en: "Could not create the temporary directory: {error}",
+    }
+    update_downloading(tag) {
+        es: "⬇️ Descargando {tag} ...",
+        en: "⬇️ Downloading {tag} ...",
+    }
+    download_failed(url, status) {
+        es: "No se pudo descargar {url} (estado {status}).",
+        en: "Could not download {url} ({status}).",
+    }
+    extract_failed(status) {
+        es: "No se pudo extraer el archivo descargado (estado {status}).",
+        en: "Could not extract the downloaded archive ({status}).",
+    }
+    release_binary_missing() {
+        es: "No se encontró el binario `git-sync` dentro del release descargado.",
+        en: "The `git-sync` binary was not found in the downloaded release.",
+    }
+    current_exe_failed(error) {
+        es: "No se pudo detectar la ruta del binario actual: {error}",
+        en: "Could not determine the path of the current binary: {error}",
+    }
+    binary_copy_failed(from, to, error) {
+        es: "No se pudo copiar el nuevo binario desde {from} a {to}: {error}",
+        en: "Could not copy the new binary from {from} to {to}: {error}",
+    }
+    binary_mode_failed(error) {
+        es: "No se pudieron ajustar permisos del nuevo binario: {error}",
+        en: "Could not set the permissions of the new binary: {error}",
+    }
+    binary_replace_failed(path, error) {
+        es: "No se pudo reemplazar el binario actual en {path}: {error}",
+        en: "Could not replace the current binary at {path}: {error}",
+    }
+    path_not_utf8(path) {
+        es: "Ruta con caracteres UTF-8 inválidos: {path}",
+        en: "Path with invalid UTF-8 characters: {path}",
+    }
+    binary_name_failed() {
+        es: "No se pudo resolver el nombre del binario actual.",
+        en: "Could not resolve the name of the current binary.",
+    }
+    purge_header(service) {
+        es: "⚠️ Se eliminarán el servicio {service} y:",
+        en: "⚠️ This removes the service {service} and:",
+    }
+    purge_config(dir) {
+        es: "  • Configuración: {dir}",
+        en: "  • Configuration: {dir}",
+    }
+    purge_logs(dir) {
+        es: "  • Logs y estado: {dir}",
+        en: "  • Logs and state: {dir}",
+    }
+    purge_repos_kept() {
+        es: "  Los repositorios sincronizados no se modifican.",
+        en: "  The synchronized repositories are not modified.",
+    }
+    confirm_continue() {
+        es: "¿Desea continuar? (y/N): ",
+        en: "Do you want to continue? (y/N): ",
+    }
+    stdout_write_failed(error) {
+        es: "No se pudo escribir en la salida: {error}",
+        en: "Could not write to the output: {error}",
+    }
+    answer_read_failed(error) {
+        es: "No se pudo leer la respuesta: {error}",
+        en: "Could not read the answer: {error}",
+    }
+    current_dir_not_utf8() {
+        es: "La ruta del directorio actual no es UTF-8 válida.",
+        en: "The path of the current directory is not valid UTF-8.",
+    }
+    current_dir_not_git(path) {
+        es: "El directorio actual no parece ser un repositorio Git: {path}",
+        en: "The current directory does not look like a Git repository: {path}",
+    }
+    current_dir(path) {
+        es: "📂 Directorio actual: {path}",
+        en: "📂 Current directory: {path}",
+    }
+    confirm_add_current() {
+        es: "¿Desea agregar este repositorio a git-sync? (y/N): ",
+        en: "Do you want to add this repository to git-sync? (y/N): ",
+    }
+    repo_already_registered(file) {
+        es: "ℹ️ El repositorio ya está registrado en {file}",
+        en: "ℹ️ The repository is already registered in {file}",
+    }
+    current_repo_added(file) {
+        es: "✅ Repositorio agregado correctamente en {file}",
+        en: "✅ Repository added to {file}",
+    }
+
+    // args.rs: ayuda y errores de uso
+    invalid_pause_duration(value) {
+        es: "Duración `{value}` no válida (use segundos o un sufijo s, m, h o d, ej. 30m o 2h)",
+        en: "Invalid duration `{value}` (use seconds or an s, m, h or d suffix, e.g. 30m or 2h)",
+    }
+    help_files(dir) {
+        es: "🗂️ Archivos de configuración\n  config.toml, repositories.txt, secrets.toml, credentials.toml y environment\n  en {dir} (o en la configuración del usuario con --user, o en --config).",
+        en: "🗂️ Configuration files\n  config.toml, repositories.txt, secrets.toml, credentials.toml and environment\n  in {dir} (or in the user configuration with --user, or in --config).",
+    }
+    help_usage() {
+        es: "Uso",
+        en: "Usage",
+    }
+    help_commands() {
+        es: "Comandos",
+        en: "Commands",
+    }
+    help_arguments() {
+        es: "Argumentos",
+        en: "Arguments",
+    }
+    help_options() {
+        es: "Opciones",
+        en: "Options",
+    }
+    help_command_value() {
+        es: "COMANDO",
+        en: "COMMAND",
+    }
+    help_options_value() {
+        es: "[OPCIONES]",
+        en: "[OPTIONS]",
+    }
+    usage_hint(usage) {
+        es: "👉 Uso: {usage}",
+        en: "👉 Usage: {usage}",
+    }
+    help_hint() {
+        es: "👉 Utilice --help para consultar los comandos disponibles.",
+        en: "👉 Use --help to see the available commands.",
+    }
+    did_you_mean(suggestion) {
+        es: " (¿quiso decir `{suggestion}`?)",
+        en: " (did you mean `{suggestion}`?)",
+    }
+    unknown_command(command, suggestion) {
+        es: "Comando desconocido: {command}{suggestion}",
+        en: "Unknown command: {command}{suggestion}",
+    }
+    unknown_option(option, suggestion) {
+        es: "Opción desconocida: {option}{suggestion}",
+        en: "Unknown option: {option}{suggestion}",
+    }
+    missing_argument(argument) {
+        es: "Falta un argumento obligatorio: {argument}",
+        en: "Missing required argument: {argument}",
+    }
+    missing_subcommand() {
+        es: "Falta el subcomando",
+        en: "Missing subcommand",
+    }
+    argument_conflict(argument, prior) {
+        es: "{argument} no se puede combinar con {prior}",
+        en: "{argument} cannot be combined with {prior}",
+    }
+    invalid_argument_value(argument, error) {
+        es: "Valor no válido para {argument}: {error}",
+        en: "Invalid value for {argument}: {error}",
+    }
+    invalid_argument_literal(argument, value) {
+        es: "Valor no válido para {argument}: `{value}`",
+        en: "Invalid value for {argument}: `{value}`",
+    }
+    invalid_usage() {
+        es: "Uso inválido",
+        en: "Invalid usage",
+    }
+    invalid_usage_of(argument) {
+        es: "Uso inválido de {argument}",
+        en: "Invalid usage of {argument}",
+    }
+
+    // events.rs: resumen de `git-sync sync`
+    result_updated() {
+        es: "actualizado",
+        en: "updated",
+    }
+    result_unchanged() {
+        es: "sin cambios",
+        en: "unchanged",
+    }
+    result_failed() {
+        es: "error",
+        en: "error",
+    }
+    result_skipped() {
+        es: "omitido por una dependencia",
+        en: "skipped because of a dependency",
+    }
+    result_paused() {
+        es: "pausado",
+        en: "paused",
+    }
+    result_disabled() {
+        es: "desactivado por fallos consecutivos",
+        en: "disabled after consecutive failures",
+    }
+    result_dry_run() {
+        es: "simulación",
+        en: "dry run",
+    }
+    result_held() {
+        es: "retenido por quiet_hours",
+        en: "held by quiet_hours",
+    }
+
+    // logger.rs, logs.rs y watch.rs
+    invalid_log_level(value) {
+        es: "Nivel `{value}` no válido (use error, warn, info, debug o trace)",
+        en: "Invalid level `{value}` (use error, warn, info, debug or trace)",
+    }
+    invalid_since(value) {
+        es: "Valor `{value}` no válido para --since (use una duración como 30m, 2h o 1d, o una fecha como 2025-01-01 10:00)",
+        en: "Invalid value `{value}` for --since (use a duration such as 30m, 2h or 1d, or a date such as 2025-01-01 10:00)",
+    }
+    journald_hint() {
+        es: "\n👉 Con log_backend = \"journald\" las entradas están en el journal: journalctl -t git-sync",
+        en: "\n👉 With log_backend = \"journald\" the entries are in the journal: journalctl -t git-sync",
+    }
+    syslog_hint() {
+        es: "\n👉 Con log_backend = \"syslog\" las entradas están en el registro del sistema",
+        en: "\n👉 With log_backend = \"syslog\" the entries are in the system log",
+    }
+    log_missing(file, hint) {
+        es: "No existe el registro {file}{hint}",
+        en: "The log {file} does not exist{hint}",
+    }
+    log_read_failed(file, error) {
+        es: "No se pudo leer el registro {file}: {error}",
+        en: "Could not read the log {file}: {error}",
+    }
+    log_open_failed(file, error) {
+        es: "No se pudo abrir el registro {file}: {error}",
+        en: "Could not open the log {file}: {error}",
+    }
+    gzip_failed(file, error) {
+        es: "No se pudo ejecutar gzip para leer {file}: {error}",
+        en: "Could not run gzip to read {file}: {error}",
+    }
+    decompress_failed(file, error) {
+        es: "No se pudo descomprimir {file}: {error}",
+        en: "Could not decompress {file}: {error}",
+    }
+
+    // processor.rs: ciclo de sincronización
+    no_repos_configured_warning() {
+        es: "⚠️ No se encontraron repositorios en el archivo de configuración.",
+        en: "⚠️ No repositories were found in the configuration file.",
+    }
+    no_repos_configured_hint() {
+        es: "👉 Agregue las rutas de los repositorios, una por línea.",
+        en: "👉 Add the repository paths, one per line.",
+    }
+    no_repos_configured() {
+        es: "No hay repositorios configurados",
+        en: "No repositories configured",
+    }
+    cycle_paused(pause, count) {
+        es: "⏸️ Sincronización pausada {pause}: se omiten {count} repositorios",
+        en: "⏸️ Sync paused {pause}: skipping {count} repositories",
+    }
+    cycle_start(count) {
+        es: "📦 Se analizarán {count} repositorios\n",
+        en: "📦 {count} repositories will be checked\n",
+    }
+    dependency_failed(repo, dependency) {
+        es: "⏭️ Se omitió {repo} porque su dependencia {dependency} falló en este ciclo",
+        en: "⏭️ Skipped {repo} because its dependency {dependency} failed in this cycle",
+    }
+    repo_disabled(repo) {
+        es: "⏸️ Repositorio pausado (sync desactivado): {repo}",
+        en: "⏸️ Repository paused (sync disabled): {repo}",
+    }
+    repo_paused_until_log(until, repo) {
+        es: "⏸️ Repositorio pausado hasta {until}: {repo}",
+        en: "⏸️ Repository paused until {until}: {repo}",
+    }
+    repo_auto_disabled(failures, repo) {
+        es: "⛔ Repositorio desactivado tras {failures} fallos consecutivos: {repo}",
+        en: "⛔ Repository disabled after {failures} consecutive failures: {repo}",
+    }
+    dry_run_failed(repo, error) {
+        es: "⚠️ Simulación fallida en {repo}: {error}",
+        en: "⚠️ Dry run failed in {repo}: {error}",
+    }
+    repo_timed_out(repo, error) {
+        es: "⏱️ Repositorio omitido {repo} por tiempo de espera agotado: {error}",
+        en: "⏱️ Skipped repository {repo} because of a timeout: {error}",
+    }
+    repo_failed(repo, error) {
+        es: "⚠️ Repositorio omitido {repo} debido a un error: {error}",
+        en: "⚠️ Skipped repository {repo} because of an error: {error}",
+    }
+    repo_now_disabled(repo, failures) {
+        es: "⛔ {repo} se desactivó tras {failures} fallos consecutivos. Corrija el problema y reactívelo desde la TUI (tecla 'p').",
+        en: "⛔ {repo} was disabled after {failures} consecutive failures. Fix the problem and re-enable it from the TUI (key 'p').",
+    }
+    state_save_failed(error) {
+        es: "⚠️ No se pudo actualizar el archivo de estado de sincronización: {error}",
+        en: "⚠️ Could not update the sync state file: {error}",
+    }
+    cycle_done() {
+        es: "🎉 Todos los repositorios fueron procesados.",
+        en: "🎉 All repositories were processed.",
+    }
+    timeout_note(count) {
+        es: " ({count} por tiempo de espera agotado)",
+        en: " ({count} because of a timeout)",
+    }
+    cycle_errors(count, note, details) {
+        es: "{count} repositorios presentaron errores durante la sincronización{note}:\n{details}",
+        en: "{count} repositories had errors during the sync{note}:\n{details}",
+    }
+    low_disk_space(repo, available, path, minimum) {
+        es: "💾 Espacio insuficiente para sincronizar {repo}: quedan {available} MB libres en {path} (mínimo min_free_mb={minimum} MB)",
+        en: "💾 Not enough space to sync {repo}: {available} MB free in {path} (minimum min_free_mb={minimum} MB)",
+    }
+
+    // processor.rs: dependencias y despliegue
+    dependency_not_in_cycle(dependency, repo) {
+        es: "ℹ️ La dependencia `{dependency}` de {repo} no está entre los repositorios de este ciclo.",
+        en: "ℹ️ The dependency `{dependency}` of {repo} is not among the repositories of this cycle.",
+    }
+    circular_dependencies(repo) {
+        es: "🔁 Dependencias circulares en `after` que incluyen {repo}; se procesa en el orden del archivo.",
+        en: "🔁 Circular dependencies in `after` involving {repo}; processing in file order.",
+    }
+    preset_ignored_bare(repo) {
+        es: "⚠️ `preset` se ignora en {repo}: los repositorios bare no tienen árbol de trabajo.",
+        en: "⚠️ `preset` is ignored in {repo}: bare repositories have no working tree.",
+    }
+    preset_step_failed(step, preset, repo, error) {
+        es: "❌ Falló el paso `{step}` del preset {preset} en {repo}: {error}",
+        en: "❌ Step `{step}` of preset {preset} failed in {repo}: {error}",
+    }
+    preset_output_missing(preset, output, repo) {
+        es: "❌ El preset {preset} terminó sin generar {output}/ en {repo}. Revise el script de compilación o indique `preset_output`.",
+        en: "❌ Preset {preset} finished without producing {output}/ in {repo}. Check the build script or set `preset_output`.",
+    }
+    deploy_retrying(repo, commit) {
+        es: "🔁 Reintentando el despliegue de {commit} en {repo}, que falló en un ciclo anterior",
+        en: "🔁 Retrying the deploy of {commit} in {repo}, which failed in an earlier cycle",
+    }
+    preset_applied(preset, repo) {
+        es: "🚀 Preset {preset} aplicado en {repo}.",
+        en: "🚀 Preset {preset} applied in {repo}.",
+    }
+    compose_ignored_bare(repo) {
+        es: "⚠️ `compose` se ignora en {repo}: los repositorios bare no tienen árbol de trabajo.",
+        en: "⚠️ `compose` is ignored in {repo}: bare repositories have no working tree.",
+    }
+    compose_done(repo) {
+        es: "🐳 Contenedores de {repo} reconstruidos y recreados.",
+        en: "🐳 Containers of {repo} rebuilt and recreated.",
+    }
+    compose_failed(engine, repo, error) {
+        es: "❌ Falló `{engine} compose up -d --build` tras sincronizar {repo}: {error}",
+        en: "❌ `{engine} compose up -d --build` failed after syncing {repo}: {error}",
+    }
+    hook_running(command, repo) {
+        es: "⚙️ Ejecutando `{command}` en {repo}...",
+        en: "⚙️ Running `{command}` in {repo}...",
+    }
+    hook_exit(status) {
+        es: "finalizó con {status}",
+        en: "finished with {status}",
+    }
+    unit_restarted(unit) {
+        es: "🔁 Servicio {unit} recargado/reiniciado.",
+        en: "🔁 Service {unit} reloaded/restarted.",
+    }
+    unit_restart_failed(unit, repo, error) {
+        es: "❌ No se pudo recargar/reiniciar {unit} tras sincronizar {repo}: {error}",
+        en: "❌ Could not reload/restart {unit} after syncing {repo}: {error}",
+    }
+    notification_failed(repo, error) {
+        es: "⚠️ No se pudo enviar la notificación de {repo}: {error}",
+        en: "⚠️ Could not send the notification for {repo}: {error}",
+    }
+    processing_repo(name, path) {
+        es: "🔄 Procesando repositorio: {name} ({path})",
+        en: "🔄 Processing repository: {name} ({path})",
+    }
+    mirror_ignored_bare(mirror, repo) {
+        es: "⚠️ Se ignoró mirror_to={mirror} en {repo}: no se admite con sync_mode=bare.",
+        en: "⚠️ Ignored mirror_to={mirror} in {repo}: not supported with sync_mode=bare.",
+    }
+
+    // processor.rs: errores de Git
+    tags_fetch_failed(error) {
+        es: "❌ No se pudieron descargar los tags: {error}",
+        en: "❌ Could not fetch the tags: {error}",
+    }
+    tags_list_failed(error) {
+        es: "❌ No se pudieron listar los tags: {error}",
+        en: "❌ Could not list the tags: {error}",
+    }
+    git_fetch_failed(error) {
+        es: "❌ No se pudo ejecutar `git fetch`: {error}",
+        en: "❌ Could not run `git fetch`: {error}",
+    }
+    repo_status_failed(error) {
+        es: "❌ No se pudo consultar el estado del repositorio: {error}",
+        en: "❌ Could not query the repository status: {error}",
+    }
+    current_branch_failed(error) {
+        es: "❌ No se pudo consultar la rama actual: {error}",
+        en: "❌ Could not query the current branch: {error}",
+    }
+    worktree_status_failed(error) {
+        es: "❌ No se pudo consultar el árbol de trabajo: {error}",
+        en: "❌ Could not query the working tree: {error}",
+    }
+    no_semver_tag(pattern) {
+        es: "❌ Ningún tag con versión semántica coincide con `{pattern}`",
+        en: "❌ No tag with a semantic version matches `{pattern}`",
+    }
+    tag_resolve_failed(tag, error) {
+        es: "❌ No se pudo resolver el tag {tag}: {error}",
+        en: "❌ Could not resolve the tag {tag}: {error}",
+    }
+
+    // processor.rs: simulación y quiet_hours
+    simulating_repo(name, path) {
+        es: "🧪 Simulando repositorio: {name} ({path})",
+        en: "🧪 Simulating repository: {name} ({path})",
+    }
+    simulation_of(repo) {
+        es: "🧪 Simulación de {repo}:",
+        en: "🧪 Dry run of {repo}:",
+    }
+    simulation_nothing() {
+        es: "   - sin cambios remotos: no se haría nada",
+        en: "   - no remote changes: nothing would be done",
+    }
+    held_clone(window, repo) {
+        es: "🌙 Franja sin despliegues ({window}): {repo} se clonará al terminar",
+        en: "🌙 Deployment quiet hours ({window}): {repo} will be cloned when they end",
+    }
+    pending_tag(tag) {
+        es: "el tag {tag}",
+        en: "tag {tag}",
+    }
+    held_pending(window, repo, pending) {
+        es: "🌙 Franja sin despliegues ({window}) en {repo}: pendiente {pending}, se aplicará al terminar",
+        en: "🌙 Deployment quiet hours ({window}) in {repo}: {pending} pending, will be applied when they end",
+    }
+    held_nothing(window, repo) {
+        es: "🌙 Franja sin despliegues ({window}): {repo} sin cambios pendientes",
+        en: "🌙 Deployment quiet hours ({window}): {repo} has no pending changes",
+    }
+    would_clone(url, repo, command) {
+        es: "se clonaría {url} en {repo} (`{command}`)",
+        en: "{url} would be cloned into {repo} (`{command}`)",
+    }
+    would_update_remotes() {
+        es: "se actualizarían los remotos con `git remote update --prune`",
+        en: "the remotes would be updated with `git remote update --prune`",
+    }
+    would_apply_preset(preset, steps) {
+        es: "se aplicaría el preset {preset} (pasos: {steps})",
+        en: "preset {preset} would be applied (steps: {steps})",
+    }
+    would_compose(engine) {
+        es: "se ejecutaría `{engine} compose up -d --build`",
+        en: "`{engine} compose up -d --build` would be run",
+    }
+    would_restart_units(units) {
+        es: "se recargarían o reiniciarían los servicios: {units}",
+        en: "these services would be reloaded or restarted: {units}",
+    }
+    would_mirror(mirror) {
+        es: "se replicarían las ramas y tags en el remoto {mirror}",
+        en: "branches and tags would be mirrored to the remote {mirror}",
+    }
+    would_checkout_tag(tag, files) {
+        es: "se cambiaría al tag {tag} ({files})",
+        en: "the working tree would switch to tag {tag} ({files})",
+    }
+    would_switch_branch(current, branch) {
+        es: "se cambiaría de la rama {current} a la configurada {branch} (branch={branch})",
+        en: "the working tree would switch from branch {current} to the configured {branch} (branch={branch})",
+    }
+    would_skip_detached() {
+        es: "se omitiría el repositorio: HEAD desacoplado (on_detached=skip)",
+        en: "the repository would be skipped: detached HEAD (on_detached=skip)",
+    }
+    would_skip_detached_commits(ahead, target) {
+        es: "se omitiría el repositorio: HEAD desacoplado con {ahead} commit(s) que no están en {target}",
+        en: "the repository would be skipped: detached HEAD with {ahead} commit(s) not in {target}",
+    }
+    would_reattach(branch) {
+        es: "se volvería a la rama {branch} (on_detached=reattach)",
+        en: "the working tree would return to branch {branch} (on_detached=reattach)",
+    }
+    would_integrate(behind, ahead, strategy) {
+        es: "se integrarían {behind} commit(s) remotos y {ahead} locales con `git {strategy}`",
+        en: "{behind} remote and {ahead} local commit(s) would be integrated with `git {strategy}`",
+    }
+    divergence(target, ahead, behind) {
+        es: "historial divergente con {target}: {ahead} commit(s) locales y {behind} remotos",
+        en: "history diverged from {target}: {ahead} local and {behind} remote commit(s)",
+    }
+    would_skip_divergence(divergence) {
+        es: "se omitiría el repositorio: {divergence} (on_divergence=skip)",
+        en: "the repository would be skipped: {divergence} (on_divergence=skip)",
+    }
+    would_reset_divergence(divergence, target) {
+        es: "{divergence}: se descartarían los commits locales con `git reset --hard {target}` (on_divergence=reset)",
+        en: "{divergence}: the local commits would be discarded with `git reset --hard {target}` (on_divergence=reset)",
+    }
+    would_rescue_divergence(divergence, target) {
+        es: "{divergence}: se guardarían en una rama git-sync/rescue-<fecha> y se haría `git reset --hard {target}` (on_divergence=rescue)",
+        en: "{divergence}: they would be saved in a git-sync/rescue-<date> branch followed by `git reset --hard {target}` (on_divergence=rescue)",
+    }
+    would_pull(behind, files) {
+        es: "se aplicarían {behind} commit(s) con `git pull` ({files})",
+        en: "{behind} commit(s) would be applied with `git pull` ({files})",
+    }
+    would_reset(target, behind, files) {
+        es: "se haría `git reset --hard {target}`: {behind} commit(s) remotos ({files})",
+        en: "`git reset --hard {target}` would be run: {behind} remote commit(s) ({files})",
+    }
+    would_discard_modified(count, files) {
+        es: "se descartarían los cambios locales de {count} archivo(s): {files}",
+        en: "the local changes to {count} file(s) would be discarded: {files}",
+    }
+    dirty_files(count, files) {
+        es: "cambios locales sin confirmar en {count} archivo(s): {files}",
+        en: "uncommitted local changes in {count} file(s): {files}",
+    }
+    would_skip_dirty(dirty) {
+        es: "se omitiría el repositorio: {dirty} (on_dirty=abort)",
+        en: "the repository would be skipped: {dirty} (on_dirty=abort)",
+    }
+    would_stash_dirty(dirty) {
+        es: "se guardarían en el stash y se restaurarían después los {dirty} (on_dirty=stash)",
+        en: "the {dirty} would be stashed and restored afterwards (on_dirty=stash)",
+    }
+    would_discard_dirty(dirty) {
+        es: "se descartarían los {dirty} (on_dirty=discard)",
+        en: "the {dirty} would be discarded (on_dirty=discard)",
+    }
+    git_clean_failed(error) {
+        es: "❌ No se pudo ejecutar `git clean -n`: {error}",
+        en: "❌ Could not run `git clean -n`: {error}",
+    }
+    would_clean(count, files) {
+        es: "se eliminarían {count} archivo(s) sin seguimiento: {files}",
+        en: "{count} untracked file(s) would be removed: {files}",
+    }
+    modified_file_count(count) {
+        es: "{count} archivo(s) modificados",
+        en: "{count} modified file(s)",
+    }
+    modified_files_unknown() {
+        es: "archivos modificados desconocidos",
+        en: "unknown modified files",
+    }
+    commits_list_failed(range, error) {
+        es: "⚠️ No se pudieron listar los commits de {range}: {error}",
+        en: "⚠️ Could not list the commits of {range}: {error}",
+    }
+    deployed(range, head) {
+        es: "📜 Desplegado {range} (HEAD {head}):",
+        en: "📜 Deployed {range} (HEAD {head}):",
+    }
+
+    // processor.rs: mantenimiento, clonado y actualización
+    shallow_pruning() {
+        es: "🧹 Recortando el historial superficial (fetch --depth + gc)...",
+        en: "🧹 Trimming the shallow history (fetch --depth + gc)...",
+    }
+    shallow_prune_failed(repo, error) {
+        es: "⚠️ No se pudo compactar {repo}: {error}",
+        en: "⚠️ Could not compact {repo}: {error}",
+    }
+    maintenance_done(repo, before, after, seconds) {
+        es: "🧰 Mantenimiento de {repo} (git gc): {before} → {after} en {seconds:.1} s",
+        en: "🧰 Maintenance of {repo} (git gc): {before} → {after} in {seconds:.1} s",
+    }
+    maintenance_failed(repo, error) {
+        es: "⚠️ No se pudo completar el mantenimiento de {repo}: {error}",
+        en: "⚠️ Could not complete the maintenance of {repo}: {error}",
+    }
+    local_branches_failed(repo, error) {
+        es: "⚠️ No se pudieron consultar las ramas locales de {repo}: {error}",
+        en: "⚠️ Could not query the local branches of {repo}: {error}",
+    }
+    local_branch_deleted(branch, remote) {
+        es: "🌿 Rama local {branch} eliminada: ya no existe en {remote}",
+        en: "🌿 Local branch {branch} deleted: it no longer exists in {remote}",
+    }
+    local_branch_kept(branch, remote) {
+        es: "⚠️ La rama local {branch} ya no existe en {remote} pero tiene commits sin integrar; se conserva",
+        en: "⚠️ The local branch {branch} no longer exists in {remote} but has unmerged commits; keeping it",
+    }
+    local_branch_delete_failed(branch, error) {
+        es: "⚠️ No se pudo eliminar la rama local {branch}: {error}",
+        en: "⚠️ Could not delete the local branch {branch}: {error}",
+    }
+    mirroring(mirror) {
+        es: "🪞 Replicando en el remoto {mirror}...",
+        en: "🪞 Mirroring to the remote {mirror}...",
+    }
+    mirrored(count, mirror) {
+        es: "✅ {count} referencias replicadas en {mirror}.",
+        en: "✅ {count} references mirrored to {mirror}.",
+    }
+    mirror_failed(mirror, error) {
+        es: "❌ No se pudo replicar en el remoto {mirror}: {error}",
+        en: "❌ Could not mirror to the remote {mirror}: {error}",
+    }
+    path_missing(path) {
+        es: "❌ La ruta no existe: {path}",
+        en: "❌ The path does not exist: {path}",
+    }
+    not_bare_repo(path) {
+        es: "❌ El directorio no es un repositorio Git bare (sync_mode=bare): {path}",
+        en: "❌ The directory is not a bare Git repository (sync_mode=bare): {path}",
+    }
+    clone_target_not_empty(path) {
+        es: "❌ No se puede clonar en {path}: el directorio existe, no está vacío y no es un repositorio Git",
+        en: "❌ Cannot clone into {path}: the directory exists, is not empty and is not a Git repository",
+    }
+    invalid_git_repo(path) {
+        es: "❌ El directorio no es un repositorio Git válido: {path}",
+        en: "❌ The directory is not a valid Git repository: {path}",
+    }
+    remote_url_mismatch(remote, repo, actual, expected) {
+        es: "❌ El remoto `{remote}` de {repo} apunta a {actual} en lugar de {expected}: se omite la sincronización. Si el cambio es intencionado, actualice la URL en repositories.txt.",
+        en: "❌ The remote `{remote}` of {repo} points to {actual} instead of {expected}: skipping the sync. If the change is intended, update the URL in repositories.txt.",
+    }
+    remote_missing(repo, remote, expected) {
+        es: "❌ {repo} no tiene el remoto `{remote}` (se esperaba {expected}): se omite la sincronización.",
+        en: "❌ {repo} has no remote `{remote}` ({expected} expected): skipping the sync.",
+    }
+    remote_url_failed(remote, repo, error) {
+        es: "❌ No se pudo leer la URL del remoto `{remote}` de {repo}: {error}",
+        en: "❌ Could not read the URL of the remote `{remote}` of {repo}: {error}",
+    }
+    cloning(url, path) {
+        es: "📥 Clonando {url} en {path}...",
+        en: "📥 Cloning {url} into {path}...",
+    }
+    dir_create_failed(path, error) {
+        es: "❌ No se pudo crear el directorio {path}: {error}",
+        en: "❌ Could not create the directory {path}: {error}",
+    }
+    clone_failed(url, error) {
+        es: "❌ No se pudo clonar {url}: {error}",
+        en: "❌ Could not clone {url}: {error}",
+    }
+    cloned(branch) {
+        es: "✅ Repositorio clonado (rama {branch}).",
+        en: "✅ Repository cloned (branch {branch}).",
+    }
+    result_cloned(url) {
+        es: "Repositorio clonado desde {url}",
+        en: "Repository cloned from {url}",
+    }
+    updating_remotes() {
+        es: "🔍 Actualizando remotos (`git remote update --prune`)...",
+        en: "🔍 Updating remotes (`git remote update --prune`)...",
+    }
+    remote_update_failed(error) {
+        es: "❌ No se pudo ejecutar `git remote update`: {error}",
+        en: "❌ Could not run `git remote update`: {error}",
+    }
+    up_to_date() {
+        es: "✅ El repositorio ya está actualizado.",
+        en: "✅ The repository is already up to date.",
+    }
+    result_no_changes() {
+        es: "Sin cambios remotos",
+        en: "No remote changes",
+    }
+    result_remotes_updated(count) {
+        es: "Remotos actualizados: {count} referencia(s)",
+        en: "Remotes updated: {count} reference(s)",
+    }
+    checking_remote() {
+        es: "🔍 Verificando el estado del remoto...",
+        en: "🔍 Checking the remote status...",
+    }
+    using_branch(branch) {
+        es: "Se utilizará la rama: {branch}",
+        en: "Using branch: {branch}",
+    }
+    pulling(behind) {
+        es: "⬇️ El remoto tiene {behind} confirmaciones nuevas. Aplicando cambios...",
+        en: "⬇️ The remote has {behind} new commits. Applying changes...",
+    }
+    pull_output(output) {
+        es: "📥 Resultado de `git pull`:\n{output}",
+        en: "📥 Output of `git pull`:\n{output}",
+    }
+    result_deploy_retried(commit) {
+        es: "Despliegue de {commit} reintentado",
+        en: "Deploy of {commit} retried",
+    }
+    result_pulled(behind) {
+        es: "Pull aplicado: {behind} commit(s)",
+        en: "Pull applied: {behind} commit(s)",
+    }
+    git_pull_failed(error) {
+        es: "❌ No se pudo ejecutar `git pull`: {error}",
+        en: "❌ Could not run `git pull`: {error}",
+    }
+    integrating(behind, ahead, strategy) {
+        es: "⬇️ El remoto tiene {behind} confirmaciones nuevas y hay {ahead} locales. Integrando con {strategy}...",
+        en: "⬇️ The remote has {behind} new commits and there are {ahead} local ones. Integrating with {strategy}...",
+    }
+    integrate_output(strategy, output) {
+        es: "📥 Resultado de `git {strategy}`:\n{output}",
+        en: "📥 Output of `git {strategy}`:\n{output}",
+    }
+
+    // processor.rs: integración, tags y políticas
+    result_rebased(behind, ahead) {
+        es: "Rebase aplicado: {behind} commit(s) remotos, {ahead} locales reaplicados",
+        en: "Rebase applied: {behind} remote commit(s), {ahead} local ones reapplied",
+    }
+    result_merged(behind, ahead) {
+        es: "Merge aplicado: {behind} commit(s) remotos, {ahead} locales",
+        en: "Merge applied: {behind} remote commit(s), {ahead} local ones",
+    }
+    integrate_failed(target, strategy, error) {
+        es: "⚠️ No se pudo integrar {target} con {strategy} y se deshizo: {error}",
+        en: "⚠️ Could not integrate {target} with {strategy}, so it was undone: {error}",
+    }
+    already_on_tag(tag) {
+        es: "✅ El repositorio ya está en el tag {tag}.",
+        en: "✅ The repository is already at tag {tag}.",
+    }
+    new_tag(tag, pattern) {
+        es: "🏷️ Nuevo tag {tag} (patrón {pattern}). Cambiando el árbol de trabajo...",
+        en: "🏷️ New tag {tag} (pattern {pattern}). Switching the working tree...",
+    }
+    tag_checkout_failed(tag, error) {
+        es: "❌ No se pudo cambiar al tag {tag}: {error}",
+        en: "❌ Could not switch to tag {tag}: {error}",
+    }
+    result_tag_deployed(tag) {
+        es: "Tag {tag} desplegado",
+        en: "Tag {tag} deployed",
+    }
+    switching_branch(current, branch) {
+        es: "🔀 Cambiando de la rama {current} a la configurada {branch}...",
+        en: "🔀 Switching from branch {current} to the configured {branch}...",
+    }
+    branch_checkout_failed(branch, error) {
+        es: "❌ No se pudo cambiar a la rama {branch}: {error}",
+        en: "❌ Could not switch to branch {branch}: {error}",
+    }
+    detached_head(head) {
+        es: "🔗 HEAD desacoplado en {head}: el repositorio no está en ninguna rama",
+        en: "🔗 Detached HEAD at {head}: the repository is not on any branch",
+    }
+    detached_skip(detached, repo, branch) {
+        es: "{detached}. Ejecute `git -C {repo} checkout {branch}` o configure on_detached=reattach. Se omitió el repositorio (on_detached=skip)",
+        en: "{detached}. Run `git -C {repo} checkout {branch}` or set on_detached=reattach. The repository was skipped (on_detached=skip)",
+    }
+    detached_commits(detached, ahead, target, branch) {
+        es: "{detached} y tiene {ahead} commit(s) que no están en {target}. Guárdelos en una rama antes de volver a {branch}. Se omitió el repositorio",
+        en: "{detached} and has {ahead} commit(s) not in {target}. Save them in a branch before returning to {branch}. The repository was skipped",
+    }
+    detached_reattach(detached, branch) {
+        es: "{detached}. Se vuelve a la rama {branch} (on_detached=reattach)",
+        en: "{detached}. Returning to branch {branch} (on_detached=reattach)",
+    }
+    reattach_failed(branch, error) {
+        es: "❌ No se pudo volver a la rama {branch}: {error}",
+        en: "❌ Could not return to branch {branch}: {error}",
+    }
+    dirty_warning(count, files) {
+        es: "⚠️ Cambios locales sin confirmar en {count} archivo(s): {files}",
+        en: "⚠️ Uncommitted local changes in {count} file(s): {files}",
+    }
+    dirty_abort(warning) {
+        es: "{warning}. Se omitió el repositorio (on_dirty=abort)",
+        en: "{warning}. The repository was skipped (on_dirty=abort)",
+    }
+    dirty_stash(warning) {
+        es: "{warning}. Se guardan en el stash durante la actualización (on_dirty=stash)",
+        en: "{warning}. Stashing them during the update (on_dirty=stash)",
+    }
+    stash_message() {
+        es: "git-sync: cambios locales antes de actualizar",
+        en: "git-sync: local changes before updating",
+    }
+    stash_failed(error) {
+        es: "❌ No se pudieron guardar los cambios locales: {error}",
+        en: "❌ Could not save the local changes: {error}",
+    }
+    dirty_discard(warning) {
+        es: "{warning}. Se descartan (on_dirty=discard)",
+        en: "{warning}. Discarding them (on_dirty=discard)",
+    }
+    discard_failed(error) {
+        es: "❌ No se pudieron descartar los cambios locales: {error}",
+        en: "❌ Could not discard the local changes: {error}",
+    }
+    stash_restored() {
+        es: "🧺 Cambios locales restaurados desde el stash.",
+        en: "🧺 Local changes restored from the stash.",
+    }
+    stash_restore_failed(error) {
+        es: "❌ Los cambios locales no se pudieron restaurar y siguen en el stash (`git stash list`): {error}",
+        en: "❌ The local changes could not be restored and are still in the stash (`git stash list`): {error}",
+    }
+    verifying_signature(rev) {
+        es: "🔏 Verificando la firma de {rev}...",
+        en: "🔏 Verifying the signature of {rev}...",
+    }
+    untrusted_signature(rev, error) {
+        es: "🔏 {rev} no tiene una firma de confianza: {error}. No se aplicaron los cambios",
+        en: "🔏 {rev} has no trusted signature: {error}. The changes were not applied",
+    }
+    divergence_warning(target, ahead, behind) {
+        es: "⚠️ Historial divergente con {target}: {ahead} commit(s) locales y {behind} remotos (¿force push?)",
+        en: "⚠️ History diverged from {target}: {ahead} local and {behind} remote commit(s) (force push?)",
+    }
+    divergence_skip(warning) {
+        es: "{warning}. Se omitió el repositorio (on_divergence=skip)",
+        en: "{warning}. The repository was skipped (on_divergence=skip)",
+    }
+    rescue_branch_failed(error) {
+        es: "❌ No se pudo crear la rama de rescate: {error}",
+        en: "❌ Could not create the rescue branch: {error}",
+    }
+    rescue_branch_created(branch) {
+        es: "🛟 Commits locales guardados en la rama {branch}",
+        en: "🛟 Local commits saved in branch {branch}",
+    }
+    rescue_stash_message(branch) {
+        es: "git-sync: cambios locales de {branch}",
+        en: "git-sync: local changes of {branch}",
+    }
+    rescue_stashed() {
+        es: "🛟 Cambios sin confirmar guardados en el stash",
+        en: "🛟 Uncommitted changes saved in the stash",
+    }
+    rescue_stash_failed(error) {
+        es: "❌ No se pudo guardar el stash de rescate: {error}",
+        en: "❌ Could not save the rescue stash: {error}",
+    }
+    untracked_removed(files) {
+        es: "🧹 Archivos sin seguimiento eliminados: {files}",
+        en: "🧹 Untracked files removed: {files}",
+    }
+    git_clean_run_failed(error) {
+        es: "❌ No se pudo ejecutar `git clean`: {error}",
+        en: "❌ Could not run `git clean`: {error}",
+    }
+    resetting(target) {
+        es: "♻️ Modo reset: alineando el árbol de trabajo con {target}...",
+        en: "♻️ Reset mode: aligning the working tree with {target}...",
+    }
+    reset_output(output) {
+        es: "📥 Resultado de `git reset --hard`:\n{output}",
+        en: "📥 Output of `git reset --hard`:\n{output}",
+    }
+    git_reset_failed(error) {
+        es: "❌ No se pudo ejecutar `git reset --hard`: {error}",
+        en: "❌ Could not run `git reset --hard`: {error}",
+    }
+    result_reset(target, behind) {
+        es: "Reset a {target} aplicado: {behind} commit(s)",
+        en: "Reset to {target} applied: {behind} commit(s)",
+    }
+
+    // processor.rs: espacio libre
+    free_space_failed(path, error) {
+        es: "No se pudo consultar el espacio libre de {path}: {error}",
+        en: "Could not check the free space of {path}: {error}",
+    }
+    df_unexpected(path) {
+        es: "Salida inesperada de `df` para {path}",
+        en: "Unexpected `df` output for {path}",
+    }
+
+    // git.rs
+    timeout(operation, seconds) {
+        es: "⏱️ Tiempo de espera agotado: `{operation}` superó {seconds} segundos y fue cancelado",
+        en: "⏱️ Timed out: `{operation}` exceeded {seconds} seconds and was cancelled",
+    }
+
+    // git/cli.rs y git/libgit2.rs
+    run_failed(command, error) {
+        es: "❌ No se pudo ejecutar `{command}`: {error}",
+        en: "❌ Could not run `{command}`: {error}",
+    }
+    git_state_failed(error) {
+        es: "❌ No se pudo comprobar el estado de Git: {error}",
+        en: "❌ Could not check the Git state: {error}",
+    }
+    unexpected_output(command, error) {
+        es: "Respuesta inesperada de `{command}`: {error}",
+        en: "Unexpected response from `{command}`: {error}",
+    }
+    stash_conflict() {
+        es: "conflicto al aplicar el stash",
+        en: "conflict while applying the stash",
+    }
+    rebase_incomplete() {
+        es: "el rebase no se pudo completar",
+        en: "the rebase could not be completed",
+    }
+    merge_incomplete() {
+        es: "la fusión no se pudo completar",
+        en: "the merge could not be completed",
+    }
+    commit_unsigned() {
+        es: "el commit no está firmado",
+        en: "the commit is not signed",
+    }
+    repo_open_failed(path, error) {
+        es: "No se pudo abrir el repositorio {path}: {error}",
+        en: "Could not open the repository {path}: {error}",
+    }
+    ssh_key_rejected(key) {
+        es: "el remoto rechazó la clave SSH {key}",
+        en: "the remote rejected the SSH key {key}",
+    }
+    no_credentials(url) {
+        es: "no hay credenciales disponibles para {url}",
+        en: "no credentials available for {url}",
+    }
+    invalid_branch_name() {
+        es: "Nombre de rama no válido",
+        en: "Invalid branch name",
+    }
+    rebase_conflict() {
+        es: "conflicto al reaplicar un commit local",
+        en: "conflict while reapplying a local commit",
+    }
+    rebase_applied(count, target) {
+        es: "{count} commit(s) locales reaplicados sobre {target}",
+        en: "{count} local commit(s) reapplied onto {target}",
+    }
+    merge_conflict() {
+        es: "conflicto al fusionar los cambios remotos",
+        en: "conflict while merging the remote changes",
+    }
+    already_up_to_date() {
+        es: "Ya está actualizado.",
+        en: "Already up to date.",
+    }
+    not_fast_forward(target) {
+        es: "La rama local divergió de {target}; no es posible un fast-forward",
+        en: "The local branch diverged from {target}; a fast-forward is not possible",
+    }
+    head_not_branch() {
+        es: "HEAD no apunta a una rama; no se puede avanzar",
+        en: "HEAD does not point to a branch; cannot fast-forward",
+    }
+    fast_forward_reflog(target) {
+        es: "git-sync: fast-forward a {target}",
+        en: "git-sync: fast-forward to {target}",
+    }
+    fast_forward_to(commit) {
+        es: "Fast-forward a {commit}",
+        en: "Fast-forward to {commit}",
+    }
+    head_now_at(commit, summary) {
+        es: "HEAD ahora en {commit} {summary}",
+        en: "HEAD is now at {commit} {summary}",
+    }
+    no_worktree() {
+        es: "El repositorio no tiene árbol de trabajo",
+        en: "The repository has no working tree",
+    }
+    remove_failed(path, error) {
+        es: "No se pudo eliminar {path}: {error}",
+        en: "Could not remove {path}: {error}",
+    }
+    remote_not_found(remote) {
+        es: "No existe el remoto `{remote}`",
+        en: "The remote `{remote}` does not exist",
+    }
+    bare_no_worktree() {
+        es: "el repositorio no tiene árbol de trabajo (bare)",
+        en: "the repository has no working tree (bare)",
+    }
+
+    // config.rs: opciones de repositories.txt
+    invalid_sync_mode(value) {
+        es: "Modo de sincronización `{value}` no válido (use pull, reset o bare)",
+        en: "Invalid sync mode `{value}` (use pull, reset or bare)",
+    }
+    invalid_pull_strategy(value) {
+        es: "Estrategia de pull `{value}` no válida (use merge o rebase)",
+        en: "Invalid pull strategy `{value}` (use merge or rebase)",
+    }
+    invalid_dirty_policy(value) {
+        es: "Política de cambios locales `{value}` no válida (use abort, stash o discard)",
+        en: "Invalid local changes policy `{value}` (use abort, stash or discard)",
+    }
+    invalid_detached_policy(value) {
+        es: "Política de HEAD desacoplado `{value}` no válida (use skip o reattach)",
+        en: "Invalid detached HEAD policy `{value}` (use skip or reattach)",
+    }
+    invalid_divergence_policy(value) {
+        es: "Política de divergencia `{value}` no válida (use skip, reset o rescue)",
+        en: "Invalid divergence policy `{value}` (use skip, reset or rescue)",
+    }
+    invalid_container_engine(value) {
+        es: "Motor de contenedores `{value}` no válido (use docker o podman)",
+        en: "Invalid container engine `{value}` (use docker or podman)",
+    }
+    clone_path_missing(url) {
+        es: "Falta la ruta local para clonar {url}. Use `url => /ruta/local`.",
+        en: "⚠️ Missing the local path to clone {url}. Use `url => /local/path`.",
+    }
+    deploy_target_ignored(source) {
+        es: "Se ignoró el destino de despliegue en {source}. El modo compilación fue eliminado.",
+        en: "⚠️ The deployment target in {source} was ignored. Build mode was removed.",
+    }
+    post_sync_ignored(path) {
+        es: "Se ignoró el comando post-sync en {path}. Esta función fue eliminada.",
+        en: "The post-sync command in {path} was ignored. This feature was removed.",
+    }
+    option_ignored(error, path) {
+        es: "{error} en {path}. Se ignoró la opción.",
+        en: "{error} in {path}. The option was ignored.",
+    }
+    invalid_var_name(name) {
+        es: "Nombre de variable no válido `{name}`",
+        en: "Invalid variable name `{name}`",
+    }
+    invalid_track(value) {
+        es: "Valor `{value}` no válido para `track` (use tag:<patrón>, ej. tag:v*)",
+        en: "Invalid value `{value}` for `track` (use tag:<pattern>, e.g. tag:v*)",
+    }
+    invalid_fetch_depth(value) {
+        es: "Valor `{value}` no válido para `fetch_depth` (use un número, 0 = completo)",
+        en: "Invalid value `{value}` for `fetch_depth` (use a number, 0 = full)",
+    }
+    signers_path_required() {
+        es: "La opción `signers` necesita una ruta",
+        en: "The `signers` option needs a path",
+    }
+    invalid_max_failures(value) {
+        es: "Valor `{value}` no válido para `max_failures` (use un número, 0 = nunca)",
+        en: "Invalid value `{value}` for `max_failures` (use a number, 0 = never)",
+    }
+    invalid_quiet_hours(error) {
+        es: "Valor no válido para `quiet_hours`: {error}",
+        en: "Invalid value for `quiet_hours`: {error}",
+    }
+    invalid_unit_name(unit) {
+        es: "Nombre de unidad systemd `{unit}` no válido",
+        en: "Invalid systemd unit name `{unit}`",
+    }
+    invalid_preset_output(value) {
+        es: "Valor `{value}` no válido para `preset_output` (use una ruta relativa al repositorio)",
+        en: "Invalid value `{value}` for `preset_output` (use a path relative to the repository)",
+    }
+    invalid_ssh_key(value) {
+        es: "Valor `{value}` no válido para `ssh_key` (use una ruta absoluta)",
+        en: "Invalid value `{value}` for `ssh_key` (use an absolute path)",
+    }
+    unknown_repo_option(key) {
+        es: "Opción desconocida `{key}`",
+        en: "Unknown option `{key}`",
+    }
+    repo_path_not_absolute(path) {
+        es: "La ruta del repositorio debe ser absoluta: {path}",
+        en: "The repository path must be absolute: {path}",
+    }
+    repo_path_parent(path) {
+        es: "La ruta del repositorio no puede contener `..`: {path}",
+        en: "The repository path cannot contain `..`: {path}",
+    }
+    repo_path_protected(path) {
+        es: "La ruta {path} es un directorio del sistema o un directorio personal y no puede sincronizarse",
+        en: "The path {path} is a system or home directory and cannot be synced",
+    }
+    repo_path_outside_roots(path, roots) {
+        es: "La ruta {path} está fuera de las raíces permitidas (allowed_roots: {roots})",
+        en: "The path {path} is outside the allowed roots (allowed_roots: {roots})",
+    }
+    invalid_interval(value) {
+        es: "Valor `{value}` no válido para `interval` (use segundos o un sufijo s, m, h o d, ej. 30s o 1h)",
+        en: "Invalid value `{value}` for `interval` (use seconds or an s, m, h or d suffix, e.g. 30s or 1h)",
+    }
+    invalid_repo_name(value) {
+        es: "Nombre de repositorio `{value}` no válido (use letras, números, `-`, `_` o `.`)",
+        en: "Invalid repository name `{value}` (use letters, digits, `-`, `_` or `.`)",
+    }
+    repo_not_found(reference) {
+        es: "No hay ningún repositorio con el nombre o la ruta `{reference}`",
+        en: "There is no repository with the name or path `{reference}`",
+    }
+    ambiguous_name(reference, paths) {
+        es: "El nombre `{reference}` es ambiguo ({paths}); asigne un `name=` distinto a cada uno",
+        en: "The name `{reference}` is ambiguous ({paths}); give each one a different `name=`",
+    }
+    invalid_remote_name(value) {
+        es: "Nombre de remoto `{value}` no válido",
+        en: "Invalid remote name `{value}`",
+    }
+    invalid_branch(value) {
+        es: "Nombre de rama `{value}` no válido",
+        en: "Invalid branch name `{value}`",
+    }
+    invalid_bool(value, key) {
+        es: "Valor `{value}` no válido para `{key}` (use true o false)",
+        en: "Invalid value `{value}` for `{key}` (use true or false)",
+    }
+
+    // config.rs: archivos de configuración
+    migration_failed(dir, error) {
+        es: "⚠️ No se pudo migrar la configuración de {dir}: {error}. Se seguirá usando tal cual.",
+        en: "⚠️ Could not migrate the configuration in {dir}: {error}. It will be used as is.",
+    }
+    add_repos_hint(file) {
+        es: "\n📌 Agregue las rutas de los repositorios en {file} y reinicie el servicio.\n",
+        en: "\n📌 Add the repository paths to {file} and restart the service.\n",
+    }
+    permissions_failed(path, error) {
+        es: "❌ No se pudieron asignar permisos a {path}: {error}",
+        en: "❌ Could not set the permissions of {path}: {error}",
+    }
+    dir_created(path) {
+        es: "📁 Directorio creado: {path}",
+        en: "📁 Directory created: {path}",
+    }
+    repos_file_template() {
+        es: "# Añada rutas absolutas de repositorios Git, una por línea\n# Use rutas locales del servidor; para clonar automáticamente\n# indique url => ruta, ej. https://github.com/org/app.git => /var/www/app\n# Para desactivar un repo temporalmente, use prefijo !\n# Opciones por repositorio tras |, ej. | groups=frontend,staging\n# Ejemplo:\n# /var/www/html/mi-app\n",
+        en: "# Add absolute paths of Git repositories, one per line\n# Use local paths on the server; to clone automatically\n# write url => path, e.g. https://github.com/org/app.git => /var/www/app\n# To disable a repo temporarily, use the ! prefix\n# Per-repository options after |, e.g. | groups=frontend,staging\n# Example:\n# /var/www/html/my-app\n",
+    }
+    repos_file_create_failed(file, error) {
+        es: "❌ No se pudo crear el archivo de repositorios {file}: {error}",
+        en: "❌ Could not create the repositories file {file}: {error}",
+    }
+    repos_file_created(file) {
+        es: "🗂️ Archivo de repositorios creado: {file}",
+        en: "🗂️ Repositories file created: {file}",
+    }
+    default_settings_serialize_failed(error) {
+        es: "❌ No se pudo serializar la configuración predeterminada: {error}",
+        en: "❌ Could not serialize the default configuration: {error}",
+    }
+    settings_file_create_failed(file, error) {
+        es: "❌ No se pudo crear el archivo de configuración {file}: {error}",
+        en: "❌ Could not create the configuration file {file}: {error}",
+    }
+    settings_file_created(file) {
+        es: "⚙️ Archivo de configuración creado: {file}",
+        en: "⚙️ Configuration file created: {file}",
+    }
+    secrets_file_template() {
+        es: "# Secretos utilizados por git-sync (permisos 0600)\n# Notificaciones en Matrix:\n# [matrix]\n# homeserver_url = \"https://matrix.example.com\"\n# access_token = \"syt_...\"\n# room_id = \"!abcdef:example.com\"\n# Para versionar los secretos cifrados, guárdelos como\n# secrets.toml.age (age) o secrets.toml.sops (sops) y\n# deje la clave age en age.key con permisos 0600.\n",
+        en: "# Secrets used by git-sync (permissions 0600)\n# Matrix notifications:\n# [matrix]\n# homeserver_url = \"https://matrix.example.com\"\n# access_token = \"syt_...\"\n# room_id = \"!abcdef:example.com\"\n# To version the secrets encrypted, save them as\n# secrets.toml.age (age) or secrets.toml.sops (sops) and\n# leave the age key in age.key with permissions 0600.\n",
+    }
+    secrets_file_create_failed(file, error) {
+        es: "❌ No se pudo crear el archivo de secretos {file}: {error}",
+        en: "❌ Could not create the secrets file {file}: {error}",
+    }
+    secrets_file_created(file) {
+        es: "🔐 Archivo de secretos creado: {file}",
+        en: "🔐 Secrets file created: {file}",
+    }
+    credentials_file_template() {
+        es: "# Tokens para remotos HTTPS privados (permisos 0600)\n# Se entregan a Git por callback o credential helper,\n# nunca en la URL ni en los registros.\n# [hosts]\n# \"github.com\" = \"ghp_...\"\n# \"git.example.com\" = {{ username = \"deploy\", token = \"...\" }}\n",
+        en: "# Tokens for private HTTPS remotes (permissions 0600)\n# They are given to Git through a callback or credential helper,\n# never in the URL or in the logs.\n# [hosts]\n# \"github.com\" = \"ghp_...\"\n# \"git.example.com\" = {{ username = \"deploy\", token = \"...\" }}\n",
+    }
+    credentials_file_create_failed(file, error) {
+        es: "❌ No se pudo crear el archivo de credenciales {file}: {error}",
+        en: "❌ Could not create the credentials file {file}: {error}",
+    }
+    credentials_file_created(file) {
+        es: "🔐 Archivo de credenciales creado: {file}",
+        en: "🔐 Credentials file created: {file}",
+    }
+    log_file_create_failed(file, error) {
+        es: "❌ No se pudo crear el archivo de registro {file}: {error}",
+        en: "❌ Could not create the log file {file}: {error}",
+    }
+    log_file_created(file) {
+        es: "📝 Archivo de registro creado: {file}",
+        en: "📝 Log file created: {file}",
+    }
+    initial_state_serialize_failed(error) {
+        es: "❌ No se pudo serializar el estado inicial de sincronización: {error}",
+        en: "❌ Could not serialize the initial sync state: {error}",
+    }
+    state_file_create_failed(file, error) {
+        es: "❌ No se pudo crear el archivo de estado {file}: {error}",
+        en: "❌ Could not create the state file {file}: {error}",
+    }
+    state_file_created(file) {
+        es: "📊 Archivo de estado creado: {file}",
+        en: "📊 State file created: {file}",
+    }
+    repos_file_read_failed(file, error) {
+        es: "No se pudo leer el archivo de repositorios {file}: {error}",
+        en: "Could not read the repositories file {file}: {error}",
+    }
+    duplicate_repo_path(path, file) {
+        es: "La ruta {path} aparece más de una vez en {file} (quizá escrita de otra forma, como `~/` o con `/` final). Deje una sola entrada.",
+        en: "⚠️ The path {path} appears more than once in {file} (perhaps written differently, like `~/` or with a trailing `/`). Keep a single entry.",
+    }
+    duplicate_repo_name(name, first, second) {
+        es: "El nombre `{name}` se repite en {first} y {second}. Asigne un `name=` distinto a cada uno.",
+        en: "⚠️ The name `{name}` is repeated in {first} and {second}. Give each one a different `name=`.",
+    }
+    repos_list_header() {
+        es: "# Lista de repositorios administrada por git-sync\n# Especifique una ruta absoluta por línea (ruta local del servidor)\n# Para clonar si la ruta no existe use: url => /ruta/al/repo\n# Para desactivar un repo temporalmente use: ! /ruta/al/repo\n# Opciones por repositorio tras |, ej.: /ruta/al/repo | groups=frontend\n# Ejemplo:\n#   /var/www/html/mi-app\n",
+        en: "# Repository list managed by git-sync\n# Write one absolute path per line (local path on the server)\n# To clone when the path does not exist use: url => /path/to/repo\n# To disable a repo temporarily use: ! /path/to/repo\n# Per-repository options after |, e.g.: /path/to/repo | groups=frontend\n# Example:\n#   /var/www/html/my-app\n",
+    }
+    repos_file_write_failed(file, error) {
+        es: "❌ No se pudo escribir en el archivo de repositorios {file}: {error}",
+        en: "❌ Could not write to the repositories file {file}: {error}",
+    }
+
+    // service.rs
+    systemd_unavailable(dir, command) {
+        es: "systemd no está disponible en este sistema (no existe {dir}). Use `{command} daemon --supervise`.",
+        en: "systemd is not available on this system ({dir} does not exist). Use `{command} daemon --supervise`.",
+    }
+    unit_reinstalled(unit) {
+        es: "🔄 {unit} reinstalado y reiniciado.",
+        en: "🔄 {unit} reinstalled and restarted.",
+    }
+    service_installed(service) {
+        es: "✅ Servicio {service} instalado y habilitado correctamente.",
+        en: "✅ Service {service} installed and enabled successfully.",
+    }
+    control_socket_path(path) {
+        es: "🔌 Socket de control: {path}",
+        en: "🔌 Control socket: {path}",
+    }
+    timer_installed(unit, seconds) {
+        es: "✅ Timer {unit} instalado y habilitado: sincronizará cada {seconds} segundos.",
+        en: "✅ Timer {unit} installed and enabled: it will sync every {seconds} seconds.",
+    }
+    linger_hint(user) {
+        es: "👉 Para que siga activo sin sesión iniciada, ejecute `sudo loginctl enable-linger {user}`.",
+        en: "👉 To keep it running without a logged-in session, run `sudo loginctl enable-linger {user}`.",
+    }
+    exe_path_failed(error) {
+        es: "❌ No se pudo determinar la ruta del ejecutable actual: {error}",
+        en: "❌ Could not determine the path of the current executable: {error}",
+    }
+    exe_path_not_utf8() {
+        es: "❌ La ruta del ejecutable contiene caracteres UTF-8 no válidos",
+        en: "❌ The executable path contains invalid UTF-8 characters",
+    }
+    config_init_failed(error) {
+        es: "❌ No se pudo inicializar la estructura de configuración: {error}",
+        en: "❌ Could not initialize the configuration structure: {error}",
+    }
+    socket_remove_failed(error) {
+        es: "❌ No se pudo eliminar el archivo del socket: {error}",
+        en: "❌ Could not remove the socket file: {error}",
+    }
+    timer_updated(service, seconds) {
+        es: "🔄 Timer {service}.timer actualizado: sincronizará cada {seconds} segundos.",
+        en: "🔄 Timer {service}.timer updated: it will sync every {seconds} seconds.",
+    }
+    timer_remove_failed(error) {
+        es: "❌ No se pudo eliminar el archivo del timer: {error}",
+        en: "❌ Could not remove the timer file: {error}",
+    }
+    service_file_remove_failed(error) {
+        es: "❌ No se pudo eliminar el archivo de servicio: {error}",
+        en: "❌ Could not remove the service file: {error}",
+    }
+    service_not_installed(service) {
+        es: "El servicio {service} no está instalado",
+        en: "The service {service} is not installed",
+    }
+    service_file_removed() {
+        es: "🗑️ Archivo de servicio eliminado.",
+        en: "🗑️ Service file removed.",
+    }
+    unit_created(path) {
+        es: "📝 {path} no existe: se crea.",
+        en: "📝 {path} does not exist: creating it.",
+    }
+    unit_unchanged(path) {
+        es: "✅ {path} sin cambios.",
+        en: "✅ {path} unchanged.",
+    }
+    unit_changes(path) {
+        es: "📝 Cambios en {path}:",
+        en: "📝 Changes in {path}:",
+    }
+    service_status(service) {
+        es: "🔎 Estado del servicio {service}",
+        en: "🔎 Status of the service {service}",
+    }
+    status_not_installed(path) {
+        es: "  ❌ No está instalado ({path} no existe)",
+        en: "  ❌ Not installed ({path} does not exist)",
+    }
+    status_definition(path) {
+        es: "  📄 Definición: {path}",
+        en: "  📄 Definition: {path}",
+    }
+    status_mode(mode) {
+        es: "  🕹️ Modo: {mode}",
+        en: "  🕹️ Mode: {mode}",
+    }
+    status_mode_mismatch(command) {
+        es: "  ⚠️ config.toml indica otro service_mode: se reinstala al ejecutar `{command}`",
+        en: "  ⚠️ config.toml sets a different service_mode: it is reinstalled when running `{command}`",
+    }
+    status_binary(binary) {
+        es: "  ✅ Binario: {binary}",
+        en: "  ✅ Binary: {binary}",
+    }
+    status_binary_outdated(binary, current) {
+        es: "  ⚠️ Binario: {binary} (el actual es {current}; reinstale el servicio)",
+        en: "  ⚠️ Binary: {binary} (the current one is {current}; reinstall the service)",
+    }
+    status_binary_missing() {
+        es: "  ⚠️ No se encontró el binario en la definición",
+        en: "  ⚠️ The binary was not found in the definition",
+    }
+    status_last_success(time, elapsed) {
+        es: "  🕒 Última sincronización correcta: {time} (hace {elapsed})",
+        en: "  🕒 Last successful sync: {time} ({elapsed} ago)",
+    }
+    status_no_success() {
+        es: "  🕒 Última sincronización correcta: sin registros",
+        en: "  🕒 Last successful sync: none recorded",
+    }
+    status_pending_deploy(repo, commit) {
+        es: "  ❌ Despliegue pendiente de {commit} en {repo}: falló y se reintenta en cada ciclo",
+        en: "  ❌ Pending deploy of {commit} in {repo}: it failed and is retried every cycle",
+    }
+    status_paused(until) {
+        es: "  ⏸️ Sincronización pausada {until}",
+        en: "  ⏸️ Sync paused {until}",
+    }
+    status_recent_lines(source) {
+        es: "\n📜 Últimas líneas ({source})",
+        en: "\n📜 Latest lines ({source})",
+    }
+    status_no_entries() {
+        es: "  (sin entradas)",
+        en: "  (no entries)",
+    }
+    service_inactive(service) {
+        es: "El servicio {service} no está activo",
+        en: "The service {service} is not active",
+    }
+    launchd_not_loaded() {
+        es: "  ❌ launchd: no cargado",
+        en: "  ❌ launchd: not loaded",
+    }
+    launchd_loaded(state) {
+        es: "  ✅ launchd: cargado (estado: {state})",
+        en: "  ✅ launchd: loaded (state: {state})",
+    }
+    task_not_registered() {
+        es: "  ❌ Tarea programada: no registrada",
+        en: "  ❌ Scheduled task: not registered",
+    }
+    task_registered(status) {
+        es: "  ✅ Tarea programada: registrada (estado: {status})",
+        en: "  ✅ Scheduled task: registered (state: {status})",
+    }
+    unknown_state() {
+        es: "desconocido",
+        en: "unknown",
+    }
+    status_enabled(mark, enabled, unit) {
+        es: "  {mark} Habilitado: {enabled} ({unit})",
+        en: "  {mark} Enabled: {enabled} ({unit})",
+    }
+    status_active(mark, active) {
+        es: "  {mark} Activo: {active}",
+        en: "  {mark} Active: {active}",
+    }
+    repo_inside_dir(repo, dir) {
+        es: "❌ El repositorio {repo} está dentro de {dir}; no se eliminará nada",
+        en: "❌ The repository {repo} is inside {dir}; nothing will be removed",
+    }
+    log_dir_remove_failed(dir, error) {
+        es: "❌ No se pudo eliminar el directorio de logs {dir}: {error}",
+        en: "❌ Could not remove the log directory {dir}: {error}",
+    }
+    logs_removed(dir) {
+        es: "🗑️ Logs eliminados: {dir}",
+        en: "🗑️ Logs removed: {dir}",
+    }
+    no_log_dir(dir) {
+        es: "ℹ️ No existe directorio de logs en {dir}",
+        en: "ℹ️ There is no log directory in {dir}",
+    }
+    config_dir_remove_failed(dir, error) {
+        es: "❌ No se pudo eliminar el directorio de configuración {dir}: {error}",
+        en: "❌ Could not remove the configuration directory {dir}: {error}",
+    }
+    config_removed(dir) {
+        es: "🗑️ Configuración eliminada: {dir}",
+        en: "🗑️ Configuration removed: {dir}",
+    }
+    no_config_dir(dir) {
+        es: "ℹ️ No existe configuración en {dir}",
+        en: "ℹ️ There is no configuration in {dir}",
+    }
+    uninstall_done() {
+        es: "✅ Desinstalación completada.",
+        en: "✅ Uninstall completed.",
+    }
+    remove_binary_hint(binary) {
+        es: "👉 Si desea eliminar el binario, borre manualmente {binary}.",
+        en: "👉 To remove the binary, delete {binary} manually.",
+    }
+    launchd_installed(kind, label) {
+        es: "✅ {kind} {label} instalado y cargado correctamente.",
+        en: "✅ {kind} {label} installed and loaded successfully.",
+    }
+    launchd_timer_installed(kind, label, seconds) {
+        es: "✅ {kind} {label} instalado y cargado: sincronizará cada {seconds} segundos.",
+        en: "✅ {kind} {label} installed and loaded: it will sync every {seconds} seconds.",
+    }
+    task_create_failed(task) {
+        es: "❌ No se pudo crear la tarea programada {task}",
+        en: "❌ Could not create the scheduled task {task}",
+    }
+    file_write_failed(path, error) {
+        es: "❌ No se pudo escribir {path}: {error}",
+        en: "❌ Could not write {path}: {error}",
+    }
+    task_installed(task) {
+        es: "✅ Tarea programada {task} instalada e iniciada correctamente.",
+        en: "✅ Scheduled task {task} installed and started successfully.",
+    }
+    task_timer_installed(task, minutes) {
+        es: "✅ Tarea programada {task} instalada: sincronizará cada {minutes} minutos.",
+        en: "✅ Scheduled task {task} installed: it will sync every {minutes} minutes.",
+    }
+    task_remove_failed(task) {
+        es: "❌ No se pudo eliminar la tarea programada {task}",
+        en: "❌ Could not remove the scheduled task {task}",
+    }
+    task_removed() {
+        es: "🗑️ Tarea programada eliminada.",
+        en: "🗑️ Scheduled task removed.",
+    }
+    service_group_missing(group) {
+        es: "❌ El grupo del servicio `{group}` no existe",
+        en: "❌ The service group `{group}` does not exist",
+    }
+    service_user_missing(user) {
+        es: "❌ El usuario del servicio `{user}` no existe",
+        en: "❌ The service user `{user}` does not exist",
+    }
+    service_user_unknown() {
+        es: "❌ No fue posible determinar la información del usuario para instalar el servicio",
+        en: "❌ Could not determine the user information to install the service",
+    }
+    invalid_service_path() {
+        es: "❌ La ruta del servicio no es válida",
+        en: "❌ The service path is not valid",
+    }
+    service_dir_create_failed(dir, error) {
+        es: "❌ No se pudo crear el directorio del servicio {dir}: {error}",
+        en: "❌ Could not create the service directory {dir}: {error}",
+    }
+    service_dir_missing(dir) {
+        es: "❌ El directorio del servicio {dir} no existe. ¿El sistema utiliza systemd?",
+        en: "❌ The service directory {dir} does not exist. Does the system use systemd?",
+    }
+    service_file_create_failed(error) {
+        es: "❌ No se pudo crear el archivo de servicio: {error}",
+        en: "❌ Could not create the service file: {error}",
+    }
+    service_file_write_failed(error) {
+        es: "❌ No se pudo escribir el archivo de servicio: {error}",
+        en: "❌ Could not write the service file: {error}",
+    }
+    service_file_sync_failed(error) {
+        es: "❌ No se pudo sincronizar el archivo de servicio en disco: {error}",
+        en: "❌ Could not flush the service file to disk: {error}",
+    }
+    service_file_permissions_failed(error) {
+        es: "❌ No se pudieron asignar permisos al archivo de servicio: {error}",
+        en: "❌ Could not set the permissions of the service file: {error}",
+    }
+    service_tool_status(tool, args, status) {
+        es: "⚠️ {tool} {args} finalizó con el estado {status}. Es posible que deba ejecutarlo manualmente.",
+        en: "⚠️ {tool} {args} exited with status {status}. You may need to run it manually.",
+    }
+    service_tool_failed(tool, args, error) {
+        es: "❌ No se pudo ejecutar {tool} {args}: {error}. Ejecútelo manualmente si es necesario.",
+        en: "❌ Could not run {tool} {args}: {error}. Run it manually if needed.",
+    }
+    chown_failed(path, error) {
+        es: "❌ No se pudo cambiar la propiedad de {path}: {error}",
+        en: "❌ Could not change the owner of {path}: {error}",
+    }
+    chown_status(path, status) {
+        es: "❌ El comando chown para {path} finalizó con el estado {status}",
+        en: "❌ The chown command for {path} exited with status {status}",
+    }
+
+    // tui.rs: terminal
+    raw_mode_failed(error) {
+        es: "No se pudo activar el modo raw del terminal: {error}",
+        en: "Could not enable the terminal raw mode: {error}",
+    }
+    alternate_screen_failed(error) {
+        es: "No se pudo abrir la pantalla alternativa: {error}",
+        en: "Could not open the alternate screen: {error}",
+    }
+    terminal_init_failed(error) {
+        es: "No se pudo inicializar el terminal: {error}",
+        en: "Could not initialize the terminal: {error}",
+    }
+    raw_mode_disable_failed(error) {
+        es: "No se pudo desactivar el modo raw del terminal: {error}",
+        en: "Could not disable the terminal raw mode: {error}",
+    }
+    screen_restore_failed(error) {
+        es: "No se pudo restaurar la pantalla original: {error}",
+        en: "Could not restore the original screen: {error}",
+    }
+    cursor_restore_failed(error) {
+        es: "No se pudo restaurar el cursor del terminal: {error}",
+        en: "Could not restore the terminal cursor: {error}",
+    }
+    render_failed(error) {
+        es: "No se pudo renderizar la interfaz: {error}",
+        en: "Could not render the interface: {error}",
+    }
+    input_event_failed(error) {
+        es: "No se pudo leer el evento de entrada: {error}",
+        en: "Could not read the input event: {error}",
+    }
+    tui_shell_path_missing(path) {
+        es: "La ruta no existe: {path}",
+        en: "The path does not exist: {path}",
+    }
+    raw_mode_leave_failed(error) {
+        es: "No se pudo salir de modo raw: {error}",
+        en: "Could not leave raw mode: {error}",
+    }
+    alternate_screen_leave_failed(error) {
+        es: "No se pudo salir de la pantalla alternativa: {error}",
+        en: "Could not leave the alternate screen: {error}",
+    }
+    shell_open_failed(path, error) {
+        es: "No se pudo abrir una terminal en {path}: {error}",
+        en: "Could not open a shell in {path}: {error}",
+    }
+    alternate_screen_restore_failed(error) {
+        es: "No se pudo restaurar pantalla alternativa: {error}",
+        en: "Could not restore the alternate screen: {error}",
+    }
+    raw_mode_restore_failed(error) {
+        es: "No se pudo reactivar modo raw: {error}",
+        en: "Could not re-enable raw mode: {error}",
+    }
+    shell_failed() {
+        es: "La sesión de terminal finalizó con error",
+        en: "The shell session ended with an error",
+    }
+
+    // tui.rs: mensajes de estado
+    tui_details_hint() {
+        es: "Pulse Espacio para ver detalles del repositorio seleccionado",
+        en: "Press Space to see the details of the selected repository",
+    }
+    tui_no_groups() {
+        es: "No hay grupos definidos (use `| groups=<nombre>` en repositories.txt)",
+        en: "No groups defined (use `| groups=<name>` in repositories.txt)",
+    }
+    tui_group_filter(group) {
+        es: "Filtro de grupo: {group}",
+        en: "Group filter: {group}",
+    }
+    tui_group_filter_off() {
+        es: "Filtro de grupo desactivado",
+        en: "Group filter off",
+    }
+    tui_pending_clone() {
+        es: "Pendiente de clonar en la próxima sincronización",
+        en: "Pending clone on the next sync",
+    }
+    tui_path_missing() {
+        es: "La ruta no existe",
+        en: "The path does not exist",
+    }
+    tui_not_git_repo() {
+        es: "No es un repositorio Git válido",
+        en: "Not a valid Git repository",
+    }
+    tui_details_on() {
+        es: "Vista detallada activada (últimos commits y errores)",
+        en: "Detailed view on (latest commits and errors)",
+    }
+    tui_details_off() {
+        es: "Vista detallada oculta",
+        en: "Detailed view hidden",
+    }
+    tui_no_selection() {
+        es: "No hay repositorio seleccionado",
+        en: "No repository selected",
+    }
+    tui_add_prompt() {
+        es: "Ruta local del repositorio (ej. /var/www/html/mi-app) o url => ruta para clonarlo",
+        en: "Local path of the repository (e.g. /var/www/html/my-app) or url => path to clone it",
+    }
+    tui_edit_prompt() {
+        es: "Edite la ruta local del repositorio seleccionado (o url => ruta)",
+        en: "Edit the local path of the selected repository (or url => path)",
+    }
+    tui_repo_removed() {
+        es: "Repositorio eliminado",
+        en: "Repository removed",
+    }
+    tui_repo_reenabled() {
+        es: "Repositorio reactivado: se sincronizará en el próximo ciclo",
+        en: "Repository re-enabled: it will sync on the next cycle",
+    }
+    tui_repo_resumed() {
+        es: "Sincronización activada para el repositorio",
+        en: "Sync enabled for the repository",
+    }
+    tui_repo_paused() {
+        es: "Sincronización pausada para el repositorio",
+        en: "Sync paused for the repository",
+    }
+    tui_choose_group() {
+        es: "Elija un grupo con 'g' para pausarlo o activarlo completo",
+        en: "Choose a group with 'g' to pause or enable all of it",
+    }
+    tui_group_resumed(group) {
+        es: "Sincronización activada para el grupo {group}",
+        en: "Sync enabled for the group {group}",
+    }
+    tui_group_paused(group) {
+        es: "Sincronización pausada para el grupo {group}",
+        en: "Sync paused for the group {group}",
+    }
+    tui_sync_auto_disabled() {
+        es: "El repositorio se desactivó por fallos repetidos. Reactívelo con 'p' antes de sincronizar.",
+        en: "The repository was disabled after repeated failures. Re-enable it with 'p' before syncing.",
+    }
+    tui_sync_paused_repo() {
+        es: "El repositorio está pausado. Actívelo con 'p' antes de sincronizar.",
+        en: "The repository is paused. Enable it with 'p' before syncing.",
+    }
+    tui_repo_synced() {
+        es: "Repositorio sincronizado",
+        en: "Repository synced",
+    }
+    tui_no_active_repos() {
+        es: "No hay repositorios activos para sincronizar",
+        en: "There are no active repositories to sync",
+    }
+    tui_manual_sync_done() {
+        es: "Sincronización manual completada",
+        en: "Manual sync completed",
+    }
+    tui_syncing() {
+        es: "Sincronizando...",
+        en: "Syncing...",
+    }
+    tui_sync_failed(error) {
+        es: "La sincronización falló: {error}",
+        en: "The sync failed: {error}",
+    }
+    tui_empty_path() {
+        es: "La ruta del repositorio no puede estar vacía",
+        en: "The repository path cannot be empty",
+    }
+    tui_already_listed(path) {
+        es: "{path} ya está en la lista",
+        en: "{path} is already in the list",
+    }
+    tui_choose_preset() {
+        es: "Repositorio añadido. Elija un preset de despliegue: laravel, vue, hugo o static (vacío o Esc = ninguno)",
+        en: "Repository added. Choose a deployment preset: laravel, vue, hugo or static (empty or Esc = none)",
+    }
+    tui_repo_not_found() {
+        es: "No se encontró el repositorio seleccionado",
+        en: "The selected repository was not found",
+    }
+    tui_repo_updated() {
+        es: "Repositorio actualizado",
+        en: "Repository updated",
+    }
+    tui_added_with_preset(preset) {
+        es: "Repositorio añadido con el preset {preset}",
+        en: "Repository added with the preset {preset}",
+    }
+    tui_added_without_preset() {
+        es: "Repositorio añadido sin preset",
+        en: "Repository added without a preset",
+    }
+    tui_cancelled() {
+        es: "Acción cancelada",
+        en: "Action cancelled",
+    }
+    tui_list_changed() {
+        es: "La lista de repositorios cambió fuera de la TUI: se recargó, repita la acción",
+        en: "The repository list changed outside the TUI: it was reloaded, repeat the action",
+    }
+    tui_read_only_mode() {
+        es: "Modo de solo lectura: no se permiten cambios en la configuración",
+        en: "Read-only mode: configuration changes are not allowed",
+    }
+    tui_read_only_action() {
+        es: "Acción no disponible en modo de solo lectura",
+        en: "Action not available in read-only mode",
+    }
+    tui_shell_closed() {
+        es: "La sesión de terminal finalizó y se restauró la interfaz.",
+        en: "The shell session ended and the interface was restored.",
+    }
+    tui_ready() {
+        es: "Listo para gestionar repositorios",
+        en: "Ready to manage repositories",
+    }
+
+    // tui.rs: vista detallada
+    tui_name(name) {
+        es: "Nombre: {name}",
+        en: "Name: {name}",
+    }
+    tui_repository(path) {
+        es: "Repositorio: {path}",
+        en: "Repository: {path}",
+    }
+    tui_paused() {
+        es: "Pausada",
+        en: "Paused",
+    }
+    tui_active() {
+        es: "Activa",
+        en: "Active",
+    }
+    tui_auto_disabled(failures) {
+        es: "Desactivada tras {failures} fallos consecutivos (pulse 'p' para reactivarla)",
+        en: "Disabled after {failures} consecutive failures (press 'p' to re-enable it)",
+    }
+    tui_sync(state) {
+        es: "Sincronización: {state}",
+        en: "Sync: {state}",
+    }
+    tui_groups(groups) {
+        es: "Grupos: {groups}",
+        en: "Groups: {groups}",
+    }
+    tui_remote(remote) {
+        es: "Remoto: {remote}",
+        en: "Remote: {remote}",
+    }
+    tui_mirror(remote) {
+        es: "Réplica en: {remote}",
+        en: "Mirrored to: {remote}",
+    }
+    tui_signed_by(signers) {
+        es: "Firma requerida: sí ({signers})",
+        en: "Signature required: yes ({signers})",
+    }
+    tui_signed() {
+        es: "Firma requerida: sí",
+        en: "Signature required: yes",
+    }
+    tui_interval(interval) {
+        es: "Intervalo propio: cada {interval}",
+        en: "Own interval: every {interval}",
+    }
+    tui_group_interval(interval) {
+        es: "Intervalo del grupo: cada {interval}",
+        en: "Group interval: every {interval}",
+    }
+    tui_after(repos) {
+        es: "Se sincroniza después de: {repos}",
+        en: "Synced after: {repos}",
+    }
+    tui_restart_units(units) {
+        es: "Servicios a recargar: {units}",
+        en: "Services to reload: {units}",
+    }
+    tui_preset(preset, steps) {
+        es: "Preset de despliegue: {preset} ({steps})",
+        en: "Deployment preset: {preset} ({steps})",
+    }
+    tui_compose(engine) {
+        es: "Contenedores: {engine} compose up -d --build",
+        en: "Containers: {engine} compose up -d --build",
+    }
+    tui_dry_run() {
+        es: "Simulación: sí (no se aplican cambios)",
+        en: "Dry run: yes (no changes are applied)",
+    }
+    tui_ssh_key(key) {
+        es: "Clave SSH: {key}",
+        en: "SSH key: {key}",
+    }
+    tui_env(names) {
+        es: "Entorno propio: {names}",
+        en: "Own environment: {names}",
+    }
+    tui_git_binary() {
+        es: "Git: binario `git` (el entorno define variables que libgit2 no aplica)",
+        en: "Git: `git` binary (the environment sets variables libgit2 does not apply)",
+    }
+    tui_sync_mode(mode) {
+        es: "Modo de sincronización: {mode}",
+        en: "Sync mode: {mode}",
+    }
+    tui_cloned_from(url) {
+        es: "Clonado desde: {url}",
+        en: "Cloned from: {url}",
+    }
+    tui_detected_branch(branch) {
+        es: "Rama detectada: {branch}",
+        en: "Detected branch: {branch}",
+    }
+    tui_pending_remote(count) {
+        es: "Commits pendientes (remoto): {count}",
+        en: "Pending commits (remote): {count}",
+    }
+    tui_last_refresh_ago(elapsed) {
+        es: "Última actualización remota: hace {elapsed}",
+        en: "Last remote refresh: {elapsed} ago",
+    }
+    tui_last_refresh_none() {
+        es: "Última actualización remota: sin datos",
+        en: "Last remote refresh: no data",
+    }
+    tui_refresh_error(error) {
+        es: "Error de actualización remota: {error}",
+        en: "Remote refresh error: {error}",
+    }
+    tui_last_pulled(commit) {
+        es: "Último commit aplicado en la actualización: {commit}",
+        en: "Last commit applied by the update: {commit}",
+    }
+    tui_last_pulled_none() {
+        es: "Último commit aplicado en la actualización: sin datos",
+        en: "Last commit applied by the update: no data",
+    }
+    tui_last_deployment(range, head) {
+        es: "Último despliegue: {range} (HEAD {head})",
+        en: "Last deployment: {range} (HEAD {head})",
+    }
+    tui_more_commits(count) {
+        es: "  ... y {count} commit(s) más",
+        en: "  ... and {count} more commit(s)",
+    }
+    tui_last_error_header() {
+        es: "Último error detallado:",
+        en: "Last detailed error:",
+    }
+    tui_last_error_none() {
+        es: "Último error detallado: sin errores registrados",
+        en: "Last detailed error: no errors recorded",
+    }
+    tui_commits_path_missing() {
+        es: "No se puede leer commits: la ruta no existe",
+        en: "Cannot read commits: the path does not exist",
+    }
+    tui_commits_not_git() {
+        es: "No se puede leer commits: no es un repositorio Git válido",
+        en: "Cannot read commits: not a valid Git repository",
+    }
+    tui_no_history() {
+        es: "Últimos commits: sin historial",
+        en: "Latest commits: no history",
+    }
+    tui_recent_commits() {
+        es: "Últimos commits locales:",
+        en: "Latest local commits:",
+    }
+    tui_commits_failed(error) {
+        es: "No se pudieron leer commits: {error}",
+        en: "Could not read commits: {error}",
+    }
+
+    // tui.rs: paneles
+    tui_mode_read_only() {
+        es: "Solo lectura",
+        en: "Read only",
+    }
+    tui_mode_add() {
+        es: "Agregar",
+        en: "Add",
+    }
+    tui_mode_edit() {
+        es: "Editar",
+        en: "Edit",
+    }
+    tui_mode(mode) {
+        es: "Modo: {mode}",
+        en: "Mode: {mode}",
+    }
+    tui_refresh_rate(seconds) {
+        es: "Actualización remota secuencial ~{seconds:.1}s/repositorio | Actualizar código: u/U",
+        en: "Sequential remote refresh ~{seconds:.1}s/repository | Update code: u/U",
+    }
+    tui_time(time) {
+        es: "Hora: {time}",
+        en: "Time: {time}",
+    }
+    tui_main_panel() {
+        es: "Panel Principal",
+        en: "Main Panel",
+    }
+    tui_group_empty(group) {
+        es: "No hay repositorios en el grupo {group}",
+        en: "There are no repositories in the group {group}",
+    }
+    tui_no_repos() {
+        es: "No hay repositorios configurados",
+        en: "No repositories configured",
+    }
+    tui_badge_paused() {
+        es: " PAUSADO ",
+        en: " PAUSED ",
+    }
+    tui_badge_disabled() {
+        es: " DESACTIVADO ",
+        en: " DISABLED ",
+    }
+    tui_badge_remote_error() {
+        es: " ERROR REMOTO ",
+        en: " REMOTE ERROR ",
+    }
+    tui_badge_outdated() {
+        es: " DESACTUALIZADO ",
+        en: " OUTDATED ",
+    }
+    tui_badge_updated() {
+        es: " ACTUALIZADO ",
+        en: " UP TO DATE ",
+    }
+    tui_badge_unknown() {
+        es: " SIN ESTADO ",
+        en: " NO STATUS ",
+    }
+    tui_ago(elapsed) {
+        es: "hace {elapsed}",
+        en: "{elapsed} ago",
+    }
+    tui_never() {
+        es: "sin registros",
+        en: "never",
+    }
+    tui_no_attempts() {
+        es: "sin intentos",
+        en: "no attempts",
+    }
+    tui_column_repo() {
+        es: "Repositorio",
+        en: "Repository",
+    }
+    tui_column_status() {
+        es: "Estado",
+        en: "Status",
+    }
+    tui_column_branch() {
+        es: "Rama",
+        en: "Branch",
+    }
+    tui_column_behind() {
+        es: "Pend.",
+        en: "Behind",
+    }
+    tui_column_last_sync() {
+        es: "Últ. sync",
+        en: "Last sync",
+    }
+    tui_column_result() {
+        es: "Res",
+        en: "Res",
+    }
+    tui_column_phase() {
+        es: "Etapa",
+        en: "Phase",
+    }
+    tui_column_groups() {
+        es: "Grupos",
+        en: "Groups",
+    }
+    tui_phase_running(phase, elapsed) {
+        es: "{phase} {elapsed}",
+        en: "{phase} {elapsed}",
+    }
+    tui_phase_fetch() {
+        es: "descarga",
+        en: "fetch",
+    }
+    tui_phase_pull() {
+        es: "integración",
+        en: "pull",
+    }
+    tui_phase_build() {
+        es: "compilación",
+        en: "build",
+    }
+    tui_phase_deploy() {
+        es: "despliegue",
+        en: "deploy",
+    }
+    tui_repos_in_group(group, visible, total) {
+        es: "Repositorios — grupo {group} ({visible}/{total})",
+        en: "Repositories — group {group} ({visible}/{total})",
+    }
+    tui_repos(total) {
+        es: "Repositorios ({total})",
+        en: "Repositories ({total})",
+    }
+    tui_status_refresh_error() {
+        es: "Error en actualización remota",
+        en: "Remote refresh error",
+    }
+    tui_status_outdated() {
+        es: "Desactualizado",
+        en: "Outdated",
+    }
+    tui_status_last_error() {
+        es: "Error en último intento",
+        en: "Error in the last attempt",
+    }
+    tui_status_ok() {
+        es: "Correcto",
+        en: "OK",
+    }
+    tui_status_unknown() {
+        es: "Sin estado remoto",
+        en: "No remote status",
+    }
+    tui_summary() {
+        es: "Resumen",
+        en: "Summary",
+    }
+    tui_total(count) {
+        es: "Total: {count}",
+        en: "Total: {count}",
+    }
+    tui_group(group) {
+        es: "Grupo: {group}",
+        en: "Group: {group}",
+    }
+    tui_all() {
+        es: "todos",
+        en: "all",
+    }
+    tui_paused_count(count) {
+        es: "Pausados: {count}",
+        en: "Paused: {count}",
+    }
+    tui_outdated_count(count) {
+        es: "Desactualizados: {count}",
+        en: "Outdated: {count}",
+    }
+    tui_error_count(count) {
+        es: "Con error: {count}",
+        en: "With errors: {count}",
+    }
+    tui_sync_header() {
+        es: "Sincronización",
+        en: "Sync",
+    }
+    tui_refresh_rate_short(seconds) {
+        es: "Actualización remota secuencial: ~{seconds:.1}s/repositorio",
+        en: "Sequential remote refresh: ~{seconds:.1}s/repository",
+    }
+    tui_quick_action() {
+        es: "Acción rápida: u/U (actualizar código)",
+        en: "Quick action: u/U (update code)",
+    }
+    tui_date(date) {
+        es: "Fecha: {date}",
+        en: "Date: {date}",
+    }
+    tui_selected() {
+        es: "Seleccionado",
+        en: "Selected",
+    }
+    tui_current_sync(repo, phase) {
+        es: "Sincronizando: {repo} ({phase})",
+        en: "Syncing: {repo} ({phase})",
+    }
+    tui_current_sync_idle() {
+        es: "Sincronizando: -",
+        en: "Syncing: -",
+    }
+    tui_path(path) {
+        es: "Ruta: {path}",
+        en: "Path: {path}",
+    }
+    tui_branch(branch) {
+        es: "Rama: {branch}",
+        en: "Branch: {branch}",
+    }
+    tui_pending(count) {
+        es: "Commits pendientes: {count}",
+        en: "Pending commits: {count}",
+    }
+    tui_last_refresh(time) {
+        es: "Última actualización remota: {time}",
+        en: "Last remote refresh: {time}",
+    }
+    tui_state(state) {
+        es: "Estado: {state}",
+        en: "Status: {state}",
+    }
+    tui_last_result(result) {
+        es: "Último resultado: {result}",
+        en: "Last result: {result}",
+    }
+    tui_last_pulled_short(commit) {
+        es: "Último commit aplicado en actualización: {commit}",
+        en: "Last commit applied by an update: {commit}",
+    }
+    tui_last_success(time) {
+        es: "Última sincronización exitosa: {time}",
+        en: "Last successful sync: {time}",
+    }
+    tui_last_attempt(time) {
+        es: "Último intento: {time}",
+        en: "Last attempt: {time}",
+    }
+    tui_last_error(error) {
+        es: "Último error: {error}",
+        en: "Last error: {error}",
+    }
+    tui_status_title() {
+        es: "Estado",
+        en: "Status",
+    }
+    tui_details_title() {
+        es: "Detalles (Espacio)",
+        en: "Details (Space)",
+    }
+    tui_input_title() {
+        es: "Entrada",
+        en: "Input",
+    }
+    tui_path_input_title() {
+        es: "Ruta del repositorio (ej. /var/www/html/mi-app)",
+        en: "Repository path (e.g. /var/www/html/my-app)",
+    }
+    tui_preset_input_title() {
+        es: "Preset de despliegue (laravel, vue, hugo o static; vacío = ninguno)",
+        en: "Deployment preset (laravel, vue, hugo or static; empty = none)",
+    }
+    tui_shortcuts_title() {
+        es: "Atajos",
+        en: "Shortcuts",
+    }
+    key_space() {
+        es: " Espacio ",
+        en: " Space ",
+    }
+    key_move() {
+        es: " mover  ",
+        en: " move  ",
+    }
+    key_details() {
+        es: " detalles  ",
+        en: " details  ",
+    }
+    key_group() {
+        es: " filtrar grupo  ",
+        en: " filter group  ",
+    }
+    key_quit() {
+        es: " salir  ",
+        en: " quit  ",
+    }
+    key_quit_last() {
+        es: " salir",
+        en: " quit",
+    }
+    key_add() {
+        es: " añadir  ",
+        en: " add  ",
+    }
+    key_edit() {
+        es: " editar  ",
+        en: " edit  ",
+    }
+    key_delete() {
+        es: " eliminar  ",
+        en: " delete  ",
+    }
+    key_shell() {
+        es: " abrir terminal  ",
+        en: " open shell  ",
+    }
+    key_toggle() {
+        es: " activar/pausar  ",
+        en: " enable/pause  ",
+    }
+    key_sync() {
+        es: " sincronizar  ",
+        en: " sync  ",
+    }
+    key_logs() {
+        es: " registro  ",
+        en: " log  ",
+    }
+    key_daemon_sync() {
+        es: " pedir sync al daemon  ",
+        en: " ask daemon to sync  ",
+    }
+    key_cancel() {
+        es: " cancelar  ",
+        en: " cancel  ",
+    }
+
+    // validate.rs: git-sync validate
+    discovered_repos(count) {
+        es: "{count} repositorio(s) encontrados con discover",
+        en: "{count} repositories found with discover",
+    }
+    validate_paused() {
+        es: " · pausado",
+        en: " · paused",
+    }
+    validating(dir) {
+        es: "🔎 Validando la configuración de {dir}",
+        en: "🔎 Validating the configuration in {dir}",
+    }
+    validate_settings_section() {
+        es: "⚙️ Configuración",
+        en: "⚙️ Configuration",
+    }
+    validate_repos_section() {
+        es: "📂 Repositorios",
+        en: "📂 Repositories",
+    }
+    validate_summary(errors, warnings) {
+        es: "\n📋 Resultado: {errors} error(es), {warnings} advertencia(s)",
+        en: "\n📋 Result: {errors} error(s), {warnings} warning(s)",
+    }
+    validate_failed(errors) {
+        es: "La validación encontró {errors} error(es)",
+        en: "The validation found {errors} error(s)",
+    }
+    validate_ok() {
+        es: "✅ La configuración es válida.",
+        en: "✅ The configuration is valid.",
+    }
+    settings_missing(file) {
+        es: "No existe {file}; se usarán los valores predeterminados",
+        en: "{file} does not exist; the default values will be used",
+    }
+    file_valid(file) {
+        es: "{file} es válido",
+        en: "{file} is valid",
+    }
+    schema_outdated(version, current) {
+        es: "Esquema de configuración versión {version}: se migrará a la {current} en el próximo inicio",
+        en: "Configuration schema version {version}: it will be migrated to {current} on the next start",
+    }
+    schema_newer(version, current) {
+        es: "Esquema de configuración versión {version}, posterior a la de este binario ({current})",
+        en: "Configuration schema version {version}, newer than this binary's ({current})",
+    }
+    jitter_too_high(jitter) {
+        es: "sync_jitter = {jitter}: el máximo es 100, se usará 100",
+        en: "sync_jitter = {jitter}: the maximum is 100, 100 will be used",
+    }
+    allowed_root_relative(root) {
+        es: "allowed_roots: la ruta `{root}` debe ser absoluta",
+        en: "allowed_roots: the path `{root}` must be absolute",
+    }
+    allowed_root_missing(root) {
+        es: "allowed_roots: no existe el directorio {root}",
+        en: "allowed_roots: the directory {root} does not exist",
+    }
+    discover_relative(pattern) {
+        es: "discover: el patrón `{pattern}` debe ser una ruta absoluta",
+        en: "discover: the pattern `{pattern}` must be an absolute path",
+    }
+    secrets_readable() {
+        es: "Secretos de notificación legibles",
+        en: "Notification secrets readable",
+    }
+    credentials_readable() {
+        es: "Credenciales HTTPS legibles",
+        en: "HTTPS credentials readable",
+    }
+    env_vars(count) {
+        es: "{count} variable(s) de entorno",
+        en: "{count} environment variable(s)",
+    }
+    read_failed(path, error) {
+        es: "No se pudo leer {path}: {error}",
+        en: "Could not read {path}: {error}",
+    }
+    line_unparsable(line, text) {
+        es: "Línea {line}: no se pudo interpretar `{text}`",
+        en: "Line {line}: could not parse `{text}`",
+    }
+    line_warning(line, warning) {
+        es: "Línea {line}: {warning}",
+        en: "Line {line}: {warning}",
+    }
+    line_duplicate_path(line, path) {
+        es: "Línea {line}: la ruta {path} ya aparece en otra línea (quizá escrita de otra forma)",
+        en: "Line {line}: the path {path} already appears on another line (perhaps written differently)",
+    }
+    line_duplicate_name(line, name, path) {
+        es: "Línea {line}: el nombre `{name}` ya lo usa {path}. Asigne un `name=` distinto.",
+        en: "Line {line}: the name `{name}` is already used by {path}. Set a different `name=`.",
+    }
+    no_repos_in(file) {
+        es: "No hay repositorios configurados en {file}",
+        en: "No repositories configured in {file}",
+    }
+    repos_file_count(file, count) {
+        es: "{file} con {count} repositorio(s)",
+        en: "{file} with {count} repositories",
+    }
+    ssh_key_unreadable(key) {
+        es: "No se puede leer la clave SSH {key}",
+        en: "Cannot read the SSH key {key}",
+    }
+    will_clone(url) {
+        es: "Se clonará desde {url}",
+        en: "It will be cloned from {url}",
+    }
+    clone_target_unwritable(path, url) {
+        es: "No se puede crear {path} para clonar {url}: sin permisos de escritura",
+        en: "Cannot create {path} to clone {url}: no write permission",
+    }
+    git_bare_repo() {
+        es: "Repositorio Git bare",
+        en: "Bare Git repository",
+    }
+    git_repo() {
+        es: "Repositorio Git",
+        en: "Git repository",
+    }
+    empty_clone_target() {
+        es: "Directorio vacío: se clonará en el primer ciclo",
+        en: "Empty directory: it will be cloned on the first cycle",
+    }
+    dir_not_bare_repo() {
+        es: "El directorio no es un repositorio Git bare (sync_mode=bare)",
+        en: "The directory is not a bare Git repository (sync_mode=bare)",
+    }
+    dir_not_git_repo() {
+        es: "El directorio no es un repositorio Git válido",
+        en: "The directory is not a valid Git repository",
+    }
+    remote_mismatch(remote, actual, expected) {
+        es: "El remoto `{remote}` apunta a {actual} en lugar de {expected}: no se sincronizará",
+        en: "The remote `{remote}` points to {actual} instead of {expected}: it will not be synced",
+    }
+    remote_absent(remote, expected) {
+        es: "No existe el remoto `{remote}` (se esperaba {expected}): no se sincronizará",
+        en: "The remote `{remote}` does not exist ({expected} was expected): it will not be synced",
+    }
+    remote_url_unreadable(remote, error) {
+        es: "No se pudo leer la URL del remoto `{remote}`: {error}",
+        en: "Could not read the URL of the remote `{remote}`: {error}",
+    }
+    not_writable() {
+        es: "Sin permisos de escritura para el usuario actual",
+        en: "No write permission for the current user",
+    }
+    compose_file_missing() {
+        es: "compose: no hay compose.yaml ni docker-compose.yml en la raíz",
+        en: "compose: there is no compose.yaml or docker-compose.yml at the root",
+    }
+    preset_marker_missing(preset, files) {
+        es: "preset={preset}: no existe {files} en la raíz",
+        en: "preset={preset}: {files} does not exist at the root",
+    }
+    preset_marker_separator() {
+        es: " ni ",
+        en: " or ",
+    }
+    remote_reachable(remote, refs) {
+        es: "Remoto `{remote}` accesible ({refs} referencias)",
+        en: "Remote `{remote}` reachable ({refs} refs)",
+    }
+    remote_unreachable(remote, error) {
+        es: "Remoto `{remote}` no accesible: {error}",
+        en: "Remote `{remote}` not reachable: {error}",
+    }
+
+    // notifier.rs: notificaciones
+    notify_unchanged(repo, result) {
+        es: "ℹ️ git-sync: {repo} — {result}",
+        en: "ℹ️ git-sync: {repo} — {result}",
+    }
+    curl_failed(error) {
+        es: "No se pudo ejecutar `curl`: {error}",
+        en: "Could not run `curl`: {error}",
+    }
+    auth_header_failed(error) {
+        es: "No se pudo enviar la cabecera de autenticación: {error}",
+        en: "Could not send the authentication header: {error}",
+    }
+    curl_wait_failed(error) {
+        es: "No se pudo esperar a `curl`: {error}",
+        en: "Could not wait for `curl`: {error}",
+    }
+    homeserver_rejected(status, error) {
+        es: "el homeserver rechazó el mensaje (estado {status}): {error}",
+        en: "the homeserver rejected the message (status {status}): {error}",
+    }
+
+    // exchange.rs: config export / import
+    invalid_format(value) {
+        es: "Formato `{value}` no válido (use json o yaml)",
+        en: "Invalid format `{value}` (use json or yaml)",
+    }
+    repo_without_path() {
+        es: "Hay un repositorio sin `path`",
+        en: "There is a repository without `path`",
+    }
+    option_error_in(error, repo) {
+        es: "{error} en {repo}",
+        en: "{error} in {repo}",
+    }
+    config_serialize_failed(error) {
+        es: "No se pudo serializar la configuración: {error}",
+        en: "Could not serialize the configuration: {error}",
+    }
+    stdin_read_failed(error) {
+        es: "No se pudo leer la entrada estándar: {error}",
+        en: "Could not read the standard input: {error}",
+    }
+    parse_failed(source, error) {
+        es: "No se pudo interpretar {source}: {error}",
+        en: "Could not parse {source}: {error}",
+    }
+    config_dir_create_failed(path, error) {
+        es: "No se pudo crear el directorio {path}: {error}",
+        en: "Could not create the directory {path}: {error}",
+    }
+    config_write_failed(path, error) {
+        es: "No se pudo escribir la configuración {path}: {error}",
+        en: "Could not write the configuration {path}: {error}",
+    }
+    config_imported(settings, repos, count) {
+        es: "✅ Configuración importada: {settings} y {repos} ({count} repositorios).",
+        en: "✅ Configuration imported: {settings} and {repos} ({count} repositories).",
+    }
+    restart_to_apply(systemctl, service) {
+        es: "👉 Reinicie el servicio para aplicarla: `{systemctl} restart {service}`.",
+        en: "👉 Restart the service to apply it: `{systemctl} restart {service}`.",
+    }
+
+    // migrate.rs: migraciones del esquema
+    migration_v1() {
+        es: "entradas antiguas de repositories.txt (`;; comando`, `origen => destino` local) y claves nuevas de config.toml",
+        en: "old repositories.txt entries (`;; command`, local `source => destination`) and new config.toml keys",
+    }
+    migration_v2() {
+        es: "`verbose` de config.toml reemplazado por `log_level`",
+        en: "`verbose` in config.toml replaced by `log_level`",
+    }
+    migration_v3() {
+        es: "`continuous_mode` desactivado: el daemon sigue haciendo un solo ciclo al iniciar y con SIGUSR1 (ponga `continuous_mode = true` para sincronizar cada sync_interval)",
+        en: "`continuous_mode` disabled: the daemon keeps running a single cycle at startup and on SIGUSR1 (set `continuous_mode = true` to sync every sync_interval)",
+    }
+    schema_too_new(file, version, current) {
+        es: "⚠️ {file} es de la versión {version} del esquema, posterior a la de este binario ({current}). Actualice git-sync.",
+        en: "⚠️ {file} uses schema version {version}, newer than this binary's ({current}). Update git-sync.",
+    }
+    backup_failed(path, error) {
+        es: "No se pudo crear la copia de seguridad {path}: {error}",
+        en: "Could not create the backup {path}: {error}",
+    }
+    migrating(version, current) {
+        es: "🔧 Migrando la configuración de la versión {version} a la {current} (copias en *.v{version}.bak)",
+        en: "🔧 Migrating the configuration from version {version} to {current} (backups in *.v{version}.bak)",
+    }
+    migration_step_failed(version, error) {
+        es: "Falló la migración a la versión {version}: {error}",
+        en: "The migration to version {version} failed: {error}",
+    }
+    config_migrated() {
+        es: "✅ Configuración migrada",
+        en: "✅ Configuration migrated",
+    }
+    config_toml_parse_failed(error) {
+        es: "Error al interpretar config.toml: {error}",
+        en: "Error while parsing config.toml: {error}",
+    }
+
+    // secrets.rs y credentials.rs: archivos con secretos
+    secrets_parse_failed(path, error) {
+        es: "No se pudo interpretar el archivo de secretos {path}: {error}",
+        en: "Could not parse the secrets file {path}: {error}",
+    }
+    decrypt_tool_failed(tool, path, error) {
+        es: "No se pudo ejecutar `{tool}` para {path}: {error}",
+        en: "Could not run `{tool}` for {path}: {error}",
+    }
+    file_read_failed(path, error) {
+        es: "No se pudo leer el archivo {path}: {error}",
+        en: "Could not read the file {path}: {error}",
+    }
+    key_read_failed(path, error) {
+        es: "No se pudo leer la clave de descifrado {path}: {error}",
+        en: "Could not read the decryption key {path}: {error}",
+    }
+    key_not_private(path) {
+        es: "La clave de descifrado {path} es accesible por otros usuarios; ajuste sus permisos a 0600 o 0400",
+        en: "The decryption key {path} is accessible by other users; set its permissions to 0600 or 0400",
+    }
+    decrypt_failed(path, status, error) {
+        es: "No se pudo descifrar {path} (estado {status}): {error}",
+        en: "Could not decrypt {path} (status {status}): {error}",
+    }
+    decrypted_not_utf8(path, error) {
+        es: "El contenido descifrado de {path} no es UTF-8 válido: {error}",
+        en: "The decrypted content of {path} is not valid UTF-8: {error}",
+    }
+    credentials_parse_failed(path, error) {
+        es: "No se pudo interpretar el archivo de credenciales {path}: {error}",
+        en: "Could not parse the credentials file {path}: {error}",
+    }
+    credentials_not_private(path) {
+        es: "El archivo de credenciales {path} es accesible por otros usuarios; ajuste sus permisos a 0600",
+        en: "The credentials file {path} is accessible by other users; set its permissions to 0600",
+    }
+
+    // control.rs: socket de control
+    control_socket_setup_failed(error) {
+        es: "No se pudo configurar el socket de control: {error}",
+        en: "Could not configure the control socket: {error}",
+    }
+    control_socket_in_use(path) {
+        es: "El socket de control {path} ya está en uso por otro proceso",
+        en: "The control socket {path} is already in use by another process",
+    }
+    control_socket_create_failed(path, error) {
+        es: "No se pudo crear el socket de control {path}: {error}",
+        en: "Could not create the control socket {path}: {error}",
+    }
+    mode_set_failed(path, error) {
+        es: "No se pudieron asignar permisos a {path}: {error}",
+        en: "Could not set the permissions of {path}: {error}",
+    }
+    control_unknown_command() {
+        es: "error: orden desconocida (use sync, sync <repositorio>, ping o watch)\n",
+        en: "error: unknown command (use sync, sync <repository>, ping or watch)\n",
+    }
+    #[cfg(unix)]
+    control_connect_failed(path, error) {
+        es: "No se pudo comunicar con el daemon por {path}: {error}",
+        en: "Could not talk to the daemon through {path}: {error}",
+    }
+    #[cfg(not(unix))]
+    control_socket_unsupported() {
+        es: "El socket de control solo está disponible en sistemas Unix",
+        en: "The control socket is only available on Unix systems",
+    }
+
+    // config_watch.rs: vigilancia de la configuración
+    #[cfg(not(target_os = "linux"))]
+    config_watch_unsupported() {
+        es: "La vigilancia de la configuración solo está disponible en Linux",
+        en: "Watching the configuration is only available on Linux",
+    }
+    invalid_config_path(path) {
+        es: "Ruta de configuración no válida: {path}",
+        en: "Invalid configuration path: {path}",
+    }
+    inotify_failed(path, error) {
+        es: "No se pudo vigilar {path} con inotify: {error}",
+        en: "Could not watch {path} with inotify: {error}",
+    }
+
+    // supervise.rs: git-sync daemon --supervise
+    supervisor_started(pid) {
+        es: "🛡️ Supervisor: daemon iniciado (pid {pid})",
+        en: "🛡️ Supervisor: daemon started (pid {pid})",
+    }
+    supervisor_stopped_with_daemon() {
+        es: "🛡️ Supervisor detenido junto con el daemon.",
+        en: "🛡️ Supervisor stopped together with the daemon.",
+    }
+    supervisor_daemon_finished() {
+        es: "🛡️ El daemon terminó correctamente; el supervisor se detiene.",
+        en: "🛡️ The daemon finished successfully; the supervisor stops.",
+    }
+    supervisor_restarting(status, seconds) {
+        es: "El daemon terminó con {status}. Se reinicia en {seconds} s.",
+        en: "The daemon ended with {status}. Restarting in {seconds} s.",
+    }
+    supervisor_stopped() {
+        es: "🛡️ Supervisor detenido.",
+        en: "🛡️ Supervisor stopped.",
+    }
+    executable_path_failed(error) {
+        es: "No se pudo determinar la ruta del ejecutable actual: {error}",
+        en: "Could not determine the path of the current executable: {error}",
+    }
+    daemon_start_failed(error) {
+        es: "No se pudo iniciar el daemon: {error}",
+        en: "Could not start the daemon: {error}",
+    }
+    daemon_status_failed(error) {
+        es: "No se pudo consultar el estado del daemon: {error}",
+        en: "Could not query the daemon status: {error}",
+    }
+    supervisor_running(pid, path) {
+        es: "Ya hay un supervisor en ejecución (pid {pid}, {path})",
+        en: "A supervisor is already running (pid {pid}, {path})",
+    }
+    write_failed(path, error) {
+        es: "No se pudo escribir {path}: {error}",
+        en: "Could not write {path}: {error}",
+    }
+
+    // settings.rs: config.toml
+    invalid_time_window(value) {
+        es: "franja horaria `{value}` no válida (use HH:MM-HH:MM)",
+        en: "invalid time window `{value}` (use HH:MM-HH:MM)",
+    }
+    settings_create_failed(error) {
+        es: "❌ No se pudo crear config.toml: {error}",
+        en: "❌ Could not create config.toml: {error}",
+    }
+    settings_created(file) {
+        es: "⚙️ Archivo de configuración creado: {file}",
+        en: "⚙️ Configuration file created: {file}",
+    }
+    settings_fallback(error) {
+        es: "⚠️ {error}. Se utilizarán los valores predeterminados.",
+        en: "⚠️ {error}. The default values will be used.",
+    }
+    config_toml_read_failed(error) {
+        es: "Error al leer config.toml: {error}",
+        en: "Error while reading config.toml: {error}",
+    }
+
+    // preset.rs: presets de despliegue
+    invalid_preset(value) {
+        es: "Preset `{value}` no válido (use laravel, vue, hugo o static)",
+        en: "Invalid preset `{value}` (use laravel, vue, hugo or static)",
+    }
+    invalid_preset_step(value) {
+        es: "Paso `{value}` no válido para `preset_steps` (use composer, npm, build, hugo, migrate, cache o queue)",
+        en: "Invalid step `{value}` for `preset_steps` (use composer, npm, build, hugo, migrate, cache or queue)",
+    }
+
+    // environment.rs: archivo de entorno
+    env_line_invalid(line, path) {
+        es: "Línea {line} inválida en {path}: se esperaba CLAVE=valor",
+        en: "Invalid line {line} in {path}: KEY=value was expected",
+    }
+    env_name_invalid(line, path, name) {
+        es: "Línea {line} inválida en {path}: nombre de variable no válido `{name}`",
+        en: "Invalid line {line} in {path}: invalid variable name `{name}`",
+    }
+
+    // signals.rs: señales del daemon
+    sigusr1_register_failed(error) {
+        es: "No se pudo registrar el manejador de SIGUSR1: {error}",
+        en: "Could not register the SIGUSR1 handler: {error}",
+    }
+    signal_register_failed(error) {
+        es: "No se pudo registrar el manejador de señales: {error}",
+        en: "Could not register the signal handler: {error}",
+    }
+
+    // sync_state.rs: estado de sincronización
+    sync_state_serialize_failed(error) {
+        es: "No se pudo serializar el estado de sincronización: {error}",
+        en: "Could not serialize the sync state: {error}",
+    }
+    sync_state_save_failed(path, error) {
+        es: "No se pudo guardar el estado de sincronización en {path}: {error}",
+        en: "Could not save the sync state in {path}: {error}",
+    }
+
+    // git/libgit2.rs y git/cli.rs: operaciones de Git
+    git_command_debug(args, dir) {
+        es: "🔧 git {args} (en {dir})",
+        en: "🔧 git {args} (in {dir})",
+    }
+    revision_not_found(rev, error) {
+        es: "No se encontró {rev}: {error}",
+        en: "{rev} was not found: {error}",
+    }
+    time_ago(value, unit) {
+        es: "hace {value} {unit}",
+        en: "{value} {unit} ago",
+    }
+    unit_seconds() {
+        es: "segundos",
+        en: "seconds",
+    }
+    unit_minutes() {
+        es: "minutos",
+        en: "minutes",
+    }
+    unit_hours() {
+        es: "horas",
+        en: "hours",
+    }
+    unit_days() {
+        es: "días",
+        en: "days",
+    }
+    unit_weeks() {
+        es: "semanas",
+        en: "weeks",
+    }
+    unit_months() {
+        es: "meses",
+        en: "months",
+    }
+    unit_years() {
+        es: "años",
+        en: "years",
+    }
+
+    // main.rs: git-sync daemon --once
+    daemon_mode_once() {
+        es: "🕹️ Modo de sincronización: un solo ciclo (--once)\n",
+        en: "🕹️ Sync mode: a single cycle (--once)\n",
+    }
+
+    daemon_once_finished() {
+        es: "👋 Ciclo único completado. Daemon detenido.",
+        en: "👋 Single cycle completed. Daemon stopped.",
+    }
+
+    // activity.rs: etapa del ciclo en curso
+    activity_serialize_failed(error) {
+        es: "No se pudo serializar la actividad en curso: {error}",
+        en: "Could not serialize the current activity: {error}",
+    }
+    activity_save_failed(path, error) {
+        es: "No se pudo guardar la actividad en curso en {path}: {error}",
+        en: "Could not save the current activity in {path}: {error}",
+    }
+
+    // tui.rs: sincronizaciones pedidas al daemon
+    tui_control_socket_missing(path) {
+        es: "⚠️ No hay socket de control en {path}: active control_socket = true en config.toml y arranque el daemon, o use u/U para sincronizar desde la TUI",
+        en: "⚠️ No control socket at {path}: set control_socket = true in config.toml and start the daemon, or use u/U to sync from the TUI",
+    }
+    tui_daemon_sync_pending() {
+        es: "⏳ Ya hay una sincronización pedida al daemon; espere a que termine",
+        en: "⏳ A sync request to the daemon is already pending; wait for it to finish",
+    }
+    tui_daemon_sync_requested(repo) {
+        es: "📨 Sincronización de {repo} pedida al daemon",
+        en: "📨 Asked the daemon to sync {repo}",
+    }
+    tui_daemon_sync_all_requested() {
+        es: "📨 Sincronización de todos los repositorios pedida al daemon",
+        en: "📨 Asked the daemon to sync every repository",
+    }
+    tui_daemon_rejected(reply) {
+        es: "❌ El daemon rechazó la orden: {reply}",
+        en: "❌ The daemon rejected the request: {reply}",
+    }
+    tui_daemon_busy() {
+        es: "⏳ El daemon está ocupado; la orden queda en cola y se atenderá al terminar el ciclo en curso",
+        en: "⏳ The daemon is busy; the request is queued and will run after the current cycle",
+    }
+    tui_daemon_progress(repo, phase) {
+        es: "🔄 Daemon sincronizando {repo}: {phase}",
+        en: "🔄 Daemon syncing {repo}: {phase}",
+    }
+    tui_daemon_repo_synced(repo, result) {
+        es: "✅ El daemon sincronizó {repo}: {result}",
+        en: "✅ The daemon synced {repo}: {result}",
+    }
+    tui_daemon_repo_failed(repo, error) {
+        es: "❌ Falló la sincronización de {repo} en el daemon: {error}",
+        en: "❌ The daemon failed to sync {repo}: {error}",
+    }
+    tui_daemon_repo_skipped(repo) {
+        es: "⏭️ El daemon terminó el ciclo sin sincronizar {repo} (pausado, fuera de ventana o en espera)",
+        en: "⏭️ The daemon finished its cycle without syncing {repo} (paused, outside its window or backing off)",
+    }
+    tui_daemon_cycle_done(ok, failed) {
+        es: "🏁 Ciclo del daemon terminado: {ok} correctos, {failed} con error",
+        en: "🏁 Daemon cycle finished: {ok} ok, {failed} failed",
+    }
+    tui_daemon_no_cycle() {
+        es: "⚠️ El daemon no empezó ningún ciclo tras la petición (¿está en pausa general?)",
+        en: "⚠️ The daemon did not start a cycle after the request (is it paused?)",
+    }
+
+    // tui.rs: panel de registro
+    tui_log_on() {
+        es: "📜 Panel de registro abierto (f: solo el repositorio seleccionado)",
+        en: "📜 Log pane open (f: selected repository only)",
+    }
+    tui_log_off() {
+        es: "📜 Panel de registro cerrado",
+        en: "📜 Log pane closed",
+    }
+    tui_log_closed() {
+        es: "Abra antes el panel de registro con 'l'",
+        en: "Open the log pane with 'l' first",
+    }
+    tui_log_filter_on(repo) {
+        es: "🔎 El registro muestra solo {repo}",
+        en: "🔎 The log shows only {repo}",
+    }
+    tui_log_filter_off() {
+        es: "🔎 El registro muestra todos los repositorios",
+        en: "🔎 The log shows every repository",
+    }
+    tui_log_title(file) {
+        es: "Registro: {file}",
+        en: "Log: {file}",
+    }
+    tui_log_title_repo(repo) {
+        es: "Registro de {repo}",
+        en: "Log for {repo}",
+    }
+    tui_log_scrolled(lines) {
+        es: " (↑ {lines} entradas más recientes)",
+        en: " (↑ {lines} newer entries)",
+    }
+    tui_log_keys() {
+        es: " RePág/AvPág desplazar · Inicio/Fin · f filtrar por repositorio · l cerrar ",
+        en: " PgUp/PgDn scroll · Home/End · f filter by repository · l close ",
+    }
+    tui_log_empty() {
+        es: "Sin entradas todavía",
+        en: "No entries yet",
+    }
+
+    // watch.rs: git-sync watch
+    watch_socket_missing(path) {
+        es: "No hay socket de control en {path}: active control_socket = true en config.toml y arranque el daemon",
+        en: "No control socket at {path}: set control_socket = true in config.toml and start the daemon",
+    }
+    #[cfg(unix)]
+    watch_rejected(path, reply) {
+        es: "El daemon en {path} no admite `watch` (respondió: {reply}); reinícielo tras actualizar git-sync",
+        en: "The daemon at {path} does not support `watch` (it replied: {reply}); restart it after upgrading git-sync",
+    }
+    watch_disconnected(path) {
+        es: "Se cerró la conexión con el daemon en {path}",
+        en: "The connection to the daemon at {path} was closed",
+    }
+    watch_cycle_start(count) {
+        es: "📦 Ciclo iniciado: {count} repositorio(s)",
+        en: "📦 Cycle started: {count} repositories",
+    }
+    watch_phase(name, phase) {
+        es: "⏳ {name}: {phase}",
+        en: "⏳ {name}: {phase}",
+    }
+    watch_cycle_end(count, failed) {
+        es: "🏁 Ciclo terminado: {count} repositorio(s), {failed} con error",
+        en: "🏁 Cycle finished: {count} repositories, {failed} failed",
+    }
+
+    // service.rs: git-sync service status --group
+    status_group(group, count) {
+        es: "  🏷️ Grupo `{group}`: {count} repositorio(s)",
+        en: "  🏷️ Group `{group}`: {count} repositories",
+    }
+    status_group_repo_ok(name, elapsed) {
+        es: "    ✅ {name}: última sincronización correcta hace {elapsed}",
+        en: "    ✅ {name}: last successful sync {elapsed} ago",
+    }
+    status_group_repo_failed(name, error) {
+        es: "    ❌ {name}: {error}",
+        en: "    ❌ {name}: {error}",
+    }
+    status_group_repo_never(name) {
+        es: "    ⏳ {name}: sin sincronizaciones correctas todavía",
+        en: "    ⏳ {name}: no successful sync yet",
+    }
+}
@@ -0,0 +1,247 @@
+use crate::config::{Config, RepoDefinition};
+use crate::logger::Logger;
+use crate::messages;
+use crate::settings::Settings;
+use std::fs;
+use std::path::Path;
+
+/// Versión del esquema de configuración que escribe esta versión de git-sync. Los
+/// `config.toml` sin `version` son anteriores al versionado (versión 0).
+pub const CONFIG_VERSION: u32 = 3;
+
+struct Migration {
+    /// Versión del esquema que resulta de aplicarla
+    version: u32,
+    description: fn() -> String,
+    apply: fn(&Config) -> Result<(), String>,
+}
+
+/// Migraciones en orden; cada una lleva la configuración de `version - 1` a `version`.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: messages::migration_v1,
+        apply: migrate_v1,
+    },
+    Migration {
+        version: 2,
+        description: messages::migration_v2,
+        apply: migrate_v2,
+    },
+    Migration {
+        version: 3,
+        description: messages::migration_v3,
+        apply: migrate_v3,
+    },
+];
+
+/// Actualiza `config.toml` y `repositories.txt` al esquema actual si son de una versión
+/// anterior. Antes de modificarlos guarda una copia `<archivo>.v<versión>.bak`, y cada
+/// migración aplicada queda en el registro.
+pub fn migrate(config: &Config) -> Result<(), String> {
+    let Ok(contents) = fs::read_to_string(&config.settings_file) else {
+        return Ok(());
+    };
+    let version = schema_version(&contents)?;
+    if version > CONFIG_VERSION {
+        eprintln!(
+            "{}",
+            messages::schema_too_new(&config.settings_file, version, CONFIG_VERSION)
+        );
+        return Ok(());
+    }
+    if version == CONFIG_VERSION {
+        return Ok(());
+    }
+
+    for file in [&config.settings_file, &config.repos_file] {
+        if Path::new(file).exists() {
+            let backup = format!("{}.v{}.bak", file, version);
+            fs::copy(file, &backup).map_err(|e| messages::backup_failed(&backup, e))?;
+        }
+    }
+
+    let logger = Logger::new(config.log_file.clone());
+    logger.log_line(&messages::migrating(version, CONFIG_VERSION));
+    for migration in MIGRATIONS.iter().filter(|m| m.version > version) {
+        (migration.apply)(config)
+            .map_err(|e| messages::migration_step_failed(migration.version, e))?;
+        logger.log_line(&format!(
+            "   • v{}: {}",
+            migration.version,
+            (migration.description)()
+        ));
+    }
+
+    let contents = fs::read_to_string(&config.settings_file)
+        .map_err(|e| messages::read_failed(&config.settings_file, e))?;
+    write_settings(
+        config,
+        &set_top_level_key(&contents, "version", &CONFIG_VERSION.to_string()),
+    )?;
+    logger.log_line(&messages::config_migrated());
+    Ok(())
+}
+
+/// Versión declarada en un `config.toml`, o 0 si no tiene `version`.
+fn schema_version(contents: &str) -> Result<u32, String> {
+    let table: toml::Table =
+        toml::from_str(contents).map_err(messages::config_toml_parse_failed)?;
+    Ok(table
+        .get("version")
+        .and_then(|value| value.as_integer())
+        .and_then(|version| u32::try_from(version).ok())
+        .unwrap_or(0))
+}
+
+/// Versión 1: reescribe las entradas con funciones eliminadas, que hasta ahora solo se
+/// ignoraban con un aviso en cada lectura, y añade a `config.toml` las claves que faltan
+/// con su valor predeterminado.
+fn migrate_v1(config: &Config) -> Result<(), String> {
+    config.rewrite_repos_file(|contents| {
+        contents
+            .lines()
+            .map(|line| upgrade_repo_line(line).unwrap_or_else(|| line.to_string()) + "\n")
+            .collect()
+    })?;
+
+    let contents = fs::read_to_string(&config.settings_file)
+        .map_err(|e| messages::read_failed(&config.settings_file, e))?;
+    let table: toml::Table =
+        toml::from_str(&contents).map_err(messages::config_toml_parse_failed)?;
+    let defaults =
+        toml::Table::try_from(Settings::default()).map_err(messages::config_serialize_failed)?;
+
+    let mut contents = contents;
+    for (key, value) in defaults {
+        if key != "version" && key != "groups" && !table.contains_key(&key) {
+            contents = set_top_level_key(&contents, &key, &value.to_string());
+        }
+    }
+    write_settings(config, &contents)
+}
+
+/// Versión 2: `verbose = true` pasa a `log_level = "info"` (el mismo registro que antes) y
+/// `verbose = false` a `log_level = "warn"`, que además omite el resultado de cada repositorio.
+fn migrate_v2(config: &Config) -> Result<(), String> {
+    let contents = fs::read_to_string(&config.settings_file)
+        .map_err(|e| messages::read_failed(&config.settings_file, e))?;
+    let table: toml::Table =
+        toml::from_str(&contents).map_err(messages::config_toml_parse_failed)?;
+    let Some(verbose) = table.get("verbose") else {
+        return Ok(());
+    };
+
+    let level = if verbose.as_bool() == Some(false) {
+        "\"warn\""
+    } else {
+        "\"info\""
+    };
+    // `log_level` no existía antes de esta versión: si está, lo añadió la migración 1 con el
+    // valor predeterminado y se reemplaza por el equivalente de `verbose`.
+    let contents = remove_top_level_key(&contents, "log_level");
+    write_settings(
+        config,
+        &rename_top_level_key(&contents, "verbose", "log_level", level),
+    )
+}
+
+/// Versión 3: hasta ahora `continuous_mode` no tenía efecto y el daemon hacía un solo ciclo,
+/// pero el `config.toml` generado lo traía en `true`. Para que al actualizar no empiece a
+/// desplegar cada `sync_interval` por su cuenta, pasa a `false`; quien quiera el modo continuo
+/// lo vuelve a activar.
+fn migrate_v3(config: &Config) -> Result<(), String> {
+    let contents = fs::read_to_string(&config.settings_file)
+        .map_err(|e| messages::read_failed(&config.settings_file, e))?;
+    let table: toml::Table =
+        toml::from_str(&contents).map_err(messages::config_toml_parse_failed)?;
+    if table
+        .get("continuous_mode")
+        .and_then(|value| value.as_bool())
+        != Some(true)
+    {
+        return Ok(());
+    }
+    write_settings(
+        config,
+        &set_top_level_key(&contents, "continuous_mode", "false"),
+    )
+}
+
+/// La línea equivalente sin el comando post-sync (`ruta ;; comando`) ni el destino del
+/// antiguo modo compilación (`origen => destino` con origen local).
+fn upgrade_repo_line(line: &str) -> Option<String> {
+    let source = line.split('|').next().unwrap_or_default();
+    if !source.contains(";;") && !source.contains("=>") {
+        return None;
+    }
+    let (repo, _) = RepoDefinition::parse_line(line)?;
+    (source.contains(";;") || repo.clone_url.is_none()).then(|| repo.to_line())
+}
+
+/// Asigna `key = value` en la parte de `config.toml` anterior a la primera tabla,
+/// reemplazando la línea existente o añadiéndola, sin tocar el resto del texto.
+/// `version` se añade al principio del archivo.
+fn set_top_level_key(contents: &str, key: &str, value: &str) -> String {
+    let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    let first_table = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with('['))
+        .unwrap_or(lines.len());
+    let new_line = format!("{} = {}", key, value);
+
+    match top_level_key_line(&lines, key) {
+        Some(index) => lines[index] = new_line,
+        None if key == "version" => lines.insert(0, new_line),
+        None => {
+            // Antes de las líneas en blanco que separan la primera tabla.
+            let mut index = first_table;
+            while index > 0 && lines[index - 1].trim().is_empty() {
+                index -= 1;
+            }
+            lines.insert(index, new_line);
+        }
+    }
+
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    contents
+}
+
+/// Índice de la línea `key = ...` en la parte de `config.toml` anterior a la primera tabla.
+fn top_level_key_line(lines: &[String], key: &str) -> Option<usize> {
+    lines
+        .iter()
+        .take_while(|line| !line.trim_start().starts_with('['))
+        .position(|line| {
+            line.split_once('=')
+                .is_some_and(|(name, _)| name.trim() == key)
+        })
+}
+
+/// Reemplaza la línea de `old` por `new = value` en el mismo lugar.
+fn rename_top_level_key(contents: &str, old: &str, new: &str, value: &str) -> String {
+    let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    match top_level_key_line(&lines, old) {
+        Some(index) => lines[index] = format!("{} = {}", new, value),
+        None => return set_top_level_key(contents, new, value),
+    }
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    contents
+}
+
+fn remove_top_level_key(contents: &str, key: &str) -> String {
+    let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    if let Some(index) = top_level_key_line(&lines, key) {
+        lines.remove(index);
+    }
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    contents
+}
+
+fn write_settings(config: &Config, contents: &str) -> Result<(), String> {
+    fs::write(&config.settings_file, contents)
+        .map_err(|e| messages::config_write_failed(&config.settings_file, e))
+}
@@ -0,0 +1,184 @@
+use crate::messages;
+use crate::secrets::{MatrixSecrets, Secrets};
+use crate::settings::{GroupSettings, Settings};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotifyOn {
+    /// No enviar notificaciones
+    Never,
+    /// Solo errores de sincronización
+    #[default]
+    Errors,
+    /// Errores y repositorios que recibieron commits nuevos
+    Changes,
+    /// Todos los resultados, incluso sin cambios remotos
+    All,
+}
+
+pub enum SyncEventKind {
+    Updated(String),
+    Unchanged,
+    Failed(String),
+}
+
+pub struct SyncEvent<'a> {
+    pub name: &'a str,
+    pub repo_path: &'a str,
+    pub groups: &'a [String],
+    pub kind: SyncEventKind,
+}
+
+enum NotificationChannel {
+    Matrix(MatrixSecrets),
+}
+
+pub struct Notifier {
+    notify_on: NotifyOn,
+    groups: BTreeMap<String, GroupSettings>,
+    channels: Vec<NotificationChannel>,
+}
+
+impl Notifier {
+    pub fn new(settings: &Settings, secrets: &Secrets) -> Self {
+        let mut channels = Vec::new();
+        if let Some(matrix) = secrets.matrix.clone() {
+            channels.push(NotificationChannel::Matrix(matrix));
+        }
+
+        Notifier {
+            notify_on: settings.notify_on,
+            groups: settings.groups.clone(),
+            channels,
+        }
+    }
+
+    /// Ajustes del primer grupo del repositorio que tenga configuración propia.
+    fn group_settings(&self, groups: &[String]) -> Option<&GroupSettings> {
+        groups.iter().find_map(|group| self.groups.get(group))
+    }
+
+    fn should_notify(notify_on: NotifyOn, kind: &SyncEventKind) -> bool {
+        match (notify_on, kind) {
+            (NotifyOn::Never, _) => false,
+            (_, SyncEventKind::Failed(_)) => true,
+            (NotifyOn::Errors, _) => false,
+            (NotifyOn::Changes, SyncEventKind::Updated(_)) => true,
+            (NotifyOn::Changes, SyncEventKind::Unchanged) => false,
+            (NotifyOn::All, _) => true,
+        }
+    }
+
+    /// Envía el evento a todos los canales configurados y devuelve los errores por canal.
+    pub fn dispatch(&self, event: &SyncEvent) -> Vec<String> {
+        let group = self.group_settings(event.groups);
+        let notify_on = group.and_then(|g| g.notify_on).unwrap_or(self.notify_on);
+        if self.channels.is_empty() || !Self::should_notify(notify_on, &event.kind) {
+            return Vec::new();
+        }
+
+        let message = format_event(event);
+        self.channels
+            .iter()
+            .filter_map(|channel| match channel {
+                NotificationChannel::Matrix(matrix) => {
+                    let mut matrix = matrix.clone();
+                    if let Some(room_id) = group.and_then(|g| g.matrix_room_id.clone()) {
+                        matrix.room_id = room_id;
+                    }
+                    send_matrix(&matrix, &message)
+                        .err()
+                        .map(|e| format!("Matrix: {}", e))
+                }
+            })
+            .collect()
+    }
+}
+
+fn format_event(event: &SyncEvent) -> String {
+    let repo = format!("{} ({})", event.name, event.repo_path);
+    match &event.kind {
+        SyncEventKind::Updated(result) => {
+            format!("✅ git-sync: {} — {}", repo, result)
+        }
+        SyncEventKind::Unchanged => messages::notify_unchanged(repo, messages::result_no_changes()),
+        SyncEventKind::Failed(error) => {
+            format!("❌ git-sync: {} — {}", repo, error.trim())
+        }
+    }
+}
+
+fn send_matrix(matrix: &MatrixSecrets, message: &str) -> Result<(), String> {
+    let txn_id = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let url = format!(
+        "{}/_matrix/client/v3/rooms/{}/send/m.room.message/git-sync-{}-{}",
+        matrix.homeserver_url.trim_end_matches('/'),
+        percent_encode(&matrix.room_id),
+        std::process::id(),
+        txn_id
+    );
+    let body = serde_json::json!({
+        "msgtype": "m.text",
+        "body": message,
+    })
+    .to_string();
+
+    // El token se entrega por stdin para que no aparezca en la lista de procesos.
+    let mut child = Command::new("curl")
+        .args([
+            "-fsS",
+            "-X",
+            "PUT",
+            "-H",
+            "@-",
+            "-H",
+            "Content-Type: application/json",
+            "--data",
+            &body,
+            &url,
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(messages::curl_failed)?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(format!("Authorization: Bearer {}\n", matrix.access_token).as_bytes())
+            .map_err(messages::auth_header_failed)?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(messages::curl_wait_failed)?;
+
+    if !output.status.success() {
+        return Err(messages::homeserver_rejected(
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn percent_encode(value: &str) -> String {
+    let mut out = String::new();
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    out
+}
@@ -0,0 +1,135 @@
+use crate::messages;
+use chrono::{Local, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+
+/// Pausas pedidas con `git-sync pause`: de todos los repositorios, o temporales de algunos
+/// (`--for`). Se guardan en `pause.toml`, aparte de `state.toml`, porque el daemon reescribe
+/// el estado al final de cada ciclo y podría descartar una pausa pedida mientras tanto.
+/// Las pausas sin plazo de un repositorio siguen siendo el prefijo `!` de `repositories.txt`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PauseState {
+    /// Pausa de todos los repositorios
+    #[serde(default)]
+    pub all: Option<Pause>,
+    /// Pausas temporales de repositorios concretos
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repos: Vec<RepoPause>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pause {
+    pub since_ts: i64,
+    /// Fin de la pausa; sin él dura hasta `git-sync resume`
+    pub until_ts: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoPause {
+    pub repo_path: String,
+    pub until_ts: i64,
+}
+
+impl PauseState {
+    pub fn load(path: &str) -> Self {
+        let Ok(contents) = fs::read_to_string(path) else {
+            return PauseState::default();
+        };
+
+        toml::from_str(&contents).unwrap_or_default()
+    }
+
+    /// Guarda las pausas vigentes; sin ninguna, el archivo se elimina.
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let mut state = self.clone();
+        state.prune();
+        if state.all.is_none() && state.repos.is_empty() {
+            return match fs::remove_file(path) {
+                Err(e) if e.kind() != ErrorKind::NotFound => {
+                    Err(messages::pause_remove_failed(path, e))
+                }
+                _ => Ok(()),
+            };
+        }
+
+        let serialized =
+            toml::to_string_pretty(&state).map_err(messages::pause_serialize_failed)?;
+        fs::write(path, serialized).map_err(|e| messages::pause_save_failed(path, e))
+    }
+
+    /// La pausa de todos los repositorios, si sigue vigente.
+    pub fn global(&self) -> Option<&Pause> {
+        let now = Utc::now().timestamp();
+        self.all
+            .as_ref()
+            .filter(|pause| pause.until_ts.is_none_or(|until| until > now))
+    }
+
+    /// Fin de la pausa temporal de `repo_path`, si sigue vigente.
+    pub fn repo_until(&self, repo_path: &str) -> Option<i64> {
+        let now = Utc::now().timestamp();
+        self.repos
+            .iter()
+            .find(|pause| pause.repo_path == repo_path && pause.until_ts > now)
+            .map(|pause| pause.until_ts)
+    }
+
+    pub fn pause_all(&mut self, until_ts: Option<i64>) {
+        self.all = Some(Pause {
+            since_ts: Utc::now().timestamp(),
+            until_ts,
+        });
+    }
+
+    pub fn pause_repo(&mut self, repo_path: &str, until_ts: i64) {
+        self.repos.retain(|pause| pause.repo_path != repo_path);
+        self.repos.push(RepoPause {
+            repo_path: repo_path.to_string(),
+            until_ts,
+        });
+    }
+
+    /// Quita la pausa global y las temporales; devuelve si había alguna vigente.
+    pub fn resume_all(&mut self) -> bool {
+        self.prune();
+        let paused = self.all.is_some() || !self.repos.is_empty();
+        self.all = None;
+        self.repos.clear();
+        paused
+    }
+
+    /// Quita la pausa temporal de `repo_path`; devuelve si estaba vigente.
+    pub fn resume_repo(&mut self, repo_path: &str) -> bool {
+        let paused = self.repo_until(repo_path).is_some();
+        self.repos.retain(|pause| pause.repo_path != repo_path);
+        paused
+    }
+
+    fn prune(&mut self) {
+        if self.global().is_none() {
+            self.all = None;
+        }
+        let now = Utc::now().timestamp();
+        self.repos.retain(|pause| pause.until_ts > now);
+    }
+}
+
+impl Pause {
+    /// Descripción para los mensajes: `hasta <fecha>` o `hasta git-sync resume`.
+    pub fn describe(&self) -> String {
+        match self.until_ts {
+            Some(until) => messages::until(format_until(until)),
+            None => messages::until_resume(),
+        }
+    }
+}
+
+/// Hora local de fin de una pausa, para los mensajes.
+pub fn format_until(until_ts: i64) -> String {
+    Local
+        .timestamp_opt(until_ts, 0)
+        .single()
+        .map(|until| until.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| until_ts.to_string())
+}
@@ -0,0 +1,269 @@
+//! Operaciones que dependen del sistema operativo. En Unix se usan permisos, inodos,
+//! propietarios y grupos de procesos; en Windows no existen y se omiten o se sustituyen
+//! por su equivalente más cercano.
+
+use std::fs::{File, Metadata, OpenOptions};
+use std::io;
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+
+#[cfg(unix)]
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::{MetadataExt, OpenOptionsExt, PermissionsExt};
+#[cfg(windows)]
+use std::os::windows::process::CommandExt;
+
+/// `CREATE_NEW_PROCESS_GROUP`: el proceso hijo no recibe el Ctrl+C de la consola.
+#[cfg(windows)]
+const CREATE_NEW_PROCESS_GROUP: u32 = 0x0000_0200;
+
+/// Si el proceso tiene privilegios de administración: UID efectivo 0 en Unix, o una
+/// consola elevada en Windows (solo ahí `net session` tiene éxito).
+pub fn is_root() -> bool {
+    #[cfg(unix)]
+    {
+        // SAFETY: geteuid no recibe argumentos y no puede fallar.
+        unsafe { libc::geteuid() == 0 }
+    }
+    #[cfg(not(unix))]
+    {
+        Command::new("net")
+            .arg("session")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|status| status.success())
+    }
+}
+
+/// UID real del proceso (0 donde no existe).
+pub fn uid() -> u32 {
+    #[cfg(unix)]
+    {
+        // SAFETY: getuid no recibe argumentos y no puede fallar.
+        unsafe { libc::getuid() }
+    }
+    #[cfg(not(unix))]
+    {
+        0
+    }
+}
+
+/// Asigna los permisos Unix `mode` a `path`. En Windows no hay equivalente directo: los
+/// archivos heredan las ACL del directorio.
+pub fn set_mode(path: impl AsRef<Path>, mode: u32) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        fs::set_permissions(path, fs::Permissions::from_mode(mode))
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = (path, mode);
+        Ok(())
+    }
+}
+
+/// Como `set_mode`, sobre un archivo abierto.
+pub fn set_file_mode(file: &File, mode: u32) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        file.set_permissions(fs::Permissions::from_mode(mode))
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = (file, mode);
+        Ok(())
+    }
+}
+
+/// Los permisos con los que se crean los archivos nuevos abiertos con `options`.
+pub fn create_mode(options: &mut OpenOptions, mode: u32) -> &mut OpenOptions {
+    #[cfg(unix)]
+    {
+        options.mode(mode)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = mode;
+        options
+    }
+}
+
+/// Si nadie más que el propietario puede leer el archivo (sin permisos de grupo ni de
+/// otros). En Windows se confía en las ACL del directorio de configuración.
+pub fn is_private(metadata: &Metadata) -> bool {
+    #[cfg(unix)]
+    {
+        metadata.mode() & 0o077 == 0
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = metadata;
+        true
+    }
+}
+
+/// Identifica el archivo en disco (el inodo) para detectar que se rotó o se reemplazó.
+/// En Windows siempre es 0 y basta con comparar el tamaño.
+pub fn file_id(metadata: &Metadata) -> u64 {
+    #[cfg(unix)]
+    {
+        metadata.ino()
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = metadata;
+        0
+    }
+}
+
+/// Da a `file` el propietario y el grupo de `metadata`. Solo root puede hacerlo; si falla
+/// se mantiene el actual.
+pub fn copy_owner(file: &File, metadata: &Metadata) {
+    #[cfg(unix)]
+    {
+        let _ = std::os::unix::fs::fchown(file, Some(metadata.uid()), Some(metadata.gid()));
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = (file, metadata);
+    }
+}
+
+/// Lanza el comando en su propio grupo de procesos para poder terminarlo junto con sus
+/// descendientes (`kill_process_group`).
+pub fn new_process_group(command: &mut Command) -> &mut Command {
+    #[cfg(unix)]
+    {
+        std::os::unix::process::CommandExt::process_group(command, 0)
+    }
+    #[cfg(windows)]
+    {
+        command.creation_flags(CREATE_NEW_PROCESS_GROUP)
+    }
+}
+
+/// Mata `child` y los procesos de su grupo (lanzado con `new_process_group`): con
+/// `kill -KILL -<pgid>` en Unix y `taskkill /T` en Windows.
+pub fn kill_process_group(child: &mut Child) {
+    let pid = child.id().to_string();
+    let mut command = if cfg!(windows) {
+        let mut command = Command::new("taskkill");
+        command.args(["/T", "/F", "/PID", &pid]);
+        command
+    } else {
+        let mut command = Command::new("kill");
+        command.args(["-KILL", "--", &format!("-{}", pid)]);
+        command
+    };
+    let _ = command.stdout(Stdio::null()).stderr(Stdio::null()).status();
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Pide a `child` que termine ordenadamente (SIGTERM). En Windows no hay señales: se mata.
+pub fn terminate(child: &mut Child) {
+    #[cfg(unix)]
+    {
+        // SAFETY: kill solo envía una señal a un proceso hijo que aún no se ha esperado.
+        unsafe {
+            libc::kill(child.id() as libc::pid_t, libc::SIGTERM);
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = child.kill();
+    }
+}
+
+/// Reenvía a `child` la petición de sincronizar de inmediato (SIGUSR1, solo en Unix).
+pub fn request_sync(child: &Child) {
+    #[cfg(unix)]
+    {
+        // SAFETY: kill solo envía una señal a un proceso hijo que aún no se ha esperado.
+        unsafe {
+            libc::kill(child.id() as libc::pid_t, libc::SIGUSR1);
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = child;
+    }
+}
+
+/// Si existe un proceso con ese PID.
+pub fn process_alive(pid: u32) -> bool {
+    #[cfg(unix)]
+    {
+        // SAFETY: la señal 0 solo comprueba si el proceso existe.
+        let result = unsafe { libc::kill(pid as libc::pid_t, 0) };
+        result == 0 || io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+    }
+    #[cfg(not(unix))]
+    {
+        Command::new("tasklist")
+            .args(["/NH", "/FI", &format!("PID eq {}", pid)])
+            .output()
+            .is_ok_and(|output| {
+                String::from_utf8_lossy(&output.stdout)
+                    .split_whitespace()
+                    .any(|field| field == pid.to_string())
+            })
+    }
+}
+
+/// Envía un datagrama a un socket Unix (journald, syslog). Devuelve `false` si no se pudo
+/// o si el sistema no tiene sockets Unix de datagramas.
+pub fn send_datagram(socket: &str, datagram: &[u8]) -> bool {
+    #[cfg(unix)]
+    {
+        std::os::unix::net::UnixDatagram::unbound()
+            .and_then(|unix_socket| unix_socket.send_to(datagram, socket))
+            .is_ok()
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = (socket, datagram);
+        false
+    }
+}
+
+/// Descarta la salida estándar del proceso y de los comandos que lance (`--quiet`); la de
+/// error se conserva. En Windows no tiene efecto.
+pub fn silence_stdout() {
+    #[cfg(unix)]
+    {
+        use std::os::fd::AsRawFd;
+
+        if let Ok(null) = OpenOptions::new().write(true).open("/dev/null") {
+            // SAFETY: dup2 solo sustituye el descriptor 1 por una copia de otro abierto.
+            unsafe {
+                libc::dup2(null.as_raw_fd(), libc::STDOUT_FILENO);
+            }
+        }
+    }
+}
+
+/// Intérprete de órdenes interactivo del usuario.
+pub fn default_shell() -> String {
+    if cfg!(windows) {
+        std::env::var("COMSPEC").unwrap_or_else(|_| "cmd.exe".to_string())
+    } else {
+        std::env::var("SHELL").unwrap_or_else(|_| "/bin/bash".to_string())
+    }
+}
+
+/// Directorio personal: `HOME` o, en Windows, `USERPROFILE`.
+pub fn home_dir() -> Option<String> {
+    std::env::var("HOME")
+        .ok()
+        .or_else(|| {
+            if cfg!(windows) {
+                std::env::var("USERPROFILE").ok()
+            } else {
+                None
+            }
+        })
+        .filter(|home| !home.is_empty())
+}
@@ -0,0 +1,195 @@
+use crate::messages;
+use std::fs;
+use std::path::Path;
+
+/// Despliegue predefinido que se ejecuta en el árbol de trabajo tras aplicar cambios.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeployPreset {
+    Laravel,
+    /// Aplicación Vue (o cualquier proyecto con `npm run build`) compilada en `dist/`
+    Vue,
+    /// Sitio Hugo generado en `public/`
+    Hugo,
+    /// Sitio estático servido tal cual: no hay nada que compilar
+    Static,
+}
+
+/// Paso de un preset. Se ejecutan siempre en el orden de la enumeración, sin importar el
+/// orden en que se escriban en `preset_steps`.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum PresetStep {
+    /// `composer install` sin dependencias de desarrollo
+    Composer,
+    /// `npm ci`, instalación limpia según `package-lock.json`
+    Npm,
+    /// `npm run build`
+    Build,
+    /// `hugo --minify`
+    Hugo,
+    /// `php artisan migrate --force`
+    Migrate,
+    /// Regenerar las cachés de configuración, rutas y vistas
+    Cache,
+    /// `php artisan queue:restart`, para que los workers carguen el código nuevo
+    Queue,
+}
+
+impl DeployPreset {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "laravel" => Ok(DeployPreset::Laravel),
+            "vue" => Ok(DeployPreset::Vue),
+            "hugo" => Ok(DeployPreset::Hugo),
+            "static" => Ok(DeployPreset::Static),
+            _ => Err(messages::invalid_preset(value)),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DeployPreset::Laravel => "laravel",
+            DeployPreset::Vue => "vue",
+            DeployPreset::Hugo => "hugo",
+            DeployPreset::Static => "static",
+        }
+    }
+
+    /// Pasos que se ejecutan cuando el repositorio no indica `preset_steps`.
+    pub fn default_steps(self) -> &'static [PresetStep] {
+        match self {
+            DeployPreset::Laravel => {
+                &[PresetStep::Composer, PresetStep::Migrate, PresetStep::Cache]
+            }
+            DeployPreset::Vue => &[PresetStep::Npm, PresetStep::Build],
+            DeployPreset::Hugo => &[PresetStep::Hugo],
+            DeployPreset::Static => &[],
+        }
+    }
+
+    /// Patrones que `clean=true` conserva cuando el repositorio no indica `clean_exclude`:
+    /// dependencias y resultados de la compilación, por si no están en `.gitignore`.
+    pub fn clean_exclude(self) -> &'static [&'static str] {
+        match self {
+            DeployPreset::Laravel => &["vendor", "node_modules", "public/build"],
+            DeployPreset::Vue => &["node_modules", "dist"],
+            DeployPreset::Hugo => &["public", "resources/_gen"],
+            DeployPreset::Static => &[],
+        }
+    }
+
+    /// Directorio que debe existir tras un paso de compilación (`build` o `hugo`) cuando
+    /// el repositorio no indica `preset_output`.
+    pub fn output_dir(self) -> Option<&'static str> {
+        match self {
+            DeployPreset::Laravel => Some("public/build"),
+            DeployPreset::Vue => Some("dist"),
+            DeployPreset::Hugo => Some("public"),
+            DeployPreset::Static => None,
+        }
+    }
+
+    /// Archivos de la raíz que identifican el tipo de proyecto; basta con uno.
+    pub fn marker_files(self) -> &'static [&'static str] {
+        match self {
+            DeployPreset::Laravel => &["artisan"],
+            DeployPreset::Vue => &["package.json"],
+            DeployPreset::Hugo => &["hugo.toml", "hugo.yaml", "hugo.json", "config.toml"],
+            DeployPreset::Static => &[],
+        }
+    }
+
+    /// Preset que corresponde al proyecto de `repo_path`, para sugerirlo al añadirlo.
+    pub fn detect(repo_path: &str) -> Option<Self> {
+        let path = Path::new(repo_path);
+        if path.join("artisan").is_file() {
+            return Some(DeployPreset::Laravel);
+        }
+        let hugo_config = ["hugo.toml", "hugo.yaml", "hugo.json"];
+        if hugo_config.iter().any(|file| path.join(file).is_file())
+            || (path.join("config.toml").is_file() && path.join("content").is_dir())
+        {
+            return Some(DeployPreset::Hugo);
+        }
+        if fs::read_to_string(path.join("package.json"))
+            .is_ok_and(|package| package.contains("\"vue\""))
+        {
+            return Some(DeployPreset::Vue);
+        }
+        path.join("index.html")
+            .is_file()
+            .then_some(DeployPreset::Static)
+    }
+}
+
+impl PresetStep {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "composer" => Ok(PresetStep::Composer),
+            "npm" => Ok(PresetStep::Npm),
+            "build" => Ok(PresetStep::Build),
+            "hugo" => Ok(PresetStep::Hugo),
+            "migrate" => Ok(PresetStep::Migrate),
+            "cache" => Ok(PresetStep::Cache),
+            "queue" => Ok(PresetStep::Queue),
+            _ => Err(messages::invalid_preset_step(value)),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PresetStep::Composer => "composer",
+            PresetStep::Npm => "npm",
+            PresetStep::Build => "build",
+            PresetStep::Hugo => "hugo",
+            PresetStep::Migrate => "migrate",
+            PresetStep::Cache => "cache",
+            PresetStep::Queue => "queue",
+        }
+    }
+
+    /// Si el paso genera el directorio de salida del preset.
+    pub fn builds(self) -> bool {
+        matches!(self, PresetStep::Build | PresetStep::Hugo)
+    }
+
+    /// Si el paso instala dependencias o compila, y por tanto se limita con `build_timeout`
+    /// en lugar de `hook_timeout`.
+    pub fn is_build(self) -> bool {
+        matches!(
+            self,
+            PresetStep::Composer | PresetStep::Npm | PresetStep::Build | PresetStep::Hugo
+        )
+    }
+
+    /// Comandos del paso, como programa y argumentos.
+    pub fn commands(self) -> Vec<(&'static str, Vec<&'static str>)> {
+        let artisan = |args: &[&'static str]| {
+            let mut full = vec!["artisan"];
+            full.extend_from_slice(args);
+            ("php", full)
+        };
+
+        match self {
+            PresetStep::Composer => vec![(
+                "composer",
+                vec![
+                    "install",
+                    "--no-dev",
+                    "--no-interaction",
+                    "--prefer-dist",
+                    "--optimize-autoloader",
+                ],
+            )],
+            PresetStep::Npm => vec![("npm", vec!["ci", "--no-audit", "--no-fund"])],
+            PresetStep::Build => vec![("npm", vec!["run", "build"])],
+            PresetStep::Hugo => vec![("hugo", vec!["--minify"])],
+            PresetStep::Migrate => vec![artisan(&["migrate", "--force"])],
+            PresetStep::Cache => vec![
+                artisan(&["config:cache"]),
+                artisan(&["route:cache"]),
+                artisan(&["view:cache"]),
+            ],
+            PresetStep::Queue => vec![artisan(&["queue:restart"])],
+        }
+    }
+}
@@ -1,13 +1,67 @@
-use crate::config::RepoDefinition;
-use crate::git::GitRepo;
-use crate::logger::Logger;
-use crate::sync_state::SyncStateSnapshot;
+use crate::activity::Activity;
+use crate::config::{
+    DetachedPolicy, DirtyPolicy, DivergencePolicy, PullStrategy, RepoDefinition, SyncMode,
+    check_repo_path, same_remote_url,
+};
+use crate::control::EventStream;
+use crate::credentials::Credentials;
+use crate::error::Error;
+use crate::events::{EventLog, SyncRecord, SyncResult};
+use crate::git::{GitBackendKind, GitRepo, is_timeout_error, timeout_error};
+use crate::logger::{Logger, Phase};
+use crate::messages;
+use crate::notifier::{Notifier, SyncEvent, SyncEventKind};
+use crate::pause::{self, PauseState};
+use crate::platform;
+use crate::semver;
+use crate::settings::{TimeWindow, Timeouts};
+use crate::sync_state::{Deployment, SyncStateSnapshot};
+use chrono::Local;
+use chrono::Utc;
+use std::cell::RefCell;
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::io::{BufRead, BufReader, Read};
 use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::span::EnteredSpan;
+
+const SHALLOW_MAINTENANCE_INTERVAL_SECS: i64 = 24 * 60 * 60;
+/// Commits del rango desplegado que se registran en el log y en el estado.
+const DEPLOYED_COMMITS_LIMIT: usize = 20;
+/// Últimas líneas de salida de un comando de despliegue que se incluyen en su error.
+const HOOK_ERROR_LINES: usize = 5;
 
 pub struct RepoProcessor<'a> {
     logger: &'a Logger,
-    verbose: bool,
     state_file: String,
+    pause_file: Option<String>,
+    activity_file: Option<String>,
+    notifier: Option<&'a Notifier>,
+    events: Option<EventLog>,
+    stream: Option<EventStream>,
+    results: Option<&'a RefCell<Vec<SyncRecord>>>,
+    env: Vec<(String, String)>,
+    credentials: Credentials,
+    git_backend: GitBackendKind,
+    timeouts: Timeouts,
+    divergence_policy: DivergencePolicy,
+    ff_only: bool,
+    dirty_policy: DirtyPolicy,
+    detached_policy: DetachedPolicy,
+    fetch_depth: u32,
+    fetch_prune: bool,
+    prune_local_branches: bool,
+    maintenance_days: u32,
+    maintenance_window: Option<TimeWindow>,
+    quiet_hours: Vec<TimeWindow>,
+    dry_run: bool,
+    max_failures: u32,
+    min_free_mb: u64,
+    allowed_roots: Vec<String>,
 }
 
 struct PullOutcome {
@@ -17,40 +71,295 @@ struct PullOutcome {
 }
 
 impl<'a> RepoProcessor<'a> {
-    pub fn new(logger: &'a Logger, verbose: bool, state_file: String) -> Self {
+    pub fn new(logger: &'a Logger, state_file: String) -> Self {
         RepoProcessor {
             logger,
-            verbose,
             state_file,
+            pause_file: None,
+            activity_file: None,
+            notifier: None,
+            events: None,
+            stream: None,
+            results: None,
+            env: Vec::new(),
+            credentials: Credentials::default(),
+            git_backend: GitBackendKind::default(),
+            timeouts: Timeouts::default(),
+            divergence_policy: DivergencePolicy::default(),
+            ff_only: true,
+            dirty_policy: DirtyPolicy::default(),
+            detached_policy: DetachedPolicy::default(),
+            fetch_depth: 0,
+            fetch_prune: false,
+            prune_local_branches: false,
+            maintenance_days: 0,
+            maintenance_window: None,
+            quiet_hours: Vec::new(),
+            dry_run: false,
+            max_failures: 0,
+            min_free_mb: 0,
+            allowed_roots: Vec::new(),
         }
     }
 
-    pub fn process_all(&self, repo_defs: Vec<RepoDefinition>) -> Result<(), String> {
+    /// Variables de entorno que se inyectan en cada comando `git`.
+    pub fn with_env(mut self, env: &[(String, String)]) -> Self {
+        self.env = env.to_vec();
+        self
+    }
+
+    pub fn with_credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = credentials.clone();
+        self
+    }
+
+    pub fn with_git_backend(mut self, git_backend: GitBackendKind) -> Self {
+        self.git_backend = git_backend;
+        self
+    }
+
+    /// Límites de tiempo de las operaciones Git y de los comandos de despliegue.
+    pub fn with_timeouts(mut self, timeouts: Timeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Política ante historial divergente para los repositorios sin `on_divergence` propio.
+    pub fn with_divergence_policy(mut self, policy: DivergencePolicy) -> Self {
+        self.divergence_policy = policy;
+        self
+    }
+
+    /// Con `false`, los repositorios sin `ff_only` propio fusionan el historial divergente.
+    pub fn with_ff_only(mut self, ff_only: bool) -> Self {
+        self.ff_only = ff_only;
+        self
+    }
+
+    /// Política ante cambios locales para los repositorios sin `on_dirty` propio.
+    pub fn with_dirty_policy(mut self, policy: DirtyPolicy) -> Self {
+        self.dirty_policy = policy;
+        self
+    }
+
+    /// Política ante HEAD desacoplado para los repositorios sin `on_detached` propio.
+    pub fn with_detached_policy(mut self, policy: DetachedPolicy) -> Self {
+        self.detached_policy = policy;
+        self
+    }
+
+    /// Profundidad de historial para los repositorios sin `fetch_depth` propio (0 = completo).
+    pub fn with_fetch_depth(mut self, fetch_depth: u32) -> Self {
+        self.fetch_depth = fetch_depth;
+        self
+    }
+
+    /// `fetch --prune` para los repositorios sin `fetch_prune` propio.
+    pub fn with_fetch_prune(mut self, fetch_prune: bool) -> Self {
+        self.fetch_prune = fetch_prune;
+        self
+    }
+
+    /// Eliminar ramas locales huérfanas en los repositorios sin `prune_local_branches` propio.
+    pub fn with_prune_local_branches(mut self, prune_local_branches: bool) -> Self {
+        self.prune_local_branches = prune_local_branches;
+        self
+    }
+
+    /// `git gc` cada `days` días (0 = nunca), solo dentro de `window` si se indica.
+    pub fn with_maintenance(mut self, days: u32, window: Option<TimeWindow>) -> Self {
+        self.maintenance_days = days;
+        self.maintenance_window = window;
+        self
+    }
+
+    /// Franjas en las que los repositorios sin `quiet_hours` propio solo descargan cambios.
+    pub fn with_quiet_hours(mut self, quiet_hours: &[TimeWindow]) -> Self {
+        self.quiet_hours = quiet_hours.to_vec();
+        self
+    }
+
+    /// Simular los repositorios sin `dry_run` propio en lugar de sincronizarlos.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Desactivar los repositorios que fallan `max_failures` ciclos seguidos (0 = nunca).
+    pub fn with_max_failures(mut self, max_failures: u32) -> Self {
+        self.max_failures = max_failures;
+        self
+    }
+
+    /// Omitir los repositorios cuyo sistema de archivos tenga menos de `min_free_mb` libres.
+    pub fn with_min_free_mb(mut self, min_free_mb: u64) -> Self {
+        self.min_free_mb = min_free_mb;
+        self
+    }
+
+    /// Raíces fuera de las que no se sincroniza ningún repositorio (vacío = cualquiera).
+    pub fn with_allowed_roots(mut self, allowed_roots: &[String]) -> Self {
+        self.allowed_roots = allowed_roots.to_vec();
+        self
+    }
+
+    pub fn with_notifier(mut self, notifier: &'a Notifier) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
+    /// Registra el resultado de cada repositorio en `events.jsonl`.
+    pub fn with_event_log(mut self, events: EventLog) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Envía los eventos del ciclo a los clientes de `git-sync watch`.
+    pub fn with_event_stream(mut self, stream: EventStream) -> Self {
+        self.stream = Some(stream);
+        self
+    }
+
+    /// Guarda también en `results` el resultado de cada repositorio, para resumirlos al final.
+    pub fn with_results(mut self, results: &'a RefCell<Vec<SyncRecord>>) -> Self {
+        self.results = Some(results);
+        self
+    }
+
+    /// Respeta las pausas de `git-sync pause` guardadas en `pause_file`.
+    pub fn with_pause_file(mut self, pause_file: String) -> Self {
+        self.pause_file = Some(pause_file);
+        self
+    }
+
+    /// Anota en `activity_file` el repositorio y la etapa en curso, para la TUI.
+    pub fn with_activity_file(mut self, activity_file: String) -> Self {
+        self.activity_file = Some(activity_file);
+        self
+    }
+
+    /// Un ciclo de sincronización con `repo_defs`. Los fallos de cada repositorio se registran
+    /// y no interrumpen el ciclo; al final se devuelven juntos en `Error::Sync`.
+    pub fn process_all(&self, repo_defs: Vec<RepoDefinition>) -> Result<(), Error> {
         if repo_defs.is_empty() {
-            self.logger
-                .log_line("⚠️ No se encontraron repositorios en el archivo de configuración.");
-            self.logger
-                .log_line("👉 Agregue las rutas de los repositorios, una por línea.");
-            return Err("No hay repositorios configurados".to_string());
+            self.logger.warn(&messages::no_repos_configured_warning());
+            self.logger.log_line(&messages::no_repos_configured_hint());
+            return Err(Error::NoRepos(messages::no_repos_configured()));
         }
 
-        if self.verbose {
-            self.logger.log_line(&format!(
-                "📦 Se analizarán {} repositorios\n",
-                repo_defs.len()
-            ));
+        // Las pausas se releen en cada ciclo: `pause` y `resume` no necesitan avisar al daemon.
+        let pauses = self
+            .pause_file
+            .as_deref()
+            .map(PauseState::load)
+            .unwrap_or_default();
+        if let Some(pause) = pauses.global() {
+            self.logger
+                .log_line(&messages::cycle_paused(pause.describe(), repo_defs.len()));
+            for repo in &repo_defs {
+                self.record_event(SyncRecord {
+                    result: SyncResult::Paused,
+                    ..SyncRecord::new(
+                        repo.repo_path.clone(),
+                        repo.name().to_string(),
+                        Local::now(),
+                        Duration::ZERO,
+                    )
+                });
+            }
+            return Ok(());
         }
 
+        let _cycle = self.logger.cycle_span(repo_defs.len()).entered();
+        self.logger
+            .log_line(&messages::cycle_start(repo_defs.len()));
+        let cycle_repos = repo_defs.len();
+        self.publish(serde_json::json!({ "event": "cycle_start", "repos": cycle_repos }));
+
         let mut sync_state = SyncStateSnapshot::load(&self.state_file);
         let mut errors: Vec<(String, String)> = Vec::new();
+        let (repo_defs, dependencies) = self.order_by_dependencies(repo_defs);
+        // Repositorios que fallaron en este ciclo, para no procesar los que dependen de ellos.
+        let mut failed: HashSet<String> = HashSet::new();
+
+        for (repo, dependencies) in repo_defs.into_iter().zip(dependencies) {
+            let _repo = self.logger.repo_span(&repo.repo_path).entered();
+            let started_at = Local::now();
+            let started = Instant::now();
+            let record = |result: SyncResult, error: Option<&String>| SyncRecord {
+                result,
+                error: error.cloned(),
+                ..SyncRecord::new(
+                    repo.repo_path.clone(),
+                    repo.name().to_string(),
+                    started_at,
+                    started.elapsed(),
+                )
+            };
+
+            if let Some(dependency) = dependencies.iter().find(|path| failed.contains(*path)) {
+                let err = messages::dependency_failed(&repo.repo_path, dependency);
+                self.logger.log_error(&err);
+                self.record_event(record(SyncResult::Skipped, Some(&err)));
+                failed.insert(repo.repo_path.clone());
+                errors.push((repo.repo_path.clone(), err));
+                continue;
+            }
+            let previous_errors = errors.len();
 
-        for repo in repo_defs {
             if !repo.enabled {
-                if self.verbose {
-                    self.logger.log_line(&format!(
-                        "⏸️ Repositorio pausado (sync desactivado): {}",
-                        repo.repo_path
-                    ));
+                self.logger
+                    .log_line(&messages::repo_disabled(&repo.repo_path));
+                self.record_event(record(SyncResult::Paused, None));
+                continue;
+            }
+
+            if let Some(until) = pauses.repo_until(&repo.repo_path) {
+                self.logger.log_line(&messages::repo_paused_until_log(
+                    pause::format_until(until),
+                    &repo.repo_path,
+                ));
+                self.record_event(record(SyncResult::Paused, None));
+                continue;
+            }
+
+            if let Some(state) = sync_state.get(&repo.repo_path)
+                && state.is_auto_disabled()
+            {
+                self.logger.log_line(&messages::repo_auto_disabled(
+                    state.consecutive_failures,
+                    &repo.repo_path,
+                ));
+                self.record_event(record(SyncResult::Disabled, None));
+                failed.insert(repo.repo_path.clone());
+                continue;
+            }
+
+            if repo.dry_run.unwrap_or(self.dry_run) {
+                match self.preview_single(&repo) {
+                    Ok(()) => self.record_event(record(SyncResult::DryRun, None)),
+                    Err(err) => {
+                        self.logger
+                            .warn(&messages::dry_run_failed(&repo.repo_path, &err));
+                        self.record_event(record(SyncResult::Failed, Some(&err)));
+                        errors.push((repo.repo_path.clone(), err));
+                        failed.insert(repo.repo_path.clone());
+                    }
+                }
+                continue;
+            }
+
+            // Los repositorios bare no despliegan nada: se actualizan también en la franja.
+            if repo.sync_mode != SyncMode::Bare
+                && let Some(window) = repo.quiet_window(&self.quiet_hours, Local::now().time())
+            {
+                match self.hold_single(&repo, window) {
+                    Ok(()) => self.record_event(record(SyncResult::Held, None)),
+                    Err(err) => {
+                        self.record_event(record(SyncResult::Failed, Some(&err)));
+                        errors.push((repo.repo_path.clone(), err));
+                        failed.insert(repo.repo_path.clone());
+                    }
                 }
                 continue;
             }
@@ -58,34 +367,120 @@ impl<'a> RepoProcessor<'a> {
             sync_state.mark_attempt(&repo.repo_path);
 
             match self.process_single(&repo) {
-                Ok((branch, result, last_pulled_commit)) => {
-                    sync_state.mark_success(&repo.repo_path, branch, result, last_pulled_commit);
-                    if self.verbose {
-                        self.logger.log("\n");
+                Ok((mut outcome, deployment)) => {
+                    let head = self.git_repo(&repo).resolve_commit("HEAD").ok();
+                    // Un despliegue que falló en un ciclo anterior se reintenta mientras HEAD
+                    // siga en el commit que no se pudo desplegar.
+                    let retry = head.is_some()
+                        && sync_state
+                            .get(&repo.repo_path)
+                            .and_then(|state| state.pending_deploy.as_ref())
+                            == head.as_ref();
+                    let updated = outcome.last_pulled_commit.is_some();
+                    if retry && !updated {
+                        let commit = short_hash(head.as_deref().unwrap_or_default());
+                        self.logger
+                            .log_line(&messages::deploy_retrying(&repo.repo_path, commit));
+                        outcome.result = messages::result_deploy_retried(commit);
+                    }
+                    let deploys = updated || retry;
+                    let mut kind = if deploys {
+                        SyncEventKind::Updated(outcome.result.clone())
+                    } else {
+                        SyncEventKind::Unchanged
+                    };
+                    let branch = outcome.branch.clone();
+                    sync_state.mark_success(
+                        &repo.repo_path,
+                        outcome.branch,
+                        outcome.result,
+                        outcome.last_pulled_commit,
+                    );
+                    if let Some(deployment) = &deployment {
+                        sync_state.mark_deployment(&repo.repo_path, deployment.clone());
+                    }
+                    let mut deploy_error = None;
+                    if deploys {
+                        match self.run_preset(&repo) {
+                            Ok(()) => {
+                                sync_state.mark_pending_deploy(&repo.repo_path, None);
+                                self.compose_up(&repo);
+                                self.restart_units(&repo);
+                            }
+                            // El código ya se actualizó, pero la aplicación quedó a medio
+                            // desplegar: no se recargan servicios, se informa como fallo y
+                            // se reintenta en los ciclos siguientes.
+                            Err(err) => {
+                                let failures = sync_state.mark_error(&repo.repo_path, err.clone());
+                                sync_state.mark_pending_deploy(&repo.repo_path, head.clone());
+                                self.disable_if_exhausted(&repo, &mut sync_state, failures);
+                                errors.push((repo.repo_path.clone(), err.clone()));
+                                kind = SyncEventKind::Failed(err.clone());
+                                deploy_error = Some(err);
+                            }
+                        }
+                    } else {
+                        // HEAD cambió por otra vía (ej. un checkout manual): ya no hay un
+                        // despliegue pendiente de ese commit.
+                        sync_state.mark_pending_deploy(&repo.repo_path, None);
                     }
+                    let result = match (&deploy_error, deploys) {
+                        (Some(_), _) => SyncResult::Failed,
+                        (None, true) => SyncResult::Updated,
+                        (None, false) => SyncResult::Unchanged,
+                    };
+                    let deployment = deployment.unwrap_or_default();
+                    self.record_event(SyncRecord {
+                        branch: Some(branch),
+                        range: (!deployment.range.is_empty()).then_some(deployment.range),
+                        head: (!deployment.head.is_empty()).then_some(deployment.head),
+                        commits: deployment.commits,
+                        files: deployment.files,
+                        ..record(result, deploy_error.as_ref())
+                    });
+                    self.notify(&repo, kind);
+                    if repo.sync_mode != SyncMode::Bare {
+                        self.maintain_shallow(&repo, &mut sync_state);
+                        self.prune_local_branches(&repo);
+                    }
+                    self.run_maintenance(&repo, &mut sync_state);
+                    self.logger.log("\n");
                 }
                 Err(err) => {
-                    sync_state.mark_error(&repo.repo_path, err.clone());
+                    let failures = sync_state.mark_error(&repo.repo_path, err.clone());
+                    self.record_event(record(SyncResult::Failed, Some(&err)));
+                    self.notify(&repo, SyncEventKind::Failed(err.clone()));
                     errors.push((repo.repo_path.clone(), err.clone()));
-                    self.logger.log_line(&format!(
-                        "⚠️ Repositorio omitido {} debido a un error: {}",
-                        repo.repo_path, err
-                    ));
+                    if is_timeout_error(&err) {
+                        self.logger
+                            .warn(&messages::repo_timed_out(&repo.repo_path, &err));
+                    } else {
+                        self.logger
+                            .warn(&messages::repo_failed(&repo.repo_path, &err));
+                    }
+
+                    self.disable_if_exhausted(&repo, &mut sync_state, failures);
                 }
             }
+
+            if errors.len() > previous_errors {
+                failed.insert(repo.repo_path);
+            }
         }
 
+        if let Some(activity_file) = &self.activity_file {
+            Activity::clear(activity_file);
+        }
         if let Err(state_err) = sync_state.save(&self.state_file) {
-            self.logger.log_line(&format!(
-                "⚠️ No se pudo actualizar el archivo de estado de sincronización: {}",
-                state_err
-            ));
+            self.logger.warn(&messages::state_save_failed(state_err));
         }
 
-        if self.verbose {
-            self.logger
-                .log_line("🎉 Todos los repositorios fueron procesados.");
-        }
+        self.logger.log_line(&messages::cycle_done());
+        self.publish(serde_json::json!({
+            "event": "cycle_end",
+            "repos": cycle_repos,
+            "failed": errors.len(),
+        }));
 
         if errors.is_empty() {
             Ok(())
@@ -95,119 +490,1716 @@ impl<'a> RepoProcessor<'a> {
                 .map(|(repo, err)| format!("- {} => {}", repo, err))
                 .collect::<Vec<_>>()
                 .join("\n");
-            Err(format!(
-                "{} repositorios presentaron errores durante la sincronización:\n{}",
-                errors.len(),
-                details
-            ))
+            let timeouts = errors
+                .iter()
+                .filter(|(_, err)| is_timeout_error(err))
+                .count();
+            let timeout_note = if timeouts > 0 {
+                messages::timeout_note(timeouts)
+            } else {
+                String::new()
+            };
+            Err(Error::Sync {
+                message: messages::cycle_errors(errors.len(), timeout_note, details),
+                failures: errors,
+            })
         }
     }
 
-    fn process_single(
-        &self,
-        repo: &RepoDefinition,
-    ) -> Result<(String, String, Option<String>), String> {
-        if self.verbose {
-            self.logger
-                .log_line("==========================================");
-            self.logger
-                .log_line(&format!("🔄 Procesando repositorio: {}", repo.repo_path));
-            self.logger
-                .log_line("==========================================");
+    /// Abre el span de `phase` y la anota en el archivo de actividad. Si no se puede escribir
+    /// solo se pierde la indicación en la TUI, así que el error se ignora.
+    fn enter_phase(&self, repo_def: &RepoDefinition, phase: Phase) -> EnteredSpan {
+        if let Some(activity_file) = &self.activity_file {
+            let _ = Activity::new(&repo_def.repo_path, phase).save(activity_file);
         }
+        self.publish(serde_json::json!({
+            "event": "phase",
+            "repo": repo_def.repo_path,
+            "name": repo_def.name(),
+            "phase": phase,
+        }));
+        self.logger.phase_span(phase).entered()
+    }
 
-        self.validate_repo(&repo.repo_path)?;
-        let outcome = self.check_and_pull(&repo.repo_path)?;
-
-        Ok((outcome.branch, outcome.result, outcome.last_pulled_commit))
+    /// Rechaza rutas fuera de `allowed_roots` o de directorios del sistema.
+    fn check_path(&self, repo_def: &RepoDefinition) -> Result<(), String> {
+        check_repo_path(&repo_def.repo_path, &self.allowed_roots).map_err(|e| {
+            let msg = format!("🚫 {}", e);
+            self.logger.log_error(&msg);
+            msg
+        })
     }
 
-    fn validate_repo(&self, repo_path: &str) -> Result<(), String> {
-        if !Path::new(repo_path).exists() {
-            let msg = format!("❌ La ruta no existe: {}", repo_path);
+    /// Con el disco lleno un pull o un clon se interrumpe a medias y deja el árbol de trabajo
+    /// desactualizado en parte o un `index.lock` que bloquea los ciclos siguientes.
+    fn check_free_space(&self, repo_def: &RepoDefinition) -> Result<(), String> {
+        if self.min_free_mb == 0 {
+            return Ok(());
+        }
+
+        // Antes del clon la ruta aún no existe: se mide el directorio existente más cercano.
+        let path = Path::new(&repo_def.repo_path)
+            .ancestors()
+            .find(|path| path.exists())
+            .unwrap_or(Path::new("/"));
+        let available = available_space_mb(path)?;
+        if available < self.min_free_mb {
+            let msg = messages::low_disk_space(
+                &repo_def.repo_path,
+                available,
+                path.display(),
+                self.min_free_mb,
+            );
             self.logger.log_error(&msg);
             return Err(msg);
         }
+        Ok(())
+    }
 
-        if !Path::new(&format!("{}/.git", repo_path)).exists() {
-            let msg = format!(
-                "❌ El directorio no es un repositorio Git válido: {}",
-                repo_path
-            );
+    /// Ordena los repositorios para que cada uno se procese después de los indicados en su
+    /// opción `after`, conservando el orden del archivo en lo demás. Devuelve, para cada
+    /// repositorio, las rutas de sus dependencias dentro de esta lista.
+    fn order_by_dependencies(
+        &self,
+        repo_defs: Vec<RepoDefinition>,
+    ) -> (Vec<RepoDefinition>, Vec<Vec<String>>) {
+        let mut dependencies: Vec<Vec<usize>> = Vec::with_capacity(repo_defs.len());
+        for (index, repo) in repo_defs.iter().enumerate() {
+            let mut resolved = Vec::new();
+            for reference in &repo.after {
+                let matches = repo_defs
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, candidate)| {
+                        *other != index && candidate.matches_reference(reference)
+                    })
+                    .map(|(other, _)| other)
+                    .collect::<Vec<_>>();
+                if matches.is_empty() {
+                    // Puede estar en otro grupo (`sync --group`): no se considera un error.
+                    self.logger.log_line(&messages::dependency_not_in_cycle(
+                        reference,
+                        &repo.repo_path,
+                    ));
+                }
+                resolved.extend(matches);
+            }
+            dependencies.push(resolved);
+        }
+
+        let mut order = Vec::with_capacity(repo_defs.len());
+        let mut placed = vec![false; repo_defs.len()];
+        while order.len() < repo_defs.len() {
+            let next = (0..repo_defs.len())
+                .find(|&i| !placed[i] && dependencies[i].iter().all(|&d| placed[d]));
+            let next = next.unwrap_or_else(|| {
+                // Dependencia circular: se rompe por el primer repositorio pendiente.
+                let first = (0..repo_defs.len()).find(|&i| !placed[i]).unwrap_or(0);
+                self.logger.log_error(&messages::circular_dependencies(
+                    &repo_defs[first].repo_path,
+                ));
+                first
+            });
+            placed[next] = true;
+            order.push(next);
+        }
+
+        let mut rank = vec![0; repo_defs.len()];
+        for (position, &index) in order.iter().enumerate() {
+            rank[index] = position;
+        }
+        let paths = dependencies
+            .iter()
+            .map(|deps| {
+                deps.iter()
+                    .map(|&d| repo_defs[d].repo_path.clone())
+                    .collect()
+            })
+            .collect::<Vec<Vec<String>>>();
+        let mut ranked = repo_defs
+            .into_iter()
+            .zip(paths)
+            .enumerate()
+            .collect::<Vec<_>>();
+        ranked.sort_by_key(|(index, _)| rank[*index]);
+        ranked.into_iter().map(|(_, entry)| entry).unzip()
+    }
+
+    /// Ejecuta los pasos del preset de despliegue en orden. Se detiene en el primer comando
+    /// que falla, porque los siguientes dependen de él (ej. migrar sin dependencias).
+    fn run_preset(&self, repo_def: &RepoDefinition) -> Result<(), String> {
+        let Some(preset) = repo_def.preset else {
+            return Ok(());
+        };
+        if repo_def.sync_mode == SyncMode::Bare {
+            self.logger
+                .warn(&messages::preset_ignored_bare(&repo_def.repo_path));
+            return Ok(());
+        }
+        let _phase = self.enter_phase(repo_def, Phase::Build);
+
+        for step in repo_def.deploy_steps() {
+            let timeout = if step.is_build() {
+                self.timeouts.build
+            } else {
+                self.timeouts.hook
+            };
+            for (program, args) in step.commands() {
+                if let Err(e) = self.run_hook(repo_def, program, &args, timeout) {
+                    let msg = messages::preset_step_failed(
+                        step.as_str(),
+                        preset.as_str(),
+                        &repo_def.repo_path,
+                        e,
+                    );
+                    self.logger.log_error(&msg);
+                    return Err(msg);
+                }
+            }
+        }
+
+        if let Some(output) = repo_def.preset_output_dir()
+            && !Path::new(&repo_def.repo_path).join(&output).is_dir()
+        {
+            let msg = messages::preset_output_missing(preset.as_str(), output, &repo_def.repo_path);
             self.logger.log_error(&msg);
             return Err(msg);
         }
 
+        self.logger.log_line(&messages::preset_applied(
+            preset.as_str(),
+            &repo_def.repo_path,
+        ));
         Ok(())
     }
 
-    fn check_and_pull(&self, repo_path: &str) -> Result<PullOutcome, String> {
-        let repo = GitRepo::new(repo_path.to_string());
+    /// Reconstruye las imágenes y recrea los contenedores del proyecto Compose del
+    /// repositorio (`compose.yaml` o `docker-compose.yml` en la raíz del árbol de trabajo).
+    fn compose_up(&self, repo_def: &RepoDefinition) {
+        let Some(engine) = repo_def.compose else {
+            return;
+        };
+        if repo_def.sync_mode == SyncMode::Bare {
+            self.logger
+                .warn(&messages::compose_ignored_bare(&repo_def.repo_path));
+            return;
+        }
+        let _phase = self.enter_phase(repo_def, Phase::Build);
+
+        match self.run_hook(
+            repo_def,
+            engine.as_str(),
+            &["compose", "up", "-d", "--build"],
+            self.timeouts.build,
+        ) {
+            Ok(()) => self
+                .logger
+                .log_line(&messages::compose_done(&repo_def.repo_path)),
+            Err(e) => self.logger.log_error(&messages::compose_failed(
+                engine.as_str(),
+                &repo_def.repo_path,
+                e,
+            )),
+        }
+    }
+
+    /// Ejecuta un comando de despliegue en el árbol de trabajo del repositorio. Cada línea de
+    /// su salida (stdout y stderr) se registra en el log con el nombre del programa como
+    /// prefijo; si falla, el error incluye las últimas líneas. Si supera `timeout` segundos
+    /// (0 = sin límite) se mata junto con los procesos que haya lanzado. Recibe el entorno de
+    /// `env_file` y, encima, las variables `env.NOMBRE=` del repositorio.
+    fn run_hook(
+        &self,
+        repo_def: &RepoDefinition,
+        program: &str,
+        args: &[&str],
+        timeout: u64,
+    ) -> Result<(), String> {
+        let command_line = format!("{} {}", program, args.join(" "));
+        self.logger
+            .log_line(&messages::hook_running(&command_line, &repo_def.repo_path));
+
+        let mut child = platform::new_process_group(
+            Command::new(program)
+                .args(args)
+                .current_dir(&repo_def.repo_path)
+                .envs(self.env.iter().cloned())
+                .envs(repo_def.env.iter().cloned())
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped()),
+        )
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+        let tail = Mutex::new(VecDeque::with_capacity(HOOK_ERROR_LINES));
+        let forward = |stream: Box<dyn Read + Send>| {
+            for line in BufReader::new(stream).lines().map_while(Result::ok) {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                self.logger.log_line(&format!("   [{}] {}", program, line));
+                let mut tail = tail.lock().unwrap_or_else(|e| e.into_inner());
+                if tail.len() == HOOK_ERROR_LINES {
+                    tail.pop_front();
+                }
+                tail.push_back(line.trim().to_string());
+            }
+        };
+        let stdout = child.stdout.take();
+        let stderr = child.stderr.take();
+        let timeout = (timeout > 0).then(|| Duration::from_secs(timeout));
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let status = thread::scope(|scope| {
+            if let Some(stderr) = stderr {
+                scope.spawn(|| forward(Box::new(stderr)));
+            }
+            if let Some(stdout) = stdout {
+                scope.spawn(|| forward(Box::new(stdout)));
+            }
+            loop {
+                match child.try_wait() {
+                    Ok(Some(status)) => break Ok(status),
+                    Ok(None) if deadline.is_some_and(|deadline| Instant::now() >= deadline) => {
+                        platform::kill_process_group(&mut child);
+                        break Err(timeout_error(&command_line, timeout.unwrap_or_default()));
+                    }
+                    Ok(None) => thread::sleep(Duration::from_millis(100)),
+                    Err(e) => break Err(e.to_string()),
+                }
+            }
+        })?;
+        if status.success() {
+            return Ok(());
+        }
+
+        let tail = tail.into_inner().unwrap_or_else(|e| e.into_inner());
+        if tail.is_empty() {
+            return Err(messages::hook_exit(status));
+        }
+        Err(Vec::from(tail).join(" | "))
+    }
+
+    /// Aplica los cambios a los servicios que usan el repositorio con
+    /// `systemctl reload-or-restart`: se recargan si lo admiten (ej. php-fpm) y si no
+    /// se reinician (ej. workers de colas). Un fallo se registra sin anular la sincronización.
+    fn restart_units(&self, repo_def: &RepoDefinition) {
+        let _phase =
+            (!repo_def.restart_units.is_empty()).then(|| self.enter_phase(repo_def, Phase::Deploy));
+        for unit in &repo_def.restart_units {
+            let result = self.run_hook(
+                repo_def,
+                "systemctl",
+                &["reload-or-restart", "--", unit],
+                self.timeouts.hook,
+            );
+
+            match result {
+                Ok(()) => self.logger.log_line(&messages::unit_restarted(unit)),
+                Err(e) => self.logger.log_error(&messages::unit_restart_failed(
+                    unit,
+                    &repo_def.repo_path,
+                    e,
+                )),
+            }
+        }
+    }
+
+    fn record_event(&self, mut record: SyncRecord) {
+        record.error = record.error.map(|error| self.logger.redact(&error));
+        if let Some(results) = self.results {
+            results.borrow_mut().push(record.clone());
+        }
+        if let Some(events) = &self.events {
+            events.record(&record);
+        }
+        if self.stream.is_some()
+            && let Ok(serde_json::Value::Object(mut event)) = serde_json::to_value(&record)
+        {
+            event.insert("event".to_string(), "repo".into());
+            self.publish(serde_json::Value::Object(event));
+        }
+    }
+
+    /// Envía `event` a los clientes de `git-sync watch`, con la hora a la que ocurrió.
+    fn publish(&self, mut event: serde_json::Value) {
+        let Some(stream) = &self.stream else {
+            return;
+        };
+        if let Some(fields) = event.as_object_mut() {
+            fields
+                .entry("timestamp")
+                .or_insert_with(|| Local::now().to_rfc3339().into());
+        }
+        stream.publish(&event);
+    }
+
+    /// Desactiva el repositorio si acumula `max_failures` fallos seguidos, sea al
+    /// sincronizar o al desplegar.
+    fn disable_if_exhausted(
+        &self,
+        repo: &RepoDefinition,
+        sync_state: &mut SyncStateSnapshot,
+        failures: u32,
+    ) {
+        let max_failures = repo.max_failures.unwrap_or(self.max_failures);
+        if max_failures > 0 && failures >= max_failures {
+            sync_state.mark_auto_disabled(&repo.repo_path);
+            self.logger
+                .log_error(&messages::repo_now_disabled(&repo.repo_path, failures));
+        }
+    }
+
+    fn notify(&self, repo: &RepoDefinition, kind: SyncEventKind) {
+        let Some(notifier) = self.notifier else {
+            return;
+        };
+
+        let kind = match kind {
+            SyncEventKind::Failed(err) => SyncEventKind::Failed(self.logger.redact(&err)),
+            kind => kind,
+        };
+
+        let event = SyncEvent {
+            name: repo.name(),
+            repo_path: &repo.repo_path,
+            groups: &repo.groups,
+            kind,
+        };
+        for err in notifier.dispatch(&event) {
+            self.logger
+                .warn(&messages::notification_failed(&repo.repo_path, err));
+        }
+    }
+
+    fn process_single(
+        &self,
+        repo: &RepoDefinition,
+    ) -> Result<(PullOutcome, Option<Deployment>), String> {
+        self.logger
+            .log_line("==========================================");
+        self.logger
+            .log_line(&messages::processing_repo(repo.name(), &repo.repo_path));
+        self.logger
+            .log_line("==========================================");
+
+        self.check_path(repo)?;
+        self.check_free_space(repo)?;
+
+        let (outcome, deployment) = match &repo.clone_url {
+            Some(url) if needs_clone(&repo.repo_path) => {
+                let _phase = self.enter_phase(repo, Phase::Fetch);
+                (self.clone_repo(repo, url)?, None)
+            }
+            _ if repo.sync_mode == SyncMode::Bare => {
+                let _phase = self.enter_phase(repo, Phase::Fetch);
+                let git_repo = self.git_repo(repo);
+                self.validate_repo(&git_repo, repo)?;
+                (self.update_bare(&git_repo)?, None)
+            }
+            _ => {
+                let git_repo = self.git_repo(repo);
+                self.validate_repo(&git_repo, repo)?;
+                let previous_head = git_repo.resolve_commit("HEAD").ok();
+                let outcome = self.check_and_pull(repo)?;
+                let deployment =
+                    previous_head.and_then(|previous| self.report_deployment(&git_repo, &previous));
+                (outcome, deployment)
+            }
+        };
+
+        if let Some(mirror) = &repo.mirror_to {
+            if repo.sync_mode == SyncMode::Bare {
+                self.logger
+                    .warn(&messages::mirror_ignored_bare(mirror, &repo.repo_path));
+            } else {
+                let _phase = self.enter_phase(repo, Phase::Deploy);
+                self.push_mirror(repo, mirror)?;
+            }
+        }
+
+        Ok((outcome, deployment))
+    }
+
+    /// Modo simulación (`dry_run`): descarga y consulta el estado como un ciclo normal, pero
+    /// solo registra lo que se haría. No modifica el árbol de trabajo ni las ramas locales,
+    /// no actualiza el archivo de estado y no envía notificaciones.
+    fn preview_single(&self, repo_def: &RepoDefinition) -> Result<(), String> {
+        self.logger
+            .log_line("==========================================");
+        self.logger.log_line(&messages::simulating_repo(
+            repo_def.name(),
+            &repo_def.repo_path,
+        ));
+        self.logger
+            .log_line("==========================================");
+        let _phase = self.enter_phase(repo_def, Phase::Fetch);
+
+        let actions = self.preview_actions(repo_def)?;
+        self.logger
+            .log_line(&messages::simulation_of(&repo_def.repo_path));
+        if actions.is_empty() {
+            self.logger.log_line(&messages::simulation_nothing());
+        }
+        for action in &actions {
+            self.logger.log_line(&format!("   - {}", action));
+        }
+        Ok(())
+    }
 
-        if self.verbose {
+    /// Dentro de una franja de `quiet_hours` solo se descargan los cambios y se registra
+    /// qué queda pendiente; se aplica en el primer ciclo después de la franja. El archivo
+    /// de estado no se modifica.
+    fn hold_single(&self, repo_def: &RepoDefinition, window: TimeWindow) -> Result<(), String> {
+        let window = String::from(window);
+        let _phase = self.enter_phase(repo_def, Phase::Fetch);
+        self.check_path(repo_def)?;
+        self.check_free_space(repo_def)?;
+        if repo_def.clone_url.is_some() && needs_clone(&repo_def.repo_path) {
             self.logger
-                .log_line("🔍 Verificando el estado del remoto...");
+                .log_line(&messages::held_clone(&window, &repo_def.repo_path));
+            return Ok(());
+        }
+
+        let repo = self.git_repo(repo_def);
+        self.validate_repo(&repo, repo_def)?;
+        let fail = |msg: String| {
+            self.logger.log_error(&msg);
+            msg
+        };
+        let pending = match &repo_def.track_tag {
+            Some(pattern) => {
+                repo.fetch_tags()
+                    .map_err(|e| fail(messages::tags_fetch_failed(e)))?;
+                let tags = repo
+                    .list_tags(pattern)
+                    .map_err(|e| fail(messages::tags_list_failed(e)))?;
+                let head = repo.resolve_commit("HEAD").ok();
+                semver::highest(&tags)
+                    .filter(|tag| repo.resolve_commit(&format!("refs/tags/{}", tag)).ok() != head)
+                    .map(messages::pending_tag)
+            }
+            None => {
+                repo.fetch()
+                    .map_err(|e| fail(messages::git_fetch_failed(e)))?;
+                let behind = repo
+                    .count_commits_behind(&repo.get_default_branch())
+                    .map_err(|e| fail(messages::repo_status_failed(e)))?;
+                (behind > 0).then(|| messages::commit_count(behind))
+            }
+        };
+
+        match pending {
+            Some(pending) => self.logger.log_line(&messages::held_pending(
+                &window,
+                &repo_def.repo_path,
+                pending,
+            )),
+            None => self
+                .logger
+                .log_line(&messages::held_nothing(&window, &repo_def.repo_path)),
+        }
+        Ok(())
+    }
+
+    fn preview_actions(&self, repo_def: &RepoDefinition) -> Result<Vec<String>, String> {
+        self.check_path(repo_def)?;
+        self.check_free_space(repo_def)?;
+        let bare = repo_def.sync_mode == SyncMode::Bare;
+        if let Some(url) = &repo_def.clone_url
+            && needs_clone(&repo_def.repo_path)
+        {
+            let command = if bare {
+                "git clone --mirror"
+            } else {
+                "git clone"
+            };
+            return Ok(vec![messages::would_clone(
+                url,
+                &repo_def.repo_path,
+                command,
+            )]);
+        }
+
+        let repo = self.git_repo(repo_def);
+        self.validate_repo(&repo, repo_def)?;
+        if bare {
+            return Ok(vec![messages::would_update_remotes()]);
         }
 
         if let Err(e) = repo.fetch() {
-            let msg = format!("❌ No se pudo ejecutar `git fetch`: {}", e);
+            let msg = messages::git_fetch_failed(e);
             self.logger.log_error(&msg);
             return Err(msg);
         }
 
-        let branch = repo.get_default_branch();
-        if self.verbose {
-            self.logger
-                .log_line(&format!("Se utilizará la rama: {}", branch));
+        let mut actions = Vec::new();
+        let changes = match &repo_def.track_tag {
+            Some(pattern) => self.preview_tag(&repo, repo_def, pattern, &mut actions)?,
+            None => self.preview_branch(&repo, repo_def, &mut actions)?,
+        };
+
+        if changes && let Some(preset) = repo_def.preset {
+            let steps = repo_def.deploy_steps();
+            let steps = steps.iter().map(|step| step.as_str());
+            actions.push(messages::would_apply_preset(
+                preset.as_str(),
+                steps.collect::<Vec<_>>().join(", "),
+            ));
+        }
+        if changes && let Some(engine) = repo_def.compose {
+            actions.push(messages::would_compose(engine.as_str()));
+        }
+        if changes && !repo_def.restart_units.is_empty() {
+            actions.push(messages::would_restart_units(
+                repo_def.restart_units.join(", "),
+            ));
+        }
+        if let Some(mirror) = &repo_def.mirror_to {
+            actions.push(messages::would_mirror(mirror));
         }
+        Ok(actions)
+    }
 
-        match repo.count_commits_behind(&branch) {
-            Ok(0) => {
-                if self.verbose {
-                    self.logger
-                        .log_line("✅ El repositorio ya está actualizado.");
+    /// Equivalente de `track_tag` en simulación. Devuelve si HEAD cambiaría.
+    fn preview_tag(
+        &self,
+        repo: &GitRepo,
+        repo_def: &RepoDefinition,
+        pattern: &str,
+        actions: &mut Vec<String>,
+    ) -> Result<bool, String> {
+        let fail = |msg: String| {
+            self.logger.log_error(&msg);
+            msg
+        };
+
+        repo.fetch_tags()
+            .map_err(|e| fail(messages::tags_fetch_failed(e)))?;
+        let tags = repo
+            .list_tags(pattern)
+            .map_err(|e| fail(messages::tags_list_failed(e)))?;
+        let Some(tag) = semver::highest(&tags) else {
+            return Err(fail(messages::no_semver_tag(pattern)));
+        };
+
+        let tag_ref = format!("refs/tags/{}", tag);
+        let target = repo
+            .resolve_commit(&tag_ref)
+            .map_err(|e| fail(messages::tag_resolve_failed(tag, e)))?;
+        if repo.resolve_commit("HEAD").ok().as_deref() == Some(target.as_str()) {
+            return Ok(false);
+        }
+
+        self.verify_signature(repo, repo_def, &tag_ref)?;
+        if !self.preview_dirty(repo, repo_def, actions)? {
+            return Ok(false);
+        }
+        actions.push(messages::would_checkout_tag(
+            tag,
+            self.preview_files(repo, &tag_ref),
+        ));
+        Ok(true)
+    }
+
+    /// Equivalente de `check_and_pull` en simulación. Devuelve si HEAD cambiaría.
+    fn preview_branch(
+        &self,
+        repo: &GitRepo,
+        repo_def: &RepoDefinition,
+        actions: &mut Vec<String>,
+    ) -> Result<bool, String> {
+        let fail = |msg: String| {
+            self.logger.log_error(&msg);
+            msg
+        };
+        let status_error = |e: Error| fail(messages::repo_status_failed(e));
+
+        let branch = repo.get_default_branch();
+        let target = repo.remote_branch(&branch);
+        match repo.current_branch() {
+            Ok(Some(current)) if repo_def.branch.is_some() && current != branch => {
+                actions.push(messages::would_switch_branch(current, &branch));
+            }
+            Ok(Some(_)) => {}
+            Ok(None) => match repo_def.on_detached.unwrap_or(self.detached_policy) {
+                DetachedPolicy::Skip => {
+                    actions.push(messages::would_skip_detached());
+                    return Ok(false);
                 }
-                Ok(PullOutcome {
-                    branch,
-                    result: "Sin cambios remotos".to_string(),
-                    last_pulled_commit: None,
-                })
+                DetachedPolicy::Reattach => {
+                    let ahead = repo.count_commits_ahead(&branch).map_err(status_error)?;
+                    if ahead > 0 {
+                        actions.push(messages::would_skip_detached_commits(ahead, &target));
+                        return Ok(false);
+                    }
+                    actions.push(messages::would_reattach(&branch));
+                }
+            },
+            Err(e) => {
+                return Err(fail(messages::current_branch_failed(e)));
             }
-            Ok(count) => {
-                if self.verbose {
-                    self.logger.log_line(&format!(
-                        "⬇️ El remoto tiene {} confirmaciones nuevas. Aplicando cambios...",
-                        count
-                    ));
+        }
+
+        let behind = repo.count_commits_behind(&branch).map_err(status_error)?;
+        if behind > 0 {
+            self.verify_signature(repo, repo_def, &target)?;
+        }
+
+        match repo_def.sync_mode {
+            SyncMode::Pull => {
+                if behind == 0 {
+                    if repo_def.clean {
+                        self.preview_clean(repo, repo_def, actions)?;
+                    }
+                    return Ok(false);
                 }
 
-                match repo.pull(&branch) {
-                    Ok(output) => {
-                        if self.verbose {
-                            self.logger.log_line(&format!(
-                                "📥 Resultado de `git pull`:\n{}",
-                                output.trim()
-                            ));
+                let ahead = repo.count_commits_ahead(&branch).map_err(status_error)?;
+                let ff_only = repo_def.ff_only.unwrap_or(self.ff_only);
+                let strategy = repo_def.pull_strategy;
+                if ahead > 0 && (strategy == PullStrategy::Rebase || !ff_only) {
+                    if !self.preview_dirty(repo, repo_def, actions)? {
+                        return Ok(false);
+                    }
+                    actions.push(messages::would_integrate(behind, ahead, strategy.as_str()));
+                } else if ahead > 0 {
+                    let divergence = messages::divergence(&target, ahead, behind);
+                    match repo_def.on_divergence.unwrap_or(self.divergence_policy) {
+                        DivergencePolicy::Skip => {
+                            actions.push(messages::would_skip_divergence(divergence));
+                            return Ok(false);
+                        }
+                        DivergencePolicy::Reset => {
+                            actions.push(messages::would_reset_divergence(divergence, &target))
+                        }
+                        DivergencePolicy::Rescue => {
+                            actions.push(messages::would_rescue_divergence(divergence, &target))
                         }
-                        let pulled_commit = repo.head_commit_summary().ok();
-                        Ok(PullOutcome {
-                            branch,
-                            result: format!("Pull aplicado: {} commit(s)", count),
-                            last_pulled_commit: pulled_commit,
-                        })
                     }
-                    Err(e) => {
-                        let msg = format!("❌ No se pudo ejecutar `git pull`: {}", e);
-                        self.logger.log_error(&msg);
-                        Err(msg)
+                } else {
+                    if !self.preview_dirty(repo, repo_def, actions)? {
+                        return Ok(false);
                     }
+                    actions.push(messages::would_pull(
+                        behind,
+                        self.preview_files(repo, &target),
+                    ));
+                }
+                if repo_def.clean {
+                    self.preview_clean(repo, repo_def, actions)?;
                 }
+                Ok(true)
             }
-            Err(e) => {
-                let msg = format!("❌ No se pudo consultar el estado del repositorio: {}", e);
-                self.logger.log_error(&msg);
-                Err(msg)
+            SyncMode::Reset => {
+                let head = repo.resolve_commit("HEAD").ok();
+                let remote_head = repo.resolve_commit(&target).ok();
+                let modified = repo.modified_files().map_err(status_error)?;
+                let moves = head != remote_head;
+                if moves || !modified.is_empty() {
+                    actions.push(messages::would_reset(
+                        &target,
+                        behind,
+                        self.preview_files(repo, &target),
+                    ));
+                }
+                if !modified.is_empty() {
+                    actions.push(messages::would_discard_modified(
+                        modified.len(),
+                        list_files(&modified),
+                    ));
+                }
+                if repo_def.clean {
+                    self.preview_clean(repo, repo_def, actions)?;
+                }
+                Ok(moves)
             }
+            SyncMode::Bare => unreachable!("los repositorios bare se simulan en preview_actions"),
         }
     }
+
+    /// Qué haría la política `on_dirty` con los cambios sin confirmar. Devuelve `false`
+    /// si la sincronización se detendría.
+    fn preview_dirty(
+        &self,
+        repo: &GitRepo,
+        repo_def: &RepoDefinition,
+        actions: &mut Vec<String>,
+    ) -> Result<bool, String> {
+        let files = repo.modified_files().map_err(|e| {
+            let msg = messages::worktree_status_failed(e);
+            self.logger.log_error(&msg);
+            msg
+        })?;
+        if files.is_empty() {
+            return Ok(true);
+        }
+
+        let dirty = messages::dirty_files(files.len(), list_files(&files));
+        match repo_def.on_dirty.unwrap_or(self.dirty_policy) {
+            DirtyPolicy::Abort => {
+                actions.push(messages::would_skip_dirty(dirty));
+                Ok(false)
+            }
+            DirtyPolicy::Stash => {
+                actions.push(messages::would_stash_dirty(dirty));
+                Ok(true)
+            }
+            DirtyPolicy::Discard => {
+                actions.push(messages::would_discard_dirty(dirty));
+                Ok(true)
+            }
+        }
+    }
+
+    fn preview_clean(
+        &self,
+        repo: &GitRepo,
+        repo_def: &RepoDefinition,
+        actions: &mut Vec<String>,
+    ) -> Result<(), String> {
+        let files = repo
+            .untracked_files(&repo_def.clean_excludes())
+            .map_err(|e| {
+                let msg = messages::git_clean_failed(e);
+                self.logger.log_error(&msg);
+                msg
+            })?;
+        if !files.is_empty() {
+            actions.push(messages::would_clean(files.len(), list_files(&files)));
+        }
+        Ok(())
+    }
+
+    /// Número de archivos que cambiarían al mover HEAD a `target`, para la simulación.
+    fn preview_files(&self, repo: &GitRepo, target: &str) -> String {
+        match repo.diff_files("HEAD", target) {
+            Ok(files) => messages::modified_file_count(files.len()),
+            Err(_) => messages::modified_files_unknown(),
+        }
+    }
+
+    /// Si HEAD cambió, registra en el log qué commits llegaron (`anterior..nuevo`, hash,
+    /// autor y asunto) para poder responder qué se desplegó.
+    fn report_deployment(&self, repo: &GitRepo, previous_head: &str) -> Option<Deployment> {
+        let head = repo.resolve_commit("HEAD").ok()?;
+        if head == previous_head {
+            return None;
+        }
+
+        let range = format!("{}..{}", short_hash(previous_head), short_hash(&head));
+        let mut commits = repo
+            .commits_between(previous_head, &head, DEPLOYED_COMMITS_LIMIT + 1)
+            .unwrap_or_else(|e| {
+                self.logger.warn(&messages::commits_list_failed(&range, e));
+                Vec::new()
+            });
+        let truncated = commits.len() > DEPLOYED_COMMITS_LIMIT;
+        commits.truncate(DEPLOYED_COMMITS_LIMIT);
+
+        self.logger.record_commits(&range);
+        self.logger.log_line(&messages::deployed(&range, &head));
+        for commit in &commits {
+            self.logger.log_line(&format!("   - {}", commit));
+        }
+        if truncated {
+            self.logger.log_line("   - ...");
+        }
+
+        let files = repo.diff_files(previous_head, &head).unwrap_or_default();
+
+        Some(Deployment {
+            range,
+            head,
+            commits,
+            files,
+        })
+    }
+
+    fn git_repo(&self, repo_def: &RepoDefinition) -> GitRepo {
+        GitRepo::new(repo_def.repo_path.clone())
+            .with_env(&self.env)
+            .with_env(&repo_def.env)
+            .with_ssh_key(repo_def.ssh_key.as_deref())
+            .with_credentials(&self.credentials)
+            .with_backend(self.git_backend)
+            .with_timeouts(&self.timeouts)
+            .with_depth(self.fetch_depth_for(repo_def))
+            .with_remote(repo_def.remote_name())
+            .with_branch(repo_def.branch.as_deref())
+            .with_prune(self.fetch_prune_for(repo_def) || self.prune_local_branches_for(repo_def))
+            .with_logger(self.logger)
+    }
+
+    fn fetch_prune_for(&self, repo_def: &RepoDefinition) -> bool {
+        repo_def.fetch_prune.unwrap_or(self.fetch_prune)
+    }
+
+    fn prune_local_branches_for(&self, repo_def: &RepoDefinition) -> bool {
+        repo_def
+            .prune_local_branches
+            .unwrap_or(self.prune_local_branches)
+    }
+
+    fn fetch_depth_for(&self, repo_def: &RepoDefinition) -> u32 {
+        repo_def.fetch_depth.unwrap_or(self.fetch_depth)
+    }
+
+    /// Los fetch habituales extienden el historial superficial; una vez al día, con HEAD
+    /// ya sincronizado, se recorta de nuevo a `fetch_depth` y se compacta el repositorio.
+    fn maintain_shallow(&self, repo_def: &RepoDefinition, sync_state: &mut SyncStateSnapshot) {
+        if self.fetch_depth_for(repo_def) == 0 {
+            return;
+        }
+
+        let now = Utc::now().timestamp();
+        let last = sync_state
+            .get(&repo_def.repo_path)
+            .and_then(|state| state.last_maintenance_ts);
+        if last.is_some_and(|last| now - last < SHALLOW_MAINTENANCE_INTERVAL_SECS) {
+            return;
+        }
+
+        self.logger.log_line(&messages::shallow_pruning());
+        match self.git_repo(repo_def).prune_history() {
+            Ok(()) => sync_state.mark_maintenance(&repo_def.repo_path),
+            Err(e) => self
+                .logger
+                .warn(&messages::shallow_prune_failed(&repo_def.repo_path, e)),
+        }
+    }
+
+    /// Mantenimiento periódico (`git gc`) tras una sincronización correcta, dentro de
+    /// `maintenance_window`. Los repositorios superficiales ya se compactan a diario.
+    fn run_maintenance(&self, repo_def: &RepoDefinition, sync_state: &mut SyncStateSnapshot) {
+        if self.maintenance_days == 0 || self.fetch_depth_for(repo_def) > 0 {
+            return;
+        }
+
+        let now = Local::now();
+        if self
+            .maintenance_window
+            .is_some_and(|window| !window.contains(now.time()))
+        {
+            return;
+        }
+
+        let interval = i64::from(self.maintenance_days) * 86400;
+        let last = sync_state
+            .get(&repo_def.repo_path)
+            .and_then(|state| state.last_maintenance_ts);
+        if last.is_some_and(|last| now.timestamp() - last < interval) {
+            return;
+        }
+
+        let repo = self.git_repo(repo_def);
+        let before = repo.object_store_size();
+        let started = Instant::now();
+        match repo.gc() {
+            Ok(()) => {
+                sync_state.mark_maintenance(&repo_def.repo_path);
+                self.logger.log_line(&messages::maintenance_done(
+                    &repo_def.repo_path,
+                    format_size(before),
+                    format_size(repo.object_store_size()),
+                    started.elapsed().as_secs_f64(),
+                ));
+            }
+            Err(e) => self
+                .logger
+                .warn(&messages::maintenance_failed(&repo_def.repo_path, e)),
+        }
+    }
+
+    /// Elimina las ramas locales cuya rama remota se borró (el fetch con `--prune` ya quitó
+    /// su rama de seguimiento). Las que tienen commits sin integrar en HEAD se conservan.
+    fn prune_local_branches(&self, repo_def: &RepoDefinition) {
+        if !self.prune_local_branches_for(repo_def) {
+            return;
+        }
+
+        let repo = self.git_repo(repo_def);
+        let branches = match repo.gone_branches() {
+            Ok(branches) => branches,
+            Err(e) => {
+                self.logger
+                    .warn(&messages::local_branches_failed(&repo_def.repo_path, e));
+                return;
+            }
+        };
+
+        let current = repo.current_branch().ok().flatten();
+        for branch in branches {
+            if current.as_deref() == Some(branch.as_str()) {
+                continue;
+            }
+            match repo.delete_merged_branch(&branch) {
+                Ok(true) => self.logger.log_line(&messages::local_branch_deleted(
+                    &branch,
+                    repo_def.remote_name(),
+                )),
+                Ok(false) => self.logger.warn(&messages::local_branch_kept(
+                    &branch,
+                    repo_def.remote_name(),
+                )),
+                Err(e) => self
+                    .logger
+                    .warn(&messages::local_branch_delete_failed(&branch, e)),
+            }
+        }
+    }
+
+    /// Replica el estado sincronizado en un remoto secundario. Se ejecuta en cada ciclo
+    /// para que un push fallido se recupere aunque el remoto principal no tenga cambios.
+    fn push_mirror(&self, repo_def: &RepoDefinition, mirror: &str) -> Result<(), String> {
+        self.logger.log_line(&messages::mirroring(mirror));
+
+        match self.git_repo(repo_def).push_mirror(mirror) {
+            Ok(count) => {
+                self.logger.log_line(&messages::mirrored(count, mirror));
+                Ok(())
+            }
+            Err(e) => {
+                let msg = messages::mirror_failed(mirror, e);
+                self.logger.log_error(&msg);
+                Err(msg)
+            }
+        }
+    }
+
+    fn validate_repo(&self, git_repo: &GitRepo, repo_def: &RepoDefinition) -> Result<(), String> {
+        let repo_path = git_repo.path.as_str();
+        if !Path::new(repo_path).exists() {
+            let msg = messages::path_missing(repo_path);
+            self.logger.log_error(&msg);
+            return Err(msg);
+        }
+
+        if repo_def.sync_mode == SyncMode::Bare {
+            if !git_repo.is_bare_repository() {
+                let msg = messages::not_bare_repo(repo_path);
+                self.logger.log_error(&msg);
+                return Err(msg);
+            }
+            return self.check_remote_url(git_repo, repo_def);
+        }
+
+        if !git_repo.is_work_tree() {
+            let msg = if repo_def.clone_url.is_some() {
+                messages::clone_target_not_empty(repo_path)
+            } else {
+                messages::invalid_git_repo(repo_path)
+            };
+            self.logger.log_error(&msg);
+            return Err(msg);
+        }
+
+        self.check_remote_url(git_repo, repo_def)
+    }
+
+    /// Con `url => ruta`, el remoto de sincronización debe seguir apuntando a esa URL: si
+    /// apunta a otra parte (el repositorio se reemplazó o alguien cambió el remoto) no se
+    /// sincroniza, para no desplegar código de otro origen.
+    fn check_remote_url(
+        &self,
+        git_repo: &GitRepo,
+        repo_def: &RepoDefinition,
+    ) -> Result<(), String> {
+        let Some(expected) = &repo_def.clone_url else {
+            return Ok(());
+        };
+
+        let remote = repo_def.remote_name();
+        let msg = match git_repo.remote_url(remote) {
+            Ok(Some(actual)) if same_remote_url(expected, &actual) => return Ok(()),
+            Ok(Some(actual)) => {
+                messages::remote_url_mismatch(remote, &repo_def.repo_path, actual, expected)
+            }
+            Ok(None) => messages::remote_missing(&repo_def.repo_path, remote, expected),
+            Err(e) => messages::remote_url_failed(remote, &repo_def.repo_path, e),
+        };
+        self.logger.log_error(&msg);
+        Err(msg)
+    }
+
+    fn clone_repo(&self, repo_def: &RepoDefinition, url: &str) -> Result<PullOutcome, String> {
+        let repo_path = repo_def.repo_path.as_str();
+        self.logger.log_line(&messages::cloning(url, repo_path));
+
+        if let Some(parent) = Path::new(repo_path).parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                let msg = messages::dir_create_failed(parent.display(), e);
+                self.logger.log_error(&msg);
+                msg
+            })?;
+        }
+
+        let repo = self.git_repo(repo_def);
+        let bare = repo_def.sync_mode == SyncMode::Bare;
+        let result = if bare {
+            repo.clone_mirror(url)
+        } else {
+            repo.clone(url)
+        };
+        if let Err(e) = result {
+            let msg = messages::clone_failed(url, e);
+            self.logger.log_error(&msg);
+            return Err(msg);
+        }
+
+        let branch = if bare {
+            bare_head_branch(&repo)
+        } else {
+            repo.get_default_branch()
+        };
+        if !bare
+            && repo_def.branch.is_some()
+            && let Ok(Some(current)) = repo.current_branch()
+            && current != branch
+        {
+            self.switch_branch(&repo, &current, &branch)?;
+        }
+        self.logger.log_line(&messages::cloned(&branch));
+
+        Ok(PullOutcome {
+            branch,
+            result: messages::result_cloned(url),
+            last_pulled_commit: repo.head_commit_summary().ok(),
+        })
+    }
+
+    /// Sincroniza un repositorio bare actualizando todos sus remotos; no hay árbol
+    /// de trabajo que integrar.
+    fn update_bare(&self, repo: &GitRepo) -> Result<PullOutcome, String> {
+        self.logger.log_line(&messages::updating_remotes());
+
+        let changed = repo.remote_update().map_err(|e| {
+            let msg = messages::remote_update_failed(e);
+            self.logger.log_error(&msg);
+            msg
+        })?;
+
+        let branch = bare_head_branch(repo);
+        if changed == 0 {
+            self.logger.log_line(&messages::up_to_date());
+            return Ok(PullOutcome {
+                branch,
+                result: messages::result_no_changes(),
+                last_pulled_commit: None,
+            });
+        }
+
+        let result = messages::result_remotes_updated(changed);
+        self.logger.log_line(&format!("✅ {}.", result));
+        Ok(PullOutcome {
+            branch,
+            result,
+            last_pulled_commit: repo.head_commit_summary().ok(),
+        })
+    }
+
+    fn check_and_pull(&self, repo_def: &RepoDefinition) -> Result<PullOutcome, String> {
+        let repo = self.git_repo(repo_def);
+
+        let fetch = self.enter_phase(repo_def, Phase::Fetch);
+        self.logger.log_line(&messages::checking_remote());
+
+        if let Err(e) = repo.fetch() {
+            let msg = messages::git_fetch_failed(e);
+            self.logger.log_error(&msg);
+            return Err(msg);
+        }
+        drop(fetch);
+        let _phase = self.enter_phase(repo_def, Phase::Pull);
+
+        if let Some(pattern) = &repo_def.track_tag {
+            return self.track_tag(&repo, repo_def, pattern);
+        }
+
+        let branch = repo.get_default_branch();
+        self.logger.log_line(&messages::using_branch(&branch));
+
+        match repo.current_branch() {
+            Ok(Some(current)) if repo_def.branch.is_some() && current != branch => {
+                self.switch_branch(&repo, &current, &branch)?
+            }
+            Ok(Some(_)) => {}
+            Ok(None) => self.reattach_head(&repo, repo_def, &branch)?,
+            Err(e) => {
+                let msg = messages::current_branch_failed(e);
+                self.logger.log_error(&msg);
+                return Err(msg);
+            }
+        }
+
+        let behind = match repo.count_commits_behind(&branch) {
+            Ok(count) => count,
+            Err(e) => {
+                let msg = messages::repo_status_failed(e);
+                self.logger.log_error(&msg);
+                return Err(msg);
+            }
+        };
+
+        if behind > 0 {
+            self.verify_signature(&repo, repo_def, &repo.remote_branch(&branch))?;
+        }
+
+        match repo_def.sync_mode {
+            SyncMode::Pull => {
+                let ahead = if behind > 0 {
+                    repo.count_commits_ahead(&branch).map_err(|e| {
+                        let msg = messages::repo_status_failed(e);
+                        self.logger.log_error(&msg);
+                        msg
+                    })?
+                } else {
+                    0
+                };
+
+                let ff_only = repo_def.ff_only.unwrap_or(self.ff_only);
+                let outcome =
+                    if ahead > 0 && (repo_def.pull_strategy == PullStrategy::Rebase || !ff_only) {
+                        self.integrate_local_commits(&repo, repo_def, branch, ahead, behind)?
+                    } else if ahead > 0 {
+                        self.recover_divergence(&repo, repo_def, branch, ahead, behind)?
+                    } else {
+                        self.pull_changes(&repo, repo_def, branch, behind)?
+                    };
+                // Tras integrar los cambios, para que el árbol de trabajo coincida con el remoto.
+                if repo_def.clean {
+                    self.clean_untracked(&repo, repo_def)?;
+                }
+                Ok(outcome)
+            }
+            SyncMode::Reset => {
+                let outcome = self.reset_to_remote(&repo, branch, behind)?;
+                if repo_def.clean {
+                    self.clean_untracked(&repo, repo_def)?;
+                }
+                Ok(outcome)
+            }
+            SyncMode::Bare => unreachable!("los repositorios bare se sincronizan con update_bare"),
+        }
+    }
+
+    fn pull_changes(
+        &self,
+        repo: &GitRepo,
+        repo_def: &RepoDefinition,
+        branch: String,
+        behind: usize,
+    ) -> Result<PullOutcome, String> {
+        if behind == 0 {
+            self.logger.log_line(&messages::up_to_date());
+            return Ok(PullOutcome {
+                branch,
+                result: messages::result_no_changes(),
+                last_pulled_commit: None,
+            });
+        }
+
+        self.logger.log_line(&messages::pulling(behind));
+
+        let stashed = self.prepare_worktree(repo, repo_def)?;
+        let pulled = repo.pull(&branch);
+        if stashed {
+            self.restore_stash(repo)?;
+        }
+
+        match pulled {
+            Ok(output) => {
+                self.logger.log_line(&messages::pull_output(output.trim()));
+                let pulled_commit = repo.head_commit_summary().ok();
+                Ok(PullOutcome {
+                    branch,
+                    result: messages::result_pulled(behind),
+                    last_pulled_commit: pulled_commit,
+                })
+            }
+            Err(e) => {
+                let msg = messages::git_pull_failed(e);
+                self.logger.log_error(&msg);
+                Err(msg)
+            }
+        }
+    }
+
+    /// Integra los commits locales (ej. hotfixes hechos en el servidor) con los remotos:
+    /// con `pull_strategy=rebase` se reaplican sobre la rama remota y con `ff_only=false`
+    /// se fusionan. Si la operación falla se deshace y se aplica `on_divergence`.
+    fn integrate_local_commits(
+        &self,
+        repo: &GitRepo,
+        repo_def: &RepoDefinition,
+        branch: String,
+        ahead: usize,
+        behind: usize,
+    ) -> Result<PullOutcome, String> {
+        let strategy = repo_def.pull_strategy;
+        self.logger
+            .log_line(&messages::integrating(behind, ahead, strategy.as_str()));
+
+        let stashed = self.prepare_worktree(repo, repo_def)?;
+        let integrated = match strategy {
+            PullStrategy::Rebase => repo.rebase(&branch),
+            PullStrategy::Merge => repo.merge(&branch),
+        };
+        if stashed {
+            self.restore_stash(repo)?;
+        }
+
+        match integrated {
+            Ok(output) => {
+                if !output.trim().is_empty() {
+                    self.logger.log_line(&messages::integrate_output(
+                        strategy.as_str(),
+                        output.trim(),
+                    ));
+                }
+                let result = match strategy {
+                    PullStrategy::Rebase => messages::result_rebased(behind, ahead),
+                    PullStrategy::Merge => messages::result_merged(behind, ahead),
+                };
+                Ok(PullOutcome {
+                    branch,
+                    result,
+                    last_pulled_commit: repo.head_commit_summary().ok(),
+                })
+            }
+            Err(e) => {
+                self.logger.warn(&messages::integrate_failed(
+                    repo.remote_branch(&branch),
+                    strategy.as_str(),
+                    e,
+                ));
+                self.recover_divergence(repo, repo_def, branch, ahead, behind)
+            }
+        }
+    }
+
+    /// Sigue el tag con la versión semántica más alta que coincide con `pattern`
+    /// en lugar de la punta de una rama. HEAD queda desacoplado en ese tag.
+    fn track_tag(
+        &self,
+        repo: &GitRepo,
+        repo_def: &RepoDefinition,
+        pattern: &str,
+    ) -> Result<PullOutcome, String> {
+        let fail = |msg: String| {
+            self.logger.log_error(&msg);
+            msg
+        };
+
+        repo.fetch_tags()
+            .map_err(|e| fail(messages::tags_fetch_failed(e)))?;
+        let tags = repo
+            .list_tags(pattern)
+            .map_err(|e| fail(messages::tags_list_failed(e)))?;
+        let Some(tag) = semver::highest(&tags) else {
+            return Err(fail(messages::no_semver_tag(pattern)));
+        };
+
+        let tag_ref = format!("refs/tags/{}", tag);
+        let target = repo
+            .resolve_commit(&tag_ref)
+            .map_err(|e| fail(messages::tag_resolve_failed(tag, e)))?;
+
+        if repo.resolve_commit("HEAD").ok().as_deref() == Some(target.as_str()) {
+            self.logger.log_line(&messages::already_on_tag(tag));
+            return Ok(PullOutcome {
+                branch: tag.to_string(),
+                result: messages::result_no_changes(),
+                last_pulled_commit: None,
+            });
+        }
+
+        self.logger.log_line(&messages::new_tag(tag, pattern));
+
+        self.verify_signature(repo, repo_def, &tag_ref)?;
+        let stashed = self.prepare_worktree(repo, repo_def)?;
+        let checked_out = repo.checkout_detached(&tag_ref);
+        if stashed {
+            self.restore_stash(repo)?;
+        }
+        checked_out.map_err(|e| fail(messages::tag_checkout_failed(tag, e)))?;
+
+        Ok(PullOutcome {
+            branch: tag.to_string(),
+            result: messages::result_tag_deployed(tag),
+            last_pulled_commit: repo.head_commit_summary().ok(),
+        })
+    }
+
+    /// Cambia a la rama configurada con `branch=` (al añadirla a un repositorio existente o
+    /// tras clonar uno cuyo remoto tiene otra predeterminada). `git checkout` no pisa cambios
+    /// locales: si los hay, falla y se omite el repositorio.
+    fn switch_branch(&self, repo: &GitRepo, current: &str, branch: &str) -> Result<(), String> {
+        self.logger
+            .log_line(&messages::switching_branch(current, branch));
+        repo.checkout_branch(branch).map_err(|e| {
+            let msg = messages::branch_checkout_failed(branch, e);
+            self.logger.log_error(&msg);
+            msg
+        })
+    }
+
+    /// HEAD desacoplado (ej. tras `git checkout <tag>` manual): `git pull` fallaría en cada
+    /// ciclo. Solo se vuelve a la rama si HEAD no tiene commits que no estén en el remoto.
+    fn reattach_head(
+        &self,
+        repo: &GitRepo,
+        repo_def: &RepoDefinition,
+        branch: &str,
+    ) -> Result<(), String> {
+        let fail = |msg: String| {
+            self.logger.log_error(&msg);
+            msg
+        };
+
+        let head = repo.resolve_commit("HEAD").unwrap_or_default();
+        let detached = messages::detached_head(short_hash(&head));
+
+        match repo_def.on_detached.unwrap_or(self.detached_policy) {
+            DetachedPolicy::Skip => Err(fail(messages::detached_skip(
+                detached,
+                &repo_def.repo_path,
+                branch,
+            ))),
+            DetachedPolicy::Reattach => {
+                let ahead = repo
+                    .count_commits_ahead(branch)
+                    .map_err(|e| fail(messages::repo_status_failed(e)))?;
+                if ahead > 0 {
+                    return Err(fail(messages::detached_commits(
+                        detached,
+                        ahead,
+                        repo.remote_branch(branch),
+                        branch,
+                    )));
+                }
+
+                self.logger
+                    .log_line(&messages::detached_reattach(detached, branch));
+                repo.checkout_branch(branch)
+                    .map_err(|e| fail(messages::reattach_failed(branch, e)))
+            }
+        }
+    }
+
+    /// Aplica la política `on_dirty` si hay archivos con seguimiento modificados antes de
+    /// actualizar el árbol de trabajo. Devuelve `true` si los cambios quedaron en el stash.
+    fn prepare_worktree(&self, repo: &GitRepo, repo_def: &RepoDefinition) -> Result<bool, String> {
+        let fail = |msg: String| {
+            self.logger.log_error(&msg);
+            msg
+        };
+
+        let files = repo
+            .modified_files()
+            .map_err(|e| fail(messages::worktree_status_failed(e)))?;
+        if files.is_empty() {
+            return Ok(false);
+        }
+
+        let policy = repo_def.on_dirty.unwrap_or(self.dirty_policy);
+        let warning = messages::dirty_warning(files.len(), list_files(&files));
+
+        match policy {
+            DirtyPolicy::Abort => Err(fail(messages::dirty_abort(warning))),
+            DirtyPolicy::Stash => {
+                self.logger.log_line(&messages::dirty_stash(warning));
+                repo.stash_changes(&messages::stash_message())
+                    .map_err(|e| fail(messages::stash_failed(e)))
+            }
+            DirtyPolicy::Discard => {
+                self.logger.log_line(&messages::dirty_discard(warning));
+                repo.discard_changes()
+                    .map_err(|e| fail(messages::discard_failed(e)))?;
+                Ok(false)
+            }
+        }
+    }
+
+    fn restore_stash(&self, repo: &GitRepo) -> Result<(), String> {
+        match repo.stash_pop() {
+            Ok(()) => {
+                self.logger.log_line(&messages::stash_restored());
+                Ok(())
+            }
+            Err(e) => {
+                let msg = messages::stash_restore_failed(e);
+                self.logger.log_error(&msg);
+                Err(msg)
+            }
+        }
+    }
+
+    /// Con `require_signed`, rechaza aplicar `rev` si su commit no tiene una firma de
+    /// confianza. Solo se verifica el commit de destino, no los intermedios.
+    fn verify_signature(
+        &self,
+        repo: &GitRepo,
+        repo_def: &RepoDefinition,
+        rev: &str,
+    ) -> Result<(), String> {
+        if !repo_def.require_signed {
+            return Ok(());
+        }
+
+        self.logger.log_line(&messages::verifying_signature(rev));
+
+        repo.verify_commit(rev, repo_def.signers.as_deref().map(Path::new))
+            .map_err(|e| {
+                let msg = messages::untrusted_signature(rev, e);
+                self.logger.log_error(&msg);
+                msg
+            })
+    }
+
+    /// La rama local tiene commits que no están en el remoto y viceversa, normalmente
+    /// por un force push: `git pull` fallaría en cada ciclo.
+    fn recover_divergence(
+        &self,
+        repo: &GitRepo,
+        repo_def: &RepoDefinition,
+        branch: String,
+        ahead: usize,
+        behind: usize,
+    ) -> Result<PullOutcome, String> {
+        let policy = repo_def.on_divergence.unwrap_or(self.divergence_policy);
+        let warning = messages::divergence_warning(repo.remote_branch(&branch), ahead, behind);
+        self.logger.log_line(&warning);
+
+        match policy {
+            DivergencePolicy::Skip => Err(messages::divergence_skip(warning)),
+            DivergencePolicy::Reset => self.reset_to_remote(repo, branch, behind),
+            DivergencePolicy::Rescue => {
+                let rescue_branch =
+                    format!("git-sync/rescue-{}", Local::now().format("%Y%m%d-%H%M%S"));
+                if let Err(e) = repo.create_branch(&rescue_branch) {
+                    let msg = messages::rescue_branch_failed(e);
+                    self.logger.log_error(&msg);
+                    return Err(msg);
+                }
+                self.logger
+                    .log_line(&messages::rescue_branch_created(&rescue_branch));
+
+                let stash_message = messages::rescue_stash_message(&rescue_branch);
+                match repo.stash_changes(&stash_message) {
+                    Ok(true) => self.logger.log_line(&messages::rescue_stashed()),
+                    Ok(false) => {}
+                    Err(e) => {
+                        let msg = messages::rescue_stash_failed(e);
+                        self.logger.log_error(&msg);
+                        return Err(msg);
+                    }
+                }
+
+                self.reset_to_remote(repo, branch, behind)
+            }
+        }
+    }
+
+    /// Elimina los archivos sin seguimiento tras la actualización, de modo que el árbol de
+    /// trabajo coincida con el remoto salvo lo que indique `clean_exclude`.
+    fn clean_untracked(&self, repo: &GitRepo, repo_def: &RepoDefinition) -> Result<(), String> {
+        match repo.clean(&repo_def.clean_excludes()) {
+            Ok(removed) => {
+                if !removed.is_empty() {
+                    self.logger
+                        .log_line(&messages::untracked_removed(removed.join(", ")));
+                }
+                Ok(())
+            }
+            Err(e) => {
+                let msg = messages::git_clean_run_failed(e);
+                self.logger.log_error(&msg);
+                Err(msg)
+            }
+        }
+    }
+
+    /// Modo reset: el árbol de trabajo se alinea con el remoto en cada ciclo,
+    /// descartando commits locales, cambios sin confirmar y force pushes.
+    fn reset_to_remote(
+        &self,
+        repo: &GitRepo,
+        branch: String,
+        behind: usize,
+    ) -> Result<PullOutcome, String> {
+        let target = repo.remote_branch(&branch);
+        let previous_head = repo.head_commit_summary().ok();
+
+        self.logger.log_line(&messages::resetting(&target));
+
+        match repo.reset_hard(&target) {
+            Ok(output) => {
+                self.logger.log_line(&messages::reset_output(output.trim()));
+            }
+            Err(e) => {
+                let msg = messages::git_reset_failed(e);
+                self.logger.log_error(&msg);
+                return Err(msg);
+            }
+        }
+
+        let current_head = repo.head_commit_summary().ok();
+        if current_head == previous_head {
+            self.logger.log_line(&messages::up_to_date());
+            return Ok(PullOutcome {
+                branch,
+                result: messages::result_no_changes(),
+                last_pulled_commit: None,
+            });
+        }
+
+        Ok(PullOutcome {
+            branch,
+            result: messages::result_reset(&target, behind),
+            last_pulled_commit: current_head,
+        })
+    }
+}
+
+/// La ruta se clona si todavía no existe o si es un directorio vacío.
+fn needs_clone(repo_path: &str) -> bool {
+    let path = Path::new(repo_path);
+    if !path.exists() {
+        return true;
+    }
+
+    path.is_dir()
+        && fs::read_dir(path)
+            .map(|mut entries| entries.next().is_none())
+            .unwrap_or(false)
+}
+
+/// Espacio disponible para usuarios sin privilegios (MB) en el sistema de archivos de `path`.
+fn available_space_mb(path: &Path) -> Result<u64, String> {
+    let output = Command::new("df")
+        .arg("-Pk")
+        .arg("--")
+        .arg(path)
+        .output()
+        .map_err(|e| messages::command_failed("df", e))?;
+    if !output.status.success() {
+        return Err(messages::free_space_failed(
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim(),
+        ));
+    }
+
+    // Formato POSIX: Filesystem 1024-blocks Used Available Capacity Mounted-on
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .nth(1)
+        .and_then(|line| line.split_whitespace().nth(3))
+        .and_then(|kb| kb.parse::<u64>().ok())
+        .map(|kb| kb / 1024)
+        .ok_or_else(|| messages::df_unexpected(path.display()))
+}
+
+/// Los primeros 7 caracteres de un hash, como `git log --oneline`.
+fn short_hash(hash: &str) -> &str {
+    hash.get(..7).unwrap_or(hash)
+}
+
+/// Primeros archivos de una lista para los mensajes del log.
+fn list_files(files: &[String]) -> String {
+    let mut listed = files.iter().take(5).cloned().collect::<Vec<_>>().join(", ");
+    if files.len() > 5 {
+        listed.push_str(", ...");
+    }
+    listed
+}
+
+/// Rama a la que apunta HEAD en un repositorio bare (la predeterminada del mirror).
+fn bare_head_branch(repo: &GitRepo) -> String {
+    repo.current_branch()
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| "HEAD".to_string())
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo(line: &str) -> RepoDefinition {
+        RepoDefinition::parse_line(line).unwrap().0
+    }
+
+    fn order(lines: &[&str]) -> (Vec<String>, Vec<Vec<String>>) {
+        // Sin ruta de registro no se abre ningún archivo: las entradas solo van a la salida
+        // estándar, que captura `cargo test`.
+        let logger = Logger::new(String::new());
+        let processor = RepoProcessor::new(&logger, String::new());
+        let (repos, dependencies) =
+            processor.order_by_dependencies(lines.iter().map(|line| repo(line)).collect());
+        (
+            repos
+                .into_iter()
+                .map(|repo| repo.name().to_string())
+                .collect(),
+            dependencies,
+        )
+    }
+
+    #[test]
+    fn keeps_the_file_order_without_dependencies() {
+        let (names, dependencies) = order(&["/srv/a", "/srv/b", "/srv/c"]);
+        assert_eq!(names, vec!["a", "b", "c"]);
+        assert!(dependencies.iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn processes_dependencies_first() {
+        let (names, dependencies) = order(&[
+            "/srv/web | after=api",
+            "/srv/api | after=/srv/db",
+            "/srv/db",
+            "/srv/docs",
+        ]);
+        assert_eq!(names, vec!["db", "api", "web", "docs"]);
+        assert_eq!(
+            dependencies,
+            vec![
+                vec![],
+                vec!["/srv/db".to_string()],
+                vec!["/srv/api".to_string()],
+                vec![],
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_dependencies_outside_the_cycle() {
+        let (names, dependencies) = order(&["/srv/web | after=api", "/srv/docs"]);
+        assert_eq!(names, vec!["web", "docs"]);
+        assert!(dependencies.iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn breaks_circular_dependencies() {
+        let (mut names, _) = order(&["/srv/a | after=b", "/srv/b | after=a", "/srv/c"]);
+        assert_eq!(names.len(), 3);
+        names.sort();
+        assert_eq!(names, vec!["a", "b", "c"]);
+    }
 }
@@ -0,0 +1,117 @@
+use crate::messages;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// Cada cuánto revisa el daemon los registros rotados.
+pub const CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Límites para el conjunto de registros rotados (`git-sync.log.N.gz`, `events.jsonl.N.gz`),
+/// además de `log_keep`: tamaño total y antigüedad máxima.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LogRetention {
+    /// Tamaño total máximo de los archivos rotados (0 = sin límite)
+    pub max_bytes: u64,
+    /// Antigüedad máxima de un archivo rotado, según su última modificación
+    pub max_age: Option<Duration>,
+}
+
+impl LogRetention {
+    pub fn is_enabled(&self) -> bool {
+        self.max_bytes > 0 || self.max_age.is_some()
+    }
+}
+
+/// Archivos eliminados en una limpieza y bytes liberados.
+#[derive(Debug, Default)]
+pub struct CleanupReport {
+    pub removed: Vec<String>,
+    pub freed_bytes: u64,
+}
+
+struct Archive {
+    path: String,
+    size: u64,
+    modified: SystemTime,
+}
+
+/// Elimina los archivos rotados de `log_files` que superan la antigüedad máxima y, del
+/// más antiguo al más reciente, los que exceden el tamaño total. Los registros en uso
+/// nunca se tocan.
+pub fn cleanup(log_files: &[&str], retention: LogRetention) -> Result<CleanupReport, String> {
+    let mut report = CleanupReport::default();
+    if !retention.is_enabled() {
+        return Ok(report);
+    }
+
+    let mut archives = Vec::new();
+    for log_file in log_files {
+        archives.extend(rotated_archives(log_file)?);
+    }
+    // Del más reciente al más antiguo.
+    archives.sort_by_key(|archive| std::cmp::Reverse(archive.modified));
+
+    let now = SystemTime::now();
+    let mut kept_bytes = 0u64;
+    // Superado el tamaño total, se eliminan también todos los anteriores.
+    let mut oversized = false;
+    for archive in archives {
+        let expired = retention.max_age.is_some_and(|max_age| {
+            now.duration_since(archive.modified)
+                .is_ok_and(|age| age > max_age)
+        });
+        oversized |= retention.max_bytes > 0 && kept_bytes + archive.size > retention.max_bytes;
+        if !expired && !oversized {
+            kept_bytes += archive.size;
+            continue;
+        }
+
+        fs::remove_file(&archive.path).map_err(|e| messages::remove_failed(&archive.path, e))?;
+        report.freed_bytes += archive.size;
+        report.removed.push(archive.path);
+    }
+
+    Ok(report)
+}
+
+/// `<registro>.N` y `<registro>.N.gz` junto a `log_file`.
+fn rotated_archives(log_file: &str) -> Result<Vec<Archive>, String> {
+    let path = Path::new(log_file);
+    let (Some(dir), Some(name)) = (path.parent(), path.file_name().and_then(|n| n.to_str())) else {
+        return Ok(Vec::new());
+    };
+    let prefix = format!("{}.", name);
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(messages::read_failed(dir.display(), e)),
+    };
+
+    let mut archives = Vec::new();
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let Some(index) = file_name
+            .to_str()
+            .and_then(|file_name| file_name.strip_prefix(&prefix))
+        else {
+            continue;
+        };
+        let index = index.strip_suffix(".gz").unwrap_or(index);
+        if index.is_empty() || !index.chars().all(|c| c.is_ascii_digit()) {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        archives.push(Archive {
+            path: entry.path().to_string_lossy().into_owned(),
+            size: metadata.len(),
+            modified: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+        });
+    }
+    Ok(archives)
+}
@@ -0,0 +1,130 @@
+use crate::config::RepoDefinition;
+use crate::settings::GroupSettings;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, RandomState};
+use std::time::{Duration, Instant};
+
+/// Próxima ejecución de cada repositorio en modo continuo. Cada repositorio se sincroniza
+/// cada `interval` propio o, si no lo tiene, cada `interval` de su grupo o cada
+/// `sync_interval` global, medido desde el inicio de su última sincronización.
+pub struct Schedule {
+    default_interval: Duration,
+    /// Ajustes de `[groups.<nombre>]`, con el intervalo propio de cada grupo
+    groups: BTreeMap<String, GroupSettings>,
+    last_run: HashMap<String, Instant>,
+    /// Despliegues retenidos por `quiet_hours`, que se ejecutan al terminar la franja
+    deferred: HashMap<String, Instant>,
+}
+
+impl Schedule {
+    pub fn new(default_interval: Duration, groups: BTreeMap<String, GroupSettings>) -> Self {
+        Schedule {
+            default_interval,
+            groups,
+            last_run: HashMap::new(),
+            deferred: HashMap::new(),
+        }
+    }
+
+    /// Nuevos `sync_interval` e intervalos de grupo tras recargar la configuración.
+    pub fn set_intervals(
+        &mut self,
+        default_interval: Duration,
+        groups: BTreeMap<String, GroupSettings>,
+    ) {
+        self.default_interval = default_interval;
+        self.groups = groups;
+    }
+
+    fn interval(&self, repo: &RepoDefinition) -> Duration {
+        repo.interval
+            .or_else(|| GroupSettings::interval_of(&self.groups, &repo.groups))
+            .map(Duration::from_secs)
+            .unwrap_or(self.default_interval)
+            .max(Duration::from_secs(1))
+    }
+
+    fn remaining(&self, repo: &RepoDefinition, now: Instant) -> Duration {
+        let interval = match self.last_run.get(&repo.repo_path) {
+            Some(last) => (*last + self.interval(repo)).saturating_duration_since(now),
+            None => Duration::ZERO,
+        };
+        match self.deferred_remaining(repo, now) {
+            Some(deferred) => interval.min(deferred),
+            None => interval,
+        }
+    }
+
+    fn deferred_remaining(&self, repo: &RepoDefinition, now: Instant) -> Option<Duration> {
+        self.deferred
+            .get(&repo.repo_path)
+            .map(|at| at.saturating_duration_since(now))
+    }
+
+    /// Repositorios activos a los que ya les toca sincronizarse.
+    pub fn due(&self, repos: Vec<RepoDefinition>, now: Instant) -> Vec<RepoDefinition> {
+        repos
+            .into_iter()
+            .filter(|repo| repo.enabled && self.remaining(repo, now).is_zero())
+            .collect()
+    }
+
+    /// Repositorios activos que aún no se sincronizaron (ej. recién añadidos) o cuyo
+    /// despliegue retenido ya puede ejecutarse. En modo manual son los únicos que se
+    /// sincronizan sin SIGUSR1.
+    pub fn due_pending(&self, repos: Vec<RepoDefinition>, now: Instant) -> Vec<RepoDefinition> {
+        repos
+            .into_iter()
+            .filter(|repo| {
+                repo.enabled
+                    && (!self.last_run.contains_key(&repo.repo_path)
+                        || self
+                            .deferred_remaining(repo, now)
+                            .is_some_and(|d| d.is_zero()))
+            })
+            .collect()
+    }
+
+    pub fn mark_run(&mut self, repos: &[RepoDefinition], now: Instant) {
+        for repo in repos {
+            self.last_run.insert(repo.repo_path.clone(), now);
+            self.deferred.remove(&repo.repo_path);
+        }
+    }
+
+    /// Vuelve a sincronizar `repo` en `at`, aunque su intervalo no haya vencido.
+    pub fn defer_until(&mut self, repo: &RepoDefinition, at: Instant) {
+        self.deferred.insert(repo.repo_path.clone(), at);
+    }
+
+    /// Tiempo hasta que le toque al próximo repositorio activo.
+    pub fn next_wait(&self, repos: &[RepoDefinition], now: Instant) -> Duration {
+        repos
+            .iter()
+            .filter(|repo| repo.enabled)
+            .map(|repo| self.remaining(repo, now))
+            .min()
+            .unwrap_or(self.default_interval)
+    }
+
+    /// Tiempo hasta el próximo despliegue retenido, si hay alguno (modo manual).
+    pub fn next_deferred(&self, repos: &[RepoDefinition], now: Instant) -> Option<Duration> {
+        repos
+            .iter()
+            .filter(|repo| repo.enabled)
+            .filter_map(|repo| self.deferred_remaining(repo, now))
+            .min()
+    }
+}
+
+/// Alarga `wait` un porcentaje al azar entre 0 y `percent` (máximo 100), para que varios
+/// servidores con el mismo intervalo no consulten el remoto en el mismo segundo.
+pub fn with_jitter(wait: Duration, percent: u32) -> Duration {
+    if percent == 0 {
+        return wait;
+    }
+    // `RandomState` usa claves aleatorias distintas en cada llamada: basta como fuente de azar.
+    let random = RandomState::new().hash_one(Instant::now());
+    let fraction = (random % 10_001) as f64 / 10_000.0;
+    wait.mul_f64(1.0 + fraction * f64::from(percent.min(100)) / 100.0)
+}
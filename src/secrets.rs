@@ -0,0 +1,108 @@
+use crate::messages;
+use crate::platform;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatrixSecrets {
+    /// URL base del homeserver (ej. https://matrix.example.com)
+    pub homeserver_url: String,
+
+    /// Token de acceso del usuario o bot que publica los mensajes
+    pub access_token: String,
+
+    /// Identificador de la sala (ej. !abcdef:example.com)
+    pub room_id: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Secrets {
+    /// Credenciales para notificaciones en una sala de Matrix
+    #[serde(default)]
+    pub matrix: Option<MatrixSecrets>,
+}
+
+impl Secrets {
+    /// Valores que no deben aparecer en el registro.
+    pub fn values(&self) -> Vec<String> {
+        self.matrix
+            .iter()
+            .map(|matrix| matrix.access_token.clone())
+            .collect()
+    }
+
+    pub fn load(path: &str, key_file: &str) -> Result<Self, String> {
+        let Some(contents) = read_protected(path, key_file)? else {
+            return Ok(Secrets::default());
+        };
+
+        toml::from_str(&contents).map_err(|e| messages::secrets_parse_failed(path, e))
+    }
+}
+
+/// Lee un archivo con secretos, descifrándolo si existe una variante cifrada.
+///
+/// Se busca primero `<ruta>.age` (cifrado con age) y después `<ruta>.sops`
+/// (cifrado con sops en modo binario); ambos se descifran con la clave age de
+/// `key_file`. Si no hay variante cifrada se lee `<ruta>` en texto plano.
+pub fn read_protected(path: &str, key_file: &str) -> Result<Option<String>, String> {
+    let age_path = format!("{}.age", path);
+    if Path::new(&age_path).exists() {
+        check_key_file(key_file)?;
+        let output = Command::new("age")
+            .args(["--decrypt", "--identity", key_file, &age_path])
+            .output()
+            .map_err(|e| messages::decrypt_tool_failed("age", &age_path, e))?;
+        return decrypted_output(&age_path, output).map(Some);
+    }
+
+    let sops_path = format!("{}.sops", path);
+    if Path::new(&sops_path).exists() {
+        check_key_file(key_file)?;
+        let output = Command::new("sops")
+            .args([
+                "--decrypt",
+                "--input-type",
+                "binary",
+                "--output-type",
+                "binary",
+                &sops_path,
+            ])
+            .env("SOPS_AGE_KEY_FILE", key_file)
+            .output()
+            .map_err(|e| messages::decrypt_tool_failed("sops", &sops_path, e))?;
+        return decrypted_output(&sops_path, output).map(Some);
+    }
+
+    if !Path::new(path).exists() {
+        return Ok(None);
+    }
+
+    fs::read_to_string(path)
+        .map(Some)
+        .map_err(|e| messages::file_read_failed(path, e))
+}
+
+fn check_key_file(key_file: &str) -> Result<(), String> {
+    let metadata = fs::metadata(key_file).map_err(|e| messages::key_read_failed(key_file, e))?;
+
+    if !platform::is_private(&metadata) {
+        return Err(messages::key_not_private(key_file));
+    }
+
+    Ok(())
+}
+
+fn decrypted_output(path: &str, output: std::process::Output) -> Result<String, String> {
+    if !output.status.success() {
+        return Err(messages::decrypt_failed(
+            path,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim(),
+        ));
+    }
+
+    String::from_utf8(output.stdout).map_err(|e| messages::decrypted_not_utf8(path, e))
+}
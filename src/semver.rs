@@ -0,0 +1,86 @@
+use std::cmp::Ordering;
+
+/// Versión semántica mínima extraída de un tag (`v1.2.3`, `release-2.0`, `1.4.0-rc.1`).
+#[derive(Debug, Eq, PartialEq)]
+struct Version {
+    numbers: [u64; 3],
+    pre_release: Option<String>,
+}
+
+impl Version {
+    fn parse(tag: &str) -> Option<Self> {
+        // Se ignora cualquier prefijo no numérico, como `v` o `release-`.
+        let start = tag.find(|c: char| c.is_ascii_digit())?;
+        let version = &tag[start..];
+        let version = version.split('+').next().unwrap_or(version);
+        let (core, pre_release) = match version.split_once('-') {
+            Some((core, pre)) => (core, Some(pre.to_string())),
+            None => (version, None),
+        };
+
+        let mut numbers = [0; 3];
+        let mut parts = core.split('.');
+        for (index, part) in parts.by_ref().take(3).enumerate() {
+            numbers[index] = part.parse().ok()?;
+        }
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Version {
+            numbers,
+            pre_release,
+        })
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.numbers.cmp(&other.numbers).then_with(|| {
+            match (&self.pre_release, &other.pre_release) {
+                (None, None) => Ordering::Equal,
+                // Una versión final es mayor que cualquiera de sus pre-releases.
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => compare_pre_release(a, b),
+            }
+        })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn compare_pre_release(a: &str, b: &str) -> Ordering {
+    let mut left = a.split('.');
+    let mut right = b.split('.');
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => {
+                let ordering = match (x.parse::<u64>(), y.parse::<u64>()) {
+                    (Ok(x), Ok(y)) => x.cmp(&y),
+                    (Ok(_), Err(_)) => Ordering::Less,
+                    (Err(_), Ok(_)) => Ordering::Greater,
+                    (Err(_), Err(_)) => x.cmp(y),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
+
+/// Devuelve el tag con la versión semántica más alta; los tags sin versión se ignoran.
+pub fn highest(tags: &[String]) -> Option<&str> {
+    tags.iter()
+        .filter_map(|tag| Version::parse(tag).map(|version| (version, tag)))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, tag)| tag.as_str())
+}
@@ -1,220 +1,1233 @@
-use crate::config::Config;
-use chrono::Local;
+use crate::config::{Config, RepoDefinition};
+use crate::messages;
+use crate::pause::PauseState;
+use crate::platform;
+use crate::settings::Settings;
+use crate::sync_state::{RepoSyncState, SyncStateSnapshot};
+use crate::tui::humanize_elapsed;
+use chrono::{Local, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
 use std::env;
 use std::fs::{self, File};
 use std::io::Write;
-use std::os::unix::fs::PermissionsExt;
 use std::path::Path;
-use std::process::Command;
+use std::process::{Command, Stdio};
 
-const SERVICE_NAME: &str = "git-sync";
-const SERVICE_PATH: &str = "/etc/systemd/system/git-sync.service";
+const SYSTEM_UNIT_DIR: &str = "/etc/systemd/system";
+const SYSTEMD_RUNTIME_DIR: &str = "/run/systemd/system";
 
-pub fn install_service() -> Result<(), String> {
-    if Path::new(SERVICE_PATH).exists() {
-        return Ok(());
+/// Líneas del registro que muestra `git-sync service status`.
+const STATUS_LOG_LINES: usize = 10;
+
+/// Cómo se instala la sincronización en systemd.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceMode {
+    /// Servicio permanente (`git-sync daemon`) que espera entre ciclos
+    #[default]
+    Daemon,
+    /// `git-sync.timer` que lanza cada `sync_interval` un servicio oneshot (`git-sync sync`)
+    Timer,
+}
+
+/// Archivo de una unidad (`service` o `timer`): la del sistema o, en modo usuario, la de
+/// `$XDG_CONFIG_HOME/systemd/user`.
+fn unit_path(config: &Config, kind: &str) -> String {
+    if !config.user_mode {
+        return format!("{}/{}.{}", SYSTEM_UNIT_DIR, config.service_name, kind);
+    }
+
+    let config_home = env::var("XDG_CONFIG_HOME")
+        .ok()
+        .filter(|dir| Path::new(dir).is_absolute())
+        .unwrap_or_else(|| format!("{}/.config", env::var("HOME").unwrap_or_default()));
+    format!(
+        "{}/systemd/user/{}.{}",
+        config_home.trim_end_matches('/'),
+        config.service_name,
+        kind
+    )
+}
+
+fn service_path(config: &Config) -> String {
+    unit_path(config, "service")
+}
+
+fn timer_path(config: &Config) -> String {
+    unit_path(config, "timer")
+}
+
+fn socket_path(config: &Config) -> String {
+    unit_path(config, "socket")
+}
+
+/// Si hay un gestor de servicios con el que instalar git-sync. En Linux, systemd solo está
+/// activo si creó su directorio de ejecución (la misma comprobación que `sd_booted`); en
+/// contenedores y sistemas con otro init no existe.
+pub fn manager_available() -> bool {
+    cfg!(target_os = "macos") || cfg!(windows) || Path::new(SYSTEMD_RUNTIME_DIR).is_dir()
+}
+
+/// Instala la unidad según `service_mode`. Si ya hay una instalada en el otro modo (se
+/// cambió `service_mode`), se detiene y se reemplaza.
+pub fn install_service(config: &Config, settings: &Settings) -> Result<(), String> {
+    install(config, settings, false)
+}
+
+/// `git-sync reinstall`: regenera la unidad con el ejecutable y la configuración actuales
+/// (ej. si el binario cambió de ruta), muestra las diferencias con la instalada, y la
+/// recarga y reinicia el servicio.
+pub fn reinstall_service(config: &Config, settings: &Settings) -> Result<(), String> {
+    install(config, settings, true)
+}
+
+fn install(config: &Config, settings: &Settings, force: bool) -> Result<(), String> {
+    if cfg!(target_os = "macos") {
+        return install_launchd(config, settings, force);
+    }
+    if cfg!(windows) {
+        return install_scheduled_task(config, settings, force);
+    }
+    if !manager_available() {
+        return Err(messages::systemd_unavailable(
+            SYSTEMD_RUNTIME_DIR,
+            config.command(),
+        ));
+    }
+
+    let mode = settings.service_mode;
+    let service_path = service_path(config);
+    let timer_path = timer_path(config);
+    let socket_path = socket_path(config);
+    let installed = if Path::new(&timer_path).exists() {
+        Some(ServiceMode::Timer)
+    } else if Path::new(&service_path).exists() {
+        Some(ServiceMode::Daemon)
+    } else {
+        None
+    };
+    // El socket de control solo se usa con el daemon; si se activó o desactivó
+    // `control_socket` desde la instalación hay que regenerar las unidades.
+    let control_socket = mode == ServiceMode::Daemon && settings.control_socket;
+    let socket_stale = installed == Some(ServiceMode::Daemon)
+        && Path::new(&socket_path).exists() != control_socket;
+    match installed {
+        Some(ServiceMode::Timer) if mode == ServiceMode::Timer && !force => {
+            return refresh_timer(config, settings);
+        }
+        Some(installed) if installed == mode && !force && !socket_stale => return Ok(()),
+        Some(installed) if installed != mode => remove_units(config)?,
+        _ => {}
     }
+    let reinstall = installed == Some(mode);
+
+    let (exec_display, user) = prepare_install(config, settings)?;
+    let (username, home_dir) = (&user.name, &user.home);
+
+    // El daemon (o cada sincronización del timer) recibe las mismas opciones de instancia
+    // con las que se instaló la unidad.
+    let mut command_args = String::from(match mode {
+        ServiceMode::Daemon => "daemon",
+        ServiceMode::Timer => "sync",
+    });
+    if config.user_mode {
+        command_args.push_str(" --user");
+    }
+    if config.custom_dir {
+        command_args.push_str(&format!(" --config \"{}\"", config.config_dir));
+    }
+
+    let (description, service_type, restart, install) = match mode {
+        ServiceMode::Daemon => (
+            "Daemon de sincronización de Git Sync",
+            "simple",
+            "Restart=on-failure\nRestartSec=60\n",
+            if config.user_mode {
+                "\n[Install]\nWantedBy=default.target\n"
+            } else {
+                "\n[Install]\nWantedBy=multi-user.target\n"
+            },
+        ),
+        // El servicio oneshot no se habilita: lo arranca el timer.
+        ServiceMode::Timer => ("Sincronización de Git Sync", "oneshot", "", ""),
+    };
 
-    let exe_path = env::current_exe().map_err(|e| {
+    let group = user
+        .group
+        .as_ref()
+        .map(|group| format!("Group={}\n", group))
+        .unwrap_or_default();
+    // Con el socket de control, systemd crea el socket antes que el daemon y se lo pasa.
+    let socket_deps = if control_socket {
         format!(
-            "❌ No se pudo determinar la ruta del ejecutable actual: {}",
-            e
+            "Requires={service}.socket\nAfter={service}.socket\n",
+            service = config.service_name
         )
-    })?;
-    let exec_display = exe_path.to_str().ok_or_else(|| {
-        "❌ La ruta del ejecutable contiene caracteres UTF-8 no válidos".to_string()
-    })?;
+    } else {
+        String::new()
+    };
+    let service_content = if config.user_mode {
+        format!(
+            "[Unit]\nDescription={description}\nAfter=network-online.target\nWants=network-online.target\n{socket_deps}\n[Service]\nType={service_type}\nWorkingDirectory=%h\nExecStart={exec_display} {command_args}\n{restart}{install}"
+        )
+    } else {
+        format!(
+            "[Unit]\nDescription={description}\nAfter=network-online.target\nWants=network-online.target\n{socket_deps}\n[Service]\nType={service_type}\nUser={username}\n{group}WorkingDirectory={home_dir}\nEnvironment=HOME={home_dir}\nExecStart={exec_display} {command_args}\n{restart}{install}"
+        )
+    };
+
+    // Solo al reinstalar se muestran las diferencias con la unidad anterior.
+    let diff = |path: &str, content: &str| force && show_unit_diff(path, content);
+    let mut changed = diff(&service_path, &service_content) | socket_stale;
+    write_service_file(config, &service_path, &service_content)?;
+    let socket_unit = format!("{}.socket", config.service_name);
+    if control_socket {
+        let socket = socket_content(config, &user);
+        changed |= diff(&socket_path, &socket);
+        write_service_file(config, &socket_path, &socket)?;
+    } else if Path::new(&socket_path).exists() {
+        remove_socket(config)?;
+    }
+
+    let unit = match mode {
+        ServiceMode::Daemon => config.service_name.clone(),
+        ServiceMode::Timer => {
+            let timer = timer_content(config, settings);
+            changed |= diff(&timer_path, &timer);
+            write_service_file(config, &timer_path, &timer)?;
+            format!("{}.timer", config.service_name)
+        }
+    };
+
+    if reinstall {
+        if changed {
+            run_systemctl(config, &["daemon-reload"]);
+        }
+        if control_socket {
+            run_systemctl(config, &["enable", "--now", &socket_unit]);
+        }
+        run_systemctl(config, &["restart", &unit]);
+        println!("{}", messages::unit_reinstalled(&unit));
+        return Ok(());
+    }
 
-    let (username, home_dir) = resolve_service_user()?;
-    let config = Config::new();
+    run_systemctl(config, &["daemon-reload"]);
+    if control_socket {
+        run_systemctl(config, &["enable", "--now", &socket_unit]);
+    }
+    run_systemctl(config, &["enable", "--now", &unit]);
+
+    match mode {
+        ServiceMode::Daemon => {
+            println!("{}", messages::service_installed(&config.service_name));
+            if control_socket {
+                println!("{}", messages::control_socket_path(&config.control_socket));
+            }
+        }
+        ServiceMode::Timer => println!(
+            "{}",
+            messages::timer_installed(&unit, settings.sync_interval.max(1))
+        ),
+    }
+    if config.user_mode {
+        println!("{}", messages::linger_hint(username));
+    }
+    Ok(())
+}
+
+/// Usuario (y grupo) con el que se ejecuta el servicio del sistema.
+struct ServiceUser {
+    name: String,
+    /// Grupo de `service_group`; sin él, el grupo principal del usuario
+    group: Option<String>,
+    home: String,
+}
+
+impl ServiceUser {
+    /// Argumento de `chown`. Sin grupo explícito se usa el del mismo nombre que el usuario
+    /// salvo en macOS, donde el grupo principal de los usuarios es `staff`.
+    fn owner(&self) -> String {
+        match &self.group {
+            Some(group) => format!("{}:{}", self.name, group),
+            None if cfg!(target_os = "macos") => self.name.clone(),
+            None => format!("{}:{}", self.name, self.name),
+        }
+    }
+}
 
-    let _ = config.ensure_exists().map_err(|e| {
+/// Ruta del ejecutable y usuario del servicio. Inicializa la configuración y, para el
+/// servicio del sistema, se la asigna al usuario.
+fn prepare_install(config: &Config, settings: &Settings) -> Result<(String, ServiceUser), String> {
+    let exe_path = env::current_exe().map_err(messages::exe_path_failed)?;
+    let exec_display = exe_path
+        .to_str()
+        .ok_or_else(messages::exe_path_not_utf8)?
+        .to_string();
+
+    let user = resolve_service_user(settings)?;
+
+    let _ = config
+        .ensure_exists()
+        .map_err(messages::config_init_failed)?;
+
+    // En Windows la tarea del sistema se ejecuta como SYSTEM y no hay propietarios que ajustar.
+    if !config.user_mode && !cfg!(windows) {
+        let owner = user.owner();
+        chown_path(&config.log_dir, &owner)?;
+        chown_path(&config.log_file, &owner)?;
+        chown_path(&config.state_file, &owner)?;
+        chown_path(&config.secrets_file, &owner)?;
+        chown_path(&config.credentials_file, &owner)?;
+        if Path::new(&config.secrets_key_file).exists() {
+            chown_path(&config.secrets_key_file, &owner)?;
+        }
+    }
+
+    Ok((exec_display, user))
+}
+
+/// Timer que arranca el servicio oneshot tras el arranque y luego cada `sync_interval`,
+/// contado desde que terminó la sincronización anterior. `sync_jitter` se traduce en un
+/// retraso aleatorio de systemd.
+fn timer_content(config: &Config, settings: &Settings) -> String {
+    let interval = settings.sync_interval.max(1);
+    let jitter = interval * u64::from(settings.sync_jitter.min(100)) / 100;
+    format!(
+        "[Unit]\nDescription=Sincronización periódica de Git Sync\n\n[Timer]\nOnBootSec=60\nOnUnitInactiveSec={interval}s\nAccuracySec=1s\nRandomizedDelaySec={jitter}s\nUnit={service}.service\n\n[Install]\nWantedBy=timers.target\n",
+        service = config.service_name
+    )
+}
+
+/// Unidad `.socket` del socket de control. systemd crea el socket con el propietario del
+/// servicio y se lo pasa al daemon (`Accept=no`), arrancándolo si aún no está activo.
+fn socket_content(config: &Config, user: &ServiceUser) -> String {
+    let owner = if config.user_mode {
+        String::new()
+    } else {
         format!(
-            "❌ No se pudo inicializar la estructura de configuración: {}",
-            e
+            "SocketUser={}\nSocketGroup={}\n",
+            user.name,
+            user.group.as_deref().unwrap_or(&user.name)
         )
-    })?;
+    };
+    format!(
+        "[Unit]\nDescription=Socket de control de Git Sync\n\n[Socket]\nListenStream={path}\nSocketMode=0660\n{owner}Accept=no\nRemoveOnStop=yes\n\n[Install]\nWantedBy=sockets.target\n",
+        path = config.control_socket
+    )
+}
 
-    chown_path(&config.log_dir, &username)?;
-    chown_path(&config.log_file, &username)?;
-    chown_path(&config.state_file, &username)?;
+/// Detiene, deshabilita y elimina la unidad del socket de control.
+fn remove_socket(config: &Config) -> Result<(), String> {
+    run_systemctl(
+        config,
+        &[
+            "disable",
+            "--now",
+            &format!("{}.socket", config.service_name),
+        ],
+    );
+    fs::remove_file(socket_path(config)).map_err(messages::socket_remove_failed)
+}
 
-    let service_content = format!(
-        "[Unit]\nDescription=Daemon de sincronización de Git Sync\nAfter=network-online.target\nWants=network-online.target\n\n[Service]\nType=simple\nUser={username}\nWorkingDirectory={home_dir}\nEnvironment=HOME={home_dir}\nExecStart={exec_display} daemon\nRestart=on-failure\nRestartSec=60\n\n[Install]\nWantedBy=multi-user.target\n"
+/// Reescribe el timer si cambiaron `sync_interval` o `sync_jitter` desde que se instaló.
+fn refresh_timer(config: &Config, settings: &Settings) -> Result<(), String> {
+    let timer_path = timer_path(config);
+    let content = timer_content(config, settings);
+    if fs::read_to_string(&timer_path).is_ok_and(|current| current == content) {
+        return Ok(());
+    }
+
+    write_service_file(config, &timer_path, &content)?;
+    run_systemctl(config, &["daemon-reload"]);
+    run_systemctl(
+        config,
+        &["restart", &format!("{}.timer", config.service_name)],
     );
+    println!(
+        "{}",
+        messages::timer_updated(&config.service_name, settings.sync_interval.max(1))
+    );
+    Ok(())
+}
 
-    write_service_file(&service_content)?;
+/// Detiene, deshabilita y elimina el servicio y, si existen, el timer y el socket.
+fn remove_units(config: &Config) -> Result<(), String> {
+    if Path::new(&socket_path(config)).exists() {
+        remove_socket(config)?;
+    }
+    let timer_path = timer_path(config);
+    if Path::new(&timer_path).exists() {
+        run_systemctl(
+            config,
+            &[
+                "disable",
+                "--now",
+                &format!("{}.timer", config.service_name),
+            ],
+        );
+        fs::remove_file(&timer_path).map_err(messages::timer_remove_failed)?;
+    }
 
-    run_systemctl(&["daemon-reload"]);
-    run_systemctl(&["enable", "--now", SERVICE_NAME]);
+    run_systemctl(config, &["disable", "--now", &config.service_name]);
 
-    println!("✅ Servicio instalado y habilitado correctamente.");
+    fs::remove_file(service_path(config)).map_err(messages::service_file_remove_failed)?;
+
+    run_systemctl(config, &["daemon-reload"]);
     Ok(())
 }
 
-pub fn uninstall_service() -> Result<(), String> {
-    if !Path::new(SERVICE_PATH).exists() {
-        return Err("ℹ️ El servicio git-sync no está instalado".to_string());
+pub fn uninstall_service(config: &Config) -> Result<(), String> {
+    if cfg!(target_os = "macos") {
+        return uninstall_launchd(config);
+    }
+    if cfg!(windows) {
+        return uninstall_scheduled_task(config);
     }
 
-    run_systemctl(&["disable", "--now", SERVICE_NAME]);
-
-    fs::remove_file(SERVICE_PATH)
-        .map_err(|e| format!("❌ No se pudo eliminar el archivo de servicio: {}", e))?;
+    let service_path = service_path(config);
+    if !Path::new(&service_path).exists() {
+        return Err(format!(
+            "ℹ️ {}",
+            messages::service_not_installed(&config.service_name)
+        ));
+    }
 
-    run_systemctl(&["daemon-reload"]);
+    remove_units(config)?;
 
-    println!("🗑️ Archivo de servicio eliminado.");
+    println!("{}", messages::service_file_removed());
     Ok(())
 }
 
-pub fn uninstall_all() -> Result<(), String> {
-    let config = Config::new();
+/// Muestra las diferencias entre la definición instalada en `path` y `content`, línea a
+/// línea (`-` la anterior, `+` la nueva). Devuelve si cambió.
+fn show_unit_diff(path: &str, content: &str) -> bool {
+    let Ok(installed) = fs::read_to_string(path) else {
+        println!("{}", messages::unit_created(path));
+        return true;
+    };
+    if installed == content {
+        println!("{}", messages::unit_unchanged(path));
+        return false;
+    }
+
+    let old: Vec<&str> = installed.lines().collect();
+    let new: Vec<&str> = content.lines().collect();
+    // Subsecuencia común más larga: las definiciones son cortas.
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    println!("{}", messages::unit_changes(path));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            println!("  - {}", old[i]);
+            i += 1;
+        } else {
+            println!("  + {}", new[j]);
+            j += 1;
+        }
+    }
+    true
+}
 
-    if Path::new(SERVICE_PATH).exists() {
-        uninstall_service()?;
+/// `git-sync service status`: archivo de la unidad y binario que ejecuta, si está
+/// habilitada y activa, las últimas líneas del registro y la última sincronización
+/// correcta. Con `group`, también el estado de cada repositorio del grupo. Devuelve `Err`
+/// si el servicio no está instalado o no está activo.
+pub fn print_status(
+    config: &Config,
+    settings: &Settings,
+    group: Option<(&str, Vec<RepoDefinition>)>,
+) -> Result<(), String> {
+    // Con `--group` el informe de sincronización se limita a los repositorios del grupo.
+    let in_scope = |repo_path: &str| {
+        group
+            .as_ref()
+            .is_none_or(|(_, repos)| repos.iter().any(|repo| repo.repo_path == repo_path))
+    };
+
+    println!("{}", messages::service_status(&config.service_name));
+
+    let unit_path = if cfg!(target_os = "macos") {
+        plist_path(config)
+    } else if cfg!(windows) {
+        task_marker_path(config)
+    } else if Path::new(&timer_path(config)).exists() {
+        timer_path(config)
+    } else {
+        service_path(config)
+    };
+    let Ok(unit) = fs::read_to_string(&unit_path) else {
+        println!("{}", messages::status_not_installed(&unit_path));
+        return Err(messages::service_not_installed(&config.service_name));
+    };
+    println!("{}", messages::status_definition(&unit_path));
+
+    let installed_mode = if cfg!(target_os = "macos") {
+        unit.contains("<key>StartInterval</key>")
+    } else if cfg!(windows) {
+        unit.contains("/SC MINUTE")
+    } else {
+        unit_path.ends_with(".timer")
+    };
+    let installed_mode = if installed_mode {
+        ServiceMode::Timer
     } else {
-        println!("ℹ️ El servicio git-sync no está instalado.");
+        ServiceMode::Daemon
+    };
+    println!(
+        "{}",
+        messages::status_mode(match installed_mode {
+            ServiceMode::Daemon => "daemon",
+            ServiceMode::Timer => "timer",
+        })
+    );
+    if installed_mode != settings.service_mode {
+        println!("{}", messages::status_mode_mismatch(config.command()));
     }
 
-    if Path::new(&config.config_dir).exists() {
-        fs::remove_dir_all(&config.config_dir).map_err(|e| {
-            format!(
-                "❌ No se pudo eliminar el directorio de configuración {}: {}",
-                config.config_dir, e
-            )
-        })?;
-        println!("🗑️ Configuración eliminada: {}", config.config_dir);
+    // En modo timer la unidad systemd solo contiene el timer: el binario está en el servicio.
+    let command_unit = if unit_path.ends_with(".timer") {
+        fs::read_to_string(service_path(config)).unwrap_or_default()
     } else {
-        println!("ℹ️ No existe configuración en {}", config.config_dir);
+        unit.clone()
+    };
+    let current = env::current_exe()
+        .map(|path| path.display().to_string())
+        .unwrap_or_default();
+    match installed_binary(&command_unit) {
+        Some(binary) if binary == current => println!("{}", messages::status_binary(binary)),
+        Some(binary) => println!("{}", messages::status_binary_outdated(binary, current)),
+        None => println!("{}", messages::status_binary_missing()),
     }
 
-    if Path::new(&config.log_dir).exists() {
-        fs::remove_dir_all(&config.log_dir).map_err(|e| {
-            format!(
-                "❌ No se pudo eliminar el directorio de logs {}: {}",
-                config.log_dir, e
+    let active = print_manager_state(config, installed_mode);
+
+    let state = SyncStateSnapshot::load(&config.state_file);
+    match state
+        .repos
+        .iter()
+        .filter(|repo| in_scope(&repo.repo_path))
+        .filter_map(|repo| repo.last_success_ts)
+        .max()
+    {
+        Some(ts) => println!(
+            "{}",
+            messages::status_last_success(
+                Local
+                    .timestamp_opt(ts, 0)
+                    .single()
+                    .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_default(),
+                humanize_elapsed(Utc::now().timestamp().saturating_sub(ts))
             )
-        })?;
-        println!("🗑️ Logs eliminados: {}", config.log_dir);
+        ),
+        None => println!("{}", messages::status_no_success()),
+    }
+    if let Some((group, repos)) = &group {
+        println!("{}", messages::status_group(group, repos.len()));
+        for repo in repos {
+            let repo_state = state.get(&repo.repo_path);
+            println!(
+                "{}",
+                match repo_state {
+                    Some(repo_state) if repo_state.has_active_error() => {
+                        messages::status_group_repo_failed(
+                            repo.name(),
+                            repo_state
+                                .last_error
+                                .as_deref()
+                                .and_then(|error| error.lines().next())
+                                .unwrap_or_default()
+                                .trim_start_matches("❌ "),
+                        )
+                    }
+                    Some(RepoSyncState {
+                        last_success_ts: Some(ts),
+                        ..
+                    }) => messages::status_group_repo_ok(
+                        repo.name(),
+                        humanize_elapsed(Utc::now().timestamp().saturating_sub(*ts))
+                    ),
+                    _ => messages::status_group_repo_never(repo.name()),
+                }
+            );
+        }
+    }
+    for repo in state.repos.iter().filter(|repo| in_scope(&repo.repo_path)) {
+        if let Some(head) = &repo.pending_deploy {
+            println!(
+                "{}",
+                messages::status_pending_deploy(&repo.repo_path, head.get(..7).unwrap_or(head))
+            );
+        }
+    }
+
+    let pauses = PauseState::load(&config.pause_file);
+    if let Some(pause) = pauses.global() {
+        println!("{}", messages::status_paused(pause.describe()));
+    }
+
+    let (source, lines) = recent_log_lines(config);
+    println!("{}", messages::status_recent_lines(source));
+    if lines.is_empty() {
+        println!("{}", messages::status_no_entries());
+    }
+    for line in lines {
+        println!("  {}", line);
+    }
+
+    if active {
+        Ok(())
     } else {
-        println!("ℹ️ No existe directorio de logs en {}", config.log_dir);
+        Err(messages::service_inactive(&config.service_name))
     }
+}
 
-    println!("✅ Desinstalación completada.");
-    println!("👉 Si desea eliminar el binario, borre manualmente /usr/local/bin/git-sync.");
-    Ok(())
+/// Ruta del ejecutable en la definición instalada: `ExecStart=` de systemd, el primer
+/// argumento del plist o el primer valor entre comillas de la tarea programada.
+fn installed_binary(unit: &str) -> Option<String> {
+    if let Some(exec) = unit
+        .lines()
+        .find_map(|line| line.strip_prefix("ExecStart="))
+    {
+        return exec.split_whitespace().next().map(str::to_string);
+    }
+    if let Some((_, arguments)) = unit.split_once("<key>ProgramArguments</key>") {
+        let (_, value) = arguments.split_once("<string>")?;
+        let (value, _) = value.split_once("</string>")?;
+        return Some(
+            value
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&amp;", "&"),
+        );
+    }
+    let (_, command) = unit.split_once("/TR \"")?;
+    command
+        .split_once('"')
+        .map(|(binary, _)| binary.to_string())
 }
 
-fn resolve_service_user() -> Result<(String, String), String> {
-    fn home_for_user(username: &str) -> Option<String> {
-        if let Ok(contents) = fs::read_to_string("/etc/passwd") {
-            for line in contents.lines() {
-                let parts: Vec<&str> = line.split(':').collect();
-                if parts.len() >= 6 && parts[0] == username {
-                    return Some(parts[5].to_string());
-                }
+/// Muestra si el gestor de servicios tiene la unidad habilitada y en ejecución, y
+/// devuelve si está activa. En modo timer lo que debe estar activo es el timer.
+fn print_manager_state(config: &Config, mode: ServiceMode) -> bool {
+    if cfg!(target_os = "macos") {
+        let target = format!("{}/{}", launchd_domain(config), launchd_label(config));
+        let Some(output) = service_tool_output(Command::new("launchctl"), &["print", &target])
+            .filter(|(success, _)| *success)
+            .map(|(_, output)| output)
+        else {
+            println!("{}", messages::launchd_not_loaded());
+            return false;
+        };
+        let state = output
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("state = "))
+            .map(str::to_string)
+            .unwrap_or_else(messages::unknown_state);
+        println!("{}", messages::launchd_loaded(&state));
+        // En modo timer el trabajo solo está en ejecución durante cada sincronización.
+        return mode == ServiceMode::Timer || state == "running";
+    }
+
+    if cfg!(windows) {
+        let Some(output) = service_tool_output(
+            Command::new("schtasks"),
+            &["/Query", "/TN", &config.service_name, "/FO", "LIST"],
+        )
+        .filter(|(success, _)| *success)
+        .map(|(_, output)| output) else {
+            println!("{}", messages::task_not_registered());
+            return false;
+        };
+        // La etiqueta depende del idioma del sistema (`Status:`, `Estado:`).
+        let status = output
+            .lines()
+            .find_map(|line| {
+                let (label, value) = line.split_once(':')?;
+                matches!(label.trim(), "Status" | "Estado").then(|| value.trim().to_string())
+            })
+            .unwrap_or_else(messages::unknown_state);
+        println!("{}", messages::task_registered(&status));
+        return mode == ServiceMode::Timer || status == "Running" || status == "En ejecución";
+    }
+
+    let unit = match mode {
+        ServiceMode::Daemon => config.service_name.clone(),
+        ServiceMode::Timer => format!("{}.timer", config.service_name),
+    };
+    let systemctl = || {
+        let mut command = Command::new("systemctl");
+        if config.user_mode {
+            command.arg("--user");
+        }
+        command
+    };
+    let query = |action: &str| {
+        service_tool_output(systemctl(), &[action, &unit])
+            .map(|(_, output)| output.trim().to_string())
+            .filter(|output| !output.is_empty())
+            .unwrap_or_else(messages::unknown_state)
+    };
+    let enabled = query("is-enabled");
+    let active = query("is-active");
+    let mark = |ok: bool| if ok { "✅" } else { "⚠️" };
+    println!(
+        "{}",
+        messages::status_enabled(mark(enabled == "enabled"), &enabled, &unit)
+    );
+    println!(
+        "{}",
+        messages::status_active(mark(active == "active"), &active)
+    );
+    active == "active"
+}
+
+/// Últimas líneas del journal del servicio o, si no está disponible (macOS, Windows o un
+/// sistema sin journald), del registro de git-sync. Devuelve también su origen.
+fn recent_log_lines(config: &Config) -> (String, Vec<String>) {
+    if !cfg!(target_os = "macos") && !cfg!(windows) {
+        let mut journalctl = Command::new("journalctl");
+        if config.user_mode {
+            journalctl.arg("--user");
+        }
+        let unit = format!("{}.service", config.service_name);
+        let lines = STATUS_LOG_LINES.to_string();
+        if let Some((true, output)) = service_tool_output(
+            journalctl,
+            &["-u", &unit, "-n", &lines, "--no-pager", "-o", "short-iso"],
+        ) {
+            let lines: Vec<String> = output
+                .lines()
+                .filter(|line| !line.starts_with("-- "))
+                .map(str::to_string)
+                .collect();
+            if !lines.is_empty() {
+                return (format!("journalctl -u {}", unit), lines);
             }
         }
-        None
     }
 
-    if let Ok(sudo_user) = env::var("SUDO_USER") {
-        if let Some(home) = home_for_user(&sudo_user) {
-            return Ok((sudo_user, home));
+    let content = fs::read_to_string(&config.log_file).unwrap_or_default();
+    let lines: Vec<&str> = content
+        .lines()
+        // Las líneas separadoras quedan como `[fecha] ` sin mensaje.
+        .filter(|line| {
+            !line
+                .split_once("] ")
+                .map_or(*line, |(_, message)| message)
+                .trim()
+                .is_empty()
+        })
+        .collect();
+    let lines = lines[lines.len().saturating_sub(STATUS_LOG_LINES)..]
+        .iter()
+        .map(|line| line.to_string())
+        .collect();
+    (config.log_file.clone(), lines)
+}
+
+/// Salida estándar de la herramienta y si terminó con éxito; `None` si no se pudo ejecutar.
+fn service_tool_output(mut command: Command, args: &[&str]) -> Option<(bool, String)> {
+    let output = command.args(args).stderr(Stdio::null()).output().ok()?;
+    Some((
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+    ))
+}
+
+/// Elimina el servicio, la configuración y los registros (estado, PID y socket incluidos).
+/// Los repositorios nunca se tocan: si alguno registrado está dentro de esos directorios
+/// (ej. con `--config`), no se elimina nada.
+pub fn uninstall_all(config: &Config) -> Result<(), String> {
+    let repos = config.try_read_repos()?;
+    for dir in [&config.config_dir, &config.log_dir] {
+        if let Some(repo) = repos
+            .iter()
+            .find(|repo| Path::new(&repo.repo_path).starts_with(dir))
+        {
+            return Err(messages::repo_inside_dir(&repo.repo_path, dir));
         }
     }
 
-    if let Ok(user) = env::var("USER") {
-        if let Some(home) = home_for_user(&user) {
-            return Ok((user, home));
+    let unit = if cfg!(target_os = "macos") {
+        plist_path(config)
+    } else if cfg!(windows) {
+        task_marker_path(config)
+    } else {
+        service_path(config)
+    };
+    if Path::new(&unit).exists() {
+        uninstall_service(config)?;
+    } else {
+        println!(
+            "ℹ️ {}.",
+            messages::service_not_installed(&config.service_name)
+        );
+    }
+
+    if Path::new(&config.log_dir).exists() {
+        fs::remove_dir_all(&config.log_dir)
+            .map_err(|e| messages::log_dir_remove_failed(&config.log_dir, e))?;
+        println!("{}", messages::logs_removed(&config.log_dir));
+    } else {
+        println!("{}", messages::no_log_dir(&config.log_dir));
+    }
+
+    if Path::new(&config.config_dir).exists() {
+        fs::remove_dir_all(&config.config_dir)
+            .map_err(|e| messages::config_dir_remove_failed(&config.config_dir, e))?;
+        println!("{}", messages::config_removed(&config.config_dir));
+    } else {
+        println!("{}", messages::no_config_dir(&config.config_dir));
+    }
+
+    println!("{}", messages::uninstall_done());
+    let binary = if cfg!(windows) {
+        env::current_exe()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|_| "git-sync.exe".to_string())
+    } else {
+        "/usr/local/bin/git-sync".to_string()
+    };
+    println!("{}", messages::remove_binary_hint(binary));
+    Ok(())
+}
+
+/// Etiqueta de launchd de esta instancia (`com.lui5gl.git-sync`).
+fn launchd_label(config: &Config) -> String {
+    format!("com.lui5gl.{}", config.service_name)
+}
+
+/// LaunchDaemon del sistema o, en modo usuario, LaunchAgent de `~/Library/LaunchAgents`.
+fn plist_path(config: &Config) -> String {
+    if config.user_mode {
+        format!(
+            "{}/Library/LaunchAgents/{}.plist",
+            env::var("HOME").unwrap_or_default().trim_end_matches('/'),
+            launchd_label(config)
+        )
+    } else {
+        format!("/Library/LaunchDaemons/{}.plist", launchd_label(config))
+    }
+}
+
+/// Dominio de launchctl: `system` o la sesión gráfica del usuario (`gui/<uid>`).
+fn launchd_domain(config: &Config) -> String {
+    if config.user_mode {
+        format!("gui/{}", platform::uid())
+    } else {
+        "system".to_string()
+    }
+}
+
+/// macOS: equivalente a la unidad systemd. En modo daemon launchd lo mantiene en ejecución
+/// (y lo relanza si termina con error); en modo timer ejecuta `git-sync sync` cada
+/// `sync_interval` con `StartInterval`. Si la configuración cambió, se recarga.
+fn install_launchd(config: &Config, settings: &Settings, force: bool) -> Result<(), String> {
+    let plist_path = plist_path(config);
+    let installed = fs::read_to_string(&plist_path).ok();
+
+    let (exec_display, user) = prepare_install(config, settings)?;
+
+    let mut arguments = vec![exec_display];
+    arguments.push(
+        match settings.service_mode {
+            ServiceMode::Daemon => "daemon",
+            ServiceMode::Timer => "sync",
         }
+        .to_string(),
+    );
+    if config.user_mode {
+        arguments.push("--user".to_string());
+    }
+    if config.custom_dir {
+        arguments.push("--config".to_string());
+        arguments.push(config.config_dir.clone());
     }
 
-    if let Ok(home) = env::var("HOME") {
-        if let Ok(user) = env::var("USER").or_else(|_| env::var("LOGNAME")) {
-            return Ok((user, home));
+    let string = |value: &str| format!("<string>{}</string>", xml_escape(value));
+    let mut plist = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n<plist version=\"1.0\">\n<dict>\n  <key>Label</key>\n  {}\n  <key>ProgramArguments</key>\n  <array>\n",
+        string(&launchd_label(config))
+    );
+    for argument in &arguments {
+        plist.push_str(&format!("    {}\n", string(argument)));
+    }
+    plist.push_str("  </array>\n  <key>RunAtLoad</key>\n  <true/>\n");
+    match settings.service_mode {
+        ServiceMode::Daemon => plist.push_str(
+            "  <key>KeepAlive</key>\n  <dict>\n    <key>SuccessfulExit</key>\n    <false/>\n  </dict>\n  <key>ThrottleInterval</key>\n  <integer>60</integer>\n",
+        ),
+        ServiceMode::Timer => plist.push_str(&format!(
+            "  <key>StartInterval</key>\n  <integer>{}</integer>\n",
+            settings.sync_interval.max(1)
+        )),
+    }
+    if !config.user_mode {
+        plist.push_str(&format!(
+            "  <key>UserName</key>\n  {}\n",
+            string(&user.name)
+        ));
+        if let Some(group) = &user.group {
+            plist.push_str(&format!("  <key>GroupName</key>\n  {}\n", string(group)));
         }
     }
+    // El PATH predeterminado de launchd no incluye Homebrew, donde suelen estar `git`,
+    // `npm`, `composer` o `hugo`.
+    plist.push_str(&format!(
+        "  <key>WorkingDirectory</key>\n  {home}\n  <key>EnvironmentVariables</key>\n  <dict>\n    <key>HOME</key>\n    {home}\n    <key>PATH</key>\n    {path}\n  </dict>\n  <key>StandardErrorPath</key>\n  {stderr}\n</dict>\n</plist>\n",
+        home = string(&user.home),
+        path = string("/opt/homebrew/bin:/usr/local/bin:/usr/bin:/bin:/usr/sbin:/sbin"),
+        stderr = string(&format!("{}/launchd.err", config.log_dir)),
+    ));
 
-    Err(
-        "❌ No fue posible determinar la información del usuario para instalar el servicio"
-            .to_string(),
-    )
+    if installed.as_deref() == Some(plist.as_str()) && !force {
+        return Ok(());
+    }
+    if force {
+        show_unit_diff(&plist_path, &plist);
+    }
+
+    // Recargar el trabajo también lo reinicia.
+    let domain = launchd_domain(config);
+    if installed.is_some() {
+        run_launchctl(&["bootout", &format!("{}/{}", domain, launchd_label(config))]);
+    }
+    write_service_file(config, &plist_path, &plist)?;
+    run_launchctl(&["bootstrap", &domain, &plist_path]);
+
+    let kind = if config.user_mode {
+        "LaunchAgent"
+    } else {
+        "LaunchDaemon"
+    };
+    match settings.service_mode {
+        ServiceMode::Daemon => println!(
+            "{}",
+            messages::launchd_installed(kind, launchd_label(config))
+        ),
+        ServiceMode::Timer => println!(
+            "{}",
+            messages::launchd_timer_installed(
+                kind,
+                launchd_label(config),
+                settings.sync_interval.max(1)
+            )
+        ),
+    }
+    Ok(())
 }
 
-fn write_service_file(content: &str) -> Result<(), String> {
-    let parent = Path::new(SERVICE_PATH)
-        .parent()
-        .ok_or_else(|| "❌ La ruta del servicio no es válida".to_string())?;
+fn uninstall_launchd(config: &Config) -> Result<(), String> {
+    let plist_path = plist_path(config);
+    if !Path::new(&plist_path).exists() {
+        return Err(format!(
+            "ℹ️ {}",
+            messages::service_not_installed(launchd_label(config))
+        ));
+    }
 
-    if !parent.exists() {
+    run_launchctl(&[
+        "bootout",
+        &format!("{}/{}", launchd_domain(config), launchd_label(config)),
+    ]);
+    fs::remove_file(&plist_path)
+        .map_err(|e| format!("❌ {}", messages::remove_failed(&plist_path, e)))?;
+
+    println!("{}", messages::service_file_removed());
+    Ok(())
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Definición con la que se creó la tarea programada, para no recrearla si no cambió.
+fn task_marker_path(config: &Config) -> String {
+    format!("{}/.scheduled-task", config.config_dir)
+}
+
+/// Windows: tarea programada (`schtasks`) en lugar de una unidad systemd. En modo daemon
+/// arranca `git-sync daemon` al iniciar el sistema (como SYSTEM) o al iniciar sesión (modo
+/// usuario); en modo timer ejecuta `git-sync sync` cada `sync_interval`, redondeado a
+/// minutos. Si la definición cambió, se reemplaza.
+fn install_scheduled_task(config: &Config, settings: &Settings, force: bool) -> Result<(), String> {
+    let (exec_display, user) = prepare_install(config, settings)?;
+
+    let mut task_command = format!(
+        "\"{}\" {}",
+        exec_display,
+        match settings.service_mode {
+            ServiceMode::Daemon => "daemon",
+            ServiceMode::Timer => "sync",
+        }
+    );
+    if config.user_mode {
+        task_command.push_str(" --user");
+    }
+    if config.custom_dir {
+        task_command.push_str(&format!(" --config \"{}\"", config.config_dir));
+    }
+
+    // `schtasks` admite como máximo 1439 minutos con `/SC MINUTE`.
+    let minutes = settings
+        .sync_interval
+        .max(1)
+        .div_ceil(60)
+        .min(1439)
+        .to_string();
+    let mut args = vec![
+        "/Create",
+        "/F",
+        "/TN",
+        config.service_name.as_str(),
+        "/TR",
+        task_command.as_str(),
+    ];
+    match settings.service_mode {
+        ServiceMode::Daemon if config.user_mode => args.extend(["/SC", "ONLOGON"]),
+        ServiceMode::Daemon => args.extend(["/SC", "ONSTART"]),
+        ServiceMode::Timer => args.extend(["/SC", "MINUTE", "/MO", minutes.as_str()]),
+    }
+    // Con `service_user`, schtasks pide la contraseña de esa cuenta.
+    if !config.user_mode {
+        let account = if settings
+            .service_user
+            .as_ref()
+            .is_some_and(|name| !name.is_empty())
+        {
+            user.name.as_str()
+        } else {
+            "SYSTEM"
+        };
+        args.extend(["/RU", account]);
+    }
+
+    let marker_path = task_marker_path(config);
+    let definition = format!("{}\n", args.join(" "));
+    let installed = fs::read_to_string(&marker_path).ok();
+    if installed.as_deref() == Some(definition.as_str()) && !force {
+        return Ok(());
+    }
+    if force {
+        show_unit_diff(&marker_path, &definition);
+    }
+
+    if installed.is_some() {
+        run_schtasks(&["/End", "/TN", &config.service_name]);
+    }
+    if !run_schtasks(&args) {
+        return Err(messages::task_create_failed(&config.service_name));
+    }
+    fs::write(&marker_path, definition)
+        .map_err(|e| messages::file_write_failed(&marker_path, e))?;
+
+    match settings.service_mode {
+        ServiceMode::Daemon => {
+            run_schtasks(&["/Run", "/TN", &config.service_name]);
+            println!("{}", messages::task_installed(&config.service_name));
+        }
+        ServiceMode::Timer => println!(
+            "{}",
+            messages::task_timer_installed(&config.service_name, minutes)
+        ),
+    }
+    Ok(())
+}
+
+fn uninstall_scheduled_task(config: &Config) -> Result<(), String> {
+    let marker_path = task_marker_path(config);
+    if !Path::new(&marker_path).exists() {
         return Err(format!(
-            "❌ El directorio del servicio {} no existe. ¿El sistema utiliza systemd?",
-            parent.display()
+            "ℹ️ {}",
+            messages::service_not_installed(&config.service_name)
         ));
     }
 
-    let mut file = File::create(SERVICE_PATH)
-        .map_err(|e| format!("❌ No se pudo crear el archivo de servicio: {}", e))?;
+    run_schtasks(&["/End", "/TN", &config.service_name]);
+    if !run_schtasks(&["/Delete", "/F", "/TN", &config.service_name]) {
+        return Err(messages::task_remove_failed(&config.service_name));
+    }
+    fs::remove_file(&marker_path)
+        .map_err(|e| format!("❌ {}", messages::remove_failed(&marker_path, e)))?;
+
+    println!("{}", messages::task_removed());
+    Ok(())
+}
+
+fn home_for_user(username: &str) -> Option<String> {
+    if let Ok(contents) = fs::read_to_string("/etc/passwd") {
+        for line in contents.lines() {
+            let parts: Vec<&str> = line.split(':').collect();
+            if parts.len() >= 6 && parts[0] == username {
+                return Some(parts[5].to_string());
+            }
+        }
+    }
+    // En macOS los usuarios no figuran en /etc/passwd (están en Directory Services).
+    let home = format!("/Users/{}", username);
+    (cfg!(target_os = "macos") && Path::new(&home).is_dir()).then_some(home)
+}
+
+fn group_exists(group: &str) -> bool {
+    // En macOS los grupos están en Directory Services; `chown` informará si no existe.
+    if cfg!(target_os = "macos") {
+        return true;
+    }
+    fs::read_to_string("/etc/group").is_ok_and(|contents| {
+        contents
+            .lines()
+            .any(|line| line.split(':').next() == Some(group))
+    })
+}
+
+/// `service_user` y `service_group` de la configuración o, si no se indicaron, el usuario
+/// que instala el servicio (el que ejecutó `sudo`).
+fn resolve_service_user(settings: &Settings) -> Result<ServiceUser, String> {
+    let group = settings
+        .service_group
+        .clone()
+        .filter(|group| !group.is_empty());
+    if let Some(group) = &group
+        && !cfg!(windows)
+        && !group_exists(group)
+    {
+        return Err(messages::service_group_missing(group));
+    }
+
+    if let Some(name) = settings
+        .service_user
+        .as_ref()
+        .filter(|name| !name.is_empty())
+    {
+        // En Windows la cuenta la valida schtasks.
+        let home = if cfg!(windows) {
+            platform::home_dir().unwrap_or_default()
+        } else {
+            home_for_user(name).ok_or_else(|| messages::service_user_missing(name))?
+        };
+        return Ok(ServiceUser {
+            name: name.clone(),
+            group,
+            home,
+        });
+    }
+
+    let user = |name: String, home: String| ServiceUser {
+        name,
+        group: group.clone(),
+        home,
+    };
+
+    if let Ok(sudo_user) = env::var("SUDO_USER")
+        && let Some(home) = home_for_user(&sudo_user)
+    {
+        return Ok(user(sudo_user, home));
+    }
+
+    if let Ok(name) = env::var("USER")
+        && let Some(home) = home_for_user(&name)
+    {
+        return Ok(user(name, home));
+    }
+
+    // En Windows no hay /etc/passwd: `USERNAME` y `USERPROFILE`.
+    if let Some(home) = platform::home_dir()
+        && let Ok(name) = env::var("USER")
+            .or_else(|_| env::var("LOGNAME"))
+            .or_else(|_| env::var("USERNAME"))
+    {
+        return Ok(user(name, home));
+    }
+
+    Err(messages::service_user_unknown())
+}
+
+fn write_service_file(config: &Config, service_path: &str, content: &str) -> Result<(), String> {
+    let parent = Path::new(service_path)
+        .parent()
+        .ok_or_else(messages::invalid_service_path)?;
+
+    if config.user_mode {
+        fs::create_dir_all(parent)
+            .map_err(|e| messages::service_dir_create_failed(parent.display(), e))?;
+    } else if !parent.exists() {
+        return Err(messages::service_dir_missing(parent.display()));
+    }
+
+    let mut file = File::create(service_path).map_err(messages::service_file_create_failed)?;
     file.write_all(content.as_bytes())
-        .map_err(|e| format!("❌ No se pudo escribir el archivo de servicio: {}", e))?;
-    file.sync_all().map_err(|e| {
-        format!(
-            "❌ No se pudo sincronizar el archivo de servicio en disco: {}",
-            e
-        )
-    })?;
+        .map_err(messages::service_file_write_failed)?;
+    file.sync_all()
+        .map_err(messages::service_file_sync_failed)?;
 
-    let permissions = fs::Permissions::from_mode(0o644);
-    fs::set_permissions(SERVICE_PATH, permissions).map_err(|e| {
-        format!(
-            "❌ No se pudieron asignar permisos al archivo de servicio: {}",
-            e
-        )
-    })?;
+    platform::set_mode(service_path, 0o644).map_err(messages::service_file_permissions_failed)?;
 
     Ok(())
 }
 
-fn run_systemctl(args: &[&str]) {
+fn run_systemctl(config: &Config, args: &[&str]) {
+    let mut command = Command::new("systemctl");
+    if config.user_mode {
+        command.arg("--user");
+    }
+    run_service_tool(command, "systemctl", args);
+}
+
+fn run_launchctl(args: &[&str]) {
+    run_service_tool(Command::new("launchctl"), "launchctl", args);
+}
+
+fn run_schtasks(args: &[&str]) -> bool {
+    run_service_tool(Command::new("schtasks"), "schtasks", args)
+}
+
+/// Ejecuta la herramienta del gestor de servicios; si falla, lo informa y devuelve `false`.
+fn run_service_tool(mut command: Command, tool: &str, args: &[&str]) -> bool {
     let log_error = |message: String| {
         let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
         eprintln!("[{}] {}", timestamp, message);
     };
 
-    match Command::new("systemctl").args(args).status() {
-        Ok(status) if status.success() => {}
+    match command.args(args).status() {
+        Ok(status) if status.success() => true,
         Ok(status) => {
-            log_error(format!(
-                "⚠️ systemctl {} finalizó con el estado {}. Es posible que deba ejecutarlo manualmente.",
-                args.join(" "),
-                status
-            ));
+            log_error(messages::service_tool_status(tool, args.join(" "), status));
+            false
         }
         Err(e) => {
-            log_error(format!(
-                "❌ No se pudo ejecutar systemctl {}: {}. Ejecútelo manualmente si es necesario.",
-                args.join(" "),
-                e
-            ));
+            log_error(messages::service_tool_failed(tool, args.join(" "), e));
+            false
         }
     }
 }
 
-fn chown_path(path: &str, username: &str) -> Result<(), String> {
+fn chown_path(path: &str, owner: &str) -> Result<(), String> {
     let status = Command::new("chown")
-        .arg(format!("{}:{}", username, username))
+        .arg(owner)
         .arg(path)
         .status()
-        .map_err(|e| format!("❌ No se pudo cambiar la propiedad de {}: {}", path, e))?;
+        .map_err(|e| messages::chown_failed(path, e))?;
 
     if status.success() {
         Ok(())
     } else {
-        Err(format!(
-            "❌ El comando chown para {} finalizó con el estado {}",
-            path, status
-        ))
+        Err(messages::chown_status(path, status))
     }
 }
@@ -1,61 +1,354 @@
+use crate::config::{DetachedPolicy, DirtyPolicy, DivergencePolicy};
+use crate::error::Error;
+use crate::git::GitBackendKind;
+use crate::i18n::Language;
+use crate::logger::{LogBackend, LogFormat, LogLevel, LogRotation};
+use crate::messages;
+use crate::migrate::CONFIG_VERSION;
+use crate::notifier::NotifyOn;
+use crate::retention::LogRetention;
+use crate::service::ServiceMode;
+use chrono::NaiveTime;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
+use std::time::Duration;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Settings {
+    /// Versión del esquema de configuración (ausente = 0, anterior al versionado)
+    #[serde(default)]
+    pub version: u32,
+
+    /// Idioma de los mensajes: es o en (predeterminado: el del entorno, ej. LANG)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+
     /// Tiempo de espera entre ciclos de sincronización (en segundos)
     pub sync_interval: u64,
 
     /// Detener el programa si hay algún error
     pub stop_on_error: bool,
 
-    /// Timeout para operaciones git (en segundos)
+    /// Timeout para clone, push y demás operaciones con el remoto (en segundos)
     pub git_timeout: u64,
 
+    /// Timeout para cada fetch (en segundos, 0 = sin límite)
+    #[serde(default = "default_fetch_timeout")]
+    pub fetch_timeout: u64,
+
+    /// Timeout para integrar los cambios: pull, rebase o merge (en segundos, 0 = sin límite)
+    #[serde(default = "default_pull_timeout")]
+    pub pull_timeout: u64,
+
+    /// Timeout para instalar dependencias, compilar o reconstruir contenedores (en segundos)
+    #[serde(default = "default_build_timeout")]
+    pub build_timeout: u64,
+
+    /// Timeout para migraciones, cachés y reinicios de servicios (en segundos)
+    #[serde(default = "default_hook_timeout")]
+    pub hook_timeout: u64,
+
     /// Número máximo de reintentos en caso de fallo temporal
     pub max_retries: u32,
 
-    /// Mostrar output detallado
-    pub verbose: bool,
+    /// Detalle del registro: error, warn, info, debug (comandos `git`) o trace (y su salida)
+    #[serde(default)]
+    pub log_level: LogLevel,
+
+    /// Formato del registro: text o json (un objeto por línea)
+    #[serde(default)]
+    pub log_format: LogFormat,
+
+    /// Destino del registro: file, journald, syslog o both (archivo y journal)
+    #[serde(default)]
+    pub log_backend: LogBackend,
+
+    /// Tamaño (MB) a partir del que se rota git-sync.log (0 = sin límite)
+    #[serde(default = "default_log_max_size_mb")]
+    pub log_max_size_mb: u64,
+
+    /// Días tras los que se rota git-sync.log aunque no alcance el tamaño (0 = nunca)
+    #[serde(default)]
+    pub log_max_age_days: u32,
+
+    /// Archivos rotados y comprimidos que se conservan
+    #[serde(default = "default_log_keep")]
+    pub log_keep: u32,
+
+    /// Tamaño total (MB) que pueden ocupar los registros rotados (0 = sin límite)
+    #[serde(default)]
+    pub log_retention_mb: u64,
+
+    /// Días tras los que se eliminan los registros rotados (0 = nunca)
+    #[serde(default)]
+    pub log_retention_days: u32,
 
     /// Ejecutar en modo continuo (loop infinito)
     pub continuous_mode: bool,
+
+    /// Instalación en systemd: daemon (servicio permanente) o timer (`git-sync sync` periódico)
+    #[serde(default)]
+    pub service_mode: ServiceMode,
+
+    /// Socket de control del daemon (`sync`, `ping`), con activación por socket de systemd
+    #[serde(default)]
+    pub control_socket: bool,
+
+    /// Usuario con el que se ejecuta el servicio del sistema (predeterminado: quien lo instala)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_user: Option<String>,
+
+    /// Grupo del servicio del sistema (predeterminado: el grupo principal del usuario)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_group: Option<String>,
+
+    /// Porcentaje máximo que se alarga al azar cada espera entre ciclos (0 = sin variación)
+    #[serde(default)]
+    pub sync_jitter: u32,
+
+    /// Resultados que generan notificaciones: never, errors, changes o all
+    #[serde(default)]
+    pub notify_on: NotifyOn,
+
+    /// Implementación de Git: libgit2 (en proceso) o cli (binario `git`)
+    #[serde(default)]
+    pub git_backend: GitBackendKind,
+
+    /// Profundidad de historial para clones y fetch (0 = historial completo)
+    #[serde(default)]
+    pub fetch_depth: u32,
+
+    /// Eliminar en cada fetch las ramas remotas que ya no existen (`fetch --prune`)
+    #[serde(default)]
+    pub fetch_prune: bool,
+
+    /// Eliminar las ramas locales integradas cuya rama remota ya no existe
+    #[serde(default)]
+    pub prune_local_branches: bool,
+
+    /// Días entre mantenimientos (`git gc`) de cada repositorio (0 = desactivado)
+    #[serde(default)]
+    pub maintenance_days: u32,
+
+    /// Franja horaria local en la que se permite el mantenimiento, ej. "02:00-05:00"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maintenance_window: Option<TimeWindow>,
+
+    /// Franjas horarias locales en las que no se despliega: solo se descargan los cambios
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub quiet_hours: Vec<TimeWindow>,
+
+    /// Política ante historial divergente: skip, reset o rescue
+    #[serde(default)]
+    pub on_divergence: DivergencePolicy,
+
+    /// Política ante cambios locales sin confirmar: abort, stash o discard
+    #[serde(default)]
+    pub on_dirty: DirtyPolicy,
+
+    /// Política ante HEAD desacoplado: skip o reattach
+    #[serde(default)]
+    pub on_detached: DetachedPolicy,
+
+    /// Nunca crear commits de merge; con false el historial divergente se fusiona
+    #[serde(default = "default_ff_only")]
+    pub ff_only: bool,
+
+    /// Simular los ciclos: se descarga y se informa de lo que se haría, sin aplicarlo
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Fallos consecutivos tras los que un repositorio se desactiva (0 = nunca)
+    #[serde(default)]
+    pub max_failures: u32,
+
+    /// Espacio libre mínimo (MB) en el sistema de archivos del repositorio para sincronizarlo
+    #[serde(default)]
+    pub min_free_mb: u64,
+
+    /// Directorios dentro de los que deben estar los repositorios (vacío = cualquiera)
+    #[serde(default)]
+    pub allowed_roots: Vec<String>,
+
+    /// Patrones de directorios (ej. "/srv/git/*") cuyos repositorios Git se sincronizan sin listarlos
+    #[serde(default)]
+    pub discover: Vec<String>,
+
+    /// Ajustes específicos por grupo de repositorios (`[groups.<nombre>]`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub groups: BTreeMap<String, GroupSettings>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct GroupSettings {
+    /// Filtro de notificaciones propio del grupo
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify_on: Option<NotifyOn>,
+
+    /// Sala de Matrix alternativa para los repositorios del grupo
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matrix_room_id: Option<String>,
+
+    /// Segundos entre sincronizaciones de los repositorios del grupo que no tienen `interval`
+    /// propio (reemplaza a `sync_interval`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<u64>,
+}
+
+impl GroupSettings {
+    /// `interval` del primer grupo de `names` que lo define.
+    pub fn interval_of(groups: &BTreeMap<String, GroupSettings>, names: &[String]) -> Option<u64> {
+        names
+            .iter()
+            .find_map(|name| groups.get(name).and_then(|group| group.interval))
+    }
+}
+
+/// Franja horaria local `HH:MM-HH:MM`; puede cruzar la medianoche (ej. `22:00-05:00`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TimeWindow {
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+impl TimeWindow {
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+
+    /// Tiempo que falta, desde `time`, para que termine la franja.
+    pub fn until_end(&self, time: NaiveTime) -> Duration {
+        let millis = self.end.signed_duration_since(time).num_milliseconds();
+        let millis = if millis > 0 {
+            millis
+        } else {
+            millis + 24 * 60 * 60 * 1000
+        };
+        Duration::from_millis(millis as u64)
+    }
+}
+
+impl TryFrom<String> for TimeWindow {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let parse = |time: &str| NaiveTime::parse_from_str(time.trim(), "%H:%M").ok();
+        value
+            .split_once('-')
+            .and_then(|(start, end)| Some((parse(start)?, parse(end)?)))
+            .map(|(start, end)| TimeWindow { start, end })
+            .ok_or_else(|| messages::invalid_time_window(&value))
+    }
+}
+
+impl From<TimeWindow> for String {
+    fn from(window: TimeWindow) -> Self {
+        format!(
+            "{}-{}",
+            window.start.format("%H:%M"),
+            window.end.format("%H:%M")
+        )
+    }
+}
+
+/// Límites de tiempo de cada tipo de operación, en segundos (0 = sin límite).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timeouts {
+    pub git: u64,
+    pub fetch: u64,
+    pub pull: u64,
+    pub build: u64,
+    pub hook: u64,
+}
+
+fn default_ff_only() -> bool {
+    true
+}
+
+fn default_log_max_size_mb() -> u64 {
+    10
+}
+
+fn default_log_keep() -> u32 {
+    5
+}
+
+fn default_fetch_timeout() -> u64 {
+    60
+}
+
+fn default_pull_timeout() -> u64 {
+    300
+}
+
+fn default_build_timeout() -> u64 {
+    600
+}
+
+fn default_hook_timeout() -> u64 {
+    120
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Settings {
+            version: CONFIG_VERSION,
+            language: None,
             sync_interval: 60,
             stop_on_error: true,
             git_timeout: 300,
+            fetch_timeout: default_fetch_timeout(),
+            pull_timeout: default_pull_timeout(),
+            build_timeout: default_build_timeout(),
+            hook_timeout: default_hook_timeout(),
             max_retries: 0,
-            verbose: true,
-            continuous_mode: true,
+            log_level: LogLevel::default(),
+            log_format: LogFormat::default(),
+            log_backend: LogBackend::default(),
+            log_max_size_mb: default_log_max_size_mb(),
+            log_max_age_days: 0,
+            log_keep: default_log_keep(),
+            log_retention_mb: 0,
+            log_retention_days: 0,
+            continuous_mode: false,
+            service_mode: ServiceMode::default(),
+            control_socket: false,
+            service_user: None,
+            service_group: None,
+            sync_jitter: 0,
+            notify_on: NotifyOn::default(),
+            git_backend: GitBackendKind::default(),
+            on_divergence: DivergencePolicy::default(),
+            on_dirty: DirtyPolicy::default(),
+            on_detached: DetachedPolicy::default(),
+            ff_only: default_ff_only(),
+            fetch_depth: 0,
+            fetch_prune: false,
+            maintenance_days: 0,
+            maintenance_window: None,
+            quiet_hours: Vec::new(),
+            prune_local_branches: false,
+            dry_run: false,
+            max_failures: 0,
+            min_free_mb: 0,
+            allowed_roots: Vec::new(),
+            discover: Vec::new(),
+            groups: BTreeMap::new(),
         }
     }
 }
 
 impl Settings {
     pub fn load_or_create(config_file: &str) -> Self {
-        if Path::new(config_file).exists() {
-            match fs::read_to_string(config_file) {
-                Ok(contents) => match toml::from_str(&contents) {
-                    Ok(settings) => return settings,
-                    Err(e) => {
-                        eprintln!(
-                            "⚠️ Error al interpretar config.toml: {}. Se utilizarán los valores predeterminados.",
-                            e
-                        );
-                    }
-                },
-                Err(e) => {
-                    eprintln!(
-                        "⚠️ Error al leer config.toml: {}. Se utilizarán los valores predeterminados.",
-                        e
-                    );
-                }
-            }
+        if let Some(settings) = Self::read(config_file) {
+            return settings;
         }
 
         let default_settings = Settings::default();
@@ -64,11 +357,138 @@ impl Settings {
             .expect("❌ No se pudo serializar la configuración");
 
         if let Err(e) = fs::write(config_file, &toml_string) {
-            eprintln!("❌ No se pudo crear config.toml: {}", e);
+            eprintln!("{}", messages::settings_create_failed(e));
         } else {
-            println!("⚙️ Archivo de configuración creado: {}", config_file);
+            println!("{}", messages::settings_created(config_file));
         }
 
         default_settings
     }
+
+    /// Lee la configuración sin crear ni reescribir el archivo.
+    pub fn load_or_default(config_file: &str) -> Self {
+        Self::read(config_file).unwrap_or_default()
+    }
+
+    fn read(config_file: &str) -> Option<Self> {
+        if !Path::new(config_file).exists() {
+            return None;
+        }
+
+        match Self::load(config_file) {
+            Ok(settings) => Some(settings),
+            Err(e) => {
+                eprintln!("{}", messages::settings_fallback(e));
+                None
+            }
+        }
+    }
+
+    pub fn timeouts(&self) -> Timeouts {
+        Timeouts {
+            git: self.git_timeout,
+            fetch: self.fetch_timeout,
+            pull: self.pull_timeout,
+            build: self.build_timeout,
+            hook: self.hook_timeout,
+        }
+    }
+
+    pub fn log_rotation(&self) -> LogRotation {
+        LogRotation {
+            max_bytes: self.log_max_size_mb.saturating_mul(1024 * 1024),
+            max_age: (self.log_max_age_days > 0)
+                .then(|| Duration::from_secs(u64::from(self.log_max_age_days) * 24 * 60 * 60)),
+            keep: self.log_keep,
+        }
+    }
+
+    pub fn log_retention(&self) -> LogRetention {
+        LogRetention {
+            max_bytes: self.log_retention_mb.saturating_mul(1024 * 1024),
+            max_age: (self.log_retention_days > 0)
+                .then(|| Duration::from_secs(u64::from(self.log_retention_days) * 24 * 60 * 60)),
+        }
+    }
+
+    /// Lee y valida la configuración, sin recurrir a los valores predeterminados.
+    pub fn load(config_file: &str) -> Result<Self, Error> {
+        let contents = fs::read_to_string(config_file)
+            .map_err(|e| Error::Config(messages::config_toml_read_failed(e)))?;
+        toml::from_str(&contents).map_err(|e| Error::Config(messages::config_toml_parse_failed(e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(value: &str) -> TimeWindow {
+        TimeWindow::try_from(value.to_string()).unwrap()
+    }
+
+    fn time(value: &str) -> NaiveTime {
+        NaiveTime::parse_from_str(value, "%H:%M").unwrap()
+    }
+
+    #[test]
+    fn time_window_parses_and_formats() {
+        assert_eq!(String::from(window("9:00 - 17:30")), "09:00-17:30");
+        assert!(TimeWindow::try_from("09:00".to_string()).is_err());
+        assert!(TimeWindow::try_from("25:00-26:00".to_string()).is_err());
+    }
+
+    #[test]
+    fn time_window_contains_its_start_but_not_its_end() {
+        let office = window("09:00-17:00");
+        assert!(office.contains(time("09:00")));
+        assert!(office.contains(time("16:59")));
+        assert!(!office.contains(time("17:00")));
+        assert!(!office.contains(time("08:59")));
+    }
+
+    #[test]
+    fn time_window_can_cross_midnight() {
+        let night = window("22:00-05:00");
+        assert!(night.contains(time("23:30")));
+        assert!(night.contains(time("00:00")));
+        assert!(night.contains(time("04:59")));
+        assert!(!night.contains(time("05:00")));
+        assert!(!night.contains(time("12:00")));
+    }
+
+    #[test]
+    fn time_window_until_end_wraps_around_midnight() {
+        let night = window("22:00-05:00");
+        assert_eq!(
+            night.until_end(time("23:00")),
+            Duration::from_secs(6 * 60 * 60)
+        );
+        assert_eq!(night.until_end(time("04:00")), Duration::from_secs(60 * 60));
+    }
+
+    #[test]
+    fn group_interval_uses_the_first_group_that_defines_one() {
+        let group = |interval| GroupSettings {
+            interval,
+            ..GroupSettings::default()
+        };
+        let groups = BTreeMap::from([
+            ("web".to_string(), group(None)),
+            ("prod".to_string(), group(Some(30))),
+            ("docs".to_string(), group(Some(3600))),
+        ]);
+        let names = |names: &[&str]| {
+            names
+                .iter()
+                .map(|name| name.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            GroupSettings::interval_of(&groups, &names(&["web", "prod", "docs"])),
+            Some(30)
+        );
+        assert_eq!(GroupSettings::interval_of(&groups, &names(&["web"])), None);
+        assert_eq!(GroupSettings::interval_of(&groups, &[]), None);
+    }
 }
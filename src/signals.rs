@@ -0,0 +1,104 @@
+use crate::config_watch::ConfigWatcher;
+use crate::control::{ControlSocket, SyncRequest};
+use crate::messages;
+use signal_hook::consts::{SIGINT, SIGTERM};
+use signal_hook::flag;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum WakeReason {
+    /// Se recibió SIGUSR1 o `sync` por el socket de control: sincronizar de inmediato
+    SyncRequested(SyncRequest),
+    /// Terminó la espera del intervalo configurado
+    IntervalElapsed,
+    /// Cambió un archivo del directorio de configuración (su nombre)
+    ConfigChanged(String),
+    /// Se recibió SIGTERM o SIGINT
+    Shutdown,
+}
+
+pub struct DaemonSignals {
+    sync_requested: Arc<AtomicBool>,
+    shutdown: Arc<AtomicBool>,
+    watcher: Option<ConfigWatcher>,
+    control: Option<ControlSocket>,
+}
+
+impl DaemonSignals {
+    pub fn register() -> Result<Self, String> {
+        let sync_requested = Arc::new(AtomicBool::new(false));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        // Windows no tiene SIGUSR1: ahí el daemon solo sincroniza por intervalo.
+        #[cfg(unix)]
+        flag::register(signal_hook::consts::SIGUSR1, Arc::clone(&sync_requested))
+            .map_err(messages::sigusr1_register_failed)?;
+
+        for signal in [SIGTERM, SIGINT] {
+            // Una segunda señal termina el proceso aunque haya un ciclo en curso.
+            flag::register_conditional_shutdown(signal, 1, Arc::clone(&shutdown))
+                .map_err(messages::signal_register_failed)?;
+            flag::register(signal, Arc::clone(&shutdown))
+                .map_err(messages::signal_register_failed)?;
+        }
+
+        Ok(DaemonSignals {
+            sync_requested,
+            shutdown,
+            watcher: None,
+            control: None,
+        })
+    }
+
+    /// Despertar también cuando cambie la configuración.
+    pub fn watch_config(&mut self, watcher: ConfigWatcher) {
+        self.watcher = Some(watcher);
+    }
+
+    /// Atender también las órdenes del socket de control.
+    pub fn listen_control(&mut self, control: ControlSocket) {
+        self.control = Some(control);
+    }
+
+    pub fn shutdown_requested(&self) -> bool {
+        self.shutdown.load(Ordering::SeqCst)
+    }
+
+    /// Espera hasta recibir una señal, hasta que cambie la configuración o hasta que expire
+    /// `timeout` (sin límite si es `None`).
+    pub fn wait(&mut self, timeout: Option<Duration>) -> WakeReason {
+        let started = Instant::now();
+
+        loop {
+            if self.shutdown_requested() {
+                return WakeReason::Shutdown;
+            }
+
+            let control_sync = self
+                .control
+                .as_ref()
+                .and_then(ControlSocket::sync_requested);
+            if self.sync_requested.swap(false, Ordering::SeqCst) {
+                return WakeReason::SyncRequested(SyncRequest::All);
+            }
+            if let Some(request) = control_sync {
+                return WakeReason::SyncRequested(request);
+            }
+
+            if let Some(file) = self.watcher.as_mut().and_then(ConfigWatcher::changed) {
+                return WakeReason::ConfigChanged(file);
+            }
+
+            if timeout.is_some_and(|limit| started.elapsed() >= limit) {
+                return WakeReason::IntervalElapsed;
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
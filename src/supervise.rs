@@ -0,0 +1,142 @@
+use crate::config::Config;
+use crate::logger::{ConsoleStyle, Logger};
+use crate::messages;
+use crate::platform;
+use crate::settings::Settings;
+use crate::signals::{DaemonSignals, WakeReason};
+use std::env;
+use std::fs;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::time::{Duration, Instant};
+
+/// Cada cuánto se comprueba si el daemon sigue en ejecución.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Espera antes del primer reinicio; se duplica con cada fallo seguido.
+const RESTART_DELAY_MIN: Duration = Duration::from_secs(1);
+const RESTART_DELAY_MAX: Duration = Duration::from_secs(60);
+/// Un daemon que funcionó al menos este tiempo no cuenta como fallo seguido.
+const STABLE_RUN: Duration = Duration::from_secs(60);
+
+/// `git-sync daemon --supervise`: para sistemas sin systemd (contenedores, distribuciones
+/// con otro init). Ejecuta el daemon como proceso hijo en primer plano y lo relanza si
+/// termina con error, con esperas crecientes hasta un minuto. Reenvía SIGTERM/SIGINT y
+/// SIGUSR1, y guarda su PID en `git-sync.pid` para que no se inicien dos supervisores.
+/// Con `--foreground` el supervisor y el daemon muestran el progreso con el mismo estilo.
+pub fn supervise(config: &Config, dry_run: bool, console: ConsoleStyle) -> Result<(), String> {
+    config.ensure_exists()?;
+    let settings = Settings::load_or_create(&config.settings_file);
+    let logger = Logger::new(config.log_file.clone())
+        .with_level(settings.log_level)
+        .with_format(settings.log_format)
+        .with_backend(settings.log_backend)
+        .with_rotation(settings.log_rotation())
+        .with_console(console);
+
+    let _pid_file = PidFile::acquire(&config.pid_file)?;
+    let mut signals = DaemonSignals::register()?;
+    let mut delay = RESTART_DELAY_MIN;
+
+    loop {
+        let started = Instant::now();
+        let mut child = spawn_daemon(config, dry_run, console != ConsoleStyle::Plain)?;
+        logger.log_line(&messages::supervisor_started(child.id()));
+
+        let Some(status) = wait_child(&mut child, &mut signals)? else {
+            logger.log_line(&messages::supervisor_stopped_with_daemon());
+            return Ok(());
+        };
+        if status.success() {
+            logger.log_line(&messages::supervisor_daemon_finished());
+            return Ok(());
+        }
+
+        if started.elapsed() >= STABLE_RUN {
+            delay = RESTART_DELAY_MIN;
+        }
+        logger.log_error(&messages::supervisor_restarting(status, delay.as_secs()));
+        if signals.wait(Some(delay)) == WakeReason::Shutdown {
+            logger.log_line(&messages::supervisor_stopped());
+            return Ok(());
+        }
+        delay = (delay * 2).min(RESTART_DELAY_MAX);
+    }
+}
+
+/// Lanza `git-sync daemon` con las mismas opciones de instancia que el supervisor. Va en
+/// su propio grupo de procesos para que un Ctrl+C en la terminal no le llegue dos veces
+/// (directamente y reenviado).
+fn spawn_daemon(config: &Config, dry_run: bool, foreground: bool) -> Result<Child, String> {
+    let exe = env::current_exe().map_err(messages::executable_path_failed)?;
+    let mut command = Command::new(exe);
+    command.arg("daemon").stdin(Stdio::null());
+    if dry_run {
+        command.arg("--dry-run");
+    }
+    if foreground {
+        command.arg("--foreground");
+    }
+    if config.user_mode {
+        command.arg("--user");
+    }
+    if config.custom_dir {
+        command.args(["--config", &config.config_dir]);
+    }
+    platform::new_process_group(&mut command)
+        .spawn()
+        .map_err(messages::daemon_start_failed)
+}
+
+/// Espera a que termine el daemon reenviándole las señales. Devuelve `None` si se pidió
+/// detener el supervisor (el daemon ya terminó).
+fn wait_child(
+    child: &mut Child,
+    signals: &mut DaemonSignals,
+) -> Result<Option<ExitStatus>, String> {
+    loop {
+        if let Some(status) = child.try_wait().map_err(messages::daemon_status_failed)? {
+            return Ok(Some(status));
+        }
+
+        match signals.wait(Some(POLL_INTERVAL)) {
+            WakeReason::Shutdown => {
+                platform::terminate(child);
+                let _ = child.wait();
+                return Ok(None);
+            }
+            WakeReason::SyncRequested(_) => platform::request_sync(child),
+            WakeReason::IntervalElapsed | WakeReason::ConfigChanged(_) => {}
+        }
+    }
+}
+
+/// Archivo con el PID del supervisor; se elimina al terminar.
+struct PidFile {
+    path: String,
+}
+
+impl PidFile {
+    /// Falla si otro supervisor sigue en ejecución; un archivo de un proceso que ya no
+    /// existe (ej. tras un reinicio del contenedor) se reemplaza.
+    fn acquire(path: &str) -> Result<Self, String> {
+        if let Some(pid) = fs::read_to_string(path)
+            .ok()
+            .and_then(|content| content.trim().parse::<u32>().ok())
+            .filter(|&pid| pid > 0 && pid != std::process::id())
+            && platform::process_alive(pid)
+        {
+            return Err(messages::supervisor_running(pid, path));
+        }
+
+        fs::write(path, format!("{}\n", std::process::id()))
+            .map_err(|e| messages::write_failed(path, e))?;
+        Ok(PidFile {
+            path: path.to_string(),
+        })
+    }
+}
+
+impl Drop for PidFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::messages;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -12,6 +13,51 @@ pub struct RepoSyncState {
     pub last_error: Option<String>,
     pub last_result: Option<String>,
     pub last_pulled_commit: Option<String>,
+    /// Último mantenimiento (`git gc`) de un repositorio con historial limitado
+    #[serde(default)]
+    pub last_maintenance_ts: Option<i64>,
+    /// Commits que llegaron en la última sincronización que movió HEAD
+    #[serde(default)]
+    pub last_deployment: Option<Deployment>,
+    /// Sincronizaciones fallidas seguidas desde el último éxito
+    #[serde(default)]
+    pub consecutive_failures: u32,
+    /// Momento en que se desactivó por superar `max_failures`; se omite hasta reactivarlo
+    #[serde(default)]
+    pub auto_disabled_ts: Option<i64>,
+    /// HEAD cuyo despliegue (preset) falló; se reintenta en cada ciclo mientras siga ahí
+    #[serde(default)]
+    pub pending_deploy: Option<String>,
+}
+
+impl RepoSyncState {
+    pub fn is_auto_disabled(&self) -> bool {
+        self.auto_disabled_ts.is_some()
+    }
+
+    /// Si el último intento terminó con error o quedó un despliegue a medias.
+    pub fn has_active_error(&self) -> bool {
+        if self.pending_deploy.is_some() {
+            return true;
+        }
+        match (self.last_error_ts, self.last_success_ts) {
+            (Some(error_ts), Some(success_ts)) => error_ts > success_ts,
+            (Some(_), None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Qué se desplegó: rango `anterior..nuevo`, hash completo de HEAD y los commits
+/// (`hash | autor | asunto`) incluidos en el rango.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Deployment {
+    pub range: String,
+    pub head: String,
+    pub commits: Vec<String>,
+    /// Archivos modificados en el rango; solo para `events.jsonl`, no se guardan en el estado
+    #[serde(skip)]
+    pub files: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -29,14 +75,9 @@ impl SyncStateSnapshot {
     }
 
     pub fn save(&self, path: &str) -> Result<(), String> {
-        let serialized = toml::to_string_pretty(self)
-            .map_err(|e| format!("No se pudo serializar el estado de sincronización: {}", e))?;
-        fs::write(path, serialized).map_err(|e| {
-            format!(
-                "No se pudo guardar el estado de sincronización en {}: {}",
-                path, e
-            )
-        })
+        let serialized =
+            toml::to_string_pretty(self).map_err(messages::sync_state_serialize_failed)?;
+        fs::write(path, serialized).map_err(|e| messages::sync_state_save_failed(path, e))
     }
 
     pub fn get(&self, repo_path: &str) -> Option<&RepoSyncState> {
@@ -63,13 +104,47 @@ impl SyncStateSnapshot {
         repo.last_result = Some(result);
         repo.last_pulled_commit = last_pulled_commit;
         repo.last_error = None;
+        repo.consecutive_failures = 0;
     }
 
-    pub fn mark_error(&mut self, repo_path: &str, error: String) {
+    pub fn mark_deployment(&mut self, repo_path: &str, deployment: Deployment) {
+        let repo = self.upsert_repo_mut(repo_path);
+        repo.last_deployment = Some(deployment);
+    }
+
+    /// Registra un fallo y devuelve cuántos van seguidos.
+    pub fn mark_error(&mut self, repo_path: &str, error: String) -> u32 {
         let now = Utc::now().timestamp();
         let repo = self.upsert_repo_mut(repo_path);
         repo.last_error_ts = Some(now);
         repo.last_error = Some(error);
+        repo.consecutive_failures += 1;
+        repo.consecutive_failures
+    }
+
+    /// Anota (o con `None` quita) el HEAD cuyo despliegue falló.
+    pub fn mark_pending_deploy(&mut self, repo_path: &str, head: Option<String>) {
+        let repo = self.upsert_repo_mut(repo_path);
+        repo.pending_deploy = head;
+    }
+
+    pub fn mark_auto_disabled(&mut self, repo_path: &str) {
+        let now = Utc::now().timestamp();
+        let repo = self.upsert_repo_mut(repo_path);
+        repo.auto_disabled_ts = Some(now);
+    }
+
+    /// Vuelve a activar un repositorio desactivado por fallos, con el contador a cero.
+    pub fn reenable(&mut self, repo_path: &str) {
+        let repo = self.upsert_repo_mut(repo_path);
+        repo.auto_disabled_ts = None;
+        repo.consecutive_failures = 0;
+    }
+
+    pub fn mark_maintenance(&mut self, repo_path: &str) {
+        let now = Utc::now().timestamp();
+        let repo = self.upsert_repo_mut(repo_path);
+        repo.last_maintenance_ts = Some(now);
     }
 
     fn upsert_repo_mut(&mut self, repo_path: &str) -> &mut RepoSyncState {
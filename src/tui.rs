@@ -1,7 +1,22 @@
-use crate::config::{Config, RepoDefinition};
-use crate::git::GitRepo;
-use crate::logger::Logger;
+use crate::activity::Activity;
+use crate::config::{
+    Config, DetachedPolicy, DirtyPolicy, DivergencePolicy, PullStrategy, RepoDefinition, SyncMode,
+    check_repo_path, format_interval, unique_name,
+};
+use crate::control;
+use crate::credentials::Credentials;
+use crate::environment::{self, load_env_file};
+use crate::events::EventLog;
+use crate::git::{self, GitBackendKind, GitRepo};
+use crate::logger::{LogBackend, LogFormat, LogLevel, LogRotation, Logger, Phase};
+use crate::logs::{self, LogFilter, LogTail};
+use crate::messages;
+use crate::notifier::Notifier;
+use crate::platform;
+use crate::preset::DeployPreset;
 use crate::processor::RepoProcessor;
+use crate::secrets::Secrets;
+use crate::settings::{GroupSettings, Settings, TimeWindow, Timeouts};
 use crate::sync_state::{RepoSyncState, SyncStateSnapshot};
 use chrono::Local;
 use crossterm::ExecutableCommand;
@@ -12,20 +27,50 @@ use crossterm::terminal::{
 use ratatui::Frame;
 use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
-use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
-use std::collections::HashMap;
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
 use std::io::{Stdout, stdout};
 use std::path::Path;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Tiempo que se espera a que el daemon empiece un ciclo pedido con `s`/`S` antes de avisar
+/// de que no parece haberlo atendido.
+const DAEMON_START_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Entradas del registro que conserva el panel `l` para desplazarse hacia atrás.
+const LOG_PANE_CAPACITY: usize = 2000;
 
 #[derive(Clone)]
 enum InputMode {
     Normal,
     AddingSource,
     EditingSource(usize),
+    /// Tras añadir un repositorio, elegir su preset de despliegue
+    ChoosingPreset(usize),
+}
+
+/// Sincronización pedida al daemon con `s`/`S`, que se sigue en la barra de estado hasta que
+/// el daemon guarda el resultado en `state.toml`.
+struct DaemonRequest {
+    /// Repositorio pedido; `None` si se pidieron todos
+    repo: Option<RepoDefinition>,
+    requested_at: SystemTime,
+    /// Si ya se vio al daemon procesarlo en `activity.toml`
+    started: bool,
+}
+
+/// Panel con las últimas entradas de `git-sync.log` (tecla `l`).
+struct LogPane {
+    tail: LogTail,
+    /// Nombre y ruta del repositorio cuyas entradas se muestran, con el filtro `f` activo
+    repo: Option<(String, String)>,
+    /// Entradas desplazadas hacia arriba desde el final; con 0 se siguen las nuevas
+    scroll: usize,
+    error: Option<String>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -36,28 +81,30 @@ struct RepoRefreshStatus {
     last_error: Option<String>,
 }
 
-pub fn run_repo_manager(config: &Config, sync_interval: u64) -> Result<(), String> {
-    enable_raw_mode().map_err(|e| format!("No se pudo activar el modo raw del terminal: {}", e))?;
+pub fn run_repo_manager(
+    config: &Config,
+    settings: &Settings,
+    read_only: bool,
+) -> Result<(), String> {
+    enable_raw_mode().map_err(messages::raw_mode_failed)?;
     let mut stdout = stdout();
     stdout
         .execute(EnterAlternateScreen)
-        .map_err(|e| format!("No se pudo abrir la pantalla alternativa: {}", e))?;
+        .map_err(messages::alternate_screen_failed)?;
 
     let backend = CrosstermBackend::new(stdout);
-    let mut terminal =
-        Terminal::new(backend).map_err(|e| format!("No se pudo inicializar el terminal: {}", e))?;
+    let mut terminal = Terminal::new(backend).map_err(messages::terminal_init_failed)?;
 
-    let result = run_loop(&mut terminal, config, sync_interval);
+    let result = run_loop(&mut terminal, config, settings, read_only);
 
-    disable_raw_mode()
-        .map_err(|e| format!("No se pudo desactivar el modo raw del terminal: {}", e))?;
+    disable_raw_mode().map_err(messages::raw_mode_disable_failed)?;
     terminal
         .backend_mut()
         .execute(LeaveAlternateScreen)
-        .map_err(|e| format!("No se pudo restaurar la pantalla original: {}", e))?;
+        .map_err(messages::screen_restore_failed)?;
     terminal
         .show_cursor()
-        .map_err(|e| format!("No se pudo restaurar el cursor del terminal: {}", e))?;
+        .map_err(messages::cursor_restore_failed)?;
 
     result
 }
@@ -65,56 +112,133 @@ pub fn run_repo_manager(config: &Config, sync_interval: u64) -> Result<(), Strin
 struct RepoManager<'a> {
     config: &'a Config,
     repos: Vec<RepoDefinition>,
-    list_state: ListState,
+    /// Líneas de `repositories.txt` al cargarlo o guardarlo por última vez, para detectar
+    /// cambios hechos por otros procesos (ej. `git-sync pause`) antes de sobrescribirlo.
+    saved_lines: Vec<String>,
+    table_state: TableState,
     input_mode: InputMode,
     input: String,
     message: Option<(String, Color)>,
     sync_interval: u64,
+    /// Ajustes de `[groups.<nombre>]`, para mostrar el intervalo de cada grupo
+    groups: BTreeMap<String, GroupSettings>,
+    notifier: Notifier,
+    /// Valores de `secrets.toml` que se ocultan en el registro
+    secret_values: Vec<String>,
+    env: Vec<(String, String)>,
+    credentials: Credentials,
+    git_backend: GitBackendKind,
+    timeouts: Timeouts,
+    divergence_policy: DivergencePolicy,
+    ff_only: bool,
+    dirty_policy: DirtyPolicy,
+    detached_policy: DetachedPolicy,
+    fetch_depth: u32,
+    fetch_prune: bool,
+    prune_local_branches: bool,
+    maintenance_days: u32,
+    maintenance_window: Option<TimeWindow>,
+    quiet_hours: Vec<TimeWindow>,
+    log_level: LogLevel,
+    log_format: LogFormat,
+    log_backend: LogBackend,
+    log_rotation: LogRotation,
+    dry_run: bool,
+    max_failures: u32,
+    min_free_mb: u64,
+    allowed_roots: Vec<String>,
     refresh_cursor: usize,
     last_refresh_step: Instant,
     refresh_step_interval: Duration,
     sync_state: SyncStateSnapshot,
+    /// Repositorio y etapa que está sincronizando el daemon (u otro proceso)
+    activity: Option<Activity>,
+    daemon_request: Option<DaemonRequest>,
+    log_pane: Option<LogPane>,
+    /// Si el panel de registro muestra solo el repositorio seleccionado
+    log_filter_repo: bool,
+    /// Entradas visibles en el panel de registro, para desplazarse por páginas
+    log_page: usize,
     refresh_status: HashMap<String, RepoRefreshStatus>,
     details_open: bool,
     details_lines: Vec<String>,
     details_repo_path: Option<String>,
+    read_only: bool,
+    group_filter: Option<String>,
 }
 
 impl<'a> RepoManager<'a> {
-    fn new(config: &'a Config, sync_interval: u64) -> Self {
-        let repos = config.read_repos();
-        let mut list_state = ListState::default();
+    fn new(config: &'a Config, settings: &Settings, read_only: bool) -> Result<Self, String> {
+        let repos = config.try_read_repos()?;
+        let sync_interval = settings.sync_interval;
+        let secrets =
+            Secrets::load(&config.secrets_file, &config.secrets_key_file).unwrap_or_default();
+        let mut table_state = TableState::default();
         if !repos.is_empty() {
-            list_state.select(Some(0));
+            table_state.select(Some(0));
         }
 
         let safe_interval = sync_interval.max(1);
         let active_count = repos.iter().filter(|repo| repo.enabled).count().max(1) as u64;
         let per_repo_ms = ((safe_interval * 1000) / active_count).clamp(800, 4000);
 
-        RepoManager {
+        Ok(RepoManager {
             config,
+            saved_lines: repos.iter().map(RepoDefinition::to_line).collect(),
             repos,
-            list_state,
+            table_state,
             input_mode: InputMode::Normal,
             input: String::new(),
             message: None,
             sync_interval: safe_interval,
+            groups: settings.groups.clone(),
+            notifier: Notifier::new(settings, &secrets),
+            secret_values: secrets.values(),
+            env: Vec::new(),
+            credentials: Credentials::default(),
+            git_backend: settings.git_backend,
+            timeouts: settings.timeouts(),
+            divergence_policy: settings.on_divergence,
+            ff_only: settings.ff_only,
+            dirty_policy: settings.on_dirty,
+            detached_policy: settings.on_detached,
+            fetch_depth: settings.fetch_depth,
+            fetch_prune: settings.fetch_prune,
+            prune_local_branches: settings.prune_local_branches,
+            maintenance_days: settings.maintenance_days,
+            maintenance_window: settings.maintenance_window,
+            quiet_hours: settings.quiet_hours.clone(),
+            log_level: settings.log_level,
+            log_format: settings.log_format,
+            log_backend: settings.log_backend,
+            log_rotation: settings.log_rotation(),
+            dry_run: settings.dry_run,
+            max_failures: settings.max_failures,
+            min_free_mb: settings.min_free_mb,
+            allowed_roots: settings.allowed_roots.clone(),
             refresh_cursor: 0,
             last_refresh_step: Instant::now(),
             refresh_step_interval: Duration::from_millis(per_repo_ms),
             sync_state: SyncStateSnapshot::load(&config.state_file),
+            activity: Activity::load(&config.activity_file),
+            daemon_request: None,
+            log_pane: None,
+            log_filter_repo: false,
+            log_page: 1,
             refresh_status: HashMap::new(),
             details_open: false,
-            details_lines: vec![
-                "Pulse Espacio para ver detalles del repositorio seleccionado.".to_string(),
-            ],
+            details_lines: vec![format!("{}.", messages::tui_details_hint())],
             details_repo_path: None,
-        }
+            read_only,
+            group_filter: None,
+        })
     }
 
     fn tick(&mut self) {
         self.sync_state = SyncStateSnapshot::load(&self.config.state_file);
+        self.activity = Activity::load(&self.config.activity_file);
+        self.follow_daemon_request();
+        self.poll_log_pane();
 
         if self.last_refresh_step.elapsed() >= self.refresh_step_interval {
             self.refresh_next_status();
@@ -129,9 +253,81 @@ impl<'a> RepoManager<'a> {
         }
     }
 
+    /// Índices de `repos` visibles con el filtro de grupo actual.
+    fn visible_indices(&self) -> Vec<usize> {
+        self.repos
+            .iter()
+            .enumerate()
+            .filter(|(_, repo)| match &self.group_filter {
+                Some(group) => repo.in_group(group),
+                None => true,
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn visible_repos(&self) -> Vec<&RepoDefinition> {
+        self.visible_indices()
+            .into_iter()
+            .filter_map(|index| self.repos.get(index))
+            .collect()
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.table_state.selected()?;
+        self.visible_indices().get(selected).copied()
+    }
+
     fn selected_repo(&self) -> Option<&RepoDefinition> {
-        let selected = self.list_state.selected()?;
-        self.repos.get(selected)
+        self.repos.get(self.selected_index()?)
+    }
+
+    fn all_groups(&self) -> Vec<String> {
+        let mut groups = self
+            .repos
+            .iter()
+            .flat_map(|repo| repo.groups.iter().cloned())
+            .collect::<Vec<_>>();
+        groups.sort();
+        groups.dedup();
+        groups
+    }
+
+    fn cycle_group_filter(&mut self) {
+        let groups = self.all_groups();
+        if groups.is_empty() {
+            self.group_filter = None;
+            self.set_message(messages::tui_no_groups(), Color::Yellow);
+            return;
+        }
+
+        self.group_filter = match &self.group_filter {
+            None => groups.first().cloned(),
+            Some(current) => groups
+                .iter()
+                .position(|group| group == current)
+                .and_then(|position| groups.get(position + 1))
+                .cloned(),
+        };
+        self.clamp_selection();
+
+        match self.group_filter.clone() {
+            Some(group) => self.set_message(messages::tui_group_filter(group), Color::Cyan),
+            None => self.set_message(messages::tui_group_filter_off(), Color::DarkGray),
+        }
+        if self.details_open {
+            self.refresh_details();
+        }
+    }
+
+    fn clamp_selection(&mut self) {
+        let visible = self.visible_indices().len();
+        if visible == 0 {
+            self.table_state.select(None);
+        } else {
+            let selected = self.table_state.selected().unwrap_or(0).min(visible - 1);
+            self.table_state.select(Some(selected));
+        }
     }
 
     fn selected_repo_state(&self) -> Option<&RepoSyncState> {
@@ -145,19 +341,22 @@ impl<'a> RepoManager<'a> {
     }
 
     fn error_count(&self) -> usize {
-        self.repos
-            .iter()
+        self.visible_repos()
+            .into_iter()
             .filter(|repo| repo.enabled)
             .filter(|repo| {
                 self.sync_state
                     .get(&repo.repo_path)
-                    .is_some_and(repo_has_active_error)
+                    .is_some_and(RepoSyncState::has_active_error)
             })
             .count()
     }
 
     fn paused_count(&self) -> usize {
-        self.repos.iter().filter(|repo| !repo.enabled).count()
+        self.visible_repos()
+            .into_iter()
+            .filter(|repo| !repo.enabled)
+            .count()
     }
 
     fn recompute_refresh_interval(&mut self) {
@@ -190,8 +389,8 @@ impl<'a> RepoManager<'a> {
     }
 
     fn outdated_count(&self) -> usize {
-        self.repos
-            .iter()
+        self.visible_repos()
+            .into_iter()
             .filter(|repo| repo.enabled)
             .filter(|repo| {
                 self.refresh_status
@@ -220,32 +419,78 @@ impl<'a> RepoManager<'a> {
         let now_ts = Local::now().timestamp();
 
         if !Path::new(&repo.repo_path).exists() {
+            let error = if repo.clone_url.is_some() {
+                messages::tui_pending_clone()
+            } else {
+                messages::tui_path_missing()
+            };
             self.refresh_status.insert(
                 repo.repo_path.clone(),
                 RepoRefreshStatus {
                     last_refresh_ts: Some(now_ts),
-                    last_error: Some("La ruta no existe".to_string()),
+                    last_error: Some(error),
                     ..RepoRefreshStatus::default()
                 },
             );
             return;
         }
 
-        if !Path::new(&format!("{}/.git", repo.repo_path)).exists() {
+        let git_repo = GitRepo::new(repo.repo_path.clone())
+            .with_env(&self.env)
+            .with_env(&repo.env)
+            .with_ssh_key(repo.ssh_key.as_deref())
+            .with_credentials(&self.credentials)
+            .with_backend(self.git_backend)
+            .with_timeouts(&self.timeouts)
+            .with_depth(repo.fetch_depth.unwrap_or(self.fetch_depth))
+            .with_remote(repo.remote_name())
+            .with_branch(repo.branch.as_deref())
+            .with_prune(
+                repo.fetch_prune.unwrap_or(self.fetch_prune)
+                    || repo
+                        .prune_local_branches
+                        .unwrap_or(self.prune_local_branches),
+            );
+
+        let bare = repo.sync_mode == SyncMode::Bare;
+        let valid = if bare {
+            git_repo.is_bare_repository()
+        } else {
+            git_repo.is_work_tree()
+        };
+        if !valid {
             self.refresh_status.insert(
                 repo.repo_path.clone(),
                 RepoRefreshStatus {
                     last_refresh_ts: Some(now_ts),
-                    last_error: Some("No es un repositorio Git válido".to_string()),
+                    last_error: Some(messages::tui_not_git_repo()),
                     ..RepoRefreshStatus::default()
                 },
             );
             return;
         }
 
-        let git_repo = GitRepo::new(repo.repo_path.clone());
-        let result = git_repo
-            .fetch()
+        // Un mirror bare no tiene rama local que comparar: actualizarlo es sincronizarlo.
+        if bare {
+            let branch = git_repo.current_branch().ok().flatten();
+            self.refresh_status.insert(
+                repo.repo_path.clone(),
+                RepoRefreshStatus {
+                    branch,
+                    last_refresh_ts: Some(now_ts),
+                    ..RepoRefreshStatus::default()
+                },
+            );
+            return;
+        }
+
+        // En solo lectura no se ejecuta `git fetch` para no modificar `.git` con otro usuario.
+        let fetch_result = if self.read_only {
+            Ok(())
+        } else {
+            git_repo.fetch()
+        };
+        let result = fetch_result
             .and_then(|_| {
                 let branch = git_repo.get_default_branch();
                 let behind = git_repo.count_commits_behind(&branch)?;
@@ -279,12 +524,13 @@ impl<'a> RepoManager<'a> {
     }
 
     fn select_next(&mut self) {
-        let next_index = match self.list_state.selected() {
-            Some(i) if !self.repos.is_empty() => (i + 1).min(self.repos.len() - 1),
+        let visible = self.visible_indices().len();
+        let next_index = match self.table_state.selected() {
+            Some(i) if visible > 0 => (i + 1).min(visible - 1),
             _ => 0,
         };
-        if !self.repos.is_empty() {
-            self.list_state.select(Some(next_index));
+        if visible > 0 {
+            self.table_state.select(Some(next_index));
             if self.details_open {
                 self.refresh_details();
             }
@@ -292,28 +538,130 @@ impl<'a> RepoManager<'a> {
     }
 
     fn select_previous(&mut self) {
-        let prev_index = match self.list_state.selected() {
+        let prev_index = match self.table_state.selected() {
             Some(i) if i > 0 => i - 1,
             _ => 0,
         };
-        if !self.repos.is_empty() {
-            self.list_state.select(Some(prev_index));
+        if !self.visible_indices().is_empty() {
+            self.table_state.select(Some(prev_index));
             if self.details_open {
                 self.refresh_details();
             }
         }
     }
 
+    fn toggle_log_pane(&mut self) {
+        if self.log_pane.take().is_some() {
+            self.set_message(messages::tui_log_off(), Color::DarkGray);
+        } else {
+            self.open_log_pane();
+            self.set_message(messages::tui_log_on(), Color::Cyan);
+        }
+    }
+
+    /// Alterna entre el registro completo y solo las entradas del repositorio seleccionado.
+    fn toggle_log_filter(&mut self) {
+        if self.log_pane.is_none() {
+            self.set_message(messages::tui_log_closed(), Color::Yellow);
+            return;
+        }
+        self.log_filter_repo = !self.log_filter_repo;
+        self.open_log_pane();
+        match self.log_pane.as_ref().and_then(|pane| pane.repo.as_ref()) {
+            Some((name, _)) => {
+                let message = messages::tui_log_filter_on(name);
+                self.set_message(message, Color::Cyan);
+            }
+            None => self.set_message(messages::tui_log_filter_off(), Color::DarkGray),
+        }
+    }
+
+    /// (Re)abre el panel leyendo el registro desde el principio con el filtro actual.
+    fn open_log_pane(&mut self) {
+        let repo = self
+            .log_filter_repo
+            .then(|| self.selected_repo())
+            .flatten()
+            .map(|repo| (repo.name().to_string(), repo.repo_path.clone()));
+        let filter = LogFilter {
+            repo: repo.clone(),
+            ..LogFilter::default()
+        };
+        self.log_pane = Some(LogPane {
+            tail: LogTail::new(&self.config.log_file, filter, LOG_PANE_CAPACITY),
+            repo,
+            scroll: 0,
+            error: None,
+        });
+        self.poll_log_pane();
+    }
+
+    fn poll_log_pane(&mut self) {
+        let Some(pane) = &self.log_pane else {
+            return;
+        };
+        let selected_path = self.selected_repo().map(|repo| repo.repo_path.as_str());
+        if self.log_filter_repo
+            && pane.repo.as_ref().map(|(_, path)| path.as_str()) != selected_path
+        {
+            self.open_log_pane();
+            return;
+        }
+
+        let log_file = &self.config.log_file;
+        let log_backend = self.log_backend;
+        let Some(pane) = &mut self.log_pane else {
+            return;
+        };
+        match pane.tail.poll() {
+            Ok(added) => {
+                pane.error = None;
+                // Desplazado hacia arriba, la vista se mantiene en las mismas entradas.
+                if pane.scroll > 0 {
+                    pane.scroll += added;
+                }
+                pane.scroll = pane.scroll.min(pane.tail.entries().len());
+            }
+            Err(_) if !Path::new(log_file).exists() => {
+                pane.error = Some(logs::missing_log(log_file, log_backend));
+            }
+            Err(err) => pane.error = Some(err),
+        }
+    }
+
+    /// Desplaza el panel de registro `pages` páginas hacia atrás (positivo) o adelante.
+    fn scroll_log(&mut self, pages: isize) {
+        let page = self.log_page;
+        let Some(pane) = &mut self.log_pane else {
+            return;
+        };
+        let oldest = pane.tail.entries().len().saturating_sub(page);
+        let offset = pages.unsigned_abs() * page;
+        pane.scroll = if pages > 0 {
+            pane.scroll.saturating_add(offset).min(oldest)
+        } else {
+            pane.scroll.saturating_sub(offset)
+        };
+    }
+
+    fn scroll_log_to(&mut self, oldest: bool) {
+        let page = self.log_page;
+        if let Some(pane) = &mut self.log_pane {
+            pane.scroll = if oldest {
+                pane.tail.entries().len().saturating_sub(page)
+            } else {
+                0
+            };
+        }
+    }
+
     fn toggle_details(&mut self) {
         self.details_open = !self.details_open;
         if self.details_open {
             self.refresh_details();
-            self.set_message(
-                "Vista detallada activada (últimos commits y errores)",
-                Color::Cyan,
-            );
+            self.set_message(messages::tui_details_on(), Color::Cyan);
         } else {
-            self.set_message("Vista detallada oculta", Color::DarkGray);
+            self.set_message(messages::tui_details_off(), Color::DarkGray);
         }
     }
 
@@ -323,7 +671,7 @@ impl<'a> RepoManager<'a> {
         let Some(repo_path) = self.selected_repo().map(|repo| repo.repo_path.clone()) else {
             self.details_repo_path = None;
             self.details_lines
-                .push("No hay repositorio seleccionado.".to_string());
+                .push(format!("{}.", messages::tui_no_selection()));
             return;
         };
         let repo_enabled = self
@@ -332,12 +680,96 @@ impl<'a> RepoManager<'a> {
             .unwrap_or(true);
 
         self.details_repo_path = Some(repo_path.clone());
+        let name = self
+            .selected_repo()
+            .map(|repo| repo.name().to_string())
+            .unwrap_or_default();
+        self.details_lines.push(messages::tui_name(name));
         self.details_lines
-            .push(format!("Repositorio: {}", repo_path));
-        self.details_lines.push(format!(
-            "Sincronización: {}",
-            if repo_enabled { "Activa" } else { "Pausada" }
-        ));
+            .push(messages::tui_repository(&repo_path));
+        let sync_label = match self.sync_state.get(&repo_path) {
+            _ if !repo_enabled => messages::tui_paused(),
+            Some(state) if state.is_auto_disabled() => {
+                messages::tui_auto_disabled(state.consecutive_failures)
+            }
+            _ => messages::tui_active(),
+        };
+        self.details_lines.push(messages::tui_sync(sync_label));
+        let groups = self
+            .selected_repo()
+            .map(|repo| repo.groups.join(", "))
+            .filter(|groups| !groups.is_empty())
+            .unwrap_or_else(|| "-".to_string());
+        self.details_lines.push(messages::tui_groups(groups));
+        if let Some(repo) = self.selected_repo() {
+            let mode = match &repo.track_tag {
+                Some(pattern) => format!("tag:{}", pattern),
+                None if repo.clean => format!("{} + clean", repo.sync_mode.as_str()),
+                None if repo.sync_mode == SyncMode::Pull
+                    && repo.pull_strategy == PullStrategy::Rebase =>
+                {
+                    "pull + rebase".to_string()
+                }
+                None if repo.sync_mode == SyncMode::Pull
+                    && !repo.ff_only.unwrap_or(self.ff_only) =>
+                {
+                    "pull + merge".to_string()
+                }
+                None => repo.sync_mode.as_str().to_string(),
+            };
+            let remote = messages::tui_remote(repo.remote_name());
+            let mirror = repo.mirror_to.as_ref().map(messages::tui_mirror);
+            let signed = repo.require_signed.then(|| match &repo.signers {
+                Some(signers) => messages::tui_signed_by(signers),
+                None => messages::tui_signed(),
+            });
+            let interval = match repo.interval {
+                Some(seconds) => Some(messages::tui_interval(format_interval(seconds))),
+                None => GroupSettings::interval_of(&self.groups, &repo.groups)
+                    .map(|seconds| messages::tui_group_interval(format_interval(seconds))),
+            };
+            let after =
+                (!repo.after.is_empty()).then(|| messages::tui_after(repo.after.join(", ")));
+            let units = (!repo.restart_units.is_empty())
+                .then(|| messages::tui_restart_units(repo.restart_units.join(", ")));
+            let preset = repo.preset.map(|preset| {
+                let steps = repo.deploy_steps();
+                let steps = steps.iter().map(|step| step.as_str());
+                messages::tui_preset(preset.as_str(), steps.collect::<Vec<_>>().join(", "))
+            });
+            let compose = repo
+                .compose
+                .map(|engine| messages::tui_compose(engine.as_str()));
+            let dry_run = repo
+                .dry_run
+                .unwrap_or(self.dry_run)
+                .then(messages::tui_dry_run);
+            let ssh_key = repo.ssh_key.as_ref().map(messages::tui_ssh_key);
+            // Solo los nombres: los valores pueden incluir credenciales del proxy.
+            let env = (!repo.env.is_empty()).then(|| {
+                let names = repo.env.iter().map(|(name, _)| name.as_str());
+                messages::tui_env(names.collect::<Vec<_>>().join(", "))
+            });
+            let git_binary = (self.git_backend == GitBackendKind::Libgit2
+                && (git::needs_git_binary(&self.env) || git::needs_git_binary(&repo.env)))
+            .then(messages::tui_git_binary);
+            self.details_lines.push(messages::tui_sync_mode(mode));
+            self.details_lines.push(remote);
+            self.details_lines.extend(mirror);
+            self.details_lines.extend(signed);
+            self.details_lines.extend(interval);
+            self.details_lines.extend(after);
+            self.details_lines.extend(units);
+            self.details_lines.extend(preset);
+            self.details_lines.extend(compose);
+            self.details_lines.extend(dry_run);
+            self.details_lines.extend(ssh_key);
+            self.details_lines.extend(env);
+            self.details_lines.extend(git_binary);
+        }
+        if let Some(url) = self.selected_repo().and_then(|repo| repo.clone_url.clone()) {
+            self.details_lines.push(messages::tui_cloned_from(url));
+        }
 
         let state = self.sync_state.get(&repo_path);
         let refresh = self.refresh_status.get(&repo_path);
@@ -346,81 +778,99 @@ impl<'a> RepoManager<'a> {
             .or_else(|| state.and_then(|s| s.last_branch.clone()))
             .unwrap_or_else(|| "-".to_string());
         self.details_lines
-            .push(format!("Rama detectada: {}", branch));
+            .push(messages::tui_detected_branch(branch));
 
         let pending = refresh
             .and_then(|s| s.commits_behind)
             .map(|count| count.to_string())
             .unwrap_or_else(|| "-".to_string());
         self.details_lines
-            .push(format!("Commits pendientes (remoto): {}", pending));
+            .push(messages::tui_pending_remote(pending));
 
         if let Some(ts) = refresh.and_then(|s| s.last_refresh_ts) {
-            self.details_lines.push(format!(
-                "Última actualización remota: hace {}",
-                humanize_elapsed(Local::now().timestamp().saturating_sub(ts))
-            ));
-        } else {
             self.details_lines
-                .push("Última actualización remota: sin datos".to_string());
+                .push(messages::tui_last_refresh_ago(humanize_elapsed(
+                    Local::now().timestamp().saturating_sub(ts),
+                )));
+        } else {
+            self.details_lines.push(messages::tui_last_refresh_none());
         }
 
         if let Some(refresh_error) = refresh.and_then(|s| s.last_error.clone()) {
-            self.details_lines.push(format!(
-                "Error de actualización remota: {}",
-                truncate_message(&refresh_error, 120)
-            ));
+            self.details_lines
+                .push(messages::tui_refresh_error(truncate_message(
+                    &refresh_error,
+                    120,
+                )));
         }
 
         if let Some(last_pull) = state.and_then(|s| s.last_pulled_commit.clone()) {
-            self.details_lines.push(format!(
-                "Último commit aplicado en la actualización: {}",
-                last_pull
-            ));
-        } else {
             self.details_lines
-                .push("Último commit aplicado en la actualización: sin datos".to_string());
+                .push(messages::tui_last_pulled(last_pull));
+        } else {
+            self.details_lines.push(messages::tui_last_pulled_none());
+        }
+
+        if let Some(deployment) = state.and_then(|s| s.last_deployment.as_ref()) {
+            self.details_lines.push(messages::tui_last_deployment(
+                &deployment.range,
+                &deployment.head,
+            ));
+            for commit in deployment.commits.iter().take(5) {
+                self.details_lines.push(format!("  - {}", commit));
+            }
+            if deployment.commits.len() > 5 {
+                self.details_lines
+                    .push(messages::tui_more_commits(deployment.commits.len() - 5));
+            }
         }
 
         if let Some(last_error) = state.and_then(|s| s.last_error.clone()) {
-            self.details_lines
-                .push("Último error detallado:".to_string());
+            self.details_lines.push(messages::tui_last_error_header());
             for line in wrap_text(&last_error, 120, 3) {
                 self.details_lines.push(format!("  {}", line));
             }
         } else {
-            self.details_lines
-                .push("Último error detallado: sin errores registrados".to_string());
+            self.details_lines.push(messages::tui_last_error_none());
         }
 
         if !Path::new(&repo_path).exists() {
             self.details_lines
-                .push("No se puede leer commits: la ruta no existe".to_string());
+                .push(messages::tui_commits_path_missing());
             return;
         }
 
-        if !Path::new(&format!("{}/.git", repo_path)).exists() {
-            self.details_lines
-                .push("No se puede leer commits: no es un repositorio Git válido".to_string());
+        let (repo_env, bare) = self
+            .selected_repo()
+            .map(|repo| (repo.env.clone(), repo.sync_mode == SyncMode::Bare))
+            .unwrap_or_default();
+        let git_repo = GitRepo::new(repo_path)
+            .with_env(&self.env)
+            .with_env(&repo_env)
+            .with_backend(self.git_backend);
+
+        let valid = if bare {
+            git_repo.is_bare_repository()
+        } else {
+            git_repo.is_work_tree()
+        };
+        if !valid {
+            self.details_lines.push(messages::tui_commits_not_git());
             return;
         }
 
-        let git_repo = GitRepo::new(repo_path);
         match git_repo.recent_commits(5) {
             Ok(commits) if commits.is_empty() => {
-                self.details_lines
-                    .push("Últimos commits: sin historial".to_string());
+                self.details_lines.push(messages::tui_no_history());
             }
             Ok(commits) => {
-                self.details_lines
-                    .push("Últimos commits locales:".to_string());
+                self.details_lines.push(messages::tui_recent_commits());
                 for commit in commits {
                     self.details_lines.push(format!("  - {}", commit));
                 }
             }
             Err(err) => {
-                self.details_lines
-                    .push(format!("No se pudieron leer commits: {}", err));
+                self.details_lines.push(messages::tui_commits_failed(err));
             }
         }
     }
@@ -428,50 +878,56 @@ impl<'a> RepoManager<'a> {
     fn start_add(&mut self) {
         self.input_mode = InputMode::AddingSource;
         self.input.clear();
-        self.set_message(
-            "Ruta local del repositorio a sincronizar (ej. /var/www/html/mi-app)",
-            Color::Cyan,
-        );
+        self.set_message(messages::tui_add_prompt(), Color::Cyan);
     }
 
     fn start_edit(&mut self) {
-        if let Some(index) = self.list_state.selected()
+        if let Some(index) = self.selected_index()
             && let Some(repo) = self.repos.get(index)
         {
             self.input_mode = InputMode::EditingSource(index);
-            self.input = repo.repo_path.clone();
-            self.set_message(
-                "Edite la ruta local del repositorio seleccionado",
-                Color::Cyan,
-            );
+            self.input = repo.source_spec();
+            self.set_message(messages::tui_edit_prompt(), Color::Cyan);
         }
     }
 
     fn delete_selected(&mut self) -> Result<(), String> {
-        if let Some(index) = self.list_state.selected()
+        if let Some(index) = self.selected_index()
             && index < self.repos.len()
         {
             self.repos.remove(index);
-            self.persist()?;
-            self.recompute_refresh_interval();
-            if self.repos.is_empty() {
-                self.list_state.select(None);
-            } else if index >= self.repos.len() {
-                self.list_state.select(Some(self.repos.len() - 1));
+            if !self.persist()? {
+                return Ok(());
             }
-            self.set_message("Repositorio eliminado", Color::Yellow);
+            self.recompute_refresh_interval();
+            self.clamp_selection();
+            self.set_message(messages::tui_repo_removed(), Color::Yellow);
         }
         Ok(())
     }
 
     fn toggle_selected_sync(&mut self) -> Result<(), String> {
-        let Some(index) = self.list_state.selected() else {
+        let Some(index) = self.selected_index() else {
             return Ok(());
         };
         if index >= self.repos.len() {
             return Ok(());
         }
 
+        // Un repositorio desactivado por fallos se reactiva en lugar de pausarse.
+        let repo_path = self.repos[index].repo_path.clone();
+        if self.is_auto_disabled(&repo_path) {
+            let mut state = SyncStateSnapshot::load(&self.config.state_file);
+            state.reenable(&repo_path);
+            state.save(&self.config.state_file)?;
+            self.sync_state = state;
+            self.set_message(messages::tui_repo_reenabled(), Color::Green);
+            if self.details_open {
+                self.refresh_details();
+            }
+            return Ok(());
+        }
+
         let mut enabled = true;
         if let Some(repo) = self.repos.get_mut(index) {
             repo.enabled = !repo.enabled;
@@ -479,13 +935,52 @@ impl<'a> RepoManager<'a> {
         }
 
         let label = if enabled {
-            "Sincronización activada para el repositorio"
+            messages::tui_repo_resumed()
         } else {
-            "Sincronización pausada para el repositorio"
+            messages::tui_repo_paused()
         };
         self.set_message(label, if enabled { Color::Green } else { Color::Yellow });
 
-        self.persist()?;
+        if !self.persist()? {
+            return Ok(());
+        }
+        self.recompute_refresh_interval();
+        if self.details_open {
+            self.refresh_details();
+        }
+        Ok(())
+    }
+
+    fn is_auto_disabled(&self, repo_path: &str) -> bool {
+        self.sync_state
+            .get(repo_path)
+            .is_some_and(|state| state.is_auto_disabled())
+    }
+
+    /// Con un filtro de grupo activo, pausa todos sus repositorios o, si ya estaban todos
+    /// pausados, los reactiva.
+    fn toggle_group_sync(&mut self) -> Result<(), String> {
+        let Some(group) = self.group_filter.clone() else {
+            self.set_message(messages::tui_choose_group(), Color::Yellow);
+            return Ok(());
+        };
+
+        let indices = self.visible_indices();
+        let enable = indices.iter().all(|&index| !self.repos[index].enabled);
+        for &index in &indices {
+            self.repos[index].enabled = enable;
+        }
+
+        let label = if enable {
+            messages::tui_group_resumed(&group)
+        } else {
+            messages::tui_group_paused(&group)
+        };
+        self.set_message(label, if enable { Color::Green } else { Color::Yellow });
+
+        if !self.persist()? {
+            return Ok(());
+        }
         self.recompute_refresh_interval();
         if self.details_open {
             self.refresh_details();
@@ -498,34 +993,187 @@ impl<'a> RepoManager<'a> {
             return Ok(());
         };
 
+        if self.is_auto_disabled(&repo.repo_path) {
+            self.set_message(messages::tui_sync_auto_disabled(), Color::Yellow);
+            return Ok(());
+        }
+
         if !repo.enabled {
-            self.set_message(
-                "El repositorio está pausado. Actívelo con 's' antes de sincronizar.",
-                Color::Yellow,
-            );
+            self.set_message(messages::tui_sync_paused_repo(), Color::Yellow);
             return Ok(());
         }
 
-        self.run_sync_now(vec![repo], "Repositorio sincronizado")
+        self.run_sync_now(vec![repo], messages::tui_repo_synced())
     }
 
     fn sync_all_now(&mut self) -> Result<(), String> {
+        // Con un filtro de grupo activo solo se sincronizan los repositorios visibles.
         let repos = self
-            .repos
-            .iter()
+            .visible_repos()
+            .into_iter()
             .filter(|repo| repo.enabled)
             .cloned()
             .collect::<Vec<_>>();
 
         if repos.is_empty() {
+            self.set_message(messages::tui_no_active_repos(), Color::Yellow);
+            return Ok(());
+        }
+
+        self.run_sync_now(repos, messages::tui_manual_sync_done())
+    }
+
+    /// Pide al daemon por el socket de control que sincronice el repositorio seleccionado o,
+    /// con `all`, todos. A diferencia de `u`/`U` la TUI no se bloquea: el progreso y el
+    /// resultado aparecen en la barra de estado.
+    fn request_daemon_sync(&mut self, all: bool) {
+        let repo = if all {
+            None
+        } else {
+            let Some(repo) = self.selected_repo().cloned() else {
+                self.set_message(messages::tui_no_selection(), Color::Yellow);
+                return;
+            };
+            Some(repo)
+        };
+        if self.daemon_request.is_some() {
+            self.set_message(messages::tui_daemon_sync_pending(), Color::Yellow);
+            return;
+        }
+        if !Path::new(&self.config.control_socket).exists() {
             self.set_message(
-                "No hay repositorios activos para sincronizar",
+                messages::tui_control_socket_missing(&self.config.control_socket),
                 Color::Yellow,
             );
-            return Ok(());
+            return;
+        }
+
+        let command = match &repo {
+            Some(repo) => format!("sync {}", repo.repo_path),
+            None => "sync".to_string(),
+        };
+        match control::send(&self.config.control_socket, &command) {
+            Ok(Some(reply)) if reply == "ok" => {
+                self.set_message(
+                    match &repo {
+                        Some(repo) => messages::tui_daemon_sync_requested(repo.name()),
+                        None => messages::tui_daemon_sync_all_requested(),
+                    },
+                    Color::Cyan,
+                );
+                self.daemon_request = Some(DaemonRequest {
+                    repo,
+                    requested_at: SystemTime::now(),
+                    started: false,
+                });
+            }
+            Ok(Some(reply)) => self.set_message(
+                truncate_message(&messages::tui_daemon_rejected(reply), 120),
+                Color::Red,
+            ),
+            Ok(None) => self.set_message(messages::tui_daemon_busy(), Color::Yellow),
+            Err(err) => self.set_message(truncate_message(&err, 120), Color::Red),
+        }
+    }
+
+    /// Muestra la etapa en curso de la sincronización pedida con `s`/`S` y, cuando el daemon
+    /// guarda el estado al terminar el ciclo, su resultado.
+    fn follow_daemon_request(&mut self) {
+        let Some(request) = &mut self.daemon_request else {
+            return;
+        };
+
+        if let Some(activity) = &self.activity {
+            request.started = true;
+            if request
+                .repo
+                .as_ref()
+                .is_none_or(|repo| repo.repo_path == activity.repo_path)
+            {
+                let name = self
+                    .repos
+                    .iter()
+                    .find(|repo| repo.repo_path == activity.repo_path)
+                    .map_or(activity.repo_path.as_str(), |repo| repo.name());
+                let message = messages::tui_daemon_progress(name, phase_label(activity.phase));
+                self.set_message(message, Color::Cyan);
+            }
+            return;
         }
 
-        self.run_sync_now(repos, "Sincronización manual completada")
+        let state_saved = fs::metadata(&self.config.state_file)
+            .and_then(|metadata| metadata.modified())
+            .is_ok_and(|modified| modified > request.requested_at);
+        if !state_saved {
+            // Sin ciclo a la vista (pausa general, repositorio que ya no está en la lista).
+            if !request.started
+                && request.requested_at.elapsed().unwrap_or_default() > DAEMON_START_TIMEOUT
+            {
+                self.daemon_request = None;
+                self.set_message(messages::tui_daemon_no_cycle(), Color::Yellow);
+            }
+            return;
+        }
+
+        let Some(request) = self.daemon_request.take() else {
+            return;
+        };
+        let requested_ts = request
+            .requested_at
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs() as i64);
+        let attempted = |state: &RepoSyncState| {
+            state
+                .last_attempt_ts
+                .is_some_and(|attempt| attempt >= requested_ts)
+        };
+
+        match request.repo {
+            Some(repo) => {
+                let (message, color) = match self.sync_state.get(&repo.repo_path) {
+                    Some(state) if attempted(state) && state.has_active_error() => (
+                        messages::tui_daemon_repo_failed(
+                            repo.name(),
+                            state.last_error.as_deref().unwrap_or("-"),
+                        ),
+                        Color::Red,
+                    ),
+                    Some(state) if attempted(state) => (
+                        messages::tui_daemon_repo_synced(
+                            repo.name(),
+                            state.last_result.as_deref().unwrap_or("-"),
+                        ),
+                        Color::Green,
+                    ),
+                    _ => (
+                        messages::tui_daemon_repo_skipped(repo.name()),
+                        Color::Yellow,
+                    ),
+                };
+                self.set_message(truncate_message(&message, 120), color);
+                self.refresh_repo_status(&repo);
+            }
+            None => {
+                let (failed, synced): (Vec<_>, Vec<_>) = self
+                    .repos
+                    .iter()
+                    .filter_map(|repo| self.sync_state.get(&repo.repo_path))
+                    .filter(|state| attempted(state))
+                    .partition(|state| state.has_active_error());
+                let color = if failed.is_empty() {
+                    Color::Green
+                } else {
+                    Color::Red
+                };
+                self.set_message(
+                    messages::tui_daemon_cycle_done(synced.len(), failed.len()),
+                    color,
+                );
+            }
+        }
+        if self.details_open {
+            self.refresh_details();
+        }
     }
 
     fn selected_repo_path(&self) -> Option<String> {
@@ -535,11 +1183,43 @@ impl<'a> RepoManager<'a> {
     fn run_sync_now(
         &mut self,
         repos: Vec<RepoDefinition>,
-        success_message: &str,
+        success_message: String,
     ) -> Result<(), String> {
-        self.set_message("Sincronizando...", Color::Cyan);
-        let logger = Logger::new(self.config.log_file.clone());
-        let processor = RepoProcessor::new(&logger, false, self.config.state_file.clone());
+        self.set_message(messages::tui_syncing(), Color::Cyan);
+        let mut secret_values = self.secret_values.clone();
+        secret_values.extend(self.credentials.tokens());
+        secret_values.extend(environment::secret_values(&self.env));
+        let logger = Logger::new(self.config.log_file.clone())
+            .with_level(self.log_level)
+            .with_format(self.log_format)
+            .with_backend(self.log_backend)
+            .with_rotation(self.log_rotation)
+            .with_secrets(secret_values);
+        let processor = RepoProcessor::new(&logger, self.config.state_file.clone())
+            .with_pause_file(self.config.pause_file.clone())
+            .with_activity_file(self.config.activity_file.clone())
+            .with_env(&self.env)
+            .with_credentials(&self.credentials)
+            .with_git_backend(self.git_backend)
+            .with_timeouts(self.timeouts)
+            .with_divergence_policy(self.divergence_policy)
+            .with_ff_only(self.ff_only)
+            .with_dirty_policy(self.dirty_policy)
+            .with_detached_policy(self.detached_policy)
+            .with_fetch_depth(self.fetch_depth)
+            .with_fetch_prune(self.fetch_prune)
+            .with_prune_local_branches(self.prune_local_branches)
+            .with_maintenance(self.maintenance_days, self.maintenance_window)
+            .with_quiet_hours(&self.quiet_hours)
+            .with_dry_run(self.dry_run)
+            .with_max_failures(self.max_failures)
+            .with_min_free_mb(self.min_free_mb)
+            .with_allowed_roots(&self.allowed_roots)
+            .with_notifier(&self.notifier)
+            .with_event_log(EventLog::new(
+                self.config.events_file.clone(),
+                self.log_rotation,
+            ));
 
         match processor.process_all(repos) {
             Ok(_) => {
@@ -555,8 +1235,9 @@ impl<'a> RepoManager<'a> {
                 if self.details_open {
                     self.refresh_details();
                 }
+                let err = logger.redact(&err.to_string());
                 self.set_message(
-                    truncate_message(&format!("La sincronización falló: {}", err), 120),
+                    truncate_message(&messages::tui_sync_failed(err), 120),
                     Color::Red,
                 );
                 Ok(())
@@ -568,39 +1249,105 @@ impl<'a> RepoManager<'a> {
         let input_value = self.input.trim().to_string();
         match self.input_mode.clone() {
             InputMode::AddingSource => {
-                if input_value.is_empty() {
-                    self.set_message("La ruta del repositorio no puede estar vacía", Color::Red);
+                let mut warnings = Vec::new();
+                let Some((repo_path, clone_url)) =
+                    RepoDefinition::parse_source(&input_value, &mut warnings)
+                else {
+                    let message = warnings.pop().unwrap_or_else(messages::tui_empty_path);
+                    self.set_message(message, Color::Red);
+                    return Ok(());
+                };
+                if let Err(err) = check_repo_path(&repo_path, &self.allowed_roots) {
+                    self.set_message(err, Color::Red);
+                    return Ok(());
+                }
+                if self.repos.iter().any(|repo| repo.repo_path == repo_path) {
+                    self.set_message(messages::tui_already_listed(&repo_path), Color::Red);
                     return Ok(());
                 }
 
-                self.repos.push(RepoDefinition::new(input_value));
-                self.persist()?;
+                let mut repo = RepoDefinition::new(repo_path);
+                repo.clone_url = clone_url;
+                repo.record_remote_url();
+                repo.name = unique_name(&self.repos, &repo);
+                self.repos.push(repo);
+                if !self.persist()? {
+                    return Ok(());
+                }
                 self.recompute_refresh_interval();
-                self.list_state.select(Some(self.repos.len() - 1));
-                self.set_message("Repositorio añadido", Color::Green);
-                self.input_mode = InputMode::Normal;
-                self.input.clear();
+                // El repositorio nuevo no tiene grupos: se quita el filtro para mostrarlo.
+                self.group_filter = None;
+                self.table_state.select(Some(self.repos.len() - 1));
+                self.input_mode = InputMode::ChoosingPreset(self.repos.len() - 1);
+                self.input = DeployPreset::detect(&self.repos[self.repos.len() - 1].repo_path)
+                    .map(|preset| preset.as_str().to_string())
+                    .unwrap_or_default();
+                self.set_message(messages::tui_choose_preset(), Color::Green);
             }
             InputMode::EditingSource(index) => {
-                if input_value.is_empty() {
-                    self.set_message("La ruta del repositorio no puede estar vacía", Color::Red);
+                let mut warnings = Vec::new();
+                let Some((repo_path, clone_url)) =
+                    RepoDefinition::parse_source(&input_value, &mut warnings)
+                else {
+                    let message = warnings.pop().unwrap_or_else(messages::tui_empty_path);
+                    self.set_message(message, Color::Red);
+                    return Ok(());
+                };
+                if let Err(err) = check_repo_path(&repo_path, &self.allowed_roots) {
+                    self.set_message(err, Color::Red);
                     return Ok(());
                 }
                 if index >= self.repos.len() {
-                    self.set_message("No se encontró el repositorio seleccionado", Color::Red);
+                    self.set_message(messages::tui_repo_not_found(), Color::Red);
                     self.cancel_input();
                     return Ok(());
                 }
+                if self
+                    .repos
+                    .iter()
+                    .enumerate()
+                    .any(|(other, repo)| other != index && repo.repo_path == repo_path)
+                {
+                    self.set_message(messages::tui_already_listed(&repo_path), Color::Red);
+                    return Ok(());
+                }
 
                 if let Some(repo) = self.repos.get_mut(index) {
-                    repo.repo_path = input_value;
+                    repo.repo_path = repo_path;
+                    repo.clone_url = clone_url;
+                }
+                if !self.persist()? {
+                    return Ok(());
                 }
-                self.persist()?;
                 self.recompute_refresh_interval();
-                self.set_message("Repositorio actualizado", Color::Green);
+                self.set_message(messages::tui_repo_updated(), Color::Green);
                 self.input_mode = InputMode::Normal;
                 self.input.clear();
             }
+            InputMode::ChoosingPreset(index) => {
+                if input_value.is_empty() || index >= self.repos.len() {
+                    self.cancel_input();
+                    return Ok(());
+                }
+                let preset = match DeployPreset::parse(&input_value) {
+                    Ok(preset) => preset,
+                    Err(err) => {
+                        self.set_message(err, Color::Red);
+                        return Ok(());
+                    }
+                };
+
+                self.repos[index].preset = Some(preset);
+                self.input_mode = InputMode::Normal;
+                self.input.clear();
+                if !self.persist()? {
+                    return Ok(());
+                }
+                self.set_message(
+                    messages::tui_added_with_preset(preset.as_str()),
+                    Color::Green,
+                );
+            }
             InputMode::Normal => {}
         }
 
@@ -608,13 +1355,40 @@ impl<'a> RepoManager<'a> {
     }
 
     fn cancel_input(&mut self) {
+        let message = if matches!(self.input_mode, InputMode::ChoosingPreset(_)) {
+            messages::tui_added_without_preset()
+        } else {
+            messages::tui_cancelled()
+        };
         self.input_mode = InputMode::Normal;
         self.input.clear();
-        self.set_message("Acción cancelada", Color::Yellow);
+        self.set_message(message, Color::Yellow);
     }
 
-    fn persist(&self) -> Result<(), String> {
-        self.config.write_repos(&self.repos)
+    /// Guarda la lista solo si nadie la modificó desde que se cargó. En caso contrario se
+    /// descarta el cambio local, se muestra la lista actual y devuelve `false`.
+    fn persist(&mut self) -> Result<bool, String> {
+        let external = self.config.update_repos(|repos| {
+            if repos
+                .iter()
+                .map(RepoDefinition::to_line)
+                .ne(self.saved_lines.iter().cloned())
+            {
+                return Ok(Some(repos.clone()));
+            }
+            *repos = self.repos.clone();
+            Ok(None)
+        })?;
+
+        let saved = external.is_none();
+        if let Some(current) = external {
+            self.repos = current;
+            self.recompute_refresh_interval();
+            self.clamp_selection();
+            self.set_message(messages::tui_list_changed(), Color::Yellow);
+        }
+        self.saved_lines = self.repos.iter().map(RepoDefinition::to_line).collect();
+        Ok(saved)
     }
 
     fn add_char(&mut self, ch: char) {
@@ -629,11 +1403,16 @@ impl<'a> RepoManager<'a> {
         self.message = Some((message.into(), color));
     }
 
-    fn mode_hint(&self) -> &'static str {
+    fn mode_hint(&self) -> String {
+        if self.read_only {
+            return messages::tui_mode_read_only();
+        }
+
         match self.input_mode {
-            InputMode::Normal => "Normal",
-            InputMode::AddingSource => "Agregar",
-            InputMode::EditingSource(_) => "Editar",
+            InputMode::Normal => "Normal".to_string(),
+            InputMode::AddingSource => messages::tui_mode_add(),
+            InputMode::EditingSource(_) => messages::tui_mode_edit(),
+            InputMode::ChoosingPreset(_) => "Preset".to_string(),
         }
     }
 }
@@ -641,9 +1420,21 @@ impl<'a> RepoManager<'a> {
 fn run_loop(
     terminal: &mut Terminal<CrosstermBackend<Stdout>>,
     config: &Config,
-    sync_interval: u64,
+    settings: &Settings,
+    read_only: bool,
 ) -> Result<(), String> {
-    let mut manager = RepoManager::new(config, sync_interval);
+    let mut manager = RepoManager::new(config, settings, read_only)?;
+    match load_env_file(&config.env_file, &config.secrets_key_file) {
+        Ok(env) => manager.env = env,
+        Err(err) => manager.set_message(truncate_message(&err, 120), Color::Red),
+    }
+    match Credentials::load(&config.credentials_file, &config.secrets_key_file) {
+        Ok(credentials) => manager.credentials = credentials,
+        Err(err) => manager.set_message(truncate_message(&err, 120), Color::Red),
+    }
+    if read_only {
+        manager.set_message(messages::tui_read_only_mode(), Color::Yellow);
+    }
     manager.refresh_all_status();
 
     loop {
@@ -651,48 +1442,60 @@ fn run_loop(
 
         terminal
             .draw(|frame| draw_ui(frame, &mut manager))
-            .map_err(|e| format!("No se pudo renderizar la interfaz: {}", e))?;
+            .map_err(messages::render_failed)?;
 
-        if !event::poll(Duration::from_millis(250))
-            .map_err(|e| format!("No se pudo leer el evento de entrada: {}", e))?
-        {
+        if !event::poll(Duration::from_millis(250)).map_err(messages::input_event_failed)? {
             continue;
         }
 
         if let Event::Key(KeyEvent {
             code, modifiers, ..
-        }) = event::read().map_err(|e| format!("No se pudo leer el evento de entrada: {}", e))?
+        }) = event::read().map_err(messages::input_event_failed)?
         {
             if modifiers.contains(KeyModifiers::CONTROL) && code == KeyCode::Char('c') {
                 return Ok(());
             }
 
+            if manager.read_only && is_mutating_key(code) {
+                manager.set_message(messages::tui_read_only_action(), Color::Yellow);
+                continue;
+            }
+
             match manager.input_mode.clone() {
                 InputMode::Normal => match code {
                     KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
                     KeyCode::Char('a') => manager.start_add(),
                     KeyCode::Char('e') | KeyCode::Enter => manager.start_edit(),
                     KeyCode::Char('d') => manager.delete_selected()?,
-                    KeyCode::Char('s') => manager.toggle_selected_sync()?,
+                    KeyCode::Char('p') => manager.toggle_selected_sync()?,
+                    KeyCode::Char('P') => manager.toggle_group_sync()?,
+                    KeyCode::Char('s') => manager.request_daemon_sync(false),
+                    KeyCode::Char('S') => manager.request_daemon_sync(true),
                     KeyCode::Char('u') => manager.sync_selected_now()?,
                     KeyCode::Char('U') => manager.sync_all_now()?,
                     KeyCode::Char('v') => {
                         if let Some(path) = manager.selected_repo_path() {
                             open_shell_in_repo(terminal, &path)?;
-                            manager.set_message(
-                                "La sesión de terminal finalizó y se restauró la interfaz.",
-                                Color::Green,
-                            );
+                            manager.set_message(messages::tui_shell_closed(), Color::Green);
                         } else {
-                            manager.set_message("No hay repositorio seleccionado", Color::Yellow);
+                            manager.set_message(messages::tui_no_selection(), Color::Yellow);
                         }
                     }
                     KeyCode::Char(' ') => manager.toggle_details(),
+                    KeyCode::Char('g') => manager.cycle_group_filter(),
+                    KeyCode::Char('l') => manager.toggle_log_pane(),
+                    KeyCode::Char('f') => manager.toggle_log_filter(),
+                    KeyCode::PageUp => manager.scroll_log(1),
+                    KeyCode::PageDown => manager.scroll_log(-1),
+                    KeyCode::Home => manager.scroll_log_to(true),
+                    KeyCode::End => manager.scroll_log_to(false),
                     KeyCode::Down => manager.select_next(),
                     KeyCode::Up => manager.select_previous(),
                     _ => {}
                 },
-                InputMode::AddingSource | InputMode::EditingSource(_) => match code {
+                InputMode::AddingSource
+                | InputMode::EditingSource(_)
+                | InputMode::ChoosingPreset(_) => match code {
                     KeyCode::Enter => manager.submit()?,
                     KeyCode::Esc => manager.cancel_input(),
                     KeyCode::Backspace => manager.backspace(),
@@ -705,35 +1508,42 @@ fn run_loop(
     }
 }
 
+fn is_mutating_key(code: KeyCode) -> bool {
+    matches!(
+        code,
+        KeyCode::Enter | KeyCode::Char('a' | 'e' | 'd' | 'p' | 'P' | 'u' | 'U' | 'v')
+    )
+}
+
 fn open_shell_in_repo(
     terminal: &mut Terminal<CrosstermBackend<Stdout>>,
     repo_path: &str,
 ) -> Result<(), String> {
     if !Path::new(repo_path).exists() {
-        return Err(format!("La ruta no existe: {}", repo_path));
+        return Err(messages::tui_shell_path_missing(repo_path));
     }
 
-    disable_raw_mode().map_err(|e| format!("No se pudo salir de modo raw: {}", e))?;
+    disable_raw_mode().map_err(messages::raw_mode_leave_failed)?;
     terminal
         .backend_mut()
         .execute(LeaveAlternateScreen)
-        .map_err(|e| format!("No se pudo salir de la pantalla alternativa: {}", e))?;
+        .map_err(messages::alternate_screen_leave_failed)?;
 
-    let shell = std::env::var("SHELL").unwrap_or_else(|_| "/bin/bash".to_string());
+    let shell = platform::default_shell();
     let shell_result = std::process::Command::new(&shell)
         .current_dir(repo_path)
         .status()
-        .map_err(|e| format!("No se pudo abrir una terminal en {}: {}", repo_path, e));
+        .map_err(|e| messages::shell_open_failed(repo_path, e));
 
     terminal
         .backend_mut()
         .execute(EnterAlternateScreen)
-        .map_err(|e| format!("No se pudo restaurar pantalla alternativa: {}", e))?;
-    enable_raw_mode().map_err(|e| format!("No se pudo reactivar modo raw: {}", e))?;
+        .map_err(messages::alternate_screen_restore_failed)?;
+    enable_raw_mode().map_err(messages::raw_mode_restore_failed)?;
 
     match shell_result {
         Ok(status) if status.success() => Ok(()),
-        Ok(_) => Err("La sesión de terminal finalizó con error".to_string()),
+        Ok(_) => Err(messages::shell_failed()),
         Err(err) => Err(err),
     }
 }
@@ -751,7 +1561,8 @@ fn draw_ui(frame: &mut Frame, manager: &mut RepoManager) {
                 } else {
                     Constraint::Length(3)
                 },
-                Constraint::Length(3),
+                // El pie de entrada se oculta en modo de solo lectura.
+                Constraint::Length(if manager.read_only { 0 } else { 3 }),
                 Constraint::Length(3),
                 Constraint::Length(3),
             ]
@@ -769,113 +1580,190 @@ fn draw_ui(frame: &mut Frame, manager: &mut RepoManager) {
         ),
         Span::raw("  |  "),
         Span::styled(
-            format!("Modo: {}", manager.mode_hint()),
+            messages::tui_mode(manager.mode_hint()),
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw("  |  "),
         Span::styled(
-            format!(
-                "Actualización remota secuencial ~{:.1}s/repositorio | Actualizar código: u/U",
-                manager.refresh_step_interval.as_millis() as f64 / 1000.0
-            ),
+            messages::tui_refresh_rate(manager.refresh_step_interval.as_millis() as f64 / 1000.0),
             Style::default().fg(Color::Green),
         ),
         Span::raw("  |  "),
         Span::styled(
-            format!("Hora: {}", Local::now().format("%H:%M:%S")),
+            messages::tui_time(Local::now().format("%H:%M:%S")),
             Style::default().fg(Color::White),
         ),
     ]))
     .block(
         Block::default()
             .borders(Borders::ALL)
-            .title("Panel Principal"),
+            .title(messages::tui_main_panel()),
     );
     frame.render_widget(header, chunks[0]);
 
+    // Con el panel de registro abierto, ocupa la parte inferior del cuerpo a todo el ancho.
+    let main_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Min(8),
+                Constraint::Percentage(if manager.log_pane.is_some() { 45 } else { 0 }),
+            ]
+            .as_ref(),
+        )
+        .split(chunks[1]);
     let body_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(70), Constraint::Percentage(30)].as_ref())
-        .split(chunks[1]);
+        .split(main_chunks[0]);
 
     let now_ts = Local::now().timestamp();
-    let items: Vec<ListItem> = if manager.repos.is_empty() {
-        vec![ListItem::new(Line::from(vec![Span::styled(
-            "No hay repositorios configurados",
-            Style::default().fg(Color::DarkGray),
-        )]))]
+    let visible = manager.visible_indices();
+    let rows: Vec<Row> = if visible.is_empty() {
+        let empty_label = match &manager.group_filter {
+            Some(group) => messages::tui_group_empty(group),
+            None => messages::tui_no_repos(),
+        };
+        vec![Row::new(vec![
+            Cell::from(""),
+            Cell::from(empty_label).style(Style::default().fg(Color::DarkGray)),
+        ])]
     } else {
-        manager
-            .repos
+        visible
             .iter()
-            .enumerate()
+            .filter_map(|&i| manager.repos.get(i).map(|repo| (i, repo)))
             .map(|(i, repo)| {
-                let base_style = Style::default().fg(Color::White);
                 let state = manager.sync_state.get(&repo.repo_path);
                 let refresh = manager.refresh_status.get(&repo.repo_path);
                 let (status_label, status_style) = match (repo.enabled, refresh, state) {
                     _ if !repo.enabled => (
-                        " PAUSADO ",
+                        messages::tui_badge_paused(),
                         Style::default().fg(Color::Black).bg(Color::Yellow),
                     ),
+                    (true, _, Some(repo_state)) if repo_state.is_auto_disabled() => (
+                        messages::tui_badge_disabled(),
+                        Style::default().fg(Color::White).bg(Color::Magenta),
+                    ),
                     (true, Some(remote), _) if remote.last_error.is_some() => (
-                        " ERROR REMOTO ",
+                        messages::tui_badge_remote_error(),
                         Style::default().fg(Color::White).bg(Color::Red),
                     ),
                     (true, Some(remote), _) if remote.commits_behind.unwrap_or(0) > 0 => (
-                        " DESACTUALIZADO ",
+                        messages::tui_badge_outdated(),
                         Style::default().fg(Color::Black).bg(Color::Yellow),
                     ),
-                    (true, _, Some(repo_state)) if repo_has_active_error(repo_state) => {
-                        (" ERROR ", Style::default().fg(Color::White).bg(Color::Red))
-                    }
+                    (true, _, Some(repo_state)) if repo_state.has_active_error() => (
+                        " ERROR ".to_string(),
+                        Style::default().fg(Color::White).bg(Color::Red),
+                    ),
                     (true, Some(_), _) => (
-                        " ACTUALIZADO ",
+                        messages::tui_badge_updated(),
                         Style::default().fg(Color::Black).bg(Color::Green),
                     ),
                     _ => (
-                        " SIN ESTADO ",
+                        messages::tui_badge_unknown(),
                         Style::default().fg(Color::Black).bg(Color::DarkGray),
                     ),
                 };
                 let last_sync_label = state
                     .and_then(|s| s.last_success_ts)
-                    .map(|ts| format!("hace {}", humanize_elapsed(now_ts.saturating_sub(ts))))
-                    .unwrap_or_else(|| "sin registros".to_string());
+                    .map(|ts| messages::tui_ago(humanize_elapsed(now_ts.saturating_sub(ts))))
+                    .unwrap_or_else(messages::tui_never);
                 let branch_label = refresh
                     .and_then(|s| s.branch.clone())
                     .or_else(|| state.and_then(|s| s.last_branch.clone()))
                     .unwrap_or_else(|| "?".to_string());
-                let behind_label = refresh
-                    .and_then(|s| s.commits_behind)
-                    .map(|count| count.to_string())
-                    .unwrap_or_else(|| "?".to_string());
-                let label = Line::from(vec![
-                    Span::styled(
-                        format!("{:>2}. ", i + 1),
-                        Style::default().fg(Color::DarkGray),
+                let behind = refresh.and_then(|s| s.commits_behind);
+                let behind_cell = match behind {
+                    Some(count) if count > 0 => {
+                        Cell::from(count.to_string()).style(Style::default().fg(Color::Yellow))
+                    }
+                    Some(count) => Cell::from(count.to_string()),
+                    None => Cell::from("?").style(Style::default().fg(Color::DarkGray)),
+                };
+                let result_cell = match state {
+                    Some(state) if state.has_active_error() => {
+                        Cell::from("✗").style(Style::default().fg(Color::Red))
+                    }
+                    Some(state) if state.last_success_ts.is_some() => {
+                        Cell::from("✓").style(Style::default().fg(Color::Green))
+                    }
+                    _ => Cell::from("-").style(Style::default().fg(Color::DarkGray)),
+                };
+                let phase_cell = match &manager.activity {
+                    Some(activity) if activity.repo_path == repo.repo_path => {
+                        Cell::from(messages::tui_phase_running(
+                            phase_label(activity.phase),
+                            humanize_elapsed(now_ts.saturating_sub(activity.since_ts)),
+                        ))
+                        .style(
+                            Style::default()
+                                .fg(Color::Cyan)
+                                .add_modifier(Modifier::BOLD),
+                        )
+                    }
+                    _ => Cell::from("-").style(Style::default().fg(Color::DarkGray)),
+                };
+                Row::new(vec![
+                    Cell::from(format!("{:>2}.", i + 1))
+                        .style(Style::default().fg(Color::DarkGray)),
+                    Cell::from(repo.name().to_string()).style(
+                        Style::default()
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD),
                     ),
-                    Span::styled("REPO ", Style::default().fg(Color::Black).bg(Color::Blue)),
-                    Span::styled(format!(" {}", repo.repo_path), base_style),
-                    Span::raw("  | "),
-                    Span::styled(status_label, status_style),
-                    Span::raw(format!(
-                        "  |  Rama: {}  |  Pendientes: {}  |  Últ. sincronización: {}",
-                        branch_label, behind_label, last_sync_label
-                    )),
-                ]);
-                ListItem::new(label)
+                    Cell::from(Span::styled(status_label, status_style)),
+                    Cell::from(branch_label),
+                    behind_cell,
+                    Cell::from(last_sync_label),
+                    result_cell,
+                    phase_cell,
+                    Cell::from(repo.groups.join(",")).style(Style::default().fg(Color::Magenta)),
+                ])
             })
             .collect()
     };
 
-    let list = List::new(items)
+    let header = Row::new(vec![
+        String::new(),
+        messages::tui_column_repo(),
+        messages::tui_column_status(),
+        messages::tui_column_branch(),
+        messages::tui_column_behind(),
+        messages::tui_column_last_sync(),
+        messages::tui_column_result(),
+        messages::tui_column_phase(),
+        messages::tui_column_groups(),
+    ])
+    .style(
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    );
+    let widths = [
+        Constraint::Length(3),
+        Constraint::Min(12),
+        Constraint::Length(16),
+        Constraint::Length(10),
+        Constraint::Length(5),
+        Constraint::Length(10),
+        Constraint::Length(3),
+        Constraint::Length(15),
+        Constraint::Length(10),
+    ];
+    let table = Table::new(rows, widths)
+        .header(header)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(format!("Repositorios ({})", manager.repos.len())),
+                .title(match &manager.group_filter {
+                    Some(group) => {
+                        messages::tui_repos_in_group(group, visible.len(), manager.repos.len())
+                    }
+                    None => messages::tui_repos(manager.repos.len()),
+                }),
         )
         .highlight_style(
             Style::default()
@@ -883,7 +1771,7 @@ fn draw_ui(frame: &mut Frame, manager: &mut RepoManager) {
                 .add_modifier(Modifier::BOLD),
         )
         .highlight_symbol("❯ ");
-    frame.render_stateful_widget(list, body_chunks[0], &mut manager.list_state);
+    frame.render_stateful_widget(table, body_chunks[0], &mut manager.table_state);
 
     let error_count = manager.error_count();
     let paused_count = manager.paused_count();
@@ -892,18 +1780,20 @@ fn draw_ui(frame: &mut Frame, manager: &mut RepoManager) {
     let selected_refresh = manager.selected_refresh_status();
     let selected_last_sync = selected_state
         .and_then(|state| state.last_success_ts)
-        .map(|ts| format!("hace {}", humanize_elapsed(now_ts.saturating_sub(ts))))
-        .unwrap_or_else(|| "sin registros".to_string());
+        .map(|ts| messages::tui_ago(humanize_elapsed(now_ts.saturating_sub(ts))))
+        .unwrap_or_else(messages::tui_never);
     let selected_last_attempt = selected_state
         .and_then(|state| state.last_attempt_ts)
-        .map(|ts| format!("hace {}", humanize_elapsed(now_ts.saturating_sub(ts))))
-        .unwrap_or_else(|| "sin intentos".to_string());
+        .map(|ts| messages::tui_ago(humanize_elapsed(now_ts.saturating_sub(ts))))
+        .unwrap_or_else(messages::tui_no_attempts);
     let selected_status = match (selected_refresh, selected_state) {
-        (Some(remote), _) if remote.last_error.is_some() => "Error en actualización remota",
-        (Some(remote), _) if remote.commits_behind.unwrap_or(0) > 0 => "Desactualizado",
-        (_, Some(state)) if repo_has_active_error(state) => "Error en último intento",
-        (Some(_), Some(_)) | (Some(_), None) => "Correcto",
-        _ => "Sin estado remoto",
+        (Some(remote), _) if remote.last_error.is_some() => messages::tui_status_refresh_error(),
+        (Some(remote), _) if remote.commits_behind.unwrap_or(0) > 0 => {
+            messages::tui_status_outdated()
+        }
+        (_, Some(state)) if state.has_active_error() => messages::tui_status_last_error(),
+        (Some(_), Some(_)) | (Some(_), None) => messages::tui_status_ok(),
+        _ => messages::tui_status_unknown(),
     };
     let selected_error = selected_state
         .and_then(|state| state.last_error.clone())
@@ -923,7 +1813,7 @@ fn draw_ui(frame: &mut Frame, manager: &mut RepoManager) {
         .unwrap_or_else(|| "-".to_string());
     let selected_refresh_at = selected_refresh
         .and_then(|state| state.last_refresh_ts)
-        .map(|ts| format!("hace {}", humanize_elapsed(now_ts.saturating_sub(ts))))
+        .map(|ts| messages::tui_ago(humanize_elapsed(now_ts.saturating_sub(ts))))
         .unwrap_or_else(|| "-".to_string());
     let selected_refresh_error = selected_refresh
         .and_then(|state| state.last_error.clone())
@@ -933,6 +1823,21 @@ fn draw_ui(frame: &mut Frame, manager: &mut RepoManager) {
         .selected_repo()
         .map(|repo| repo.enabled)
         .unwrap_or(true);
+    let current_sync = match &manager.activity {
+        Some(activity) => messages::tui_current_sync(
+            manager
+                .repos
+                .iter()
+                .find(|repo| repo.repo_path == activity.repo_path)
+                .map_or(activity.repo_path.as_str(), |repo| repo.name()),
+            phase_label(activity.phase),
+        ),
+        None => messages::tui_current_sync_idle(),
+    };
+    let selected_path = manager
+        .selected_repo()
+        .map(|repo| repo.repo_path.clone())
+        .unwrap_or_else(|| "-".to_string());
     let selected_pulled_commit = selected_state
         .and_then(|state| state.last_pulled_commit.clone())
         .map(|commit| truncate_message(&commit, 72))
@@ -940,71 +1845,71 @@ fn draw_ui(frame: &mut Frame, manager: &mut RepoManager) {
 
     let panel_lines = vec![
         Line::from(vec![Span::styled(
-            "Resumen",
+            messages::tui_summary(),
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
         )]),
-        Line::from(format!("Total: {}", manager.repos.len())),
-        Line::from(format!("Pausados: {}", paused_count)),
-        Line::from(format!("Desactualizados: {}", outdated_count)),
-        Line::from(format!("Con error: {}", error_count)),
+        Line::from(messages::tui_total(visible.len())),
+        Line::from(messages::tui_group(
+            manager
+                .group_filter
+                .clone()
+                .unwrap_or_else(messages::tui_all),
+        )),
+        Line::from(messages::tui_paused_count(paused_count)),
+        Line::from(messages::tui_outdated_count(outdated_count)),
+        Line::from(messages::tui_error_count(error_count)),
+        Line::from(current_sync),
         Line::from(""),
         Line::from(vec![Span::styled(
-            "Sincronización",
+            messages::tui_sync_header(),
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
         )]),
-        Line::from(format!(
-            "Actualización remota secuencial: ~{:.1}s/repositorio",
-            manager.refresh_step_interval.as_millis() as f64 / 1000.0
+        Line::from(messages::tui_refresh_rate_short(
+            manager.refresh_step_interval.as_millis() as f64 / 1000.0,
         )),
-        Line::from("Acción rápida: u/U (actualizar código)"),
-        Line::from(format!("Fecha: {}", Local::now().format("%Y-%m-%d"))),
+        Line::from(messages::tui_quick_action()),
+        Line::from(messages::tui_date(Local::now().format("%Y-%m-%d"))),
         Line::from(""),
         Line::from(vec![Span::styled(
-            "Seleccionado",
+            messages::tui_selected(),
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
         )]),
-        Line::from(format!("Rama: {}", selected_branch)),
-        Line::from(format!(
-            "Sincronización: {}",
-            if selected_repo_enabled {
-                "Activa"
-            } else {
-                "Pausada"
-            }
-        )),
-        Line::from(format!("Commits pendientes: {}", selected_behind)),
-        Line::from(format!(
-            "Última actualización remota: {}",
-            selected_refresh_at
-        )),
-        Line::from(format!(
-            "Error de actualización remota: {}",
-            selected_refresh_error
-        )),
-        Line::from(format!("Estado: {}", selected_status)),
-        Line::from(format!("Último resultado: {}", selected_result)),
-        Line::from(format!(
-            "Último commit aplicado en actualización: {}",
-            selected_pulled_commit
-        )),
-        Line::from(format!(
-            "Última sincronización exitosa: {}",
-            selected_last_sync
-        )),
-        Line::from(format!("Último intento: {}", selected_last_attempt)),
-        Line::from(format!("Último error: {}", selected_error)),
+        Line::from(messages::tui_path(selected_path)),
+        Line::from(messages::tui_branch(selected_branch)),
+        Line::from(messages::tui_sync(if selected_repo_enabled {
+            messages::tui_active()
+        } else {
+            messages::tui_paused()
+        })),
+        Line::from(messages::tui_pending(selected_behind)),
+        Line::from(messages::tui_last_refresh(selected_refresh_at)),
+        Line::from(messages::tui_refresh_error(selected_refresh_error)),
+        Line::from(messages::tui_state(selected_status)),
+        Line::from(messages::tui_last_result(selected_result)),
+        Line::from(messages::tui_last_pulled_short(selected_pulled_commit)),
+        Line::from(messages::tui_last_success(selected_last_sync)),
+        Line::from(messages::tui_last_attempt(selected_last_attempt)),
+        Line::from(messages::tui_last_error(selected_error)),
     ];
     let panel = Paragraph::new(panel_lines)
         .style(Style::default().fg(Color::White))
-        .block(Block::default().borders(Borders::ALL).title("Estado"));
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(messages::tui_status_title()),
+        );
     frame.render_widget(panel, body_chunks[1]);
 
+    if manager.log_pane.is_some() {
+        draw_log_pane(frame, manager, main_chunks[1]);
+    }
+
     let details_lines: Vec<Line> = if manager.details_open {
         manager
             .details_lines
@@ -1012,155 +1917,299 @@ fn draw_ui(frame: &mut Frame, manager: &mut RepoManager) {
             .map(|line| Line::from(line.clone()))
             .collect()
     } else {
-        vec![Line::from(
-            "Pulse Espacio para ver detalles del repositorio seleccionado",
-        )]
+        vec![Line::from(messages::tui_details_hint())]
     };
     let details = Paragraph::new(details_lines)
         .style(Style::default().fg(Color::White))
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Detalles (Espacio)"),
+                .title(messages::tui_details_title()),
         );
     frame.render_widget(details, chunks[2]);
 
     let (input_text, input_title) = match manager.input_mode {
-        InputMode::Normal => ("".to_string(), "Entrada"),
-        InputMode::AddingSource | InputMode::EditingSource(_) => (
-            manager.input.clone(),
-            "Ruta del repositorio (ej. /var/www/html/mi-app)",
-        ),
+        InputMode::Normal => ("".to_string(), messages::tui_input_title()),
+        InputMode::AddingSource | InputMode::EditingSource(_) => {
+            (manager.input.clone(), messages::tui_path_input_title())
+        }
+        InputMode::ChoosingPreset(_) => (manager.input.clone(), messages::tui_preset_input_title()),
     };
 
-    let input_block = Paragraph::new(input_text)
-        .style(Style::default().fg(Color::White))
-        .block(Block::default().borders(Borders::ALL).title(input_title));
-    frame.render_widget(input_block, chunks[3]);
+    if !manager.read_only {
+        let input_block = Paragraph::new(input_text)
+            .style(Style::default().fg(Color::White))
+            .block(Block::default().borders(Borders::ALL).title(input_title));
+        frame.render_widget(input_block, chunks[3]);
+    }
 
-    if matches!(
-        manager.input_mode,
-        InputMode::AddingSource | InputMode::EditingSource(_)
-    ) {
+    if !matches!(manager.input_mode, InputMode::Normal) {
         frame.set_cursor(
             chunks[3].x + manager.input.len() as u16 + 1,
             chunks[3].y + 1,
         );
     }
 
-    let (status_text, status_color) = manager.message.clone().unwrap_or((
-        "Listo para gestionar repositorios".to_string(),
-        Color::DarkGray,
-    ));
+    let (status_text, status_color) = manager
+        .message
+        .clone()
+        .unwrap_or((messages::tui_ready(), Color::DarkGray));
     let status = Paragraph::new(status_text)
         .style(Style::default().fg(status_color))
-        .block(Block::default().borders(Borders::ALL).title("Estado"));
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(messages::tui_status_title()),
+        );
     frame.render_widget(status, chunks[4]);
 
-    let shortcuts = Paragraph::new(Line::from(vec![
-        Span::styled(
-            " ↑/↓ ",
-            Style::default()
-                .fg(Color::Black)
-                .bg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::raw(" mover  "),
-        Span::styled(
-            " A ",
-            Style::default()
-                .fg(Color::Black)
-                .bg(Color::Green)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::raw(" añadir  "),
-        Span::styled(
-            " E/Enter ",
-            Style::default()
-                .fg(Color::Black)
-                .bg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::raw(" editar  "),
-        Span::styled(
-            " D ",
-            Style::default()
-                .fg(Color::White)
-                .bg(Color::Red)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::raw(" eliminar  "),
-        Span::styled(
-            " V ",
-            Style::default()
-                .fg(Color::Black)
-                .bg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::raw(" abrir terminal  "),
-        Span::styled(
-            " S ",
-            Style::default()
-                .fg(Color::Black)
-                .bg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::raw(" activar/pausar  "),
-        Span::styled(
-            " U/u ",
-            Style::default()
-                .fg(Color::Black)
-                .bg(Color::Green)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::raw(" sincronizar  "),
-        Span::styled(
-            " Espacio ",
-            Style::default()
-                .fg(Color::Black)
-                .bg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::raw(" detalles  "),
-        Span::styled(
-            " Esc ",
-            Style::default()
-                .fg(Color::Black)
-                .bg(Color::Gray)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::raw(" cancelar  "),
-        Span::styled(
-            " Q ",
-            Style::default()
-                .fg(Color::Black)
-                .bg(Color::Magenta)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::raw(" salir  "),
-        Span::styled(
-            " Ctrl+C ",
-            Style::default()
-                .fg(Color::Black)
-                .bg(Color::Blue)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::raw(" salir"),
-    ]))
-    .style(Style::default().fg(Color::White))
-    .block(Block::default().borders(Borders::ALL).title("Atajos"));
+    let shortcut_spans = if manager.read_only {
+        vec![
+            Span::styled(
+                " ↑/↓ ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_move()),
+            Span::styled(
+                " S/s ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_daemon_sync()),
+            Span::styled(
+                messages::key_space(),
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_details()),
+            Span::styled(
+                " L ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_logs()),
+            Span::styled(
+                " G ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_group()),
+            Span::styled(
+                " Q ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_quit()),
+            Span::styled(
+                " Ctrl+C ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_quit_last()),
+        ]
+    } else {
+        vec![
+            Span::styled(
+                " ↑/↓ ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_move()),
+            Span::styled(
+                " A ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_add()),
+            Span::styled(
+                " E/Enter ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_edit()),
+            Span::styled(
+                " D ",
+                Style::default()
+                    .fg(Color::White)
+                    .bg(Color::Red)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_delete()),
+            Span::styled(
+                " V ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_shell()),
+            Span::styled(
+                " P/p ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_toggle()),
+            Span::styled(
+                " S/s ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_daemon_sync()),
+            Span::styled(
+                " U/u ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_sync()),
+            Span::styled(
+                messages::key_space(),
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_details()),
+            Span::styled(
+                " L ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_logs()),
+            Span::styled(
+                " G ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_group()),
+            Span::styled(
+                " Esc ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Gray)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_cancel()),
+            Span::styled(
+                " Q ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_quit()),
+            Span::styled(
+                " Ctrl+C ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(messages::key_quit_last()),
+        ]
+    };
+    let shortcuts = Paragraph::new(Line::from(shortcut_spans))
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(messages::tui_shortcuts_title()),
+        );
     frame.render_widget(shortcuts, chunks[5]);
 }
 
-fn repo_has_active_error(state: &RepoSyncState) -> bool {
-    match (state.last_error_ts, state.last_success_ts) {
-        (Some(error_ts), Some(success_ts)) => error_ts > success_ts,
-        (Some(_), None) => true,
-        _ => false,
+fn draw_log_pane(frame: &mut Frame, manager: &mut RepoManager, area: Rect) {
+    let Some(pane) = &manager.log_pane else {
+        return;
+    };
+    let height = area.height.saturating_sub(2) as usize;
+    manager.log_page = height.max(1);
+
+    let entries = pane.tail.entries();
+    let end = entries.len().saturating_sub(pane.scroll);
+    let start = end.saturating_sub(height);
+    let lines: Vec<Line> = if let Some(error) = &pane.error {
+        error
+            .lines()
+            .map(|line| Line::styled(line.to_string(), Style::default().fg(Color::Red)))
+            .collect()
+    } else if entries.is_empty() {
+        vec![Line::styled(
+            messages::tui_log_empty(),
+            Style::default().fg(Color::DarkGray),
+        )]
+    } else {
+        entries
+            .range(start..end)
+            .map(|entry| {
+                let (level, text) = logs::display_entry(entry);
+                let style = match level {
+                    LogLevel::Error => Style::default().fg(Color::Red),
+                    LogLevel::Warn => Style::default().fg(Color::Yellow),
+                    LogLevel::Info => Style::default().fg(Color::White),
+                    _ => Style::default().fg(Color::DarkGray),
+                };
+                Line::styled(text, style)
+            })
+            .collect()
+    };
+
+    let mut title = match &pane.repo {
+        Some((name, _)) => messages::tui_log_title_repo(name),
+        None => messages::tui_log_title(&manager.config.log_file),
+    };
+    if pane.scroll > 0 {
+        title.push_str(&messages::tui_log_scrolled(pane.scroll));
+    }
+    let log = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_bottom(messages::tui_log_keys()),
+    );
+    frame.render_widget(log, area);
+}
+
+fn phase_label(phase: Phase) -> String {
+    match phase {
+        Phase::Fetch => messages::tui_phase_fetch(),
+        Phase::Pull => messages::tui_phase_pull(),
+        Phase::Build => messages::tui_phase_build(),
+        Phase::Deploy => messages::tui_phase_deploy(),
     }
 }
 
-fn humanize_elapsed(seconds: i64) -> String {
+pub(crate) fn humanize_elapsed(seconds: i64) -> String {
     if seconds <= 1 {
         return "1s".to_string();
     }
@@ -0,0 +1,344 @@
+use crate::config::{
+    Config, RepoDefinition, SyncMode, check_repo_path, find_repo, same_remote_url,
+};
+use crate::credentials::Credentials;
+use crate::discover::discover_repos;
+use crate::environment;
+use crate::git::GitRepo;
+use crate::messages;
+use crate::migrate::CONFIG_VERSION;
+use crate::secrets::Secrets;
+use crate::settings::Settings;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const COMPOSE_FILES: [&str; 4] = [
+    "compose.yaml",
+    "compose.yml",
+    "docker-compose.yaml",
+    "docker-compose.yml",
+];
+
+/// Resultado de la validación. Cada comprobación se muestra en cuanto se realiza.
+#[derive(Default)]
+struct Report {
+    errors: usize,
+    warnings: usize,
+}
+
+impl Report {
+    fn section(&self, title: &str) {
+        println!("\n{}", title);
+    }
+
+    fn ok(&self, message: &str) {
+        println!("  ✅ {}", message);
+    }
+
+    fn warn(&mut self, message: &str) {
+        self.warnings += 1;
+        println!("  ⚠️ {}", message);
+    }
+
+    fn error(&mut self, message: &str) {
+        self.errors += 1;
+        println!("  ❌ {}", message);
+    }
+}
+
+/// Revisa la configuración y cada repositorio sin modificar nada. Con `check_remotes`
+/// también comprueba que los remotos responden. Devuelve `Err` si encontró errores.
+pub fn validate(config: &Config, check_remotes: bool) -> Result<(), String> {
+    let mut report = Report::default();
+    println!("{}", messages::validating(&config.config_dir));
+
+    report.section(&messages::validate_settings_section());
+    let settings = validate_settings(&mut report, config);
+    let (env, credentials) = validate_secrets(&mut report, config);
+
+    report.section(&messages::validate_repos_section());
+    let mut repos = validate_repos_file(&mut report, config);
+    let discovered = discover_repos(&settings.discover, &repos);
+    if !settings.discover.is_empty() {
+        report.ok(&messages::discovered_repos(discovered.len()));
+    }
+    repos.extend(discovered);
+
+    for repo in &repos {
+        let paused = if repo.enabled {
+            String::new()
+        } else {
+            messages::validate_paused()
+        };
+        report.section(&format!(
+            "📦 {} ({}){}",
+            repo.name(),
+            repo.repo_path,
+            paused
+        ));
+
+        let git_repo = GitRepo::new(repo.repo_path.clone())
+            .with_env(&env)
+            .with_env(&repo.env)
+            .with_ssh_key(repo.ssh_key.as_deref())
+            .with_credentials(&credentials)
+            .with_backend(settings.git_backend)
+            .with_timeouts(&settings.timeouts())
+            .with_remote(repo.remote_name())
+            .with_branch(repo.branch.as_deref());
+        validate_repo(&mut report, repo, &repos, &settings, &git_repo);
+        if check_remotes {
+            validate_remotes(&mut report, repo, &git_repo);
+        }
+    }
+
+    println!(
+        "{}",
+        messages::validate_summary(report.errors, report.warnings)
+    );
+    if report.errors > 0 {
+        return Err(messages::validate_failed(report.errors));
+    }
+    println!("{}", messages::validate_ok());
+    Ok(())
+}
+
+fn validate_settings(report: &mut Report, config: &Config) -> Settings {
+    if !Path::new(&config.settings_file).exists() {
+        report.warn(&messages::settings_missing(&config.settings_file));
+        return Settings::default();
+    }
+
+    let settings = match Settings::load(&config.settings_file) {
+        Ok(settings) => {
+            report.ok(&messages::file_valid(&config.settings_file));
+            settings
+        }
+        Err(err) => {
+            report.error(&err.to_string());
+            return Settings::default();
+        }
+    };
+
+    if settings.version < CONFIG_VERSION {
+        report.warn(&messages::schema_outdated(settings.version, CONFIG_VERSION));
+    } else if settings.version > CONFIG_VERSION {
+        report.error(&messages::schema_newer(settings.version, CONFIG_VERSION));
+    }
+
+    if settings.sync_jitter > 100 {
+        report.warn(&messages::jitter_too_high(settings.sync_jitter));
+    }
+
+    for root in &settings.allowed_roots {
+        if !Path::new(root).is_absolute() {
+            report.error(&messages::allowed_root_relative(root));
+        } else if !Path::new(root).is_dir() {
+            report.warn(&messages::allowed_root_missing(root));
+        }
+    }
+    for pattern in &settings.discover {
+        if !pattern.starts_with('/') {
+            report.error(&messages::discover_relative(pattern));
+        }
+    }
+    settings
+}
+
+fn validate_secrets(report: &mut Report, config: &Config) -> (Vec<(String, String)>, Credentials) {
+    match Secrets::load(&config.secrets_file, &config.secrets_key_file) {
+        Ok(_) => report.ok(&messages::secrets_readable()),
+        Err(err) => report.error(&err),
+    }
+
+    let credentials = match Credentials::load(&config.credentials_file, &config.secrets_key_file) {
+        Ok(credentials) => {
+            report.ok(&messages::credentials_readable());
+            credentials
+        }
+        Err(err) => {
+            report.error(&err);
+            Credentials::default()
+        }
+    };
+
+    let env = match environment::load_env_file(&config.env_file, &config.secrets_key_file) {
+        Ok(env) => {
+            if !env.is_empty() {
+                report.ok(&messages::env_vars(env.len()));
+            }
+            env
+        }
+        Err(err) => {
+            report.error(&err);
+            Vec::new()
+        }
+    };
+
+    (env, credentials)
+}
+
+fn validate_repos_file(report: &mut Report, config: &Config) -> Vec<RepoDefinition> {
+    let contents = match fs::read_to_string(&config.repos_file) {
+        Ok(contents) => contents,
+        Err(e) => {
+            report.error(&messages::read_failed(&config.repos_file, e));
+            return Vec::new();
+        }
+    };
+
+    let mut repos: Vec<RepoDefinition> = Vec::new();
+    for (number, line) in contents.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let Some((repo, warnings)) = RepoDefinition::parse_line(line) else {
+            report.error(&messages::line_unparsable(number + 1, trimmed));
+            continue;
+        };
+        for warning in warnings {
+            report.error(&messages::line_warning(number + 1, warning));
+        }
+        if repos.iter().any(|other| other.repo_path == repo.repo_path) {
+            report.error(&messages::line_duplicate_path(number + 1, &repo.repo_path));
+        } else if let Some(first) = repos.iter().find(|other| other.name() == repo.name()) {
+            report.error(&messages::line_duplicate_name(
+                number + 1,
+                repo.name(),
+                &first.repo_path,
+            ));
+        }
+        repos.push(repo);
+    }
+
+    if repos.is_empty() {
+        report.warn(&messages::no_repos_in(&config.repos_file));
+    } else {
+        report.ok(&messages::repos_file_count(&config.repos_file, repos.len()));
+    }
+    repos
+}
+
+fn validate_repo(
+    report: &mut Report,
+    repo: &RepoDefinition,
+    repos: &[RepoDefinition],
+    settings: &Settings,
+    git_repo: &GitRepo,
+) {
+    if let Err(err) = check_repo_path(&repo.repo_path, &settings.allowed_roots) {
+        report.error(&err);
+        return;
+    }
+
+    for reference in &repo.after {
+        if let Err(err) = find_repo(repos, reference) {
+            report.error(&format!("after={}: {}", reference, err));
+        }
+    }
+
+    if let Some(key) = &repo.ssh_key
+        && fs::File::open(key).is_err()
+    {
+        report.error(&messages::ssh_key_unreadable(key));
+    }
+
+    let path = Path::new(&repo.repo_path);
+    if !path.exists() {
+        match &repo.clone_url {
+            Some(url) => {
+                // Se clonará en el primer ciclo: basta con poder crear el directorio.
+                let parent = path.ancestors().skip(1).find(|dir| dir.exists());
+                match parent {
+                    Some(parent) if is_writable(parent) => report.ok(&messages::will_clone(url)),
+                    _ => report.error(&messages::clone_target_unwritable(&repo.repo_path, url)),
+                }
+            }
+            None => report.error(&messages::tui_path_missing()),
+        }
+        return;
+    }
+
+    let is_repo = if repo.sync_mode == SyncMode::Bare {
+        git_repo.is_bare_repository()
+    } else {
+        git_repo.is_work_tree()
+    };
+    let empty_clone_target = repo.clone_url.is_some()
+        && fs::read_dir(path).is_ok_and(|mut entries| entries.next().is_none());
+    if is_repo {
+        report.ok(&if repo.sync_mode == SyncMode::Bare {
+            messages::git_bare_repo()
+        } else {
+            messages::git_repo()
+        });
+    } else if empty_clone_target {
+        report.ok(&messages::empty_clone_target());
+    } else if repo.sync_mode == SyncMode::Bare {
+        report.error(&messages::dir_not_bare_repo());
+    } else {
+        report.error(&messages::dir_not_git_repo());
+    }
+
+    if is_repo && let Some(expected) = &repo.clone_url {
+        let remote = repo.remote_name();
+        match git_repo.remote_url(remote) {
+            Ok(Some(actual)) if same_remote_url(expected, &actual) => {}
+            Ok(Some(actual)) => report.error(&messages::remote_mismatch(remote, actual, expected)),
+            Ok(None) => report.error(&messages::remote_absent(remote, expected)),
+            Err(err) => report.error(&messages::remote_url_unreadable(remote, err)),
+        }
+    }
+
+    if !is_writable(path) {
+        report.error(&messages::not_writable());
+    }
+
+    if repo.compose.is_some() && !COMPOSE_FILES.iter().any(|file| path.join(file).exists()) {
+        report.error(&messages::compose_file_missing());
+    }
+    if let Some(preset) = repo.preset {
+        let markers = preset.marker_files();
+        if !markers.is_empty() && !markers.iter().any(|file| path.join(file).exists()) {
+            let files: Vec<String> = markers.iter().map(|file| format!("`{}`", file)).collect();
+            report.error(&messages::preset_marker_missing(
+                preset.as_str(),
+                files.join(&messages::preset_marker_separator()),
+            ));
+        }
+    }
+}
+
+fn validate_remotes(report: &mut Report, repo: &RepoDefinition, git_repo: &GitRepo) {
+    let source = if Path::new(&repo.repo_path).exists()
+        && (git_repo.is_work_tree() || git_repo.is_bare_repository())
+    {
+        repo.remote_name()
+    } else if let Some(url) = &repo.clone_url {
+        url.as_str()
+    } else {
+        return;
+    };
+
+    for remote in std::iter::once(source).chain(repo.mirror_to.as_deref()) {
+        match git_repo.ls_remote(remote) {
+            Ok(refs) => report.ok(&messages::remote_reachable(remote, refs)),
+            Err(err) => report.error(&messages::remote_unreachable(
+                remote,
+                err.to_string().lines().next().unwrap_or_default(),
+            )),
+        }
+    }
+}
+
+/// Si el usuario actual puede escribir en la ruta (`test -w`).
+fn is_writable(path: &Path) -> bool {
+    Command::new("test")
+        .arg("-w")
+        .arg(path)
+        .status()
+        .is_ok_and(|status| status.success())
+}
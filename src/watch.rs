@@ -0,0 +1,60 @@
+use crate::control;
+use crate::events::SyncRecord;
+use crate::messages;
+use chrono::DateTime;
+use serde_json::Value;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+/// Se conecta al socket de control del daemon y muestra cada evento de sincronización en
+/// cuanto ocurre: una línea legible o, con `json`, el objeto tal como lo envía el daemon.
+pub fn watch_events(control_socket: &str, json: bool) -> Result<(), String> {
+    if !Path::new(control_socket).exists() {
+        return Err(messages::watch_socket_missing(control_socket));
+    }
+    let reader = control::watch(control_socket)?;
+    let mut stdout = io::stdout();
+
+    for line in reader.lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        let output = if json {
+            Some(line)
+        } else {
+            format_event(&line)
+        };
+        // Si la salida se cerró (por ejemplo `| head`) se termina sin error.
+        if let Some(output) = output
+            && (writeln!(stdout, "{}", output).is_err() || stdout.flush().is_err())
+        {
+            return Ok(());
+        }
+    }
+
+    Err(messages::watch_disconnected(control_socket))
+}
+
+/// Línea legible de un evento del daemon; `None` si no se reconoce.
+fn format_event(line: &str) -> Option<String> {
+    let event: Value = serde_json::from_str(line).ok()?;
+    let count = |field: &str| event[field].as_u64().unwrap_or_default();
+    let name = event["name"].as_str().unwrap_or_default();
+
+    let text = match event["event"].as_str()? {
+        "cycle_start" => messages::watch_cycle_start(count("repos")),
+        "phase" => messages::watch_phase(name, event["phase"].as_str().unwrap_or_default()),
+        "repo" => serde_json::from_value::<SyncRecord>(event.clone())
+            .ok()?
+            .summary(),
+        "cycle_end" => messages::watch_cycle_end(count("repos"), count("failed")),
+        _ => return None,
+    };
+
+    let time = event["timestamp"]
+        .as_str()
+        .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+        .map(|timestamp| timestamp.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default();
+    Some(format!("[{}] {}", time, text))
+}